@@ -0,0 +1,23 @@
+use std::io::BufRead;
+
+use tanitc_name::mangling::demangle_text;
+
+// Usage: tanitc demangle [SYMBOL]...
+// Without symbols the standard input is demangled, so linker errors
+// and the output of "nm" can be piped through it
+pub fn run(args: &[String]) -> Result<(), String> {
+    if !args.is_empty() {
+        for symbol in args.iter() {
+            println!("{}", demangle_text(symbol));
+        }
+
+        return Ok(());
+    }
+
+    for line in std::io::stdin().lock().lines() {
+        let line = line.map_err(|err| err.to_string())?;
+        println!("{}", demangle_text(&line));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use tanitc_messages::codes::ErrorCode;
+
+// Usage: tanitc --explain CODE
+pub fn run(args: &[String]) -> Result<(), String> {
+    let Some(code) = args.first() else {
+        return Err("Error code is not set, e.g. \"tanitc --explain E0003\"".to_string());
+    };
+
+    let Some(code) = ErrorCode::from_code(code) else {
+        return Err(format!("Unknown error code: {code}"));
+    };
+
+    print!("{code}: {}", code.get_explanation());
+
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use std::path::Path;
+
+use tanitc_path::PathId;
+
+// Usage: tanitc fmt [--check] FILE...
+// Files are rewritten in place. With "--check" they are only compared,
+// and the command fails if any of them isn't formatted
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut check = false;
+    let mut files = Vec::<&str>::new();
+
+    for arg in args.iter() {
+        match arg.as_str() {
+            "--check" => check = true,
+            opt if opt.starts_with('-') => return Err(format!("Unexpected option: {opt}")),
+            file => files.push(file),
+        }
+    }
+
+    if files.is_empty() {
+        return Err("Files are not set, e.g. \"tanitc fmt main.tt\"".to_string());
+    }
+
+    let mut failed = 0;
+    let mut unformatted = 0;
+
+    for file in files {
+        match format_file(Path::new(file), check) {
+            Ok(true) => {}
+            Ok(false) => {
+                println!("{file} is not formatted");
+                unformatted += 1;
+            }
+            Err(err) => {
+                eprintln!("{err}");
+                failed += 1;
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!("Failed to format {failed} file(s)"));
+    }
+
+    if unformatted > 0 {
+        return Err(format!("{unformatted} file(s) are not formatted"));
+    }
+
+    Ok(())
+}
+
+// Returns whether the file was already formatted
+fn format_file(path: &Path, check: bool) -> Result<bool, String> {
+    let text = std::fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read file {path:?}: {err}"))?;
+
+    // Diagnostics show lines of the source
+    PathId::from(path.to_path_buf()).set_source(&text);
+
+    let formatted = tanitc_fmt::format_source(path, &text).map_err(|messages| {
+        messages.print_errors();
+        format!("Failed to parse {path:?}")
+    })?;
+
+    if formatted == text {
+        return Ok(true);
+    }
+
+    if check {
+        return Ok(false);
+    }
+
+    std::fs::write(path, formatted)
+        .map_err(|err| format!("Failed to write file {path:?}: {err}"))?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::run;
+
+    #[test]
+    fn fmt_check_test() {
+        // Given
+        let dir = std::env::temp_dir().join(format!("tanitc_fmt_check_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let path = dir.join("main.tt");
+        std::fs::write(&path, "func main(){\nvar a=1\n}").unwrap();
+
+        let args = |check: bool| {
+            let mut args = vec![path.to_string_lossy().to_string()];
+            if check {
+                args.insert(0, "--check".to_string());
+            }
+            args
+        };
+
+        // When
+        let check_before = run(&args(true));
+        let format = run(&args(false));
+        let check_after = run(&args(true));
+
+        // Then
+        assert!(check_before.is_err());
+        assert!(format.is_ok());
+        assert!(check_after.is_ok());
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "func main() {\n    var a = 1\n}\n"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,8 +1,39 @@
+pub mod demangle;
+pub mod explain;
+pub mod fmt;
 pub mod options;
 
+use tanitc_messages::{listener::MessageListener, Message};
+use tanitc_options::ErrorFormat;
+
 fn main() {
-    let compile_options = match options::CommandLineParser::new(std::env::args().collect()).parse()
-    {
+    let args: Vec<String> = std::env::args().collect();
+
+    if args.get(1).is_some_and(|arg| arg == "demangle") {
+        if let Err(err) = demangle::run(&args[2..]) {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.get(1).is_some_and(|arg| arg == "fmt") {
+        if let Err(err) = fmt::run(&args[2..]) {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if args.get(1).is_some_and(|arg| arg == "--explain") {
+        if let Err(err) = explain::run(&args[2..]) {
+            eprintln!("{err}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let compile_options = match options::CommandLineParser::new(args).parse() {
         Err(err) => {
             eprintln!("{err}");
             return;
@@ -10,6 +41,8 @@ fn main() {
         Ok(options) => options,
     };
 
+    let is_json = compile_options.error_format == ErrorFormat::Json;
+
     let mut c = match tanitc_crate::Crate::new(compile_options) {
         Err(err) => {
             eprintln!("{err}");
@@ -19,7 +52,22 @@ fn main() {
     };
 
     match c.process() {
-        Err(err) => eprintln!("{err}"),
-        Ok(_) => println!("Compilation finished!"),
+        Err(err) => {
+            // Output of JSON mode consists of diagnostics only
+            if !is_json {
+                eprintln!("{err}");
+            } else if !c.has_reported_errors() {
+                let mut messages = MessageListener::new();
+                messages.set_json(true);
+                messages.error(Message {
+                    text: err,
+                    ..Default::default()
+                });
+                messages.print_errors();
+            }
+            std::process::exit(1);
+        }
+        Ok(_) if !is_json => println!("Compilation finished!"),
+        Ok(_) => {}
     }
 }
@@ -1,6 +1,10 @@
 use std::path::PathBuf;
 
-use tanitc_options::{Backend, CompileOptions, CrateType, SerializationOption};
+use tanitc_lints::{find_lint, get_lint_names, LintLevel};
+use tanitc_options::{
+    Backend, CfgOption, ColorMode, CompileOptions, CrateType, ErrorFormat, ExternCrate, LintOption,
+    SerializationOption,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Argument {
@@ -159,6 +163,15 @@ impl CommandLineParser {
 
                 Ok(())
             }
+            "-I" => {
+                let Some(path) = self.next_token() else {
+                    return Err("Include path is not set".to_string());
+                };
+
+                self.options.include_paths.push(PathBuf::from(path));
+
+                Ok(())
+            }
             "-o" => {
                 let Some(output) = self.next_token() else {
                     return Err("Library path is not set".to_string());
@@ -177,6 +190,9 @@ impl CommandLineParser {
 
                 Ok(())
             }
+            "-A" => self.parse_lint_option(LintLevel::Allow),
+            "-W" => self.parse_lint_option(LintLevel::Warn),
+            "-D" => self.parse_lint_option(LintLevel::Deny),
             _ => Err(format!("Unexpected short option: {option}")),
         }
     }
@@ -196,16 +212,42 @@ impl CommandLineParser {
                 Ok(())
             }
             "--dump-ast" => {
-                self.options.dump_ast_mode = SerializationOption::Enabled;
+                self.options.dump_ast_mode = SerializationOption::Ron;
+                Ok(())
+            }
+            option if option.starts_with("--dump-ast=") => {
+                self.options.dump_ast_mode =
+                    Self::serialization_format(&option["--dump-ast=".len()..])?;
+                Ok(())
+            }
+            "--dump-hir" => {
+                self.options.dump_hir_mode = SerializationOption::Ron;
+                Ok(())
+            }
+            option if option.starts_with("--dump-hir=") => {
+                self.options.dump_hir_mode =
+                    Self::serialization_format(&option["--dump-hir=".len()..])?;
                 Ok(())
             }
             "--variants" => {
                 self.options.allow_variants = true;
                 Ok(())
             }
+            "--deny-warnings" => {
+                self.options.deny_warnings = true;
+                Ok(())
+            }
             "--crate-type" => self.parse_crate_type(),
             "--crate-name" => self.parse_crate_name(),
             "--backend" => self.parse_backend(),
+            "--extern" => self.parse_extern_crate(),
+            "--cfg" => self.parse_cfg_option(),
+            "--error-limit" => self.parse_error_limit(),
+            "--color" => self.parse_color_mode(),
+            "--error-format" => self.parse_error_format(),
+            option if option.starts_with("--error-format=") => {
+                self.set_error_format(&option["--error-format=".len()..])
+            }
             _ => Err(format!("Unexpected short option: {option}")),
         }
     }
@@ -239,6 +281,130 @@ impl CommandLineParser {
         Ok(())
     }
 
+    fn parse_extern_crate(&mut self) -> Result<(), String> {
+        let Some(next) = self.next_token() else {
+            return Err("Extern crate is not set".to_string());
+        };
+
+        let Some((name, path)) = next.split_once('=') else {
+            return Err(format!("Bad extern crate \"{next}\", expected name=path"));
+        };
+
+        if name.is_empty() || path.is_empty() {
+            return Err(format!("Bad extern crate \"{next}\", expected name=path"));
+        }
+
+        self.options.extern_crates.push(ExternCrate {
+            name: name.to_string(),
+            path: PathBuf::from(path),
+        });
+
+        Ok(())
+    }
+
+    fn parse_error_limit(&mut self) -> Result<(), String> {
+        let Some(next) = self.next_token() else {
+            return Err("Error limit is not set".to_string());
+        };
+
+        let limit = next
+            .parse::<usize>()
+            .ok()
+            .filter(|limit| *limit > 0)
+            .ok_or(format!(
+                "Bad error limit \"{next}\", expected positive number"
+            ))?;
+
+        self.options.error_limit = Some(limit);
+
+        Ok(())
+    }
+
+    fn parse_color_mode(&mut self) -> Result<(), String> {
+        let Some(next) = self.next_token() else {
+            return Err("Color mode is not set".to_string());
+        };
+
+        self.options.color = match &next[..] {
+            "auto" => ColorMode::Auto,
+            "always" => ColorMode::Always,
+            "never" => ColorMode::Never,
+            _ => return Err(format!("Unknown color mode: {next}")),
+        };
+
+        Ok(())
+    }
+
+    fn parse_lint_option(&mut self, level: LintLevel) -> Result<(), String> {
+        let Some(name) = self.next_token() else {
+            return Err("Lint name is not set".to_string());
+        };
+
+        if find_lint(&name).is_none() {
+            return Err(format!(
+                "Unknown lint \"{name}\", known lints: {}",
+                get_lint_names()
+            ));
+        }
+
+        self.options.lint_options.push(LintOption { name, level });
+
+        Ok(())
+    }
+
+    fn parse_error_format(&mut self) -> Result<(), String> {
+        let Some(next) = self.next_token() else {
+            return Err("Error format is not set".to_string());
+        };
+
+        self.set_error_format(&next)
+    }
+
+    fn set_error_format(&mut self, format: &str) -> Result<(), String> {
+        self.options.error_format = match format {
+            "human" => ErrorFormat::Human,
+            "json" => ErrorFormat::Json,
+            _ => return Err(format!("Unknown error format: {format}")),
+        };
+
+        Ok(())
+    }
+
+    fn serialization_format(format: &str) -> Result<SerializationOption, String> {
+        match format {
+            "json" => Ok(SerializationOption::Json),
+            "ron" => Ok(SerializationOption::Ron),
+            _ => Err(format!("Unknown dump format: {format}")),
+        }
+    }
+
+    fn parse_cfg_option(&mut self) -> Result<(), String> {
+        let Some(next) = self.next_token() else {
+            return Err("Cfg option is not set".to_string());
+        };
+
+        let (name, value) = match next.split_once('=') {
+            Some((name, value)) => (name, Some(value.trim_matches('"').to_string())),
+            None => (next.as_str(), None),
+        };
+
+        let is_valid_name = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        if !is_valid_name {
+            return Err(format!(
+                "Bad cfg option \"{next}\", expected name or name=\"value\""
+            ));
+        }
+
+        self.options.cfg_options.push(CfgOption {
+            name: name.to_string(),
+            value,
+        });
+
+        Ok(())
+    }
+
     fn parse_backend(&mut self) -> Result<(), String> {
         let Some(next) = self.next_token() else {
             return Err("Backend is not set".to_string());
@@ -255,8 +421,13 @@ impl CommandLineParser {
 
 #[cfg(test)]
 mod tests {
+    use std::path::PathBuf;
+
     use crate::options::{Argument, CommandLineParser};
-    use tanitc_options::{CrateType, SerializationOption};
+    use tanitc_lints::LintLevel;
+    use tanitc_options::{
+        CfgOption, ColorMode, CrateType, ErrorFormat, ExternCrate, LintOption, SerializationOption,
+    };
 
     #[test]
     fn parser_token_test() {
@@ -325,7 +496,26 @@ mod tests {
         let mut parser = CommandLineParser::new(args);
 
         let options = parser.parse().unwrap();
-        assert_eq!(options.dump_ast_mode, SerializationOption::Enabled);
+        assert_eq!(options.dump_ast_mode, SerializationOption::Ron);
+        assert_eq!(options.dump_hir_mode, SerializationOption::Disabled);
+    }
+
+    #[test]
+    fn parser_dump_format_test() {
+        let args = vec![
+            "tanitc".to_string(),
+            "--dump-ast=json".to_string(),
+            "--dump-hir=ron".to_string(),
+        ];
+
+        let mut parser = CommandLineParser::new(args);
+
+        let options = parser.parse().unwrap();
+        assert_eq!(options.dump_ast_mode, SerializationOption::Json);
+        assert_eq!(options.dump_hir_mode, SerializationOption::Ron);
+
+        let args = vec!["tanitc".to_string(), "--dump-hir=yaml".to_string()];
+        assert!(CommandLineParser::new(args).parse().is_err());
     }
 
     #[test]
@@ -383,4 +573,200 @@ mod tests {
         assert_eq!(options.input_file.to_str(), Some("hello.tt"));
         assert_eq!(options.output_file.to_str(), Some("hello"));
     }
+
+    #[test]
+    fn parser_include_paths_test() {
+        let args = vec![
+            "tanitc".to_string(),
+            "-I".to_string(),
+            "lib/tanit".to_string(),
+            "-I".to_string(),
+            "/usr/share/tanit".to_string(),
+        ];
+
+        let mut parser = CommandLineParser::new(args);
+
+        let options = parser.parse().unwrap();
+        assert_eq!(
+            options.include_paths,
+            vec![
+                PathBuf::from("lib/tanit"),
+                PathBuf::from("/usr/share/tanit")
+            ]
+        );
+    }
+
+    #[test]
+    fn parser_extern_crates_test() {
+        let args = vec![
+            "tanitc".to_string(),
+            "--extern".to_string(),
+            "math=libs/math.a".to_string(),
+        ];
+
+        let mut parser = CommandLineParser::new(args);
+
+        let options = parser.parse().unwrap();
+        assert_eq!(
+            options.extern_crates,
+            vec![ExternCrate {
+                name: "math".to_string(),
+                path: PathBuf::from("libs/math.a"),
+            }]
+        );
+    }
+
+    #[test]
+    fn parser_bad_extern_crate_test() {
+        let args = vec![
+            "tanitc".to_string(),
+            "--extern".to_string(),
+            "libs/math.a".to_string(),
+        ];
+
+        let mut parser = CommandLineParser::new(args);
+
+        let err = parser.parse().expect_err("Expected error");
+        assert_eq!(err, "Bad extern crate \"libs/math.a\", expected name=path");
+    }
+
+    #[test]
+    fn parser_cfg_options_test() {
+        let args = vec![
+            "tanitc".to_string(),
+            "--cfg".to_string(),
+            "debug".to_string(),
+            "--cfg".to_string(),
+            "target_os=\"linux\"".to_string(),
+        ];
+
+        let mut parser = CommandLineParser::new(args);
+
+        let options = parser.parse().unwrap();
+        assert_eq!(
+            options.cfg_options,
+            vec![
+                CfgOption {
+                    name: "debug".to_string(),
+                    value: None,
+                },
+                CfgOption {
+                    name: "target_os".to_string(),
+                    value: Some("linux".to_string()),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn parser_bad_cfg_option_test() {
+        let args = vec![
+            "tanitc".to_string(),
+            "--cfg".to_string(),
+            "=linux".to_string(),
+        ];
+
+        let mut parser = CommandLineParser::new(args);
+
+        let err = parser.parse().expect_err("Expected error");
+        assert_eq!(
+            err,
+            "Bad cfg option \"=linux\", expected name or name=\"value\""
+        );
+    }
+
+    #[test]
+    fn parser_error_limit_test() {
+        let args = vec![
+            "tanitc".to_string(),
+            "--error-limit".to_string(),
+            "5".to_string(),
+        ];
+
+        let mut parser = CommandLineParser::new(args);
+
+        let options = parser.parse().unwrap();
+        assert_eq!(options.error_limit, Some(5));
+
+        let args = vec![
+            "tanitc".to_string(),
+            "--error-limit".to_string(),
+            "0".to_string(),
+        ];
+
+        let mut parser = CommandLineParser::new(args);
+        assert!(parser.parse().is_err());
+    }
+
+    #[test]
+    fn parser_color_test() {
+        let args = vec![
+            "tanitc".to_string(),
+            "--color".to_string(),
+            "never".to_string(),
+        ];
+
+        let mut parser = CommandLineParser::new(args);
+
+        let options = parser.parse().unwrap();
+        assert_eq!(options.color, ColorMode::Never);
+    }
+
+    #[test]
+    fn parser_error_format_test() {
+        let args = vec!["tanitc".to_string(), "--error-format=json".to_string()];
+
+        let mut parser = CommandLineParser::new(args);
+
+        let options = parser.parse().unwrap();
+        assert_eq!(options.error_format, ErrorFormat::Json);
+
+        let args = vec![
+            "tanitc".to_string(),
+            "--error-format".to_string(),
+            "yaml".to_string(),
+        ];
+
+        let mut parser = CommandLineParser::new(args);
+        assert!(parser.parse().is_err());
+    }
+
+    #[test]
+    fn parser_lint_options_test() {
+        let args = vec![
+            "tanitc".to_string(),
+            "-A".to_string(),
+            "unused_imports".to_string(),
+            "-D".to_string(),
+            "deprecated".to_string(),
+            "--deny-warnings".to_string(),
+        ];
+
+        let mut parser = CommandLineParser::new(args);
+
+        let options = parser.parse().unwrap();
+        assert_eq!(
+            options.lint_options,
+            vec![
+                LintOption {
+                    name: "unused_imports".to_string(),
+                    level: LintLevel::Allow,
+                },
+                LintOption {
+                    name: "deprecated".to_string(),
+                    level: LintLevel::Deny,
+                },
+            ]
+        );
+        assert!(options.deny_warnings);
+
+        let args = vec![
+            "tanitc".to_string(),
+            "-W".to_string(),
+            "unknown_lint".to_string(),
+        ];
+
+        let mut parser = CommandLineParser::new(args);
+        assert!(parser.parse().is_err());
+    }
 }
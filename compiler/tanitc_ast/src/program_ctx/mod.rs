@@ -1,10 +1,11 @@
 use crate::program_ctx::statement_ctx::StatementsCtx;
+use serde::Serialize;
 
 pub mod name_ctx;
 pub mod statement_ctx;
 pub mod type_ctx;
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize)]
 pub struct ProgramCtx {
     pub statements_ctx: StatementsCtx,
 }
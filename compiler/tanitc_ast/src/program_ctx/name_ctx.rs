@@ -1,9 +1,10 @@
+use serde::Serialize;
 use std::fmt::Display;
 
 use tanitc_ident::Ident;
 use tanitc_lexer::token::Token;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct NameCtx {
     pub name_tkn: Token, // identifier
 }
@@ -13,7 +14,7 @@ pub type NameSpecSegmentCtx = (
     Option<Token>, // '::'?
 );
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct NameSpecCtx {
     pub names: Vec<NameSpecSegmentCtx>,
 }
@@ -1,8 +1,55 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
-#[derive(Default, Debug, Clone)]
+use crate::program_ctx::name_ctx::NameCtx;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MetaListCtx {
+    pub lparen_tkn: Token, // '('
+    pub items: Vec<(
+        MetaItemCtx,
+        Option<Token>, // ','?
+    )>,
+    pub rparen_tkn: Token, // ')'
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum MetaItemCtx {
+    Word(NameCtx), // ID
+    NameValue {
+        name_ctx: NameCtx,
+        assign_tkn: Token, // '='
+        value_tkn: Token,  // literal
+    },
+    List {
+        name_ctx: NameCtx,
+        list_ctx: MetaListCtx,
+    },
+    Literal(Token), // literal
+}
+
+impl MetaItemCtx {
+    pub fn name_ctx(&self) -> Option<&NameCtx> {
+        match self {
+            Self::Word(name_ctx)
+            | Self::NameValue { name_ctx, .. }
+            | Self::List { name_ctx, .. } => Some(name_ctx),
+            Self::Literal(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AttributeCtx {
+    pub hash_lsb_tkn: Token, // '#['
+    pub meta_ctx: MetaItemCtx,
+    pub rsb_tkn: Token, // ']'
+}
+
+#[derive(Default, Debug, Clone, Serialize)]
 pub struct AttributesCtx {
-    pub pub_tkn: Option<Token>,    // ('pub')?
-    pub safe_tkn: Option<Token>,   // ('safe')?
-    pub unsafe_tkn: Option<Token>, // ('unsafe')?
+    pub attributes: Vec<AttributeCtx>, // ('#[' meta ']')*
+    pub pub_tkn: Option<Token>,        // ('pub')?
+    pub safe_tkn: Option<Token>,       // ('safe')?
+    pub unsafe_tkn: Option<Token>,     // ('unsafe')?
 }
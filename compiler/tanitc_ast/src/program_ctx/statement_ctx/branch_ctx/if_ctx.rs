@@ -1,8 +1,9 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::statement_ctx::{block_ctx::BlockCtx, expression_ctx::ExpressionCtx};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IfCtx {
     pub if_tkn: Token, // 'if'
     pub expression_ctx: Box<ExpressionCtx>,
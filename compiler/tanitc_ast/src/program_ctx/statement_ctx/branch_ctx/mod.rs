@@ -1,9 +1,11 @@
+use serde::Serialize;
+
 pub mod else_ctx;
 pub mod if_ctx;
 pub mod loop_ctx;
 pub mod while_ctx;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum BranchCtx {
     If(if_ctx::IfCtx),
     Else(else_ctx::ElseCtx),
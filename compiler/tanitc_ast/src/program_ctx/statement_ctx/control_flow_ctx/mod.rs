@@ -1,8 +1,10 @@
+use serde::Serialize;
+
 pub mod break_ctx;
 pub mod continue_ctx;
 pub mod return_ctx;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ControlFlowCtx {
     Return(return_ctx::ReturnCtx),
     Break(break_ctx::BreakCtx),
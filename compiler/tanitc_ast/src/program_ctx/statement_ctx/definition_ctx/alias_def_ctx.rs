@@ -1,10 +1,11 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::{
     name_ctx::NameCtx, statement_ctx::attributes_ctx::AttributesCtx, type_ctx::TypeCtx,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct AliasDefCtx {
     pub attributes_ctx: Box<AttributesCtx>,
     pub alias_tkn: Token, // 'alias'
@@ -1,20 +1,29 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
-use crate::program_ctx::{name_ctx::NameCtx, statement_ctx::attributes_ctx::AttributesCtx};
+use crate::program_ctx::{
+    name_ctx::NameCtx, statement_ctx::attributes_ctx::AttributesCtx, type_ctx::TypeCtx,
+};
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize)]
 pub struct EnumDefUnitAssignCtx {
     pub colon_tkn: Token, // ':'
     pub value_tkn: Token, // integer
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+pub struct EnumDefReprCtx {
+    pub colon_tkn: Token, // ':'
+    pub type_ctx: Box<TypeCtx>,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct EnumDefUnitCtx {
     pub name_ctx: Box<NameCtx>,
     pub assign_ctx: Option<EnumDefUnitAssignCtx>,
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize)]
 pub struct EnumDefBodyCtx {
     pub lcb_tkn: Token, // '{'
     pub units_ctx: Vec<(
@@ -24,10 +33,11 @@ pub struct EnumDefBodyCtx {
     pub rcb_tkn: Token, // '}'
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct EnumDefCtx {
     pub attributes_ctx: Box<AttributesCtx>,
     pub enum_tkn: Token, // 'enum'
     pub name_ctx: Box<NameCtx>,
+    pub repr_ctx: Option<EnumDefReprCtx>,
     pub body_ctx: EnumDefBodyCtx,
 }
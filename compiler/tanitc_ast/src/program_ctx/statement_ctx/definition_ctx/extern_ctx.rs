@@ -1,8 +1,9 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::statement_ctx::{attributes_ctx::AttributesCtx, block_ctx::BlockCtx};
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize)]
 pub struct ExternCtx {
     pub attributes_ctx: Box<AttributesCtx>,
     pub extern_tkn: Token, // 'extern'
@@ -1,3 +1,4 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::{
@@ -6,7 +7,7 @@ use crate::program_ctx::{
     type_ctx::{func_type_ctx::FuncTypeReturnTypeCtx, TypeCtx},
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FuncDefCommonParamCtx {
     pub mut_tkn: Option<Token>, // ('mut')?
     pub name_ctx: Box<NameCtx>,
@@ -14,20 +15,20 @@ pub struct FuncDefCommonParamCtx {
     pub type_ctx: Box<TypeCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FuncDefSelfRefParamCtx {
     pub ampersand_tkn: Token,   // '&'
     pub mut_tkn: Option<Token>, // ('mut')?
     pub self_tkn: Token,        // 'self'
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FuncDefSelfValParamCtx {
     pub mut_tkn: Option<Token>, // ('mut')?
     pub self_tkn: Token,        // 'self'
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum FuncDefParamKindCtx {
     CommonParam(FuncDefCommonParamCtx),
     SelfRef(FuncDefSelfRefParamCtx),
@@ -44,23 +45,31 @@ impl FuncDefParamKindCtx {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FuncDefParamCtx {
     pub param_ctx: FuncDefParamKindCtx,
     pub comma_tkn: Option<Token>, // (',')?
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize)]
 pub struct FuncDefParamsCtx {
     pub lparen_tkn: Token, // '('
     pub params_ctx: Vec<FuncDefParamCtx>,
-    pub rparen_tkn: Token, // ')'
+    pub ellipsis_tkn: Option<Token>, // ('...')?
+    pub rparen_tkn: Token,           // ')'
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+pub struct FuncDefAbiCtx {
+    pub extern_tkn: Token, // 'extern'
+    pub abi_tkn: Token,    // '\"' "apiName" '\"'
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct FuncDefCtx {
     pub attributes_ctx: Box<AttributesCtx>,
-    pub func_tkn: Token, // 'func'
+    pub abi_ctx: Option<Box<FuncDefAbiCtx>>, // ('extern' '"abi"')?
+    pub func_tkn: Token,                     // 'func'
     pub name_ctx: Box<NameCtx>,
     pub params_ctx: FuncDefParamsCtx,
     pub return_type_ctx: Option<FuncTypeReturnTypeCtx>,
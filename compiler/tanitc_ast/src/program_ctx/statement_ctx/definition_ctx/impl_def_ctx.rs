@@ -1,3 +1,4 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::{
@@ -5,12 +6,12 @@ use crate::program_ctx::{
     statement_ctx::{attributes_ctx::AttributesCtx, block_ctx::BlockCtx},
 };
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize)]
 pub struct ImplDefBodyCtx {
     pub block_ctx: Box<BlockCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ImplDefCtx {
     pub attributes_ctx: Box<AttributesCtx>,
     pub impl_tkn: Token, // 'impl'
@@ -1,3 +1,5 @@
+use serde::Serialize;
+
 use crate::program_ctx::statement_ctx::{
     attributes_ctx::AttributesCtx,
     definition_ctx::{
@@ -21,7 +23,7 @@ pub mod union_def_ctx;
 pub mod var_def_ctx;
 pub mod variant_def_ctx;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum DefinitionCtx {
     Alias(AliasDefCtx),
     Const(ConstDefCtx),
@@ -55,6 +57,23 @@ impl DefinitionCtx {
         }
     }
 
+    pub fn attributes_ctx(&self) -> &AttributesCtx {
+        match self {
+            Self::Alias(ctx) => &ctx.attributes_ctx,
+            Self::Const(ctx) => &ctx.attributes_ctx,
+            Self::Enum(ctx) => &ctx.attributes_ctx,
+            Self::Func(ctx) => &ctx.attributes_ctx,
+            Self::Module(ctx) => &ctx.attributes_ctx,
+            Self::Static(ctx) => &ctx.attributes_ctx,
+            Self::Struct(ctx) => &ctx.attributes_ctx,
+            Self::Union(ctx) => &ctx.attributes_ctx,
+            Self::Variable(ctx) => &ctx.attributes_ctx,
+            Self::Variant(ctx) => &ctx.attributes_ctx,
+            Self::Impl(ctx) => &ctx.attributes_ctx,
+            Self::Extern(ctx) => &ctx.attributes_ctx,
+        }
+    }
+
     pub fn set_attributes(&mut self, attrs: AttributesCtx) {
         match self {
             Self::Alias(ctx) => *ctx.attributes_ctx = attrs,
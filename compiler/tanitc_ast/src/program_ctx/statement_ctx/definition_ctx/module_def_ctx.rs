@@ -1,3 +1,4 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::{
@@ -6,7 +7,7 @@ use crate::program_ctx::{
     ProgramCtx,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ModuleDefBodyCtx {
     Internal(Box<BlockCtx>),   // '{' statements* '}'
     External(Box<ProgramCtx>), // statements*
@@ -21,7 +22,7 @@ impl ModuleDefBodyCtx {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ModuleDefCtx {
     pub attributes_ctx: Box<AttributesCtx>,
     pub def_tkn: Option<Token>, // ('def')?
@@ -1,3 +1,4 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::{
@@ -6,24 +7,24 @@ use crate::program_ctx::{
     type_ctx::TypeCtx,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StaticDefTypeCtx {
     pub colon_tkn: Token, // ':'
     pub type_ctx: Box<TypeCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StaticDefValueCtx {
     pub equal_tkn: Token, // '='
     pub value_ctx: Box<ExpressionCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StaticDefCtx {
     pub attributes_ctx: Box<AttributesCtx>,
     pub static_tkn: Token, // 'static'
     pub name_ctx: Box<NameCtx>,
     pub mut_tkn: Option<Token>, // ('mut')?
     pub type_ctx: StaticDefTypeCtx,
-    pub value_ctx: StaticDefValueCtx,
+    pub value_ctx: Option<StaticDefValueCtx>, // absent in extern blocks
 }
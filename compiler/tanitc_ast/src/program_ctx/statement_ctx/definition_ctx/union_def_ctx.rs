@@ -1,10 +1,11 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::{
     name_ctx::NameCtx, statement_ctx::attributes_ctx::AttributesCtx, type_ctx::TypeCtx,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UnionDefFieldCtx {
     pub pub_tkn: Option<Token>, // ('pub')?
     pub name_ctx: Box<NameCtx>,
@@ -12,7 +13,7 @@ pub struct UnionDefFieldCtx {
     pub type_ctx: Box<TypeCtx>,
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize)]
 pub struct UnionDefBodyCtx {
     pub lcb_tkn: Token, // '{'
     pub fields_ctx: Vec<(
@@ -22,7 +23,7 @@ pub struct UnionDefBodyCtx {
     pub rcb_tkn: Token, // '}'
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct UnionDefCtx {
     pub attributes_ctx: Box<AttributesCtx>,
     pub union_tkn: Token, // 'union'
@@ -1,3 +1,4 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::{
@@ -6,19 +7,19 @@ use crate::program_ctx::{
     type_ctx::TypeCtx,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VarDefTypeCtx {
     pub colon_tkn: Token, // ':'
     pub type_ctx: Box<TypeCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VarDefValueCtx {
     pub equal_tkn: Token, // '='
     pub value_ctx: Box<ExpressionCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VarDefCtx {
     pub attributes_ctx: Box<AttributesCtx>,
     pub var_tkn: Token,         // 'var'
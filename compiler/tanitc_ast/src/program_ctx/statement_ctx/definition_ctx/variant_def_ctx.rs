@@ -1,3 +1,4 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::{
@@ -8,38 +9,38 @@ use crate::program_ctx::{
     type_ctx::{tuple_type_ctx::TupleTypeCtx, TypeCtx},
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VariantDefEnumFieldCtx {
     pub name_ctx: Box<NameCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VariantDefStructSubfieldCtx {
     pub name_ctx: Box<NameCtx>,
     pub colon_tkn: Token, // ':'
     pub type_ctx: Box<TypeCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VariantDefStructFieldCtx {
     pub name_ctx: Box<NameCtx>,
     pub struct_body_ctx: Box<StructDefBodyCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VariantDefTupleFieldCtx {
     pub name_ctx: Box<NameCtx>,
     pub tuple_type_ctx: Box<TupleTypeCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum VariantDefFieldCtx {
     Enum(VariantDefEnumFieldCtx),
     Struct(VariantDefStructFieldCtx),
     Tuple(VariantDefTupleFieldCtx),
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize)]
 pub struct VariantDefBodyCtx {
     pub lcb_tkn: Token, // '{'
     pub fields_ctx: Vec<(
@@ -49,7 +50,7 @@ pub struct VariantDefBodyCtx {
     pub rcb_tkn: Token, // '}'
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct VariantDefCtx {
     pub attributes_ctx: Box<AttributesCtx>,
     pub variant_tkn: Token, // 'variant'
@@ -1,8 +1,9 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::statement_ctx::expression_ctx::ExpressionCtx;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum BinaryOpCtx {
     Add(Token),              // '+'
     Sub(Token),              // '-'
@@ -37,7 +38,7 @@ pub enum BinaryOpCtx {
     ScopeRes(Token),         // '::'
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct BinaryCtx {
     pub left_ctx: Box<ExpressionCtx>,
     pub binary_op_ctx: BinaryOpCtx,
@@ -1,26 +1,27 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::{name_ctx::NameCtx, statement_ctx::expression_ctx::ExpressionCtx};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct NamedCallParamCtx {
     pub name_ctx: Box<NameCtx>,
     pub colon_tkn: Token, // ':'
     pub expression_ctx: Box<ExpressionCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct PositionalCallParamCtx {
     pub expression_ctx: Box<ExpressionCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum CallParamCtx {
     Named(NamedCallParamCtx),
     Positional(PositionalCallParamCtx),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CallParamsCtx {
     pub params: Vec<(
         Option<CallParamCtx>,
@@ -28,7 +29,7 @@ pub struct CallParamsCtx {
     )>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct CallCtx {
     pub expression_ctx: Box<ExpressionCtx>,
     pub lparen_tkn: Token, // '('
@@ -1,13 +1,14 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::statement_ctx::expression_ctx::ExpressionCtx;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IndexCtx {
     pub expression_ctx: Box<ExpressionCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct IndexingCtx {
     pub expression_ctx: Box<ExpressionCtx>,
     pub lsb_tkn: Token, // '['
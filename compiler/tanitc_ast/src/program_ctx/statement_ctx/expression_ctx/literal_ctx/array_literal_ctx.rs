@@ -1,8 +1,9 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::statement_ctx::expression_ctx::ExpressionCtx;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ArrayLiteralCtx {
     pub lsb_tkn: Token, // '['
     pub elements: Vec<(
@@ -1,10 +1,11 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 pub mod array_literal_ctx;
 pub mod struct_literal_ctx;
 pub mod tuple_literal_ctx;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum LiteralCtx {
     Integer(Token),
     Decimal(Token),
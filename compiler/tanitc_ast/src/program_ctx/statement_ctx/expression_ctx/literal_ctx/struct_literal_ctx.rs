@@ -1,3 +1,4 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::{
@@ -5,14 +6,14 @@ use crate::program_ctx::{
     statement_ctx::expression_ctx::ExpressionCtx,
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StructFieldLiteralCtx {
     pub name_ctx: NameCtx,
     pub colon_tkn: Token, // ':'
     pub expression_ctx: Box<ExpressionCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct StructLiteralCtx {
     pub name_ctx: NameSpecCtx,
     pub lcb_tkn: Token, // '{'
@@ -0,0 +1,11 @@
+use serde::Serialize;
+use tanitc_lexer::token::Token;
+
+use crate::program_ctx::statement_ctx::expression_ctx::ExpressionCtx;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MemberAccessCtx {
+    pub expression_ctx: Box<ExpressionCtx>,
+    pub dot_tkn: Token, // '.'
+    pub id_tkn: Token,
+}
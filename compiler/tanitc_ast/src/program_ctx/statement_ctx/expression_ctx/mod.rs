@@ -1,16 +1,18 @@
 use crate::program_ctx::name_ctx::NameSpecCtx;
+use serde::Serialize;
 
 pub mod binary_ctx;
 pub mod call_ctx;
 pub mod conversion_ctx;
 pub mod indexing_ctx;
 pub mod literal_ctx;
+pub mod member_access_ctx;
 pub mod operand_ctx;
 pub mod operator_ctx;
 pub mod paren_ctx;
 pub mod unary_ctx;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ExpressionCtx {
     Binary(binary_ctx::BinaryCtx),
     Unary(unary_ctx::UnaryCtx),
@@ -18,6 +20,7 @@ pub enum ExpressionCtx {
     Call(call_ctx::CallCtx),
     ParenCtx(paren_ctx::ParenCtx),
     Indexing(indexing_ctx::IndexingCtx),
+    MemberAccess(member_access_ctx::MemberAccessCtx),
     Literal(literal_ctx::LiteralCtx),
     Variable(NameSpecCtx),
 }
@@ -31,6 +34,7 @@ impl ExpressionCtx {
             Self::Call(_) => "call-ctx",
             Self::ParenCtx(_) => "paren-ctx",
             Self::Indexing(_) => "indexing-ctx",
+            Self::MemberAccess(_) => "member-access-ctx",
             Self::Literal(_) => "literal-ctx",
             Self::Variable(_) => "variable-ctx",
         }
@@ -60,6 +64,10 @@ impl ExpressionCtx {
         matches!(self, ExpressionCtx::Indexing(_))
     }
 
+    pub fn is_member_access(&self) -> bool {
+        matches!(self, ExpressionCtx::MemberAccess(_))
+    }
+
     pub fn is_literal(&self) -> bool {
         matches!(self, ExpressionCtx::Literal(_))
     }
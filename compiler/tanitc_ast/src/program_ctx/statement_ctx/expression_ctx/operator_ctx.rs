@@ -1,6 +1,7 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct OperatorCtx {
     pub token: Token, // ('+' | '=' | '&' | ...)
 }
@@ -1,5 +1,8 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
+use crate::program_ctx::statement_ctx::attributes_ctx::AttributesCtx;
+
 pub mod attributes_ctx;
 pub mod block_ctx;
 pub mod branch_ctx;
@@ -8,7 +11,7 @@ pub mod definition_ctx;
 pub mod expression_ctx;
 pub mod use_ctx;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum StatementCtx {
     ControlFlow(control_flow_ctx::ControlFlowCtx),
     Definition(definition_ctx::DefinitionCtx),
@@ -16,6 +19,14 @@ pub enum StatementCtx {
     Block(block_ctx::BlockCtx),
     Expression(expression_ctx::ExpressionCtx),
     Use(use_ctx::UseCtx),
+    Attributed(AttributedCtx),
+}
+
+// Statement, that has no attributes of its own, preceded by `#[cfg(...)]`
+#[derive(Debug, Clone, Serialize)]
+pub struct AttributedCtx {
+    pub attributes_ctx: Box<AttributesCtx>,
+    pub statement_ctx: Box<StatementCtx>,
 }
 
 impl StatementCtx {
@@ -27,11 +38,22 @@ impl StatementCtx {
             Self::Expression(ctx) => ctx.kind_str(),
             Self::Block(_) => "block-ctx",
             Self::Use(_) => "use-ctx",
+            Self::Attributed(ctx) => ctx.statement_ctx.kind_str(),
+        }
+    }
+
+    pub fn attributes_ctx(&self) -> Option<&AttributesCtx> {
+        match self {
+            Self::Definition(ctx) => Some(ctx.attributes_ctx()),
+            Self::Block(ctx) => Some(&ctx.attributes_ctx),
+            Self::Use(ctx) => Some(&ctx.attributes_ctx),
+            Self::Attributed(ctx) => Some(&ctx.attributes_ctx),
+            Self::ControlFlow(_) | Self::Branch(_) | Self::Expression(_) => None,
         }
     }
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, Serialize)]
 pub struct StatementsCtx {
     pub statements: Vec<(
         Option<StatementCtx>,
@@ -1,9 +1,43 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
-use crate::program_ctx::name_ctx::NameSpecCtx;
+use crate::program_ctx::{
+    name_ctx::{NameCtx, NameSpecCtx},
+    statement_ctx::attributes_ctx::AttributesCtx,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
+pub struct UseAliasCtx {
+    pub as_tkn: Token, // 'as'
+    pub name_ctx: NameCtx,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UseGroupCtx {
+    pub lcb_tkn: Token, // '{'
+    pub trees: Vec<(
+        UseTreeCtx,
+        Option<Token>, // ','?
+    )>,
+    pub rcb_tkn: Token, // '}'
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub enum UseTailCtx {
+    Alias(UseAliasCtx),
+    Glob(Token), // '*'
+    Group(UseGroupCtx),
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UseTreeCtx {
+    pub name_spec_ctx: NameSpecCtx,
+    pub tail_ctx: Option<UseTailCtx>,
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct UseCtx {
+    pub attributes_ctx: Box<AttributesCtx>,
     pub use_tkn: Token, // 'use'
-    pub name_spec_ctx: NameSpecCtx,
+    pub tree_ctx: UseTreeCtx,
 }
@@ -1,14 +1,15 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::{statement_ctx::expression_ctx::ExpressionCtx, type_ctx::TypeCtx};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ArrayTypeLengthCtx {
     pub colon_tkn: Token, // ':'
     pub expression_ctx: Box<ExpressionCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ArrayTypeCtx {
     pub lsb_tkn: Token,                         // '['
     pub type_ctx: Box<TypeCtx>,                 // some_type
@@ -1,29 +1,34 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
-use crate::program_ctx::type_ctx::TypeCtx;
+use crate::program_ctx::{
+    statement_ctx::definition_ctx::func_def_ctx::FuncDefAbiCtx, type_ctx::TypeCtx,
+};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FuncTypeReturnTypeCtx {
     pub colon_tkn: Token, // ':'
     pub type_ctx: Box<TypeCtx>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FuncTypeParamCtx {
     pub type_ctx: Box<TypeCtx>,
     pub comma_tkn: Option<Token>, // (',')?
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FuncTypeParamsCtx {
     pub lparen_tkn: Token, // '('
     pub parameters: Vec<FuncTypeParamCtx>,
-    pub rparen_tkn: Token, // ')'
+    pub ellipsis_tkn: Option<Token>, // ('...')?
+    pub rparen_tkn: Token,           // ')'
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct FuncTypeCtx {
-    pub func_tkn: Token, // 'func'
+    pub abi_ctx: Option<Box<FuncDefAbiCtx>>, // ('extern' '"abi"')?
+    pub func_tkn: Token,                     // 'func'
     pub params_ctx: FuncTypeParamsCtx,
     pub return_type: Option<FuncTypeReturnTypeCtx>,
 }
@@ -1,3 +1,5 @@
+use serde::Serialize;
+
 use crate::program_ctx::type_ctx::{
     array_type_ctx::ArrayTypeCtx, func_type_ctx::FuncTypeCtx, named_type_ctx::NamedTypeCtx,
     never_type_ctx::NeverTypeCtx, ptr_type_ctx::PtrTypeCtx, ref_type_ctx::RefTypeCtx,
@@ -12,7 +14,7 @@ pub mod ptr_type_ctx;
 pub mod ref_type_ctx;
 pub mod tuple_type_ctx;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum TypeCtx {
     Named(NamedTypeCtx),
     Never(NeverTypeCtx),
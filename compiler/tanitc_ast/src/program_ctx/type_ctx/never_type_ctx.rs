@@ -1,6 +1,7 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct NeverTypeCtx {
     pub excm_tkn: Token, // '!'
 }
@@ -1,8 +1,9 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::type_ctx::TypeCtx;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct RefTypeCtx {
     pub ampersand_tkn: Token,   // '&'
     pub mut_tkn: Option<Token>, // ('mut')?,
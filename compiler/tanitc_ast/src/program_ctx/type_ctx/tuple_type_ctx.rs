@@ -1,14 +1,15 @@
+use serde::Serialize;
 use tanitc_lexer::token::Token;
 
 use crate::program_ctx::type_ctx::TypeCtx;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TupleTypeUnitCtx {
     pub type_ctx: Box<TypeCtx>,
     pub comma_tkn: Option<Token>, // (',')?
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct TupleTypeCtx {
     pub lparen_tkn: Token, // '('
     pub units_ctx: Vec<TupleTypeUnitCtx>,
@@ -26,6 +26,8 @@ impl AstLowering {
 
     pub fn low(&mut self, program_ctx: &ProgramCtx) -> Result<Box<Hir>, MessageListener> {
         match self.low_program_ctx(program_ctx) {
+            // Errors of nested definitions are recorded without aborting lowering
+            Ok(_) if self.messages.has_errors() => Err(std::mem::take(self.messages_mut())),
             Ok(hir) => Ok(Box::new(hir)),
             Err(msg) => {
                 self.error(msg);
@@ -1,6 +1,7 @@
 use tanitc_ast::program_ctx::name_ctx::{NameCtx, NameSpecCtx};
-use tanitc_messages::Message;
-use tanitc_name::NameSpec;
+use tanitc_lexer::token::{lexeme::Lexeme, Token};
+use tanitc_messages::{codes::ErrorCode, Message};
+use tanitc_name::{NamePathSegment, NameSpec};
 
 use crate::{AstLowResult, AstLowering};
 
@@ -14,17 +15,27 @@ impl AstLowering {
 
     pub(crate) fn low_name_spec_ctx(&self, name_spec_ctx: &NameSpecCtx) -> AstLowResult<NameSpec> {
         let (first, _) = name_spec_ctx.names.first().ok_or(Message {
-            location: None,
+            code: Some(ErrorCode::EmptyNameSpec),
             text: "Empty name-spec ctx".to_string(),
+            ..Default::default()
         })?;
 
         let location = first.get_location();
         let path = name_spec_ctx
             .names
             .iter()
-            .map(|(id_tkn, _)| id_tkn.identifier().into())
+            .map(|(id_tkn, _)| self.low_name_segment_token(id_tkn))
             .collect();
 
         Ok(NameSpec { location, path })
     }
+
+    pub(crate) fn low_name_segment_token(&self, tkn: &Token) -> NamePathSegment {
+        match tkn.lexeme_ref() {
+            Lexeme::KwSuper => NamePathSegment::SuperNameSpace,
+            Lexeme::KwCrate => NamePathSegment::CrateNameSpace,
+            Lexeme::KwSelf => NamePathSegment::SelfNameSpace,
+            _ => tkn.identifier().into(),
+        }
+    }
 }
@@ -1,4 +1,6 @@
+use tanitc_ast::program_ctx::statement_ctx::attributes_ctx::{AttributesCtx, MetaItemCtx};
 use tanitc_attributes::{Mutability, Publicity, Safety};
+use tanitc_hir::attributes::{Attribute, MetaItem};
 use tanitc_lexer::token::{lexeme::Lexeme, Token};
 use tanitc_messages::Message;
 
@@ -62,7 +64,7 @@ impl AstLowering {
             (Some(_), None) => Ok(self.low_safe_token(safe_tkn)),
             (None, Some(_)) => Ok(self.low_unsafe_token(unsafe_tkn)),
             (None, None) => Ok(Safety::Inherited),
-            (Some(safe_tkn), Some(_)) => Err(Message::new(
+            (Some(safe_tkn), Some(_)) => Err(Message::conflicting_attributes(
                 safe_tkn.get_location(),
                 "Used both \"safe\" and \"unsafe\" attributes. Only one is allowed",
             )),
@@ -71,7 +73,7 @@ impl AstLowering {
 
     pub(crate) fn expect_incompatible_attribute(&self, tkn: &Option<Token>) -> AstLowResult<()> {
         if let Some(tkn) = tkn {
-            return Err(Message::new(
+            return Err(Message::misplaced_attribute(
                 tkn.get_location(),
                 format!("Incorrect attribute \"{}\"", tkn.lexeme_ref()),
             ));
@@ -79,4 +81,64 @@ impl AstLowering {
 
         Ok(())
     }
+
+    pub(crate) fn low_meta_attributes(&self, ctx: &AttributesCtx) -> AstLowResult<Vec<Attribute>> {
+        let mut attributes = Vec::<Attribute>::new();
+
+        for attribute_ctx in ctx.attributes.iter() {
+            // `#[cfg]` is evaluated before lowering of the statement
+            if Self::is_cfg_attribute_ctx(attribute_ctx) {
+                continue;
+            }
+
+            attributes.push(Attribute {
+                location: attribute_ctx.hash_lsb_tkn.get_location(),
+                meta: self.low_meta_item_ctx(&attribute_ctx.meta_ctx)?,
+            });
+        }
+
+        Ok(attributes)
+    }
+
+    pub(crate) fn expect_no_meta_attributes(&self, ctx: &AttributesCtx) -> AstLowResult<()> {
+        if let Some(attribute) = self.low_meta_attributes(ctx)?.first() {
+            return Err(Message::misplaced_attribute(
+                attribute.location,
+                format!("Incorrect attribute \"{attribute}\""),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn low_meta_item_ctx(&self, ctx: &MetaItemCtx) -> AstLowResult<MetaItem> {
+        match ctx {
+            MetaItemCtx::Word(name_ctx) => Ok(MetaItem::Word(name_ctx.identifier())),
+            MetaItemCtx::NameValue {
+                name_ctx,
+                value_tkn,
+                ..
+            } => Ok(MetaItem::NameValue(
+                name_ctx.identifier(),
+                self.low_meta_literal_token(value_tkn)?,
+            )),
+            MetaItemCtx::List { name_ctx, list_ctx } => {
+                let mut items = Vec::<MetaItem>::new();
+                for (item_ctx, _) in list_ctx.items.iter() {
+                    items.push(self.low_meta_item_ctx(item_ctx)?);
+                }
+
+                Ok(MetaItem::List(name_ctx.identifier(), items))
+            }
+            MetaItemCtx::Literal(tkn) => Ok(MetaItem::Literal(self.low_meta_literal_token(tkn)?)),
+        }
+    }
+
+    fn low_meta_literal_token(&self, tkn: &Token) -> AstLowResult<String> {
+        match tkn.lexeme_ref() {
+            Lexeme::Text(value) => Ok(value.trim_matches('"').to_string()),
+            Lexeme::Integer(value) | Lexeme::Decimal(value) => Ok(value.clone()),
+            _ => Err(Message::unexpected_token_expecting(tkn, "literal")),
+        }
+    }
 }
@@ -14,6 +14,7 @@ impl AstLowering {
     }
 
     fn low_block_attributes(&self, ctx: &AttributesCtx) -> AstLowResult<BlockAttributes> {
+        self.expect_no_meta_attributes(ctx)?;
         self.expect_incompatible_attribute(&ctx.pub_tkn)?;
 
         Ok(BlockAttributes {
@@ -0,0 +1,236 @@
+use tanitc_ast::program_ctx::statement_ctx::{attributes_ctx::AttributeCtx, StatementCtx};
+use tanitc_hir::attributes::MetaItem;
+use tanitc_lexer::location::Location;
+use tanitc_messages::Message;
+
+use crate::{AstLowResult, AstLowering};
+
+const CFG_ATTR: &str = "cfg";
+
+impl AstLowering {
+    pub(crate) fn is_cfg_attribute_ctx(attribute_ctx: &AttributeCtx) -> bool {
+        attribute_ctx
+            .meta_ctx
+            .name_ctx()
+            .is_some_and(|name_ctx| name_ctx.to_string() == CFG_ATTR)
+    }
+
+    // Statement is enabled if all its `#[cfg(...)]` predicates hold
+    pub(crate) fn is_statement_enabled(&self, statement_ctx: &StatementCtx) -> AstLowResult<bool> {
+        let Some(attributes_ctx) = statement_ctx.attributes_ctx() else {
+            return Ok(true);
+        };
+
+        for attribute_ctx in attributes_ctx.attributes.iter() {
+            if !Self::is_cfg_attribute_ctx(attribute_ctx) {
+                continue;
+            }
+
+            let location = attribute_ctx.hash_lsb_tkn.get_location();
+            let meta = self.low_meta_item_ctx(&attribute_ctx.meta_ctx)?;
+
+            let MetaItem::List(_, predicates) = &meta else {
+                return Err(Self::malformed_cfg(
+                    location,
+                    &meta,
+                    "expected \"cfg(predicate)\"",
+                ));
+            };
+
+            let [predicate] = predicates.as_slice() else {
+                return Err(Self::malformed_cfg(
+                    location,
+                    &meta,
+                    "expected exactly one predicate",
+                ));
+            };
+
+            if !self.eval_cfg_predicate(predicate, location)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    fn eval_cfg_predicate(&self, predicate: &MetaItem, location: Location) -> AstLowResult<bool> {
+        match predicate {
+            MetaItem::Word(name) => {
+                let name = name.to_string();
+                Ok(self
+                    .compile_options
+                    .cfg_options
+                    .iter()
+                    .any(|option| option.name == name && option.value.is_none()))
+            }
+            MetaItem::NameValue(name, value) => {
+                let name = name.to_string();
+                Ok(self
+                    .compile_options
+                    .cfg_options
+                    .iter()
+                    .any(|option| option.name == name && option.value.as_ref() == Some(value)))
+            }
+            MetaItem::List(name, items) => match name.to_string().as_str() {
+                "all" => {
+                    for item in items.iter() {
+                        if !self.eval_cfg_predicate(item, location)? {
+                            return Ok(false);
+                        }
+                    }
+                    Ok(true)
+                }
+                "any" => {
+                    for item in items.iter() {
+                        if self.eval_cfg_predicate(item, location)? {
+                            return Ok(true);
+                        }
+                    }
+                    Ok(false)
+                }
+                "not" => match items.as_slice() {
+                    [item] => Ok(!self.eval_cfg_predicate(item, location)?),
+                    _ => Err(Self::malformed_cfg(
+                        location,
+                        predicate,
+                        "\"not\" takes exactly one predicate",
+                    )),
+                },
+                _ => Err(Self::malformed_cfg(
+                    location,
+                    predicate,
+                    "expected \"all\", \"any\" or \"not\"",
+                )),
+            },
+            MetaItem::Literal(_) => Err(Self::malformed_cfg(
+                location,
+                predicate,
+                "expected \"key\" or \"key = \\\"value\\\"\"",
+            )),
+        }
+    }
+
+    fn malformed_cfg(location: Location, meta: &MetaItem, reason: &str) -> Message {
+        Message::malformed_attribute(
+            location,
+            format!("Malformed cfg predicate \"{meta}\": {reason}"),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_hir::hir::{definitions::Definition, Hir};
+    use tanitc_options::{CfgOption, CompileOptions};
+    use tanitc_parser::Parser;
+
+    use crate::AstLowering;
+
+    fn low_with_cfg(src: &str, cfg_options: Vec<CfgOption>) -> Result<Box<Hir>, Vec<String>> {
+        let mut parser = Parser::from_text(src);
+        let program_ctx = parser.parse_program().unwrap();
+        assert!(!parser.messages_ref().has_errors());
+
+        let compile_options = CompileOptions {
+            cfg_options,
+            ..Default::default()
+        };
+
+        let mut lowering = AstLowering::with_compile_options(compile_options);
+        lowering.low(program_ctx.as_ref()).map_err(|messages| {
+            messages
+                .errors_ref()
+                .iter()
+                .map(|err| err.text.clone())
+                .collect()
+        })
+    }
+
+    fn func_names(hir: &Hir) -> Vec<String> {
+        let Hir::Block(block) = hir else {
+            panic!("Expected block, actually: {}", hir.kind_str());
+        };
+
+        block
+            .statements
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Hir::Definition(Definition::Func(func_def)) => Some(func_def.name.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn cfg_func_def_test() {
+        // Given
+        const SRC_TEXT: &str = "#[cfg(debug)]\n\
+                                func log() { }\n\
+                                #[cfg(not(debug))]\n\
+                                func silent() { }\n\
+                                #[cfg(all(target_os = \"linux\", any(x86, arm)))]\n\
+                                func linux() { }\n\
+                                func main() { }\n";
+
+        let cfg_options = vec![
+            CfgOption {
+                name: "debug".to_string(),
+                value: None,
+            },
+            CfgOption {
+                name: "target_os".to_string(),
+                value: Some("linux".to_string()),
+            },
+            CfgOption {
+                name: "arm".to_string(),
+                value: None,
+            },
+        ];
+
+        // When
+        let hir = low_with_cfg(SRC_TEXT, cfg_options).expect("Expected no errors");
+
+        // Then
+        assert_eq!(func_names(&hir), vec!["log", "linux", "main"]);
+    }
+
+    #[test]
+    fn cfg_disabled_code_is_not_resolved_test() {
+        // Given
+        const SRC_TEXT: &str = "#[cfg(feature = \"gpu\")]\n\
+                                use gpu::render\n\
+                                #[cfg(feature = \"gpu\")]\n\
+                                func draw() { }\n\
+                                func main() { }\n";
+
+        // When
+        let hir = low_with_cfg(SRC_TEXT, vec![]).expect("Expected no errors");
+
+        // Then
+
+        let Hir::Block(block) = hir.as_ref() else {
+            panic!("Expected block, actually: {}", hir.kind_str());
+        };
+        assert_eq!(block.statements.len(), 1);
+        assert_eq!(func_names(&hir), vec!["main"]);
+    }
+
+    #[test]
+    fn cfg_malformed_predicate_test() {
+        // Given
+        const SRC_TEXT: &str = "#[cfg(some(debug))]\n\
+                                func log() { }\n\
+                                func main() { }\n";
+
+        // When
+        let errors = low_with_cfg(SRC_TEXT, vec![]).expect_err("Expected errors");
+
+        // Then
+        assert_eq!(
+            errors,
+            vec![
+                "Syntax error: Malformed cfg predicate \"some(debug)\": expected \"all\", \"any\" or \"not\""
+            ]
+        );
+    }
+}
@@ -26,6 +26,7 @@ impl AstLowering {
 
         Ok(AliasAttributes {
             publicity: self.low_publicity_token(&ctx.pub_tkn),
+            meta: self.low_meta_attributes(ctx)?,
         })
     }
 }
@@ -35,6 +35,7 @@ impl AstLowering {
     }
 
     fn low_const_def_attributes(&self, ctx: &AttributesCtx) -> AstLowResult<VariableAttributes> {
+        self.expect_no_meta_attributes(ctx)?;
         self.expect_incompatible_attribute(&ctx.safe_tkn)?;
         self.expect_incompatible_attribute(&ctx.unsafe_tkn)?;
 
@@ -5,7 +5,7 @@ use tanitc_ast::program_ctx::statement_ctx::{
     },
 };
 
-use tanitc_hir::hir::definitions::enums::{EnumAttributes, EnumDef, EnumUnits};
+use tanitc_hir::hir::definitions::enums::{EnumAttributes, EnumDef, EnumUnitInfo, EnumUnits};
 use tanitc_ident::Ident;
 use tanitc_lexer::token::lexeme::Lexeme;
 use tanitc_messages::Message;
@@ -17,12 +17,17 @@ impl AstLowering {
         let location = enum_def_ctx.enum_tkn.get_location();
         let attributes = self.low_enum_def_attributes(&enum_def_ctx.attributes_ctx)?;
         let name = self.low_name_ctx(&enum_def_ctx.name_ctx);
+        let repr = match &enum_def_ctx.repr_ctx {
+            Some(repr_ctx) => Some(self.low_type_ctx(&repr_ctx.type_ctx)?.ty),
+            None => None,
+        };
         let units = self.low_enum_def_body_ctx(&enum_def_ctx.body_ctx)?;
 
         Ok(EnumDef {
             location,
             attributes,
             name,
+            repr,
             units,
         })
     }
@@ -33,6 +38,7 @@ impl AstLowering {
 
         Ok(EnumAttributes {
             publicity: self.low_publicity_token(&ctx.pub_tkn),
+            meta: self.low_meta_attributes(ctx)?,
         })
     }
 
@@ -63,15 +69,21 @@ impl AstLowering {
     fn low_enum_def_unit_ctx(
         &self,
         unit_ctx: &EnumDefUnitCtx,
-    ) -> AstLowResult<(Ident, Option<usize>)> {
+    ) -> AstLowResult<(Ident, EnumUnitInfo)> {
         let id = unit_ctx.name_ctx.identifier();
-        let unit = if let Some(assign_ctx) = &unit_ctx.assign_ctx {
+        let value = if let Some(assign_ctx) = &unit_ctx.assign_ctx {
             Some(self.low_enum_def_unit_assign_ctx(assign_ctx)?)
         } else {
             None
         };
 
-        Ok((id, unit))
+        Ok((
+            id,
+            EnumUnitInfo {
+                location: unit_ctx.name_ctx.name_tkn.get_location(),
+                value,
+            },
+        ))
     }
 
     fn low_enum_def_unit_assign_ctx(
@@ -85,7 +97,10 @@ impl AstLowering {
 
         match value.parse::<usize>() {
             Ok(val) => Ok(val),
-            Err(err) => Err(Message::new(assign_ctx.value_tkn.get_location(), err)),
+            Err(err) => Err(Message::invalid_literal(
+                assign_ctx.value_tkn.get_location(),
+                err,
+            )),
         }
     }
 }
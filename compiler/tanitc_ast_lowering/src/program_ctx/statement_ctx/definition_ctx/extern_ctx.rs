@@ -3,48 +3,187 @@ use tanitc_ast::program_ctx::statement_ctx::{
     definition_ctx::{extern_ctx::ExternCtx, DefinitionCtx},
     StatementCtx,
 };
-use tanitc_hir::hir::definitions::{externs::ExternDef, functions::FunctionDef};
-use tanitc_lexer::location::Location;
+use tanitc_attributes::Abi;
+use tanitc_hir::hir::definitions::externs::ExternDef;
+use tanitc_lexer::token::{lexeme::Lexeme, Token};
 use tanitc_messages::Message;
 
 use crate::{AstLowResult, AstLowering};
 
 impl AstLowering {
     pub(crate) fn low_extern_def_ctx(&mut self, ctx: &ExternCtx) -> AstLowResult<ExternDef> {
+        self.expect_no_meta_attributes(&ctx.attributes_ctx)?;
+
         let location = ctx.extern_tkn.get_location();
-        let abi_name = ctx.abi_tkn.to_string();
-        let functions = self.low_extern_def_body_ctx(&ctx.body_ctx, location)?;
+        let abi = self.low_abi_token(&ctx.abi_tkn)?;
 
-        Ok(ExternDef {
+        let mut extern_def = ExternDef {
             location,
-            abi_name,
-            functions,
+            abi,
+            ..Default::default()
+        };
+
+        self.low_extern_def_body_ctx(&ctx.body_ctx, &mut extern_def)?;
+
+        Ok(extern_def)
+    }
+
+    pub(crate) fn low_abi_token(&self, tkn: &Token) -> AstLowResult<Abi> {
+        let Lexeme::Text(abi_name) = tkn.lexeme_ref() else {
+            unreachable!()
+        };
+
+        // Quotes are not part of the name
+        let abi_name = abi_name
+            .strip_prefix('"')
+            .and_then(|name| name.strip_suffix('"'))
+            .unwrap_or(abi_name);
+
+        Abi::from_extern_name(abi_name).ok_or_else(|| {
+            Message::unsupported_abi(
+                tkn.get_location(),
+                format!("Unsupported ABI \"{abi_name}\", expected \"C\""),
+            )
         })
     }
 
     fn low_extern_def_body_ctx(
         &mut self,
         ctx: &BlockCtx,
-        location: Location,
-    ) -> AstLowResult<Vec<FunctionDef>> {
-        let mut functions = Vec::<FunctionDef>::new();
+        extern_def: &mut ExternDef,
+    ) -> AstLowResult<()> {
+        let location = extern_def.location;
 
         for (stmt, _) in &ctx.statements_ctx.statements {
+            if let Some(stmt) = stmt {
+                match self.is_statement_enabled(stmt) {
+                    Ok(true) => {}
+                    Ok(false) => continue,
+                    Err(err) => {
+                        self.error(err);
+                        continue;
+                    }
+                }
+            }
+
             match stmt {
                 Some(StatementCtx::Definition(DefinitionCtx::Func(func))) => {
                     match self.low_func_def_ctx(func) {
-                        Ok(func_def) => functions.push(func_def),
+                        Ok(mut func_def) => {
+                            func_def.attributes.abi = extern_def.abi;
+                            extern_def.functions.push(func_def);
+                        }
+                        Err(err) => self.error(err),
+                    }
+                }
+                Some(StatementCtx::Definition(DefinitionCtx::Static(static_def))) => {
+                    match self.low_extern_static_def_ctx(static_def) {
+                        Ok(var_def) => extern_def.statics.push(var_def),
                         Err(err) => self.error(err),
                     }
                 }
-                Some(stmt) => self.error(Message::new(
+                Some(stmt) => self.error(Message::misplaced_statement(
                     location,
-                    format!("{} is now allowed in extern", stmt.kind_str()),
+                    format!("{} is not allowed in extern", stmt.kind_str()),
                 )),
                 _ => continue,
             }
         }
 
-        Ok(functions)
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_attributes::{Abi, Mutability};
+    use tanitc_hir::hir::{definitions::Definition, type_spec::Type, Hir};
+    use tanitc_parser::Parser;
+
+    use crate::AstLowering;
+
+    fn low(src: &str) -> Result<Box<Hir>, Vec<String>> {
+        let mut parser = Parser::from_text(src);
+        let program_ctx = parser.parse_program().unwrap();
+
+        let mut lowering = AstLowering::new();
+        lowering.low(program_ctx.as_ref()).map_err(|messages| {
+            messages
+                .errors_ref()
+                .iter()
+                .map(|err| err.text.clone())
+                .collect()
+        })
+    }
+
+    #[test]
+    fn extern_statics_test() {
+        // Given
+        const SRC_TEXT: &str = "extern \"C\" {\n\
+                                    static mut errno: i32\n\
+                                    func abs(x: i32): i32\n\
+                                }\n";
+
+        // When
+        let hir = low(SRC_TEXT).expect("Expected no errors");
+
+        // Then
+        let Hir::Block(block) = hir.as_ref() else {
+            panic!("Expected block, actually: {}", hir.kind_str());
+        };
+        let Hir::Definition(Definition::Extern(extern_def)) = &block.statements[0] else {
+            panic!(
+                "Expected extern, actually: {}",
+                block.statements[0].kind_str()
+            );
+        };
+
+        assert_eq!(extern_def.abi, Abi::C);
+        assert_eq!(extern_def.functions.len(), 1);
+        assert_eq!(extern_def.functions[0].attributes.abi, Abi::C);
+
+        assert_eq!(extern_def.statics.len(), 1);
+        assert_eq!(extern_def.statics[0].identifier.to_string(), "errno");
+        assert_eq!(extern_def.statics[0].var_type, Type::I32);
+        assert_eq!(extern_def.statics[0].mutability, Mutability::Mutable);
+        assert!(extern_def.statics[0].value.is_none());
+    }
+
+    #[test]
+    fn extern_unsupported_abi_test() {
+        // Given
+        const SRC_TEXT: &str = "extern \"C++\" {\n\
+                                    func abs(x: i32): i32\n\
+                                }\n";
+
+        // When
+        let errors = low(SRC_TEXT).expect_err("Expected errors");
+
+        // Then
+        assert_eq!(
+            errors,
+            vec!["Syntax error: Unsupported ABI \"C++\", expected \"C\""]
+        );
+    }
+
+    #[test]
+    fn extern_static_initializer_test() {
+        // Given
+        const SRC_TEXT: &str = "extern \"C\" {\n\
+                                    static limit: i32 = 5\n\
+                                }\n\
+                                static count: i32\n";
+
+        // When
+        let errors = low(SRC_TEXT).expect_err("Expected errors");
+
+        // Then
+        assert_eq!(
+            errors,
+            vec![
+                "Syntax error: Extern static \"limit\" can not be initialized",
+                "Syntax error: Static \"count\" must be initialized",
+            ]
+        );
     }
 }
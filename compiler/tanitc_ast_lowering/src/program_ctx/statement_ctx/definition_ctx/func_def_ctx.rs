@@ -6,7 +6,7 @@ use tanitc_ast::program_ctx::statement_ctx::{
     },
 };
 
-use tanitc_attributes::{Mutability, Visibility};
+use tanitc_attributes::{Abi, Mutability, Visibility};
 use tanitc_hir::hir::{
     definitions::{
         functions::{FunctionAttributes, FunctionDef, FunctionParam},
@@ -26,7 +26,11 @@ impl AstLowering {
         func_def_ctx: &FuncDefCtx,
     ) -> AstLowResult<FunctionDef> {
         let location = func_def_ctx.func_tkn.get_location();
-        let attributes = self.low_func_def_attributes(&func_def_ctx.attributes_ctx)?;
+        let mut attributes = self.low_func_def_attributes(&func_def_ctx.attributes_ctx)?;
+        if let Some(abi_ctx) = &func_def_ctx.abi_ctx {
+            attributes.abi = self.low_abi_token(&abi_ctx.abi_tkn)?;
+        }
+
         let name = self.low_name_ctx(&func_def_ctx.name_ctx);
         let parameters = self.low_func_def_params_ctx(&func_def_ctx.params_ctx, &name)?;
 
@@ -47,6 +51,7 @@ impl AstLowering {
             attributes,
             name,
             parameters,
+            is_variadic: func_def_ctx.params_ctx.ellipsis_tkn.is_some(),
             return_type,
             body,
         })
@@ -56,6 +61,8 @@ impl AstLowering {
         Ok(FunctionAttributes {
             publicity: self.low_publicity_token(&ctx.pub_tkn),
             safety: self.low_safety(&ctx.safe_tkn, &ctx.unsafe_tkn)?,
+            abi: Abi::Tanit,
+            meta: self.low_meta_attributes(ctx)?,
         })
     }
 
@@ -152,6 +159,6 @@ impl AstLowering {
     }
 
     fn wrong_self_param_position(&mut self, location: Location) -> Message {
-        Message::new(location, "unexpected \"self\" parameter in function. Must be the first parameter of an associated function")
+        Message::misplaced_self(location, "unexpected \"self\" parameter in function. Must be the first parameter of an associated function")
     }
 }
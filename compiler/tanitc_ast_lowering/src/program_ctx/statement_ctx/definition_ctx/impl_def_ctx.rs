@@ -25,11 +25,13 @@ impl AstLowering {
             location,
             attrs,
             name,
+            type_name: None,
             methods,
         })
     }
 
     fn low_impl_def_attributes(&self, ctx: &AttributesCtx) -> AstLowResult<ImplAttributes> {
+        self.expect_no_meta_attributes(ctx)?;
         self.expect_incompatible_attribute(&ctx.safe_tkn)?;
         self.expect_incompatible_attribute(&ctx.unsafe_tkn)?;
         self.expect_incompatible_attribute(&ctx.pub_tkn)?;
@@ -41,12 +43,13 @@ impl AstLowering {
         let body = self.low_block_ctx(&ctx.block_ctx)?;
         let mut methods = Vec::<FunctionDef>::with_capacity(body.statements.len());
 
-        for statement in &body.statements {
-            if !matches!(statement, Hir::Definition(Definition::Func(_))) {
-                self.error(Message::new(
+        for statement in body.statements {
+            match statement {
+                Hir::Definition(Definition::Func(method)) => methods.push(method),
+                _ => self.error(Message::misplaced_statement(
                     statement.location(),
                     format!("{} is not supported in impls", statement.kind_str()),
-                ));
+                )),
             }
         }
 
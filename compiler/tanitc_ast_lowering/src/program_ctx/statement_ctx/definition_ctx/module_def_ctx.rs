@@ -36,6 +36,7 @@ impl AstLowering {
         Ok(ModuleAttributes {
             publicity: self.low_publicity_token(&ctx.pub_tkn),
             safety: self.low_safety(&ctx.safe_tkn, &ctx.unsafe_tkn)?,
+            meta: self.low_meta_attributes(ctx)?,
         })
     }
 }
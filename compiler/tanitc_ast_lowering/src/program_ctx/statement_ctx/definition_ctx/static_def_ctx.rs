@@ -17,14 +17,19 @@ impl AstLowering {
         let var_type = self.low_type_ctx(&static_def_ctx.type_ctx.type_ctx)?.ty;
         let mutability = self.low_mut_token(&static_def_ctx.mut_tkn);
         let visibility = Visibility::Global;
-        let value = Some(Box::new(
-            self.low_expression_ctx(&static_def_ctx.value_ctx.value_ctx)?,
-        ));
         let identifier = self
             .low_name_ctx(&static_def_ctx.name_ctx)
             .get_id()
             .ok_or(Message::empty_name_spec(location))?;
 
+        let Some(value_ctx) = &static_def_ctx.value_ctx else {
+            return Err(Message::static_initializer(
+                location,
+                format!("Static \"{identifier}\" must be initialized"),
+            ));
+        };
+        let value = Some(Box::new(self.low_expression_ctx(&value_ctx.value_ctx)?));
+
         Ok(VariableDef {
             location,
             attributes,
@@ -36,7 +41,40 @@ impl AstLowering {
         })
     }
 
+    // Extern statics are declared only, their storage is defined elsewhere
+    pub(crate) fn low_extern_static_def_ctx(
+        &mut self,
+        static_def_ctx: &StaticDefCtx,
+    ) -> AstLowResult<VariableDef> {
+        let location = static_def_ctx.static_tkn.get_location();
+        let attributes = self.low_static_def_attributes(&static_def_ctx.attributes_ctx)?;
+        let var_type = self.low_type_ctx(&static_def_ctx.type_ctx.type_ctx)?.ty;
+        let mutability = self.low_mut_token(&static_def_ctx.mut_tkn);
+        let identifier = self
+            .low_name_ctx(&static_def_ctx.name_ctx)
+            .get_id()
+            .ok_or(Message::empty_name_spec(location))?;
+
+        if static_def_ctx.value_ctx.is_some() {
+            return Err(Message::static_initializer(
+                location,
+                format!("Extern static \"{identifier}\" can not be initialized"),
+            ));
+        }
+
+        Ok(VariableDef {
+            location,
+            attributes,
+            identifier,
+            var_type,
+            visibility: Visibility::Global,
+            mutability,
+            value: None,
+        })
+    }
+
     fn low_static_def_attributes(&self, ctx: &AttributesCtx) -> AstLowResult<VariableAttributes> {
+        self.expect_no_meta_attributes(ctx)?;
         self.expect_incompatible_attribute(&ctx.safe_tkn)?;
         self.expect_incompatible_attribute(&ctx.unsafe_tkn)?;
 
@@ -53,7 +53,10 @@ impl AstLowering {
         Ok((
             id,
             StructFieldInfo {
-                attributes: StructFieldAttributes::default(),
+                location: field_ctx.name_ctx.name_tkn.get_location(),
+                attributes: StructFieldAttributes {
+                    publicity: self.low_publicity_token(&field_ctx.pub_tkn),
+                },
                 ty,
             },
         ))
@@ -65,6 +68,7 @@ impl AstLowering {
 
         Ok(StructAttributes {
             publicity: self.low_publicity_token(&ctx.pub_tkn),
+            meta: self.low_meta_attributes(ctx)?,
         })
     }
 }
@@ -53,7 +53,10 @@ impl AstLowering {
         Ok((
             id,
             UnionFieldInfo {
-                attributes: UnionFieldAttributes::default(),
+                location: field_ctx.name_ctx.name_tkn.get_location(),
+                attributes: UnionFieldAttributes {
+                    publicity: self.low_publicity_token(&field_ctx.pub_tkn),
+                },
                 ty,
             },
         ))
@@ -65,6 +68,7 @@ impl AstLowering {
 
         Ok(UnionAttributes {
             publicity: self.low_publicity_token(&ctx.pub_tkn),
+            meta: self.low_meta_attributes(ctx)?,
         })
     }
 }
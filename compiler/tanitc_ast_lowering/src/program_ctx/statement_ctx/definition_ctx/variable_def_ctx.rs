@@ -26,7 +26,8 @@ impl AstLowering {
         let var_type = if let Some(type_ctx) = &var_def_ctx.type_ctx {
             self.low_type_ctx(&type_ctx.type_ctx)?.ty
         } else {
-            Type::unit()
+            // Inferred from the value by the analyzer
+            Type::Auto
         };
         let value = if let Some(value_ctx) = &var_def_ctx.value_ctx {
             Some(Box::new(self.low_expression_ctx(&value_ctx.value_ctx)?))
@@ -46,6 +47,7 @@ impl AstLowering {
     }
 
     fn low_var_def_attributes(&self, ctx: &AttributesCtx) -> AstLowResult<VariableAttributes> {
+        self.expect_no_meta_attributes(ctx)?;
         self.expect_incompatible_attribute(&ctx.safe_tkn)?;
         self.expect_incompatible_attribute(&ctx.unsafe_tkn)?;
 
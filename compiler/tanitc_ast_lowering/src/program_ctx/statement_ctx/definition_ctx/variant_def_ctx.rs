@@ -39,6 +39,7 @@ impl AstLowering {
 
         Ok(VariantAttributes {
             publicity: self.low_publicity_token(&ctx.pub_tkn),
+            meta: self.low_meta_attributes(ctx)?,
         })
     }
 
@@ -33,7 +33,7 @@ impl AstLowering {
 
         let value = value_str
             .parse::<usize>()
-            .map_err(|err| Message::new(location, err))?;
+            .map_err(|err| Message::invalid_literal(location, err))?;
 
         Ok(Integer { location, value })
     }
@@ -47,7 +47,7 @@ impl AstLowering {
 
         let value = value_str
             .parse::<f64>()
-            .map_err(|err| Message::new(location, err))?;
+            .map_err(|err| Message::invalid_literal(location, err))?;
 
         Ok(Decimal { location, value })
     }
@@ -59,6 +59,12 @@ impl AstLowering {
             unreachable!()
         };
 
+        // Quotes are not part of the value
+        let value = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .unwrap_or(value);
+
         Ok(Text {
             location,
             value: value.to_string(),
@@ -0,0 +1,19 @@
+use tanitc_ast::program_ctx::statement_ctx::expression_ctx::member_access_ctx::MemberAccessCtx;
+use tanitc_hir::hir::expressions::member_access::MemberAccessExpr;
+
+use crate::{AstLowResult, AstLowering};
+
+impl AstLowering {
+    pub(crate) fn low_member_access_expression_ctx(
+        &mut self,
+        ctx: &MemberAccessCtx,
+    ) -> AstLowResult<MemberAccessExpr> {
+        let lhs = Box::new(self.low_expression_ctx(&ctx.expression_ctx)?);
+
+        Ok(MemberAccessExpr {
+            location: ctx.id_tkn.get_location(),
+            lhs,
+            id: ctx.id_tkn.identifier(),
+        })
+    }
+}
@@ -10,6 +10,7 @@ pub(crate) mod call_ctx;
 pub(crate) mod conversion_ctx;
 pub(crate) mod indexing_ctx;
 pub(crate) mod literal_ctx;
+pub(crate) mod member_access_ctx;
 pub(crate) mod unary_ctx;
 
 impl AstLowering {
@@ -27,6 +28,9 @@ impl AstLowering {
             ExpressionCtx::Indexing(ctx) => self
                 .low_indexing_expression_ctx(ctx)
                 .map(Expression::Indexing),
+            ExpressionCtx::MemberAccess(ctx) => self
+                .low_member_access_expression_ctx(ctx)
+                .map(Expression::MemberAccess),
             ExpressionCtx::Call(ctx) => self.low_call_ctx(ctx).map(Expression::Call),
             ExpressionCtx::Variable(ctx) => self.low_variable_ctx(ctx).map(Expression::Variable),
         }
@@ -36,6 +40,10 @@ impl AstLowering {
         let name = self.low_name_spec_ctx(var_name_spec_ctx)?;
         let location = name.location;
 
-        Ok(Variable { location, name })
+        Ok(Variable {
+            location,
+            name,
+            item_name: None,
+        })
     }
 }
@@ -6,6 +6,7 @@ use crate::{AstLowResult, AstLowering};
 pub(crate) mod attributes_ctx;
 pub(crate) mod block_ctx;
 pub(crate) mod branch_ctx;
+pub(crate) mod cfg_ctx;
 pub(crate) mod control_flow_ctx;
 pub(crate) mod definition_ctx;
 pub(crate) mod expression_ctx;
@@ -23,6 +24,15 @@ impl AstLowering {
                 continue;
             };
 
+            match self.is_statement_enabled(statement_ctx) {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(err) => {
+                    self.error(err);
+                    continue;
+                }
+            }
+
             match self.low_statement_ctx(statement_ctx) {
                 Err(err) => self.error(err),
                 Ok(stmt) => statements_hir.push(stmt),
@@ -41,6 +51,7 @@ impl AstLowering {
             StatementCtx::ControlFlow(ctx) => self.low_control_flow_ctx(ctx).map(Hir::ControlFlow),
             StatementCtx::Branch(ctx) => self.low_branch_ctx(ctx).map(Hir::BranchStmt),
             StatementCtx::Use(ctx) => self.low_use_ctx(ctx).map(Hir::Use),
+            StatementCtx::Attributed(ctx) => self.low_statement_ctx(&ctx.statement_ctx),
         }
     }
 }
@@ -1,13 +1,96 @@
-use tanitc_ast::program_ctx::statement_ctx::use_ctx::UseCtx;
-use tanitc_hir::hir::uses::Use;
+use tanitc_ast::program_ctx::statement_ctx::{
+    attributes_ctx::AttributesCtx,
+    use_ctx::{UseCtx, UseTailCtx, UseTreeCtx},
+};
+use tanitc_hir::hir::uses::{Use, UseAttributes, UseItem};
+use tanitc_lexer::location::Location;
+use tanitc_messages::Message;
+use tanitc_name::{NamePathSegment, NameSpec};
 
 use crate::{AstLowResult, AstLowering};
 
 impl AstLowering {
     pub(crate) fn low_use_ctx(&self, ctx: &UseCtx) -> AstLowResult<Use> {
         let location = ctx.use_tkn.get_location();
-        let name = self.low_name_spec_ctx(&ctx.name_spec_ctx)?;
+        let attributes = self.low_use_attributes(&ctx.attributes_ctx)?;
 
-        Ok(Use { location, name })
+        let mut items = Vec::new();
+        self.low_use_tree_ctx(location, &[], &ctx.tree_ctx, &mut items)?;
+
+        Ok(Use {
+            location,
+            attributes,
+            items,
+        })
+    }
+
+    fn low_use_tree_ctx(
+        &self,
+        location: Location,
+        prefix: &[NamePathSegment],
+        tree_ctx: &UseTreeCtx,
+        items: &mut Vec<UseItem>,
+    ) -> AstLowResult<()> {
+        let location = tree_ctx
+            .name_spec_ctx
+            .names
+            .first()
+            .map_or(location, |(tkn, _)| tkn.get_location());
+
+        let segments: Vec<NamePathSegment> = tree_ctx
+            .name_spec_ctx
+            .names
+            .iter()
+            .map(|(tkn, _)| self.low_name_segment_token(tkn))
+            .collect();
+
+        let mut path = prefix.to_vec();
+
+        // `use a::{self}` imports the module "a" itself
+        if prefix.is_empty() || segments != [NamePathSegment::SelfNameSpace] {
+            path.extend(segments);
+        }
+
+        match &tree_ctx.tail_ctx {
+            None => items.push(UseItem {
+                name: NameSpec { location, path },
+                alias: None,
+            }),
+
+            Some(UseTailCtx::Alias(alias_ctx)) => items.push(UseItem {
+                name: NameSpec { location, path },
+                alias: Some(alias_ctx.name_ctx.identifier()),
+            }),
+
+            Some(UseTailCtx::Glob(_)) => {
+                path.push(NamePathSegment::AllIdents);
+                items.push(UseItem {
+                    name: NameSpec { location, path },
+                    alias: None,
+                });
+            }
+
+            Some(UseTailCtx::Group(group_ctx)) => {
+                for (tree_ctx, _) in group_ctx.trees.iter() {
+                    self.low_use_tree_ctx(location, &path, tree_ctx, items)?;
+                }
+            }
+        }
+
+        if items.iter().any(|item| item.name.path.is_empty()) {
+            return Err(Message::empty_name_spec(location));
+        }
+
+        Ok(())
+    }
+
+    fn low_use_attributes(&self, ctx: &AttributesCtx) -> AstLowResult<UseAttributes> {
+        self.expect_no_meta_attributes(ctx)?;
+        self.expect_incompatible_attribute(&ctx.safe_tkn)?;
+        self.expect_incompatible_attribute(&ctx.unsafe_tkn)?;
+
+        Ok(UseAttributes {
+            publicity: self.low_publicity_token(&ctx.pub_tkn),
+        })
     }
 }
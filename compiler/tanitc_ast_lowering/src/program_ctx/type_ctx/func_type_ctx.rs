@@ -1,8 +1,9 @@
 use tanitc_ast::program_ctx::type_ctx::func_type_ctx::{
     FuncTypeCtx, FuncTypeParamCtx, FuncTypeParamsCtx,
 };
-use tanitc_attributes::Safety;
+use tanitc_attributes::{Abi, Safety};
 use tanitc_hir::hir::type_spec::{FuncType, FuncTypeParam, Type, TypeSpec};
+use tanitc_messages::Message;
 
 use crate::{AstLowResult, AstLowering};
 
@@ -10,6 +11,20 @@ impl AstLowering {
     pub(crate) fn low_func_type_ctx(&self, type_ctx: &FuncTypeCtx) -> AstLowResult<TypeSpec> {
         let location = type_ctx.func_tkn.get_location();
 
+        let abi = if let Some(abi_ctx) = &type_ctx.abi_ctx {
+            self.low_abi_token(&abi_ctx.abi_tkn)?
+        } else {
+            Abi::Tanit
+        };
+
+        let is_variadic = type_ctx.params_ctx.ellipsis_tkn.is_some();
+        if is_variadic && !abi.is_c() {
+            return Err(Message::invalid_variadic(
+                location,
+                "Function type can not be variadic: only \"C\" function types are allowed to have \"...\"",
+            ));
+        }
+
         let parameters = self.low_func_type_params_ctx(&type_ctx.params_ctx)?;
         let return_type = Box::new(if let Some(return_type_ctx) = &type_ctx.return_type {
             self.low_type_ctx(&return_type_ctx.type_ctx)?.ty
@@ -21,6 +36,8 @@ impl AstLowering {
             parameters,
             return_type,
             safety: Safety::Safe,
+            abi,
+            is_variadic,
         });
 
         Ok(TypeSpec { location, ty })
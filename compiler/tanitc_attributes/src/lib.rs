@@ -1,6 +1,7 @@
+use serde::Serialize;
 use std::fmt::Display;
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum Mutability {
     #[default]
     Immutable,
@@ -23,7 +24,7 @@ impl Display for Mutability {
     }
 }
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum Safety {
     #[default]
     Inherited,
@@ -51,7 +52,7 @@ impl Display for Safety {
     }
 }
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum Publicity {
     #[default]
     Private,
@@ -64,7 +65,7 @@ impl Display for Publicity {
     }
 }
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum Visibility {
     #[default]
     Local,
@@ -86,3 +87,30 @@ impl Display for Visibility {
         write!(f, "{self:?}")
     }
 }
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Abi {
+    #[default]
+    Tanit,
+    C,
+}
+
+impl Abi {
+    pub fn is_c(&self) -> bool {
+        *self == Self::C
+    }
+
+    // Returns ABI allowed in `extern "..."` blocks
+    pub fn from_extern_name(name: &str) -> Option<Self> {
+        match name {
+            "C" => Some(Self::C),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Abi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
@@ -31,11 +31,21 @@ pub fn build_object_file(
     execute_command(&mut cmd)
 }
 
+// Archives of extern crates are linked as input files
+fn get_extern_crates_paths(options: &CompileOptions) -> Vec<&Path> {
+    options
+        .extern_crates
+        .iter()
+        .map(|extern_crate| extern_crate.path.as_path())
+        .collect()
+}
+
 fn build_executable(inputs: &[&Path], options: &CompileOptions) -> Result<(), String> {
     let utility = get_utility_name(options.backend);
 
     let mut cmd = Command::new(utility);
     cmd.args(inputs);
+    cmd.args(get_extern_crates_paths(options));
     cmd.arg("-o");
     cmd.arg(&options.output_file);
 
@@ -67,6 +77,7 @@ fn build_dynamic_lib(inputs: &[&Path], options: &CompileOptions) -> Result<(), S
 
     let mut cmd = Command::new(utility);
     cmd.args(inputs);
+    cmd.args(get_extern_crates_paths(options));
     cmd.arg("-shared");
     cmd.arg("-o");
     cmd.arg(&options.output_file);
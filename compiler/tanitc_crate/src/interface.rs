@@ -0,0 +1,624 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use tanitc_attributes::Publicity;
+use tanitc_hir::{
+    attributes::{find_attribute, Attribute, MetaItem},
+    hir::{
+        definitions::{
+            aliases::AliasDef,
+            enums::EnumDef,
+            functions::{FunctionDef, FunctionParam},
+            modules::{ModuleAttributes, ModuleDef, ModuleDefBody},
+            structs::StructDef,
+            unions::UnionDef,
+            variants::{VariantDef, VariantField},
+            Definition,
+        },
+        type_spec::{ArraySize, Type},
+        Hir,
+    },
+};
+use tanitc_ident::Ident;
+use tanitc_lexer::location::Location;
+use tanitc_name::NameSpec;
+
+// Interface is stored next to the library: "libs/math.a" -> "libs/math.tti"
+pub fn get_interface_path(library_path: &Path) -> PathBuf {
+    library_path.with_extension("tti")
+}
+
+// Extern crate is represented by a module with the interface as its body.
+// Interface keeps private fields of structs, so they are not reported as dead code
+pub fn create_extern_crate_module(
+    name: &str,
+    location: Location,
+    interface: Box<Hir>,
+) -> ModuleDef {
+    let allow_dead_code = Attribute {
+        location,
+        meta: MetaItem::List(
+            Ident::from("allow".to_string()),
+            vec![MetaItem::Word(Ident::from("dead_code".to_string()))],
+        ),
+    };
+
+    ModuleDef {
+        location,
+        attributes: ModuleAttributes {
+            meta: vec![allow_dead_code],
+            ..Default::default()
+        },
+        name: NameSpec {
+            location,
+            path: vec![Ident::from(name.to_string()).into()],
+        },
+        body: ModuleDefBody::External(interface),
+    }
+}
+
+// Writes declarations of the public items of a library crate in Tanit syntax.
+// Crates depending on the library parse the interface back as a module.
+pub struct InterfaceWriter<'a> {
+    stream: &'a mut dyn Write,
+    indent: usize,
+}
+
+impl<'a> InterfaceWriter<'a> {
+    pub fn new(stream: &'a mut dyn Write) -> Self {
+        Self { stream, indent: 0 }
+    }
+
+    pub fn write_interface(&mut self, crate_name: &str, hir: &Hir) -> std::io::Result<()> {
+        writeln!(self.stream, "# Interface of crate \"{crate_name}\"")?;
+
+        self.write_item(hir)
+    }
+}
+
+impl InterfaceWriter<'_> {
+    fn indentation(&self) -> String {
+        "    ".repeat(self.indent)
+    }
+
+    // Only attributes that affect users of the crate are written
+    fn write_attributes(&mut self, attributes: &[Attribute]) -> std::io::Result<()> {
+        let indentation = self.indentation();
+
+        for attribute in attributes.iter() {
+            let name = attribute
+                .name()
+                .map(|id| id.to_string())
+                .unwrap_or_default();
+            if matches!(name.as_str(), "no_mangle" | "deprecated" | "flags") {
+                writeln!(self.stream, "{indentation}{attribute}")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_item(&mut self, hir: &Hir) -> std::io::Result<()> {
+        match hir {
+            Hir::Block(block) => {
+                for statement in block.statements.iter() {
+                    self.write_item(statement)?;
+                }
+
+                Ok(())
+            }
+            Hir::Definition(definition) => self.write_definition(definition),
+            _ => Ok(()),
+        }
+    }
+
+    fn write_definition(&mut self, definition: &Definition) -> std::io::Result<()> {
+        match definition {
+            Definition::Module(node) => self.write_module_def(node),
+            Definition::Struct(node) => self.write_struct_def(node),
+            Definition::Union(node) => self.write_union_def(node),
+            Definition::Variant(node) => self.write_variant_def(node),
+            Definition::Enum(node) => self.write_enum_def(node),
+            Definition::Func(node) => self.write_func_def(node),
+            Definition::Alias(node) => self.write_alias_def(node),
+            Definition::Impl(_) | Definition::Variable(_) | Definition::Extern(_) => Ok(()),
+        }
+    }
+
+    fn write_module_def(&mut self, module_def: &ModuleDef) -> std::io::Result<()> {
+        if module_def.attributes.publicity != Publicity::Public {
+            return Ok(());
+        }
+
+        let indentation = self.indentation();
+        self.write_attributes(&module_def.attributes.meta)?;
+        let name = item_name(&module_def.name);
+        let safety = if module_def.attributes.safety.is_unsafe() {
+            "unsafe "
+        } else {
+            ""
+        };
+
+        writeln!(self.stream, "{indentation}pub {safety}module {name} {{")?;
+
+        self.indent += 1;
+        match &module_def.body {
+            ModuleDefBody::Internal(block) => {
+                for statement in block.statements.iter() {
+                    self.write_item(statement)?;
+                }
+            }
+            ModuleDefBody::External(body) => self.write_item(body)?,
+        }
+        self.indent -= 1;
+
+        writeln!(self.stream, "{indentation}}}")
+    }
+
+    fn write_struct_def(&mut self, struct_def: &StructDef) -> std::io::Result<()> {
+        if struct_def.attributes.publicity != Publicity::Public {
+            return Ok(());
+        }
+
+        let indentation = self.indentation();
+        self.write_attributes(&struct_def.attributes.meta)?;
+        let name = item_name(&struct_def.name);
+
+        // Private fields are kept to preserve the layout of the struct
+        writeln!(self.stream, "{indentation}pub struct {name} {{")?;
+        for (field_id, field_info) in struct_def.fields.iter() {
+            let publicity = publicity_str(field_info.attributes.publicity);
+            let ty = type_str(&field_info.ty.ty);
+            writeln!(self.stream, "{indentation}    {publicity}{field_id}: {ty}")?;
+        }
+        writeln!(self.stream, "{indentation}}}")
+    }
+
+    fn write_union_def(&mut self, union_def: &UnionDef) -> std::io::Result<()> {
+        if union_def.attributes.publicity != Publicity::Public {
+            return Ok(());
+        }
+
+        let indentation = self.indentation();
+        self.write_attributes(&union_def.attributes.meta)?;
+        let name = item_name(&union_def.name);
+
+        writeln!(self.stream, "{indentation}pub union {name} {{")?;
+        for (field_id, field_info) in union_def.fields.iter() {
+            let publicity = publicity_str(field_info.attributes.publicity);
+            let ty = type_str(&field_info.ty.ty);
+            writeln!(self.stream, "{indentation}    {publicity}{field_id}: {ty}")?;
+        }
+        writeln!(self.stream, "{indentation}}}")
+    }
+
+    fn write_variant_def(&mut self, variant_def: &VariantDef) -> std::io::Result<()> {
+        if variant_def.attributes.publicity != Publicity::Public {
+            return Ok(());
+        }
+
+        let indentation = self.indentation();
+        self.write_attributes(&variant_def.attributes.meta)?;
+        let name = item_name(&variant_def.name);
+
+        writeln!(self.stream, "{indentation}pub variant {name} {{")?;
+        for (unit_id, unit) in variant_def.fields.iter() {
+            match unit {
+                VariantField::Enum => writeln!(self.stream, "{indentation}    {unit_id}")?,
+                VariantField::Tuple(units) => {
+                    let units = units.iter().map(type_str).collect::<Vec<_>>().join(", ");
+                    writeln!(self.stream, "{indentation}    {unit_id}({units})")?;
+                }
+                VariantField::Struct(fields) => {
+                    writeln!(self.stream, "{indentation}    {unit_id} {{")?;
+                    for (field_id, field_info) in fields.iter() {
+                        let ty = type_str(&field_info.ty.ty);
+                        writeln!(self.stream, "{indentation}        {field_id}: {ty}")?;
+                    }
+                    writeln!(self.stream, "{indentation}    }}")?;
+                }
+            }
+        }
+        writeln!(self.stream, "{indentation}}}")
+    }
+
+    fn write_enum_def(&mut self, enum_def: &EnumDef) -> std::io::Result<()> {
+        if enum_def.attributes.publicity != Publicity::Public {
+            return Ok(());
+        }
+
+        let indentation = self.indentation();
+        self.write_attributes(&enum_def.attributes.meta)?;
+        let name = item_name(&enum_def.name);
+
+        let repr = enum_def
+            .repr
+            .as_ref()
+            .map(|repr| format!(": {}", type_str(repr)))
+            .unwrap_or_default();
+
+        // Values are marked by the analyzer, so they don't depend on the order of units
+        writeln!(self.stream, "{indentation}pub enum {name}{repr} {{")?;
+        for (unit_id, unit) in enum_def.units.iter() {
+            match unit.value {
+                Some(value) => writeln!(self.stream, "{indentation}    {unit_id}: {value}")?,
+                None => writeln!(self.stream, "{indentation}    {unit_id}")?,
+            }
+        }
+        writeln!(self.stream, "{indentation}}}")
+    }
+
+    fn write_func_def(&mut self, func_def: &FunctionDef) -> std::io::Result<()> {
+        if func_def.attributes.publicity != Publicity::Public {
+            return Ok(());
+        }
+
+        // Inline functions are not exported from the library
+        if find_attribute(&func_def.attributes.meta, "inline").is_some() {
+            return Ok(());
+        }
+
+        let indentation = self.indentation();
+        self.write_attributes(&func_def.attributes.meta)?;
+        let name = item_name(&func_def.name);
+        let safety = if func_def.attributes.safety.is_unsafe() {
+            "unsafe "
+        } else {
+            ""
+        };
+
+        let parameters = func_def
+            .parameters
+            .iter()
+            .filter_map(|param| match param {
+                FunctionParam::Common(var_def) => Some(format!(
+                    "{}: {}",
+                    var_def.identifier,
+                    type_str(&var_def.var_type)
+                )),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let abi = if func_def.attributes.abi.is_c() {
+            "extern \"C\" "
+        } else {
+            ""
+        };
+
+        write!(
+            self.stream,
+            "{indentation}pub {safety}{abi}func {name}({parameters})"
+        )?;
+
+        if !func_def.return_type.is_unit() {
+            write!(self.stream, ": {}", type_str(&func_def.return_type))?;
+        }
+
+        writeln!(self.stream)
+    }
+
+    fn write_alias_def(&mut self, alias_def: &AliasDef) -> std::io::Result<()> {
+        if alias_def.attributes.publicity != Publicity::Public {
+            return Ok(());
+        }
+
+        let indentation = self.indentation();
+        self.write_attributes(&alias_def.attributes.meta)?;
+        let name = item_name(&alias_def.name);
+        let ty = type_str(&alias_def.value.ty);
+
+        writeln!(self.stream, "{indentation}pub alias {name} = {ty}")
+    }
+}
+
+// Definitions are declared in the module, so only the last segment is written
+fn item_name(name: &NameSpec) -> String {
+    match name.get_id() {
+        Some(id) => id.to_string(),
+        None => name.to_string(),
+    }
+}
+
+fn publicity_str(publicity: Publicity) -> &'static str {
+    match publicity {
+        Publicity::Public => "pub ",
+        Publicity::Private => "",
+    }
+}
+
+// Unlike Display of Type, the result can be parsed back as a type
+fn type_str(ty: &Type) -> String {
+    match ty {
+        Type::Ref(ref_type) => {
+            let mutability = if ref_type.mutability.is_mutable() {
+                "mut "
+            } else {
+                ""
+            };
+
+            format!("&{mutability}{}", type_str(&ref_type.ref_to))
+        }
+        Type::Ptr(ptr_type) => {
+            let mutability = if ptr_type.mutability.is_mutable() {
+                "mut"
+            } else {
+                "const"
+            };
+
+            format!("*{mutability} {}", type_str(&ptr_type.ptr_to))
+        }
+        Type::Tuple(tuple_type) => {
+            let units = tuple_type
+                .units
+                .iter()
+                .map(type_str)
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("({units})")
+        }
+        Type::Array { size, value_type } => match size {
+            ArraySize::Fixed(size) => format!("[{}: {size}]", type_str(value_type)),
+            ArraySize::Unknown => format!("[{}]", type_str(value_type)),
+        },
+        Type::Template {
+            identifier,
+            generics,
+        } => {
+            let generics = generics.iter().map(type_str).collect::<Vec<_>>().join(", ");
+
+            format!("{identifier}<{generics}>")
+        }
+        Type::Func(func_type) => {
+            let parameters = func_type
+                .parameters
+                .iter()
+                .map(|param| type_str(&param.ty))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            if func_type.return_type.is_unit() {
+                format!("func({parameters})")
+            } else {
+                format!("func({parameters}): {}", type_str(&func_type.return_type))
+            }
+        }
+        _ => ty.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tanitc_ast_lowering::AstLowering;
+    use tanitc_attributes::{Abi, Mutability};
+    use tanitc_hir::hir::{expressions::Expression, type_spec::RefType};
+    use tanitc_hir_analyzer::Analyzer;
+    use tanitc_hir_test::{
+        create_call_expr, create_common_func_param, create_custom_type, create_decimal_lit,
+        create_enum_def, create_func_def, create_integer_lit, create_main_func_def,
+        create_module_def, create_program, create_struct_def, create_var_def,
+        create_word_attribute,
+    };
+    use tanitc_options::{CompileOptions, CrateType};
+    use tanitc_parser::Parser;
+
+    use pretty_assertions::assert_str_eq;
+
+    fn write_interface(crate_name: &str, hir: &Hir) -> String {
+        let mut buffer = Vec::<u8>::new();
+        InterfaceWriter::new(&mut buffer)
+            .write_interface(crate_name, hir)
+            .unwrap();
+
+        String::from_utf8(buffer).unwrap()
+    }
+
+    fn read_interface(text: &str) -> Box<Hir> {
+        let mut parser = Parser::from_text(text);
+        let program_ctx = parser.parse_program().unwrap();
+        assert!(!parser.messages_ref().has_errors());
+
+        AstLowering::new().low(program_ctx.as_ref()).unwrap()
+    }
+
+    #[test]
+    fn write_interface_test() {
+        // Given
+        let mut point_def = create_struct_def(
+            "Point",
+            vec![("point_x", Type::F32), ("point_y", Type::F32)],
+        );
+        point_def.attributes.publicity = Publicity::Public;
+        point_def
+            .fields
+            .get_mut(&Ident::from("point_x".to_string()))
+            .unwrap()
+            .attributes
+            .publicity = Publicity::Public;
+
+        let mut norm_def = create_func_def(
+            "norm",
+            vec![create_common_func_param(
+                "point",
+                Mutability::Immutable,
+                Type::Ref(RefType {
+                    ref_to: Box::new(create_custom_type(&["Point"])),
+                    mutability: Mutability::Immutable,
+                }),
+            )],
+            Type::F32,
+            vec![],
+        );
+        norm_def.attributes.publicity = Publicity::Public;
+
+        let mut unit_len_def = create_func_def("unit_len", vec![], Type::F32, vec![]);
+        unit_len_def.attributes.publicity = Publicity::Public;
+        unit_len_def.attributes.abi = Abi::C;
+
+        let hidden_def = create_func_def("hidden", vec![], Type::I32, vec![]);
+
+        let mut geometry_def = create_module_def(
+            "geometry",
+            vec![
+                point_def.into(),
+                norm_def.into(),
+                unit_len_def.into(),
+                hidden_def.into(),
+            ],
+        );
+        geometry_def.attributes.publicity = Publicity::Public;
+
+        let private_def = create_module_def("private", vec![]);
+
+        let program = create_program(vec![geometry_def.into(), private_def.into()]);
+
+        const EXPECTED: &str = "# Interface of crate \"shapes\"\
+                              \npub module geometry {\
+                              \n    pub struct Point {\
+                              \n        pub point_x: f32\
+                              \n        point_y: f32\
+                              \n    }\
+                              \n    pub func norm(point: &Point): f32\
+                              \n    pub extern \"C\" func unit_len(): f32\
+                              \n}\n";
+
+        // When
+        let interface = write_interface("shapes", &program);
+
+        // Then
+        assert_str_eq!(interface, EXPECTED);
+        read_interface(&interface);
+    }
+
+    #[test]
+    fn extern_crate_analysis_test() {
+        /* Library crate "colors":
+         * pub enum Color {
+         *     Red
+         *     Blue: 5
+         * }
+         * pub func mix(first: i32, second: i32): i32 { }
+         */
+
+        // Given
+        let mut color_def = create_enum_def("Color", vec![("Red", None), ("Blue", Some(5))]);
+        color_def.attributes.publicity = Publicity::Public;
+
+        let mut mix_def = create_func_def(
+            "mix",
+            vec![
+                create_common_func_param("first", Mutability::Immutable, Type::I32),
+                create_common_func_param("second", Mutability::Immutable, Type::I32),
+            ],
+            Type::I32,
+            vec![],
+        );
+        mix_def.attributes.publicity = Publicity::Public;
+
+        let mut library = create_program(vec![color_def.into(), mix_def.into()]);
+
+        let mut library_analyzer = Analyzer::with_compile_options(CompileOptions {
+            crate_type: CrateType::StaticLib,
+            ..Default::default()
+        });
+        assert!(library_analyzer.analyze_program(&mut library).is_ok());
+
+        const EXPECTED: &str = "# Interface of crate \"colors\"\
+                              \npub enum Color {\
+                              \n    Red: 0\
+                              \n    Blue: 5\
+                              \n}\
+                              \npub func mix(first: i32, second: i32): i32\n";
+
+        /* Crates depending on "colors":
+         * func main(): i32 {
+         *     var a = colors::mix(1, 2)   # Ok
+         * }
+         * func main(): i32 {
+         *     var a = colors::mix(1, 2.5) # Error
+         * }
+         */
+
+        let create_program_with_call = |interface: Box<Hir>, arg: Expression| {
+            let colors_def = create_extern_crate_module("colors", Location::default(), interface);
+
+            let main_def = create_main_func_def(vec![create_var_def(
+                "a",
+                Mutability::Immutable,
+                Type::Auto,
+                Some(create_call_expr(
+                    &["colors", "mix"],
+                    vec![create_integer_lit(1), arg],
+                )),
+            )
+            .into()]);
+
+            create_program(vec![colors_def.into(), main_def.into()])
+        };
+
+        // When
+        let interface = write_interface("colors", &library);
+
+        let mut good_program =
+            create_program_with_call(read_interface(&interface), create_integer_lit(2));
+        let good_res = Analyzer::new().analyze_program(&mut good_program);
+
+        let mut bad_program =
+            create_program_with_call(read_interface(&interface), create_decimal_lit(2.5));
+        let bad_res = Analyzer::new().analyze_program(&mut bad_program);
+
+        // Then
+        assert_str_eq!(interface, EXPECTED);
+        assert!(good_res.is_ok());
+
+        let errors = bad_res.expect_err("Expected errors");
+        assert_eq!(errors.errors_ref().len(), 1);
+        assert_eq!(
+            errors.errors_ref()[0].text,
+            "Semantic error: Mismatched types. Call: positional parameter \"1\" has type \"f64\" but expected \"i32\""
+        );
+    }
+
+    #[test]
+    fn flags_enum_interface_test() {
+        /* #[flags]
+         * pub enum Access: u8 {
+         *     Read
+         *     Write
+         * }
+         */
+
+        // Given
+        let mut access_def = create_enum_def("Access", vec![("Read", None), ("Write", None)]);
+        access_def.attributes.publicity = Publicity::Public;
+        access_def.attributes.meta = vec![create_word_attribute("flags")];
+        access_def.repr = Some(Type::U8);
+
+        let mut library = create_program(vec![access_def.into()]);
+
+        let mut library_analyzer = Analyzer::with_compile_options(CompileOptions {
+            crate_type: CrateType::StaticLib,
+            ..Default::default()
+        });
+        assert!(library_analyzer.analyze_program(&mut library).is_ok());
+
+        const EXPECTED: &str = "# Interface of crate \"access\"\
+                              \n#[flags]\
+                              \npub enum Access: u8 {\
+                              \n    Read: 1\
+                              \n    Write: 2\
+                              \n}\n";
+
+        // When
+        let interface = write_interface("access", &library);
+
+        // Then
+        assert_str_eq!(interface, EXPECTED);
+        read_interface(&interface);
+    }
+}
@@ -1,13 +1,39 @@
-use std::{io::Read, path::PathBuf};
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
 
+use serde::Serialize;
 use tanitc_ast::program_ctx::ProgramCtx;
 use tanitc_ast_lowering::AstLowering;
 use tanitc_builder::{build_object_file, link_crate_objects};
 use tanitc_hir::hir::Hir;
 use tanitc_hir_analyzer::Analyzer;
-use tanitc_lexer::Lexer;
-use tanitc_options::{CompileOptions, SerializationOption};
+use tanitc_lexer::{location::Location, Lexer};
+use tanitc_messages::listener::MessageListener;
+use tanitc_options::{
+    ColorMode, CompileOptions, CrateType, ErrorFormat, ExternCrate, SerializationOption,
+};
 use tanitc_parser::Parser;
+use tanitc_path::PathId;
+
+pub mod interface;
+
+pub fn serialize_to_string<T: Serialize>(
+    value: &T,
+    mode: SerializationOption,
+) -> Result<String, String> {
+    match mode {
+        SerializationOption::Disabled => Ok(String::new()),
+        SerializationOption::Json => {
+            serde_json::to_string_pretty(value).map_err(|err| err.to_string())
+        }
+        SerializationOption::Ron => {
+            ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+                .map_err(|err| err.to_string())
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Crate {
@@ -15,6 +41,7 @@ pub struct Crate {
     initial_path: PathBuf,
     output_path: PathBuf,
     compile_options: CompileOptions,
+    errors_reported: bool, // failure is already explained by printed diagnostics
 }
 
 impl Default for Crate {
@@ -24,6 +51,7 @@ impl Default for Crate {
             initial_path: PathBuf::from("./main.tt".to_string()),
             output_path: PathBuf::from("./main"),
             compile_options: CompileOptions::default(),
+            errors_reported: false,
         }
     }
 }
@@ -40,52 +68,101 @@ impl Crate {
     }
 
     pub fn process(&mut self) -> Result<(), String> {
+        let extern_crates = self.process_extern_crates()?;
+
         let ast = self.process_parsing()?;
-        if SerializationOption::Enabled == self.compile_options.dump_ast_mode {
-            self.serialize_ast(&ast)?;
-        }
 
         let mut hir = self.process_ast_lowering(ast.as_ref())?;
+        Self::import_extern_crates(&mut hir, extern_crates);
 
         self.process_analyze(&mut hir)?;
+
+        // Dumps are taken after analysis, so the HIR has resolved types
+        self.serialize(ast.as_ref(), "ast", self.compile_options.dump_ast_mode)?;
+        self.serialize(hir.as_ref(), "hir", self.compile_options.dump_hir_mode)?;
         self.process_codegen(&hir)?;
 
+        if self.compile_options.crate_type != CrateType::Bin {
+            self.process_interface(&hir)?;
+            self.process_public_header(&hir)?;
+        }
+
         self.process_building()?;
         self.process_linkage()?;
 
         Ok(())
     }
+
+    pub fn has_reported_errors(&self) -> bool {
+        self.errors_reported
+    }
 }
 
 impl Crate {
-    fn serialize_ast(&mut self, program_ctx: &ProgramCtx) -> Result<(), String> {
-        use std::io::Write;
-
-        let file_name = format!("{}.ast.ron", &self.name);
-        let mut file = std::fs::File::create(&file_name)
-            .map_err(|err| format!("Failed to open \"{file_name}\": {err}"))?;
-
-        writeln!(file, "{program_ctx:#?}")
-            .map_err(|err| format!("Failed to serialize AST: {err}"))?;
+    // Writes "<crate>.<kind>.json" or "<crate>.<kind>.ron"
+    fn serialize<T: Serialize>(
+        &self,
+        value: &T,
+        kind: &str,
+        mode: SerializationOption,
+    ) -> Result<(), String> {
+        let extension = match mode {
+            SerializationOption::Disabled => return Ok(()),
+            SerializationOption::Json => "json",
+            SerializationOption::Ron => "ron",
+        };
+
+        let text = serialize_to_string(value, mode)
+            .map_err(|err| format!("Failed to serialize {}: {err}", kind.to_uppercase()))?;
+
+        let file_name = format!("{}.{kind}.{extension}", &self.name);
+        std::fs::write(&file_name, text + "\n")
+            .map_err(|err| format!("Failed to write \"{file_name}\": {err}"))
+    }
 
-        Ok(())
+    fn create_message_listener(&self) -> MessageListener {
+        use std::io::IsTerminal;
+
+        let color = match self.compile_options.color {
+            ColorMode::Always => true,
+            ColorMode::Never => false,
+            ColorMode::Auto => {
+                std::io::stderr().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+            }
+        };
+
+        let mut messages = MessageListener::with_error_limit(self.compile_options.error_limit);
+        messages.set_color(color);
+        messages.set_json(self.compile_options.error_format == ErrorFormat::Json);
+        messages
     }
 
     fn process_parsing(&mut self) -> Result<Box<ProgramCtx>, String> {
-        let mut file = std::fs::File::open(&self.initial_path)
-            .map_err(|err| format!("Failed to open {:?}: {err}", self.initial_path))?;
+        let initial_path = self.initial_path.clone();
+        self.parse_file(&initial_path)
+    }
+
+    fn parse_file(&mut self, path: &Path) -> Result<Box<ProgramCtx>, String> {
+        let mut file =
+            std::fs::File::open(path).map_err(|err| format!("Failed to open {path:?}: {err}"))?;
 
         let mut buffer = String::new();
         file.read_to_string(&mut buffer)
-            .map_err(|err| format!("Failed to read file {:?}: {err}", self.initial_path))?;
+            .map_err(|err| format!("Failed to read file {path:?}: {err}"))?;
 
-        let mut lexer = Lexer::new(buffer.chars().peekable(), &self.initial_path);
+        // Diagnostics show lines of the source
+        PathId::from(path.to_path_buf()).set_source(&buffer);
+
+        let mut lexer = Lexer::new(buffer.chars().peekable(), path);
         lexer.verbose_tokens = self.compile_options.verbose_tokens;
 
         let mut parser = Parser::new(lexer);
+        parser.set_include_paths(self.compile_options.include_paths.clone());
+        parser.set_message_listener(self.create_message_listener());
 
         let program_ctx = parser.parse_program().map_err(|messages| {
             messages.print_errors();
+            self.errors_reported = true;
             "Failed to parse program".to_string()
         })?;
 
@@ -97,10 +174,12 @@ impl Crate {
     }
 
     fn process_ast_lowering(&mut self, program_ctx: &ProgramCtx) -> Result<Box<Hir>, String> {
-        let mut lowering = AstLowering::new();
+        let mut lowering = AstLowering::with_compile_options(self.compile_options.clone());
+        lowering.set_message_listener(self.create_message_listener());
 
         let hir = lowering.low(program_ctx).map_err(|messages| {
             messages.print_errors();
+            self.errors_reported = true;
             "Failed to analyze program".to_string()
         })?;
 
@@ -111,11 +190,54 @@ impl Crate {
         Ok(hir)
     }
 
+    // Interfaces are parsed before the crate itself
+    fn process_extern_crates(&mut self) -> Result<Vec<Hir>, String> {
+        let extern_crates = self.compile_options.extern_crates.clone();
+        let mut modules = Vec::<Hir>::with_capacity(extern_crates.len());
+
+        for extern_crate in extern_crates.iter() {
+            modules.push(self.process_extern_crate(extern_crate)?);
+        }
+
+        Ok(modules)
+    }
+
+    fn process_extern_crate(&mut self, extern_crate: &ExternCrate) -> Result<Hir, String> {
+        let interface_path = interface::get_interface_path(&extern_crate.path);
+        if !interface_path.exists() {
+            return Err(format!(
+                "Interface of crate \"{}\" not found: {interface_path:?}",
+                extern_crate.name
+            ));
+        }
+
+        let ast = self.parse_file(&interface_path)?;
+        let body = self.process_ast_lowering(ast.as_ref())?;
+
+        Ok(interface::create_extern_crate_module(
+            &extern_crate.name,
+            Location::new(&interface_path),
+            body,
+        )
+        .into())
+    }
+
+    // Extern crates are visible as modules in the root of the crate
+    fn import_extern_crates(hir: &mut Hir, extern_crates: Vec<Hir>) {
+        let Hir::Block(block) = hir else {
+            return;
+        };
+
+        block.statements.splice(0..0, extern_crates);
+    }
+
     fn process_analyze(&mut self, hir: &mut Hir) -> Result<(), String> {
         let mut analyzer = Analyzer::with_compile_options(self.compile_options.clone());
+        analyzer.set_message_listener(self.create_message_listener());
 
         analyzer.analyze_program(hir).map_err(|messages| {
             messages.print_errors();
+            self.errors_reported = true;
             "Failed to analyze program".to_string()
         })?;
 
@@ -147,17 +269,165 @@ impl Crate {
         Ok(())
     }
 
+    // Public header is stored next to the library: "libs/math.a" -> "libs/math.h"
+    #[cfg(feature = "backend_C")]
+    fn process_public_header(&self, hir: &Hir) -> Result<(), String> {
+        let header_path = self.compile_options.output_file.with_extension("h");
+        let mut header_stream = std::fs::File::create(&header_path)
+            .map_err(|err| format!("Failed to create {header_path:?}: {err}"))?;
+
+        // Definitions of the exported functions are already in the crate source
+        let mut source_stream = std::io::sink();
+
+        let mut codegen = tanitc_ir_c::CodeGenStream::with_compile_options(
+            &mut header_stream,
+            &mut source_stream,
+            self.compile_options.clone(),
+        );
+
+        codegen.codegen_public_header(hir)
+    }
+
+    fn process_interface(&self, hir: &Hir) -> Result<(), String> {
+        let interface_path = interface::get_interface_path(&self.compile_options.output_file);
+        let mut interface_stream = std::fs::File::create(&interface_path)
+            .map_err(|err| format!("Failed to create {interface_path:?}: {err}"))?;
+
+        interface::InterfaceWriter::new(&mut interface_stream)
+            .write_interface(&self.name, hir)
+            .map_err(|err| format!("Failed to write interface {interface_path:?}: {err}"))?;
+
+        Ok(())
+    }
+
+    fn get_object_path(&self) -> PathBuf {
+        PathBuf::from(format!("{}.o", &self.name))
+    }
+
     fn process_building(&mut self) -> Result<(), String> {
-        let built_path = PathBuf::from(format!("{}.o", &self.name));
+        let built_path = self.get_object_path();
 
         build_object_file(&self.output_path, &built_path, &self.compile_options)?;
 
         Ok(())
     }
 
+    // Archives must contain objects, so the built object is linked instead of the source
     fn process_linkage(&mut self) -> Result<(), String> {
-        link_crate_objects(&[&self.output_path], &self.compile_options)?;
+        let built_path = self.get_object_path();
+
+        link_crate_objects(&[&built_path], &self.compile_options)?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tanitc_parser::Parser;
+
+    #[test]
+    fn serialize_dumps_test() {
+        // Given
+        let mut parser = Parser::from_text("func main() {\n    var a: i32 = 1\n}");
+        let program_ctx = parser.parse_program().unwrap();
+
+        let mut hir = AstLowering::new().low(program_ctx.as_ref()).unwrap();
+        Analyzer::new().analyze_program(&mut hir).unwrap();
+
+        // When
+        let ast_json =
+            serialize_to_string(program_ctx.as_ref(), SerializationOption::Json).unwrap();
+        let hir_json = serialize_to_string(hir.as_ref(), SerializationOption::Json).unwrap();
+        let hir_ron = serialize_to_string(hir.as_ref(), SerializationOption::Ron).unwrap();
+
+        // Then
+        let ast_value: serde_json::Value = serde_json::from_str(&ast_json).unwrap();
+        let hir_value: serde_json::Value = serde_json::from_str(&hir_json).unwrap();
+        let ron_value: ron::Value = ron::from_str(&hir_ron).unwrap();
+
+        // Identifiers and paths are written as text
+        assert!(ast_value.to_string().contains("\"main\""));
+        assert!(hir_value.to_string().contains("\"path\":\"text\""));
+        assert_ne!(ron_value, ron::Value::Unit);
+    }
+
+    #[test]
+    fn reported_errors_test() {
+        // Given
+        let dir = std::env::temp_dir().join(format!("tanitc_reported_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let bad_path = dir.join("bad.tt");
+        std::fs::write(&bad_path, "func main() {\n    nothere\n}\n").unwrap();
+
+        let create_crate = |input_file: &Path| {
+            Crate::new(CompileOptions {
+                input_file: input_file.to_path_buf(),
+                error_format: ErrorFormat::Json,
+                ..Default::default()
+            })
+            .unwrap()
+        };
+
+        let mut bad_crate = create_crate(&bad_path);
+        let mut missing_crate = create_crate(&dir.join("missing.tt"));
+
+        // When
+        let bad_res = bad_crate.process();
+        let missing_res = missing_crate.process();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // Then
+        assert_eq!(bad_res, Err("Failed to analyze program".to_string()));
+        assert!(bad_crate.has_reported_errors());
+
+        assert!(missing_res.is_err());
+        assert!(!missing_crate.has_reported_errors());
+    }
+
+    #[cfg(feature = "backend_C")]
+    #[test]
+    fn callback_build_test() {
+        // Given
+        const SRC: &str = "extern \"C\" {\n    func atexit(cb: extern \"C\" func()): i32\n}\n\nfunc bye() { }\n\nfunc main() {\n    atexit(bye)\n}\n";
+
+        let mut parser = Parser::from_text(SRC);
+        let program_ctx = parser.parse_program().unwrap();
+
+        let mut hir = AstLowering::new().low(program_ctx.as_ref()).unwrap();
+        Analyzer::new().analyze_program(&mut hir).unwrap();
+
+        let compile_options = CompileOptions {
+            crate_name: "callback".to_string(),
+            ..Default::default()
+        };
+
+        let dir = std::env::temp_dir().join(format!("tanitc_callback_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let header_path = dir.join("callback.tt.h");
+        let source_path = dir.join("callback.tt.c");
+        let object_path = dir.join("callback.o");
+
+        // When
+        let mut header_buffer = Vec::<u8>::new();
+        let mut source_buffer = Vec::<u8>::new();
+        tanitc_ir_c::CodeGenStream::with_compile_options(
+            &mut header_buffer,
+            &mut source_buffer,
+            compile_options.clone(),
+        )
+        .codegen_program(&hir)
+        .unwrap();
+
+        std::fs::write(&header_path, &header_buffer).unwrap();
+        std::fs::write(&source_path, &source_buffer).unwrap();
+        let res = build_object_file(&source_path, &object_path, &compile_options);
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // Then
+        let source = String::from_utf8(source_buffer).unwrap();
+        assert!(source.contains("atexit(_TF8callback3byeE);"), "{source}");
+        res.expect("Generated source must build");
+    }
+}
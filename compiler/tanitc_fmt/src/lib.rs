@@ -0,0 +1,388 @@
+use std::path::Path;
+
+use tanitc_lexer::{
+    comment::Comment,
+    location::Location,
+    token::{lexeme::Lexeme, Token},
+    Lexer,
+};
+use tanitc_messages::listener::MessageListener;
+use tanitc_parser::Parser;
+
+pub(crate) mod program_ctx;
+
+// Prints the program back in the canonical style, comments are kept
+pub fn format_source(path: &Path, text: &str) -> Result<String, MessageListener> {
+    let mut parser = Parser::new(Lexer::new(text.chars().peekable(), path));
+    let program_ctx = parser.parse_program()?;
+
+    let mut formatter = Formatter::new(text, parser.comments_ref());
+    formatter.format_program_ctx(&program_ctx);
+
+    Ok(formatter.output)
+}
+
+pub struct Formatter<'a> {
+    lines: Vec<&'a str>, // source lines, to keep blank ones between items
+    comments: &'a [Comment],
+    next_comment: usize, // comments before it are already written
+    output: String,
+    indent: usize,
+    at_line_start: bool,
+    last_row: Option<usize>, // source row of the last written token
+    item_start: bool,        // next token starts a statement, a field or a unit
+    block_start: bool,       // nothing is written after '{' yet
+}
+
+impl<'a> Formatter<'a> {
+    pub fn new(text: &'a str, comments: &'a [Comment]) -> Self {
+        Self {
+            lines: text.lines().collect(),
+            comments,
+            next_comment: 0,
+            output: String::new(),
+            indent: 0,
+            at_line_start: true,
+            last_row: None,
+            item_start: false,
+            block_start: false,
+        }
+    }
+
+    pub(crate) fn write(&mut self, text: &str) {
+        if text.is_empty() {
+            return;
+        }
+
+        if self.at_line_start {
+            for _ in 0..self.indent {
+                self.output.push_str("    ");
+            }
+            self.at_line_start = false;
+        }
+
+        self.block_start = false;
+        self.output.push_str(text);
+    }
+
+    pub(crate) fn space(&mut self) {
+        self.write(" ");
+    }
+
+    fn line_break(&mut self) {
+        while self.output.ends_with(' ') {
+            self.output.pop();
+        }
+
+        self.output.push('\n');
+        self.at_line_start = true;
+    }
+
+    // Ends the current line, the comment after the last token stays on it
+    pub(crate) fn new_line(&mut self) {
+        if let Some(comment) = self.comments.get(self.next_comment) {
+            if !self.at_line_start && self.last_row == Some(comment.location.row) {
+                self.space();
+                self.write(&comment.text);
+                self.next_comment += 1;
+            }
+        }
+
+        if !self.at_line_start {
+            self.line_break();
+        }
+    }
+
+    pub(crate) fn token(&mut self, tkn: &Token) {
+        let location = tkn.get_location();
+
+        self.flush_comments_before(&location);
+
+        if self.item_start {
+            self.item_start = false;
+            self.blank_line_before(location.row);
+        }
+
+        self.write(&Self::token_text(tkn));
+        self.last_row = Some(location.row);
+    }
+
+    pub(crate) fn has_comment_before(&self, tkn: &Token) -> bool {
+        self.comments
+            .get(self.next_comment)
+            .is_some_and(|comment| Self::is_before(comment, &tkn.get_location()))
+    }
+
+    pub(crate) fn flush_comments_before(&mut self, location: &Location) {
+        while let Some(comment) = self.comments.get(self.next_comment) {
+            if !Self::is_before(comment, location) {
+                break;
+            }
+
+            self.write_comment(comment);
+            self.next_comment += 1;
+        }
+    }
+
+    pub(crate) fn flush_comments(&mut self) {
+        while let Some(comment) = self.comments.get(self.next_comment) {
+            self.write_comment(comment);
+            self.next_comment += 1;
+        }
+    }
+
+    fn write_comment(&mut self, comment: &Comment) {
+        let row = comment.location.row;
+
+        if self.at_line_start || self.last_row != Some(row) {
+            if !self.at_line_start {
+                self.line_break();
+            }
+
+            self.blank_line_before(row);
+            self.last_row = Some(row);
+        } else {
+            self.space();
+        }
+
+        self.write(&comment.text);
+        self.line_break();
+    }
+
+    pub(crate) fn is_blank_line_before(&self, row: usize) -> bool {
+        row > 0
+            && self
+                .lines
+                .get(row - 1)
+                .is_some_and(|line| line.trim().is_empty())
+    }
+
+    // Several blank lines are printed as one, none at the start of a block
+    pub(crate) fn blank_line_before(&mut self, row: usize) {
+        if self.is_blank_line_before(row) {
+            self.blank_line();
+        }
+    }
+
+    pub(crate) fn blank_line(&mut self) {
+        if !self.at_line_start
+            || self.block_start
+            || self.output.is_empty()
+            || self.output.ends_with("\n\n")
+        {
+            return;
+        }
+
+        self.output.push('\n');
+    }
+
+    pub(crate) fn open_body(&mut self, lcb_tkn: &Token) {
+        self.token(lcb_tkn);
+        self.indent += 1;
+        self.new_line();
+        self.block_start = true;
+    }
+
+    pub(crate) fn close_body(&mut self, rcb_tkn: &Token) {
+        self.new_line();
+        self.flush_comments_before(&rcb_tkn.get_location());
+        self.indent -= 1;
+        self.token(rcb_tkn);
+    }
+
+    // Items of struct, union, enum and variant bodies are written one per line
+    pub(crate) fn format_items<T>(
+        &mut self,
+        lcb_tkn: &Token,
+        items: &[(Option<T>, Option<Token>)],
+        rcb_tkn: &Token,
+        format_item: fn(&mut Self, &T),
+    ) {
+        let mut items = items
+            .iter()
+            .filter_map(|(item, _)| item.as_ref())
+            .peekable();
+
+        if items.peek().is_none() && !self.has_comment_before(rcb_tkn) {
+            self.token(lcb_tkn);
+            self.token(rcb_tkn);
+            return;
+        }
+
+        self.open_body(lcb_tkn);
+
+        for item in items {
+            self.new_line();
+            self.item_start = true;
+            format_item(self, item);
+        }
+
+        self.close_body(rcb_tkn);
+    }
+
+    fn is_before(comment: &Comment, location: &Location) -> bool {
+        (comment.location.row, comment.location.col) < (location.row, location.col)
+    }
+
+    fn token_text(tkn: &Token) -> String {
+        match tkn.lexeme_ref() {
+            Lexeme::Identifier(id) => id.to_string(),
+            Lexeme::Integer(text) | Lexeme::Decimal(text) | Lexeme::Text(text) => text.clone(),
+            Lexeme::EndOfLine => String::new(),
+            lexeme => lexeme.to_string().trim_matches('\'').to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::format_source;
+
+    fn format(text: &str) -> String {
+        format_source(Path::new("test.tt"), text).unwrap()
+    }
+
+    #[test]
+    fn format_program_test() {
+        // Given
+        const SRC_TEXT: &str = "struct Point\
+                              \n{\
+                              \n  x:   i32\
+                              \n  pub y:f32\
+                              \n}\
+                              \nfunc sum(a:i32,b : i32):i32{\
+                              \nvar mut res=a+b*2\
+                              \nres+=foo(x:1,  2)\
+                              \n        if res>=10&&res!=20{\
+                              \n return res as i32\
+                              \n}\
+                              \nelse{\
+                              \n    return -res\
+                              \n   }\
+                              \n}";
+
+        // When
+        let res = format(SRC_TEXT);
+
+        // Then
+        const EXPECTED: &str = "struct Point {\
+                              \n    x: i32\
+                              \n    pub y: f32\
+                              \n}\
+                              \nfunc sum(a: i32, b: i32): i32 {\
+                              \n    var mut res = a + b * 2\
+                              \n    res += foo(x: 1, 2)\
+                              \n    if res >= 10 && res != 20 {\
+                              \n        return res as i32\
+                              \n    } else {\
+                              \n        return -res\
+                              \n    }\
+                              \n}\n";
+
+        assert_eq!(res, EXPECTED);
+        assert_eq!(format(&res), res);
+    }
+
+    #[test]
+    fn format_comments_test() {
+        // Given
+        const SRC_TEXT: &str = "# define func\
+                              \nfunc loop_func() {   \
+                              \n    var mut a = 0\
+                              \n    loop { # loop statement\
+                              \n        # increase counter\
+                              \n        a += 1   # by one\
+                              \n        # nothing else\
+                              \n    }\
+                              \n    loop {\
+                              \n        # empty\
+                              \n    }\
+                              \n} # end of loop_func\
+                              \n# the last one\n";
+
+        // When
+        let res = format(SRC_TEXT);
+
+        // Then
+        const EXPECTED: &str = "# define func\
+                              \nfunc loop_func() {\
+                              \n    var mut a = 0\
+                              \n    loop { # loop statement\
+                              \n        # increase counter\
+                              \n        a += 1 # by one\
+                              \n        # nothing else\
+                              \n    }\
+                              \n    loop {\
+                              \n        # empty\
+                              \n    }\
+                              \n} # end of loop_func\
+                              \n# the last one\n";
+
+        assert_eq!(res, EXPECTED);
+        assert_eq!(format(&res), res);
+    }
+
+    #[test]
+    fn format_blank_lines_test() {
+        // Given
+        const SRC_TEXT: &str = "\n\
+                              \nstruct S1\
+                              \n{\
+                              \n\
+                              \n    f1: i32\
+                              \n\
+                              \n\
+                              \n    f2: i32\
+                              \n\
+                              \n}\
+                              \n\
+                              \n\
+                              \n# comment\
+                              \n\
+                              \nfunc main() {\
+                              \n    var a = 1\
+                              \n\
+                              \n    var b = 2\
+                              \n}\n\n";
+
+        // When
+        let res = format(SRC_TEXT);
+
+        // Then
+        const EXPECTED: &str = "struct S1 {\
+                              \n    f1: i32\
+                              \n\
+                              \n    f2: i32\
+                              \n}\
+                              \n\
+                              \n# comment\
+                              \n\
+                              \nfunc main() {\
+                              \n    var a = 1\
+                              \n\
+                              \n    var b = 2\
+                              \n}\n";
+
+        assert_eq!(res, EXPECTED);
+        assert_eq!(format(&res), res);
+    }
+
+    #[test]
+    fn format_syntax_error_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                              \n    var a: = 1\
+                              \n}";
+
+        // When
+        let res = format_source(Path::new("test.tt"), SRC_TEXT);
+
+        // Then
+        let messages = res.expect_err("Expected errors");
+        assert!(messages.has_errors());
+    }
+}
@@ -0,0 +1,71 @@
+use tanitc_ast::program_ctx::{
+    statement_ctx::{block_ctx::BlockCtx, branch_ctx::BranchCtx, StatementCtx, StatementsCtx},
+    ProgramCtx,
+};
+
+use crate::Formatter;
+
+pub(crate) mod name_ctx;
+pub(crate) mod statement_ctx;
+pub(crate) mod type_ctx;
+
+impl Formatter<'_> {
+    pub fn format_program_ctx(&mut self, program_ctx: &ProgramCtx) {
+        self.format_statements_ctx(&program_ctx.statements_ctx);
+        self.flush_comments();
+        self.new_line();
+    }
+
+    pub(crate) fn format_statements_ctx(&mut self, statements_ctx: &StatementsCtx) {
+        let statements: Vec<&StatementCtx> = statements_ctx
+            .statements
+            .iter()
+            .filter_map(|(statement_ctx, _)| statement_ctx.as_ref())
+            .collect();
+
+        let mut index = 0;
+        while index < statements.len() {
+            // "else" continues the line of the previous "}"
+            if let StatementCtx::Branch(BranchCtx::Else(else_ctx)) = statements[index] {
+                if index > 0 && !self.has_comment_before(&else_ctx.else_tkn) {
+                    self.space();
+                    self.format_else_ctx(else_ctx);
+                    index += 1;
+                    continue;
+                }
+            }
+
+            let use_run = self.use_run(&statements[index..]);
+            if use_run.len() > 1 {
+                self.format_use_run(&use_run);
+                index += use_run.len();
+                continue;
+            }
+
+            self.new_line();
+            self.item_start = true;
+            self.format_statement_ctx(statements[index]);
+            index += 1;
+        }
+
+        self.new_line();
+    }
+
+    pub(crate) fn format_block_ctx(&mut self, block_ctx: &BlockCtx) {
+        let is_empty = block_ctx
+            .statements_ctx
+            .statements
+            .iter()
+            .all(|(statement_ctx, _)| statement_ctx.is_none());
+
+        if is_empty && !self.has_comment_before(&block_ctx.rcb_tkn) {
+            self.token(&block_ctx.lcb_tkn);
+            self.token(&block_ctx.rcb_tkn);
+            return;
+        }
+
+        self.open_body(&block_ctx.lcb_tkn);
+        self.format_statements_ctx(&block_ctx.statements_ctx);
+        self.close_body(&block_ctx.rcb_tkn);
+    }
+}
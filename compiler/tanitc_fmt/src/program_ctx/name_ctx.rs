@@ -0,0 +1,19 @@
+use tanitc_ast::program_ctx::name_ctx::{NameCtx, NameSpecCtx};
+
+use crate::Formatter;
+
+impl Formatter<'_> {
+    pub(crate) fn format_name_ctx(&mut self, name_ctx: &NameCtx) {
+        self.token(&name_ctx.name_tkn);
+    }
+
+    pub(crate) fn format_name_spec_ctx(&mut self, name_spec_ctx: &NameSpecCtx) {
+        for (name_tkn, dcolon_tkn) in name_spec_ctx.names.iter() {
+            self.token(name_tkn);
+
+            if let Some(dcolon_tkn) = dcolon_tkn {
+                self.token(dcolon_tkn);
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use tanitc_ast::program_ctx::statement_ctx::attributes_ctx::{AttributesCtx, MetaItemCtx};
+
+use crate::Formatter;
+
+impl Formatter<'_> {
+    // "#[...]" are written on their own lines, then "pub safe unsafe" in this order
+    pub(crate) fn format_attributes_ctx(&mut self, attributes_ctx: &AttributesCtx) {
+        for attribute_ctx in attributes_ctx.attributes.iter() {
+            self.token(&attribute_ctx.hash_lsb_tkn);
+            self.format_meta_item_ctx(&attribute_ctx.meta_ctx);
+            self.token(&attribute_ctx.rsb_tkn);
+            self.new_line();
+        }
+
+        let keywords = [
+            &attributes_ctx.pub_tkn,
+            &attributes_ctx.safe_tkn,
+            &attributes_ctx.unsafe_tkn,
+        ];
+
+        for keyword_tkn in keywords.into_iter().flatten() {
+            self.token(keyword_tkn);
+            self.space();
+        }
+    }
+
+    fn format_meta_item_ctx(&mut self, meta_ctx: &MetaItemCtx) {
+        match meta_ctx {
+            MetaItemCtx::Word(name_ctx) => self.format_name_ctx(name_ctx),
+            MetaItemCtx::NameValue {
+                name_ctx,
+                assign_tkn,
+                value_tkn,
+            } => {
+                self.format_name_ctx(name_ctx);
+                self.space();
+                self.token(assign_tkn);
+                self.space();
+                self.token(value_tkn);
+            }
+            MetaItemCtx::List { name_ctx, list_ctx } => {
+                self.format_name_ctx(name_ctx);
+                self.token(&list_ctx.lparen_tkn);
+                for (index, (item_ctx, _)) in list_ctx.items.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.format_meta_item_ctx(item_ctx);
+                }
+                self.token(&list_ctx.rparen_tkn);
+            }
+            MetaItemCtx::Literal(value_tkn) => self.token(value_tkn),
+        }
+    }
+}
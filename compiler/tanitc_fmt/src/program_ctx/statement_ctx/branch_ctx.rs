@@ -0,0 +1,46 @@
+use tanitc_ast::program_ctx::statement_ctx::branch_ctx::{
+    else_ctx::{ElseBodyCtx, ElseCtx},
+    if_ctx::IfCtx,
+    BranchCtx,
+};
+
+use crate::Formatter;
+
+impl Formatter<'_> {
+    pub(crate) fn format_branch_ctx(&mut self, branch_ctx: &BranchCtx) {
+        match branch_ctx {
+            BranchCtx::If(if_ctx) => self.format_if_ctx(if_ctx),
+            BranchCtx::Else(else_ctx) => self.format_else_ctx(else_ctx),
+            BranchCtx::Loop(loop_ctx) => {
+                self.token(&loop_ctx.loop_tkn);
+                self.space();
+                self.format_block_ctx(&loop_ctx.block_ctx);
+            }
+            BranchCtx::While(while_ctx) => {
+                self.token(&while_ctx.while_tkn);
+                self.space();
+                self.format_expression_ctx(&while_ctx.expression_ctx);
+                self.space();
+                self.format_block_ctx(&while_ctx.block_ctx);
+            }
+        }
+    }
+
+    fn format_if_ctx(&mut self, if_ctx: &IfCtx) {
+        self.token(&if_ctx.if_tkn);
+        self.space();
+        self.format_expression_ctx(&if_ctx.expression_ctx);
+        self.space();
+        self.format_block_ctx(&if_ctx.block_ctx);
+    }
+
+    pub(crate) fn format_else_ctx(&mut self, else_ctx: &ElseCtx) {
+        self.token(&else_ctx.else_tkn);
+        self.space();
+
+        match &else_ctx.body_ctx {
+            ElseBodyCtx::Block(block_ctx) => self.format_block_ctx(block_ctx),
+            ElseBodyCtx::If(if_ctx) => self.format_if_ctx(if_ctx),
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use tanitc_ast::program_ctx::statement_ctx::control_flow_ctx::ControlFlowCtx;
+
+use crate::Formatter;
+
+impl Formatter<'_> {
+    pub(crate) fn format_control_flow_ctx(&mut self, control_flow_ctx: &ControlFlowCtx) {
+        match control_flow_ctx {
+            ControlFlowCtx::Return(return_ctx) => {
+                self.token(&return_ctx.return_tkn);
+                if let Some(expression_ctx) = &return_ctx.return_expression_ctx {
+                    self.space();
+                    self.format_expression_ctx(expression_ctx);
+                }
+            }
+            ControlFlowCtx::Break(break_ctx) => self.token(&break_ctx.break_tkn),
+            ControlFlowCtx::Continue(continue_ctx) => self.token(&continue_ctx.continue_tkn),
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use tanitc_ast::program_ctx::statement_ctx::definition_ctx::{
+    alias_def_ctx::AliasDefCtx,
+    const_def_ctx::ConstDefCtx,
+    enum_def_ctx::{EnumDefCtx, EnumDefUnitCtx},
+    extern_ctx::ExternCtx,
+    func_def_ctx::{FuncDefCtx, FuncDefParamKindCtx},
+    impl_def_ctx::ImplDefCtx,
+    module_def_ctx::{ModuleDefBodyCtx, ModuleDefCtx},
+    static_def_ctx::StaticDefCtx,
+    struct_def_ctx::{StructDefBodyCtx, StructDefCtx, StructDefFieldCtx},
+    union_def_ctx::{UnionDefCtx, UnionDefFieldCtx},
+    var_def_ctx::VarDefCtx,
+    variant_def_ctx::{VariantDefCtx, VariantDefFieldCtx},
+    DefinitionCtx,
+};
+
+use crate::Formatter;
+
+impl Formatter<'_> {
+    pub(crate) fn format_definition_ctx(&mut self, definition_ctx: &DefinitionCtx) {
+        self.format_attributes_ctx(definition_ctx.attributes_ctx());
+
+        match definition_ctx {
+            DefinitionCtx::Alias(alias_ctx) => self.format_alias_def_ctx(alias_ctx),
+            DefinitionCtx::Const(const_ctx) => self.format_const_def_ctx(const_ctx),
+            DefinitionCtx::Enum(enum_ctx) => self.format_enum_def_ctx(enum_ctx),
+            DefinitionCtx::Func(func_ctx) => self.format_func_def_ctx(func_ctx),
+            DefinitionCtx::Module(module_ctx) => self.format_module_def_ctx(module_ctx),
+            DefinitionCtx::Static(static_ctx) => self.format_static_def_ctx(static_ctx),
+            DefinitionCtx::Struct(struct_ctx) => self.format_struct_def_ctx(struct_ctx),
+            DefinitionCtx::Union(union_ctx) => self.format_union_def_ctx(union_ctx),
+            DefinitionCtx::Variable(var_ctx) => self.format_var_def_ctx(var_ctx),
+            DefinitionCtx::Variant(variant_ctx) => self.format_variant_def_ctx(variant_ctx),
+            DefinitionCtx::Impl(impl_ctx) => self.format_impl_def_ctx(impl_ctx),
+            DefinitionCtx::Extern(extern_ctx) => self.format_extern_ctx(extern_ctx),
+        }
+    }
+
+    fn format_alias_def_ctx(&mut self, alias_ctx: &AliasDefCtx) {
+        self.token(&alias_ctx.alias_tkn);
+        self.space();
+        self.format_name_ctx(&alias_ctx.name_ctx);
+        self.space();
+        self.token(&alias_ctx.assign_tkn);
+        self.space();
+        self.format_type_ctx(&alias_ctx.type_ctx);
+    }
+
+    fn format_const_def_ctx(&mut self, const_ctx: &ConstDefCtx) {
+        self.token(&const_ctx.const_tkn);
+        self.space();
+        self.format_name_ctx(&const_ctx.name_ctx);
+        self.token(&const_ctx.type_ctx.colon_tkn);
+        self.space();
+        self.format_type_ctx(&const_ctx.type_ctx.type_ctx);
+        self.space();
+        self.token(&const_ctx.value_ctx.equal_tkn);
+        self.space();
+        self.format_expression_ctx(&const_ctx.value_ctx.value_ctx);
+    }
+
+    fn format_enum_def_ctx(&mut self, enum_ctx: &EnumDefCtx) {
+        self.token(&enum_ctx.enum_tkn);
+        self.space();
+        self.format_name_ctx(&enum_ctx.name_ctx);
+        if let Some(repr_ctx) = &enum_ctx.repr_ctx {
+            self.token(&repr_ctx.colon_tkn);
+            self.space();
+            self.format_type_ctx(&repr_ctx.type_ctx);
+        }
+        self.space();
+
+        let body_ctx = &enum_ctx.body_ctx;
+        self.format_items(
+            &body_ctx.lcb_tkn,
+            &body_ctx.units_ctx,
+            &body_ctx.rcb_tkn,
+            Self::format_enum_def_unit_ctx,
+        );
+    }
+
+    fn format_enum_def_unit_ctx(&mut self, unit_ctx: &EnumDefUnitCtx) {
+        self.format_name_ctx(&unit_ctx.name_ctx);
+        if let Some(assign_ctx) = &unit_ctx.assign_ctx {
+            self.token(&assign_ctx.colon_tkn);
+            self.space();
+            self.token(&assign_ctx.value_tkn);
+        }
+    }
+
+    fn format_func_def_ctx(&mut self, func_ctx: &FuncDefCtx) {
+        if let Some(abi_ctx) = &func_ctx.abi_ctx {
+            self.token(&abi_ctx.extern_tkn);
+            self.space();
+            self.token(&abi_ctx.abi_tkn);
+            self.space();
+        }
+
+        self.token(&func_ctx.func_tkn);
+        self.space();
+        self.format_name_ctx(&func_ctx.name_ctx);
+
+        let params_ctx = &func_ctx.params_ctx;
+        self.token(&params_ctx.lparen_tkn);
+        for (index, param_ctx) in params_ctx.params_ctx.iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.format_func_def_param_kind_ctx(&param_ctx.param_ctx);
+        }
+        if let Some(ellipsis_tkn) = &params_ctx.ellipsis_tkn {
+            if !params_ctx.params_ctx.is_empty() {
+                self.write(", ");
+            }
+            self.token(ellipsis_tkn);
+        }
+        self.token(&params_ctx.rparen_tkn);
+
+        if let Some(return_type_ctx) = &func_ctx.return_type_ctx {
+            self.format_return_type_ctx(return_type_ctx);
+        }
+
+        if let Some(body_ctx) = &func_ctx.body_ctx {
+            self.space();
+            self.format_block_ctx(body_ctx);
+        }
+    }
+
+    fn format_func_def_param_kind_ctx(&mut self, param_ctx: &FuncDefParamKindCtx) {
+        match param_ctx {
+            FuncDefParamKindCtx::CommonParam(common_ctx) => {
+                if let Some(mut_tkn) = &common_ctx.mut_tkn {
+                    self.token(mut_tkn);
+                    self.space();
+                }
+                self.format_name_ctx(&common_ctx.name_ctx);
+                self.token(&common_ctx.colon_tkn);
+                self.space();
+                self.format_type_ctx(&common_ctx.type_ctx);
+            }
+            FuncDefParamKindCtx::SelfRef(self_ref_ctx) => {
+                self.token(&self_ref_ctx.ampersand_tkn);
+                if let Some(mut_tkn) = &self_ref_ctx.mut_tkn {
+                    self.token(mut_tkn);
+                    self.space();
+                }
+                self.token(&self_ref_ctx.self_tkn);
+            }
+            FuncDefParamKindCtx::SelfVal(self_val_ctx) => {
+                if let Some(mut_tkn) = &self_val_ctx.mut_tkn {
+                    self.token(mut_tkn);
+                    self.space();
+                }
+                self.token(&self_val_ctx.self_tkn);
+            }
+        }
+    }
+
+    fn format_module_def_ctx(&mut self, module_ctx: &ModuleDefCtx) {
+        if let Some(def_tkn) = &module_ctx.def_tkn {
+            self.token(def_tkn);
+            self.space();
+        }
+
+        self.token(&module_ctx.module_tkn);
+        self.space();
+        self.format_name_ctx(&module_ctx.name_ctx);
+
+        // Body of "def module" lives in its own file
+        if let ModuleDefBodyCtx::Internal(block_ctx) = &module_ctx.body_ctx {
+            self.space();
+            self.format_block_ctx(block_ctx);
+        }
+    }
+
+    fn format_static_def_ctx(&mut self, static_ctx: &StaticDefCtx) {
+        self.token(&static_ctx.static_tkn);
+        self.space();
+        if let Some(mut_tkn) = &static_ctx.mut_tkn {
+            self.token(mut_tkn);
+            self.space();
+        }
+        self.format_name_ctx(&static_ctx.name_ctx);
+        self.token(&static_ctx.type_ctx.colon_tkn);
+        self.space();
+        self.format_type_ctx(&static_ctx.type_ctx.type_ctx);
+
+        if let Some(value_ctx) = &static_ctx.value_ctx {
+            self.space();
+            self.token(&value_ctx.equal_tkn);
+            self.space();
+            self.format_expression_ctx(&value_ctx.value_ctx);
+        }
+    }
+
+    fn format_struct_def_ctx(&mut self, struct_ctx: &StructDefCtx) {
+        self.token(&struct_ctx.struct_tkn);
+        self.space();
+        self.format_name_ctx(&struct_ctx.name_ctx);
+        self.space();
+        self.format_struct_def_body_ctx(&struct_ctx.body_ctx);
+    }
+
+    fn format_struct_def_body_ctx(&mut self, body_ctx: &StructDefBodyCtx) {
+        self.format_items(
+            &body_ctx.lcb_tkn,
+            &body_ctx.fields_ctx,
+            &body_ctx.rcb_tkn,
+            Self::format_struct_def_field_ctx,
+        );
+    }
+
+    fn format_struct_def_field_ctx(&mut self, field_ctx: &StructDefFieldCtx) {
+        if let Some(pub_tkn) = &field_ctx.pub_tkn {
+            self.token(pub_tkn);
+            self.space();
+        }
+        self.format_name_ctx(&field_ctx.name_ctx);
+        self.token(&field_ctx.colon_tkn);
+        self.space();
+        self.format_type_ctx(&field_ctx.type_ctx);
+    }
+
+    fn format_union_def_ctx(&mut self, union_ctx: &UnionDefCtx) {
+        self.token(&union_ctx.union_tkn);
+        self.space();
+        self.format_name_ctx(&union_ctx.name_ctx);
+        self.space();
+
+        let body_ctx = &union_ctx.body_ctx;
+        self.format_items(
+            &body_ctx.lcb_tkn,
+            &body_ctx.fields_ctx,
+            &body_ctx.rcb_tkn,
+            Self::format_union_def_field_ctx,
+        );
+    }
+
+    fn format_union_def_field_ctx(&mut self, field_ctx: &UnionDefFieldCtx) {
+        if let Some(pub_tkn) = &field_ctx.pub_tkn {
+            self.token(pub_tkn);
+            self.space();
+        }
+        self.format_name_ctx(&field_ctx.name_ctx);
+        self.token(&field_ctx.colon_tkn);
+        self.space();
+        self.format_type_ctx(&field_ctx.type_ctx);
+    }
+
+    fn format_var_def_ctx(&mut self, var_ctx: &VarDefCtx) {
+        self.token(&var_ctx.var_tkn);
+        self.space();
+        if let Some(mut_tkn) = &var_ctx.mut_tkn {
+            self.token(mut_tkn);
+            self.space();
+        }
+        self.format_name_ctx(&var_ctx.name_ctx);
+
+        if let Some(type_ctx) = &var_ctx.type_ctx {
+            self.token(&type_ctx.colon_tkn);
+            self.space();
+            self.format_type_ctx(&type_ctx.type_ctx);
+        }
+
+        if let Some(value_ctx) = &var_ctx.value_ctx {
+            self.space();
+            self.token(&value_ctx.equal_tkn);
+            self.space();
+            self.format_expression_ctx(&value_ctx.value_ctx);
+        }
+    }
+
+    fn format_variant_def_ctx(&mut self, variant_ctx: &VariantDefCtx) {
+        self.token(&variant_ctx.variant_tkn);
+        self.space();
+        self.format_name_ctx(&variant_ctx.name_ctx);
+        self.space();
+
+        let body_ctx = &variant_ctx.body_ctx;
+        self.format_items(
+            &body_ctx.lcb_tkn,
+            &body_ctx.fields_ctx,
+            &body_ctx.rcb_tkn,
+            Self::format_variant_def_field_ctx,
+        );
+    }
+
+    fn format_variant_def_field_ctx(&mut self, field_ctx: &VariantDefFieldCtx) {
+        match field_ctx {
+            VariantDefFieldCtx::Enum(enum_ctx) => self.format_name_ctx(&enum_ctx.name_ctx),
+            VariantDefFieldCtx::Struct(struct_ctx) => {
+                self.format_name_ctx(&struct_ctx.name_ctx);
+                self.space();
+                self.format_struct_def_body_ctx(&struct_ctx.struct_body_ctx);
+            }
+            VariantDefFieldCtx::Tuple(tuple_ctx) => {
+                self.format_name_ctx(&tuple_ctx.name_ctx);
+                self.format_tuple_type_ctx(&tuple_ctx.tuple_type_ctx);
+            }
+        }
+    }
+
+    fn format_impl_def_ctx(&mut self, impl_ctx: &ImplDefCtx) {
+        self.token(&impl_ctx.impl_tkn);
+        self.space();
+        self.format_name_ctx(&impl_ctx.name_ctx);
+        self.space();
+        self.format_block_ctx(&impl_ctx.body_ctx.block_ctx);
+    }
+
+    fn format_extern_ctx(&mut self, extern_ctx: &ExternCtx) {
+        self.token(&extern_ctx.extern_tkn);
+        self.space();
+        self.token(&extern_ctx.abi_tkn);
+        self.space();
+        self.format_block_ctx(&extern_ctx.body_ctx);
+    }
+}
@@ -0,0 +1,207 @@
+use tanitc_ast::program_ctx::statement_ctx::expression_ctx::{
+    binary_ctx::{BinaryCtx, BinaryOpCtx},
+    call_ctx::{CallCtx, CallParamCtx},
+    literal_ctx::{struct_literal_ctx::StructFieldLiteralCtx, LiteralCtx},
+    unary_ctx::{UnaryCtx, UnaryOpCtx},
+    ExpressionCtx,
+};
+use tanitc_lexer::token::Token;
+
+use crate::Formatter;
+
+impl Formatter<'_> {
+    pub(crate) fn format_expression_ctx(&mut self, expression_ctx: &ExpressionCtx) {
+        match expression_ctx {
+            ExpressionCtx::Binary(binary_ctx) => self.format_binary_ctx(binary_ctx),
+            ExpressionCtx::Unary(unary_ctx) => self.format_unary_ctx(unary_ctx),
+            ExpressionCtx::Conversion(conversion_ctx) => {
+                self.format_expression_ctx(&conversion_ctx.expression_ctx);
+                self.space();
+                self.token(&conversion_ctx.as_tkn);
+                self.space();
+                self.format_type_ctx(&conversion_ctx.type_ctx);
+            }
+            ExpressionCtx::Call(call_ctx) => self.format_call_ctx(call_ctx),
+            ExpressionCtx::ParenCtx(paren_ctx) => {
+                self.token(&paren_ctx.lparen_tkn);
+                self.format_expression_ctx(&paren_ctx.expression_ctx);
+                self.token(&paren_ctx.rparen_tkn);
+            }
+            ExpressionCtx::Indexing(indexing_ctx) => {
+                self.format_expression_ctx(&indexing_ctx.expression_ctx);
+                self.token(&indexing_ctx.lsb_tkn);
+                self.format_expression_ctx(&indexing_ctx.index_ctx.expression_ctx);
+                self.token(&indexing_ctx.rsb_tkn);
+            }
+            ExpressionCtx::MemberAccess(member_access_ctx) => {
+                self.format_expression_ctx(&member_access_ctx.expression_ctx);
+                self.token(&member_access_ctx.dot_tkn);
+                self.token(&member_access_ctx.id_tkn);
+            }
+            ExpressionCtx::Literal(literal_ctx) => self.format_literal_ctx(literal_ctx),
+            ExpressionCtx::Variable(name_spec_ctx) => self.format_name_spec_ctx(name_spec_ctx),
+        }
+    }
+
+    // Operator is taken from its token, the context kind isn't always precise
+    fn format_binary_ctx(&mut self, binary_ctx: &BinaryCtx) {
+        let (op_tkn, is_spaced) = match &binary_ctx.binary_op_ctx {
+            BinaryOpCtx::Access(op_tkn) | BinaryOpCtx::ScopeRes(op_tkn) => (op_tkn, false),
+            BinaryOpCtx::Add(op_tkn)
+            | BinaryOpCtx::Sub(op_tkn)
+            | BinaryOpCtx::Mul(op_tkn)
+            | BinaryOpCtx::Div(op_tkn)
+            | BinaryOpCtx::Mod(op_tkn)
+            | BinaryOpCtx::BitAnd(op_tkn)
+            | BinaryOpCtx::LogicAnd(op_tkn)
+            | BinaryOpCtx::BitOr(op_tkn)
+            | BinaryOpCtx::LogicOr(op_tkn)
+            | BinaryOpCtx::BitXor(op_tkn)
+            | BinaryOpCtx::Eq(op_tkn)
+            | BinaryOpCtx::Ne(op_tkn)
+            | BinaryOpCtx::Lt(op_tkn)
+            | BinaryOpCtx::Le(op_tkn)
+            | BinaryOpCtx::Gt(op_tkn)
+            | BinaryOpCtx::Ge(op_tkn)
+            | BinaryOpCtx::Shl(op_tkn)
+            | BinaryOpCtx::Shr(op_tkn)
+            | BinaryOpCtx::Assign(op_tkn)
+            | BinaryOpCtx::AddAssign(op_tkn)
+            | BinaryOpCtx::SubAssign(op_tkn)
+            | BinaryOpCtx::MulAssign(op_tkn)
+            | BinaryOpCtx::DivAssign(op_tkn)
+            | BinaryOpCtx::ModAssign(op_tkn)
+            | BinaryOpCtx::BitAndAssign(op_tkn)
+            | BinaryOpCtx::BitOrAssign(op_tkn)
+            | BinaryOpCtx::BitXorAssign(op_tkn)
+            | BinaryOpCtx::LeftShiftAssign(op_tkn)
+            | BinaryOpCtx::RightShiftAssign(op_tkn) => (op_tkn, true),
+        };
+
+        self.format_expression_ctx(&binary_ctx.left_ctx);
+        if is_spaced {
+            self.space();
+            self.token(op_tkn);
+            self.space();
+        } else {
+            self.token(op_tkn);
+        }
+        self.format_expression_ctx(&binary_ctx.right_ctx);
+    }
+
+    fn format_unary_ctx(&mut self, unary_ctx: &UnaryCtx) {
+        match &unary_ctx.unary_op_ctx {
+            UnaryOpCtx::Add(op_tkn) | UnaryOpCtx::Sub(op_tkn) => self.token(op_tkn),
+            UnaryOpCtx::Ref(ampersand_tkn, mut_tkn) => {
+                self.token(ampersand_tkn);
+                if let Some(mut_tkn) = mut_tkn {
+                    self.token(mut_tkn);
+                    self.space();
+                }
+            }
+        }
+
+        self.format_expression_ctx(&unary_ctx.expression_ctx);
+    }
+
+    fn format_call_ctx(&mut self, call_ctx: &CallCtx) {
+        self.format_expression_ctx(&call_ctx.expression_ctx);
+        self.token(&call_ctx.lparen_tkn);
+
+        let params = call_ctx.params_ctx.params.iter();
+        for (index, param_ctx) in params
+            .filter_map(|(param_ctx, _)| param_ctx.as_ref())
+            .enumerate()
+        {
+            if index > 0 {
+                self.write(", ");
+            }
+
+            match param_ctx {
+                CallParamCtx::Named(named_ctx) => {
+                    self.format_name_ctx(&named_ctx.name_ctx);
+                    self.token(&named_ctx.colon_tkn);
+                    self.space();
+                    self.format_expression_ctx(&named_ctx.expression_ctx);
+                }
+                CallParamCtx::Positional(positional_ctx) => {
+                    self.format_expression_ctx(&positional_ctx.expression_ctx)
+                }
+            }
+        }
+
+        self.token(&call_ctx.rparen_tkn);
+    }
+
+    fn format_literal_ctx(&mut self, literal_ctx: &LiteralCtx) {
+        match literal_ctx {
+            LiteralCtx::Integer(value_tkn)
+            | LiteralCtx::Decimal(value_tkn)
+            | LiteralCtx::Text(value_tkn) => self.token(value_tkn),
+            LiteralCtx::Array(array_ctx) => {
+                self.format_elements(&array_ctx.lsb_tkn, &array_ctx.elements, &array_ctx.rsb_tkn)
+            }
+            LiteralCtx::Tuple(tuple_ctx) => self.format_elements(
+                &tuple_ctx.lparen_tkn,
+                &tuple_ctx.elements,
+                &tuple_ctx.rparen_tkn,
+            ),
+            LiteralCtx::Struct(struct_ctx) => {
+                self.format_name_spec_ctx(&struct_ctx.name_ctx);
+                self.space();
+
+                // Fields aren't separated by commas, so only a single one fits a line
+                let mut fields = struct_ctx
+                    .elements
+                    .iter()
+                    .filter_map(|(field, _)| field.as_ref());
+                if let (Some(field_ctx), None) = (fields.next(), fields.next()) {
+                    if !self.has_comment_before(&struct_ctx.rcb_tkn) {
+                        self.token(&struct_ctx.lcb_tkn);
+                        self.space();
+                        self.format_struct_field_literal_ctx(field_ctx);
+                        self.space();
+                        self.token(&struct_ctx.rcb_tkn);
+                        return;
+                    }
+                }
+
+                self.format_items(
+                    &struct_ctx.lcb_tkn,
+                    &struct_ctx.elements,
+                    &struct_ctx.rcb_tkn,
+                    Self::format_struct_field_literal_ctx,
+                );
+            }
+        }
+    }
+
+    fn format_elements(
+        &mut self,
+        open_tkn: &Token,
+        elements: &[(Option<ExpressionCtx>, Option<Token>)],
+        close_tkn: &Token,
+    ) {
+        self.token(open_tkn);
+
+        let elements = elements.iter();
+        for (index, element_ctx) in elements
+            .filter_map(|(element, _)| element.as_ref())
+            .enumerate()
+        {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.format_expression_ctx(element_ctx);
+        }
+
+        self.token(close_tkn);
+    }
+
+    fn format_struct_field_literal_ctx(&mut self, field_ctx: &StructFieldLiteralCtx) {
+        self.format_name_ctx(&field_ctx.name_ctx);
+        self.token(&field_ctx.colon_tkn);
+        self.space();
+        self.format_expression_ctx(&field_ctx.expression_ctx);
+    }
+}
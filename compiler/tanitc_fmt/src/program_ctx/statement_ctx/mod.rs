@@ -0,0 +1,32 @@
+use tanitc_ast::program_ctx::statement_ctx::StatementCtx;
+
+use crate::Formatter;
+
+pub(crate) mod attributes_ctx;
+pub(crate) mod branch_ctx;
+pub(crate) mod control_flow_ctx;
+pub(crate) mod definition_ctx;
+pub(crate) mod expression_ctx;
+pub(crate) mod use_ctx;
+
+impl Formatter<'_> {
+    pub(crate) fn format_statement_ctx(&mut self, statement_ctx: &StatementCtx) {
+        match statement_ctx {
+            StatementCtx::ControlFlow(control_flow_ctx) => {
+                self.format_control_flow_ctx(control_flow_ctx)
+            }
+            StatementCtx::Definition(definition_ctx) => self.format_definition_ctx(definition_ctx),
+            StatementCtx::Branch(branch_ctx) => self.format_branch_ctx(branch_ctx),
+            StatementCtx::Block(block_ctx) => {
+                self.format_attributes_ctx(&block_ctx.attributes_ctx);
+                self.format_block_ctx(block_ctx);
+            }
+            StatementCtx::Expression(expression_ctx) => self.format_expression_ctx(expression_ctx),
+            StatementCtx::Use(use_ctx) => self.format_use_ctx(use_ctx),
+            StatementCtx::Attributed(attributed_ctx) => {
+                self.format_attributes_ctx(&attributed_ctx.attributes_ctx);
+                self.format_statement_ctx(&attributed_ctx.statement_ctx);
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use tanitc_ast::program_ctx::statement_ctx::{
+    use_ctx::{UseCtx, UseTailCtx, UseTreeCtx},
+    StatementCtx,
+};
+use tanitc_lexer::token::lexeme::Lexeme;
+
+use crate::Formatter;
+
+impl Formatter<'_> {
+    pub(crate) fn format_use_ctx(&mut self, use_ctx: &UseCtx) {
+        self.format_attributes_ctx(&use_ctx.attributes_ctx);
+        self.token(&use_ctx.use_tkn);
+        self.space();
+
+        // Tree is written at once, since groups are sorted
+        self.write(&Self::use_tree_text(&use_ctx.tree_ctx));
+        self.last_row = Some(Self::use_last_row(use_ctx));
+    }
+
+    // Consecutive "use" lines without attributes, blank lines and comments between
+    // them are sorted together
+    pub(crate) fn use_run<'s>(&self, statements: &[&'s StatementCtx]) -> Vec<&'s UseCtx> {
+        let mut run = Vec::<&UseCtx>::new();
+
+        for statement_ctx in statements.iter() {
+            let StatementCtx::Use(use_ctx) = statement_ctx else {
+                break;
+            };
+
+            if !use_ctx.attributes_ctx.attributes.is_empty() {
+                break;
+            }
+
+            let first_row = Self::use_first_row(use_ctx);
+            if run
+                .last()
+                .is_some_and(|prev_ctx| Self::use_last_row(prev_ctx) + 1 != first_row)
+            {
+                break;
+            }
+
+            let last_row = Self::use_last_row(use_ctx);
+            let has_comments = self.comments[self.next_comment..]
+                .iter()
+                .any(|comment| (first_row..=last_row).contains(&comment.location.row));
+            if has_comments {
+                break;
+            }
+
+            run.push(use_ctx);
+        }
+
+        run
+    }
+
+    pub(crate) fn format_use_run(&mut self, run: &[&UseCtx]) {
+        let first_tkn = run[0]
+            .attributes_ctx
+            .pub_tkn
+            .as_ref()
+            .unwrap_or(&run[0].use_tkn);
+
+        self.new_line();
+        self.flush_comments_before(&first_tkn.get_location());
+        self.blank_line_before(first_tkn.get_location().row);
+
+        let mut sorted = run.to_vec();
+        sorted.sort_by_cached_key(|use_ctx| Self::use_tree_text(&use_ctx.tree_ctx));
+
+        for use_ctx in sorted {
+            self.new_line();
+            self.format_use_ctx(use_ctx);
+        }
+    }
+
+    fn use_tree_text(tree_ctx: &UseTreeCtx) -> String {
+        let mut text = String::new();
+
+        for (name_tkn, dcolon_tkn) in tree_ctx.name_spec_ctx.names.iter() {
+            text.push_str(&Self::token_text(name_tkn));
+
+            if dcolon_tkn.is_some() {
+                text.push_str("::");
+            }
+        }
+
+        match &tree_ctx.tail_ctx {
+            Some(UseTailCtx::Alias(alias_ctx)) => {
+                text.push_str(" as ");
+                text.push_str(&Self::token_text(&alias_ctx.name_ctx.name_tkn));
+            }
+            Some(UseTailCtx::Glob(_)) => text.push('*'),
+            Some(UseTailCtx::Group(group_ctx)) => {
+                // "self" goes first, the rest is sorted by the text
+                let mut trees: Vec<(bool, String)> = group_ctx
+                    .trees
+                    .iter()
+                    .map(|(tree_ctx, _)| {
+                        (!Self::is_self_tree(tree_ctx), Self::use_tree_text(tree_ctx))
+                    })
+                    .collect();
+                trees.sort();
+
+                let trees: Vec<String> = trees.into_iter().map(|(_, text)| text).collect();
+
+                text.push('{');
+                text.push_str(&trees.join(", "));
+                text.push('}');
+            }
+            None => {}
+        }
+
+        text
+    }
+
+    fn is_self_tree(tree_ctx: &UseTreeCtx) -> bool {
+        matches!(
+            tree_ctx.name_spec_ctx.names.as_slice(),
+            [(name_tkn, None)] if *name_tkn.lexeme_ref() == Lexeme::KwSelf
+        )
+    }
+
+    fn use_first_row(use_ctx: &UseCtx) -> usize {
+        let first_tkn = use_ctx
+            .attributes_ctx
+            .pub_tkn
+            .as_ref()
+            .unwrap_or(&use_ctx.use_tkn);
+
+        first_tkn.get_location().row
+    }
+
+    fn use_last_row(use_ctx: &UseCtx) -> usize {
+        let tree_ctx = &use_ctx.tree_ctx;
+
+        let last_tkn = match &tree_ctx.tail_ctx {
+            Some(UseTailCtx::Alias(alias_ctx)) => Some(&alias_ctx.name_ctx.name_tkn),
+            Some(UseTailCtx::Glob(star_tkn)) => Some(star_tkn),
+            Some(UseTailCtx::Group(group_ctx)) => Some(&group_ctx.rcb_tkn),
+            None => tree_ctx
+                .name_spec_ctx
+                .names
+                .last()
+                .map(|(name_tkn, _)| name_tkn),
+        };
+
+        last_tkn.unwrap_or(&use_ctx.use_tkn).get_location().row
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::format_source;
+
+    #[test]
+    fn format_use_sorting_test() {
+        // Given
+        const SRC_TEXT: &str = "use std::io::{write, self, read as r}\
+                              \nuse crate::b::*\
+                              \npub use a::{z, c::{y, x}}\
+                              \n\
+                              \nuse q\
+                              \nuse p # keeps the order\
+                              \n#[cfg(linux)]\
+                              \nuse m\
+                              \nuse l\n";
+
+        // When
+        let res = format_source(Path::new("test.tt"), SRC_TEXT).unwrap();
+
+        // Then
+        const EXPECTED: &str = "pub use a::{c::{x, y}, z}\
+                              \nuse crate::b::*\
+                              \nuse std::io::{self, read as r, write}\
+                              \n\
+                              \nuse q\
+                              \nuse p # keeps the order\
+                              \n#[cfg(linux)]\
+                              \nuse m\
+                              \nuse l\n";
+
+        assert_eq!(res, EXPECTED);
+    }
+}
@@ -0,0 +1,108 @@
+use tanitc_ast::program_ctx::type_ctx::{
+    func_type_ctx::{FuncTypeCtx, FuncTypeReturnTypeCtx},
+    named_type_ctx::NamedTypeCtx,
+    tuple_type_ctx::TupleTypeCtx,
+    TypeCtx,
+};
+
+use crate::Formatter;
+
+impl Formatter<'_> {
+    pub(crate) fn format_type_ctx(&mut self, type_ctx: &TypeCtx) {
+        match type_ctx {
+            TypeCtx::Named(named_ctx) => self.format_named_type_ctx(named_ctx),
+            TypeCtx::Never(never_ctx) => self.token(&never_ctx.excm_tkn),
+            TypeCtx::Ref(ref_ctx) => {
+                self.token(&ref_ctx.ampersand_tkn);
+                if let Some(mut_tkn) = &ref_ctx.mut_tkn {
+                    self.token(mut_tkn);
+                    self.space();
+                }
+                self.format_type_ctx(&ref_ctx.type_ctx);
+            }
+            TypeCtx::Ptr(ptr_ctx) => {
+                self.token(&ptr_ctx.star_tkn);
+                self.token(&ptr_ctx.mut_tkn);
+                self.space();
+                self.format_type_ctx(&ptr_ctx.type_ctx);
+            }
+            TypeCtx::Func(func_ctx) => self.format_func_type_ctx(func_ctx),
+            TypeCtx::Tuple(tuple_ctx) => self.format_tuple_type_ctx(tuple_ctx),
+            TypeCtx::Array(array_ctx) => {
+                self.token(&array_ctx.lsb_tkn);
+                self.format_type_ctx(&array_ctx.type_ctx);
+                if let Some(length_ctx) = &array_ctx.length_ctx {
+                    self.token(&length_ctx.colon_tkn);
+                    self.space();
+                    self.format_expression_ctx(&length_ctx.expression_ctx);
+                }
+                self.token(&array_ctx.rsb_tkn);
+            }
+        }
+    }
+
+    fn format_named_type_ctx(&mut self, named_ctx: &NamedTypeCtx) {
+        self.format_name_ctx(&named_ctx.name_ctx);
+
+        let Some(generic_ctx) = &named_ctx.generic_ctx else {
+            return;
+        };
+
+        self.token(&generic_ctx.lt_tkn);
+        for (index, unit_ctx) in generic_ctx.units_ctx.iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.format_type_ctx(&unit_ctx.type_ctx);
+        }
+        self.token(&generic_ctx.gt_tkn);
+    }
+
+    pub(crate) fn format_tuple_type_ctx(&mut self, tuple_ctx: &TupleTypeCtx) {
+        self.token(&tuple_ctx.lparen_tkn);
+        for (index, unit_ctx) in tuple_ctx.units_ctx.iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.format_type_ctx(&unit_ctx.type_ctx);
+        }
+        self.token(&tuple_ctx.rparen_tkn);
+    }
+
+    fn format_func_type_ctx(&mut self, func_ctx: &FuncTypeCtx) {
+        if let Some(abi_ctx) = &func_ctx.abi_ctx {
+            self.token(&abi_ctx.extern_tkn);
+            self.space();
+            self.token(&abi_ctx.abi_tkn);
+            self.space();
+        }
+
+        self.token(&func_ctx.func_tkn);
+
+        let params_ctx = &func_ctx.params_ctx;
+        self.token(&params_ctx.lparen_tkn);
+        for (index, param_ctx) in params_ctx.parameters.iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.format_type_ctx(&param_ctx.type_ctx);
+        }
+        if let Some(ellipsis_tkn) = &params_ctx.ellipsis_tkn {
+            if !params_ctx.parameters.is_empty() {
+                self.write(", ");
+            }
+            self.token(ellipsis_tkn);
+        }
+        self.token(&params_ctx.rparen_tkn);
+
+        if let Some(return_type) = &func_ctx.return_type {
+            self.format_return_type_ctx(return_type);
+        }
+    }
+
+    pub(crate) fn format_return_type_ctx(&mut self, return_type: &FuncTypeReturnTypeCtx) {
+        self.token(&return_type.colon_tkn);
+        self.space();
+        self.format_type_ctx(&return_type.type_ctx);
+    }
+}
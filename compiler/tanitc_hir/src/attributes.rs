@@ -1,4 +1,9 @@
+use serde::Serialize;
+use std::fmt::Display;
+
 use tanitc_attributes::{Publicity, Safety};
+use tanitc_ident::Ident;
+use tanitc_lexer::location::Location;
 use tanitc_messages::Message;
 
 use crate::{
@@ -18,10 +23,72 @@ use crate::{
     visitor::VisitorMut,
 };
 
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum MetaItem {
+    Word(Ident),
+    NameValue(Ident, String),
+    List(Ident, Vec<MetaItem>),
+    Literal(String),
+}
+
+impl MetaItem {
+    pub fn name(&self) -> Option<Ident> {
+        match self {
+            Self::Word(name) | Self::NameValue(name, _) | Self::List(name, _) => Some(*name),
+            Self::Literal(_) => None,
+        }
+    }
+}
+
+impl Display for MetaItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Word(name) => write!(f, "{name}"),
+            Self::NameValue(name, value) => write!(f, "{name} = \"{value}\""),
+            Self::List(name, items) => {
+                write!(f, "{name}(")?;
+                for (idx, item) in items.iter().enumerate() {
+                    if idx > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, ")")
+            }
+            Self::Literal(value) => write!(f, "\"{value}\""),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Attribute {
+    pub location: Location,
+    pub meta: MetaItem,
+}
+
+impl Attribute {
+    pub fn name(&self) -> Option<Ident> {
+        self.meta.name()
+    }
+}
+
+impl Display for Attribute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#[{}]", self.meta)
+    }
+}
+
+pub fn find_attribute<'a>(attributes: &'a [Attribute], name: &str) -> Option<&'a Attribute> {
+    attributes
+        .iter()
+        .find(|attr| attr.name().is_some_and(|id| id.to_string() == name))
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct ParsedAttributes {
     pub safety: Option<Safety>,
     pub publicity: Option<Publicity>,
+    pub meta: Vec<Attribute>,
 }
 
 pub struct AttributesApply {
@@ -31,10 +98,12 @@ pub struct AttributesApply {
 impl VisitorMut for AttributesApply {
     fn visit_alias_def(&mut self, alias_def: &mut AliasDef) -> Result<(), Message> {
         alias_def.attributes.publicity = self.attrs.publicity.unwrap_or_default();
+        alias_def.attributes.meta = self.attrs.meta.clone();
         Ok(())
     }
 
-    fn visit_use(&mut self, _u: &mut Use) -> Result<(), Message> {
+    fn visit_use(&mut self, u: &mut Use) -> Result<(), Message> {
+        u.attributes.publicity = self.attrs.publicity.unwrap_or_default();
         Ok(())
     }
 
@@ -49,12 +118,14 @@ impl VisitorMut for AttributesApply {
 
     fn visit_enum_def(&mut self, enum_def: &mut EnumDef) -> Result<(), Message> {
         enum_def.attributes.publicity = self.attrs.publicity.unwrap_or_default();
+        enum_def.attributes.meta = self.attrs.meta.clone();
         Ok(())
     }
 
     fn visit_func_def(&mut self, func_def: &mut FunctionDef) -> Result<(), Message> {
         func_def.attributes.safety = self.attrs.safety.unwrap_or_default();
         func_def.attributes.publicity = self.attrs.publicity.unwrap_or_default();
+        func_def.attributes.meta = self.attrs.meta.clone();
         Ok(())
     }
 
@@ -68,6 +139,7 @@ impl VisitorMut for AttributesApply {
 
     fn visit_union_def(&mut self, union_def: &mut UnionDef) -> Result<(), Message> {
         union_def.attributes.publicity = self.attrs.publicity.unwrap_or_default();
+        union_def.attributes.meta = self.attrs.meta.clone();
         Ok(())
     }
 
@@ -78,16 +150,19 @@ impl VisitorMut for AttributesApply {
     fn visit_module_def(&mut self, module_def: &mut ModuleDef) -> Result<(), Message> {
         module_def.attributes.safety = self.attrs.safety.unwrap_or_default();
         module_def.attributes.publicity = self.attrs.publicity.unwrap_or_default();
+        module_def.attributes.meta = self.attrs.meta.clone();
         Ok(())
     }
 
     fn visit_struct_def(&mut self, struct_def: &mut StructDef) -> Result<(), Message> {
         struct_def.attributes.publicity = self.attrs.publicity.unwrap_or_default();
+        struct_def.attributes.meta = self.attrs.meta.clone();
         Ok(())
     }
 
     fn visit_variant_def(&mut self, variant_def: &mut VariantDef) -> Result<(), Message> {
         variant_def.attributes.publicity = self.attrs.publicity.unwrap_or_default();
+        variant_def.attributes.meta = self.attrs.meta.clone();
         Ok(())
     }
 
@@ -1,14 +1,15 @@
+use serde::Serialize;
 use tanitc_attributes::Safety;
 use tanitc_lexer::location::Location;
 
 use crate::hir::Hir;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct BlockAttributes {
     pub safety: Safety,
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct Block {
     pub location: Location,
     pub attributes: BlockAttributes,
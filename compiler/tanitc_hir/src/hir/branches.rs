@@ -1,40 +1,41 @@
+use serde::Serialize;
 use tanitc_lexer::location::Location;
 
 use crate::hir::{blocks::Block, expressions::Expression, Hir};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Loop {
     pub location: Location,
     pub body: Box<Block>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct While {
     pub location: Location,
     pub body: Box<Block>,
     pub condition: Box<Expression>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct If {
     pub location: Location,
     pub body: Box<Block>,
     pub condition: Box<Expression>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum ElseBody {
     Block(Box<Block>),
     If(Box<If>),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Else {
     pub location: Location,
     pub body: ElseBody,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum Branch {
     Loop(Loop),
     While(While),
@@ -1,8 +1,9 @@
+use serde::Serialize;
 use tanitc_lexer::location::Location;
 
 use crate::hir::{expressions::Expression, Hir};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum ControlFlowKind {
     Return { ret: Option<Box<Expression>> },
     Break { ret: Option<Box<Expression>> },
@@ -19,7 +20,7 @@ impl ControlFlowKind {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ControlFlow {
     pub location: Location,
     pub kind: ControlFlowKind,
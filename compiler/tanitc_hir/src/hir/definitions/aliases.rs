@@ -1,15 +1,18 @@
+use serde::Serialize;
 use tanitc_attributes::Publicity;
 use tanitc_lexer::location::Location;
 use tanitc_name::NameSpec;
 
+use crate::attributes::Attribute;
 use crate::hir::{definitions::Definition, type_spec::TypeSpec, Hir};
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
 pub struct AliasAttributes {
     pub publicity: Publicity,
+    pub meta: Vec<Attribute>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct AliasDef {
     pub location: Location,
     pub attributes: AliasAttributes,
@@ -1,3 +1,4 @@
+use serde::Serialize;
 use std::collections::BTreeMap;
 
 use tanitc_attributes::Publicity;
@@ -5,21 +6,29 @@ use tanitc_ident::Ident;
 use tanitc_lexer::location::Location;
 use tanitc_name::NameSpec;
 
-use crate::hir::{definitions::Definition, Hir};
+use crate::attributes::Attribute;
+use crate::hir::{definitions::Definition, type_spec::Type, Hir};
 
-pub type EnumUnitValue = Option<usize>;
-pub type EnumUnits = BTreeMap<Ident, EnumUnitValue>;
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct EnumUnitInfo {
+    pub location: Location,
+    pub value: Option<usize>,
+}
+
+pub type EnumUnits = BTreeMap<Ident, EnumUnitInfo>;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
 pub struct EnumAttributes {
     pub publicity: Publicity,
+    pub meta: Vec<Attribute>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct EnumDef {
     pub location: Location,
     pub attributes: EnumAttributes,
     pub name: NameSpec,
+    pub repr: Option<Type>, // underlying integer type, plain C enum if not set
     pub units: EnumUnits,
 }
 
@@ -1,15 +1,18 @@
+use serde::Serialize;
+use tanitc_attributes::Abi;
 use tanitc_lexer::location::Location;
 
 use crate::hir::{
-    definitions::{functions::FunctionDef, Definition},
+    definitions::{functions::FunctionDef, variables::VariableDef, Definition},
     Hir,
 };
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ExternDef {
     pub location: Location,
-    pub abi_name: String,
+    pub abi: Abi,
     pub functions: Vec<FunctionDef>,
+    pub statics: Vec<VariableDef>,
 }
 
 impl From<ExternDef> for Hir {
@@ -17,3 +20,12 @@ impl From<ExternDef> for Hir {
         Self::Definition(Definition::Extern(value))
     }
 }
+
+impl ExternDef {
+    // Functions of the block are defined by foreign code, so they follow its ABI
+    pub fn get_foreign_func(&self, func_def: &FunctionDef) -> FunctionDef {
+        let mut func_def = func_def.clone();
+        func_def.attributes.abi = self.abi;
+        func_def
+    }
+}
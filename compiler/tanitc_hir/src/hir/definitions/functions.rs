@@ -1,7 +1,9 @@
-use tanitc_attributes::{Mutability, Publicity, Safety};
+use serde::Serialize;
+use tanitc_attributes::{Abi, Mutability, Publicity, Safety};
 use tanitc_lexer::location::Location;
 use tanitc_name::NameSpec;
 
+use crate::attributes::Attribute;
 use crate::hir::{
     blocks::Block,
     definitions::{variables::VariableDef, Definition},
@@ -9,13 +11,15 @@ use crate::hir::{
     Hir,
 };
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
 pub struct FunctionAttributes {
     pub publicity: Publicity,
     pub safety: Safety,
+    pub abi: Abi,
+    pub meta: Vec<Attribute>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum FunctionParam {
     SelfVal(Mutability),
     SelfRef(Mutability),
@@ -23,13 +27,14 @@ pub enum FunctionParam {
     Common(VariableDef),
 }
 
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
 pub struct FunctionDef {
     pub location: Location,
     pub attributes: FunctionAttributes,
     pub name: NameSpec,
     pub return_type: Type,
     pub parameters: Vec<FunctionParam>,
+    pub is_variadic: bool,
     pub body: Option<Box<Block>>,
 }
 
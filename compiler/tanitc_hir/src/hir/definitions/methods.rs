@@ -1,3 +1,4 @@
+use serde::Serialize;
 use tanitc_lexer::location::Location;
 use tanitc_name::NameSpec;
 
@@ -6,17 +7,25 @@ use crate::hir::{
     Hir,
 };
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct ImplAttributes {}
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct ImplDef {
     pub location: Location,
     pub attrs: ImplAttributes,
     pub name: NameSpec,
+    pub type_name: Option<NameSpec>, // absolute name of the implemented type
     pub methods: Vec<FunctionDef>,
 }
 
+impl ImplDef {
+    // Name of the type resolved by analyzer, or the one written in code
+    pub fn get_type_name(&self) -> &NameSpec {
+        self.type_name.as_ref().unwrap_or(&self.name)
+    }
+}
+
 impl From<ImplDef> for Hir {
     fn from(value: ImplDef) -> Self {
         Self::Definition(Definition::Impl(value))
@@ -17,6 +17,7 @@ use {
     functions::FunctionDef,
     methods::ImplDef,
     modules::ModuleDef,
+    serde::Serialize,
     structs::StructDef,
     tanitc_lexer::location::Location,
     tanitc_messages::Message,
@@ -25,7 +26,7 @@ use {
     variants::VariantDef,
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum Definition {
     Module(ModuleDef),
     Struct(StructDef),
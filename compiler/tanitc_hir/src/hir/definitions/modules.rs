@@ -1,22 +1,25 @@
+use serde::Serialize;
 use tanitc_attributes::{Publicity, Safety};
 use tanitc_lexer::location::Location;
 use tanitc_name::NameSpec;
 
+use crate::attributes::Attribute;
 use crate::hir::{blocks::Block, definitions::Definition, Hir};
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
 pub struct ModuleAttributes {
     pub publicity: Publicity,
     pub safety: Safety,
+    pub meta: Vec<Attribute>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum ModuleDefBody {
     Internal(Box<Block>),
     External(Box<Hir>),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ModuleDef {
     pub location: Location,
     pub attributes: ModuleAttributes,
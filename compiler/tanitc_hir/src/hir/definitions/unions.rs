@@ -1,3 +1,4 @@
+use serde::Serialize;
 use std::collections::BTreeMap;
 
 use tanitc_attributes::Publicity;
@@ -5,27 +6,30 @@ use tanitc_ident::Ident;
 use tanitc_lexer::location::Location;
 use tanitc_name::NameSpec;
 
+use crate::attributes::Attribute;
 use crate::hir::{definitions::Definition, type_spec::TypeSpec, Hir};
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct UnionFieldAttributes {
     pub publicity: Publicity,
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UnionFieldInfo {
+    pub location: Location,
     pub ty: TypeSpec,
     pub attributes: UnionFieldAttributes,
 }
 
 pub type UnionFieldsInfo = BTreeMap<Ident, UnionFieldInfo>;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
 pub struct UnionAttributes {
     pub publicity: Publicity,
+    pub meta: Vec<Attribute>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct UnionDef {
     pub location: Location,
     pub attributes: UnionAttributes,
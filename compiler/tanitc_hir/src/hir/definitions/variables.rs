@@ -1,15 +1,16 @@
+use serde::Serialize;
 use tanitc_attributes::{Mutability, Publicity, Visibility};
 use tanitc_ident::Ident;
 use tanitc_lexer::location::Location;
 
 use crate::hir::{definitions::Definition, expressions::Expression, type_spec::Type, Hir};
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
 pub struct VariableAttributes {
     pub publicity: Publicity,
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct VariableDef {
     pub location: Location,
     pub attributes: VariableAttributes,
@@ -1,3 +1,4 @@
+use serde::Serialize;
 use std::collections::BTreeMap;
 
 use tanitc_attributes::Publicity;
@@ -5,13 +6,14 @@ use tanitc_ident::Ident;
 use tanitc_lexer::location::Location;
 use tanitc_name::NameSpec;
 
+use crate::attributes::Attribute;
 use crate::hir::{
     definitions::{structs::StructFieldsInfo, Definition},
     type_spec::Type,
     Hir,
 };
 
-#[derive(Clone, Debug, PartialEq, Default)]
+#[derive(Clone, Debug, PartialEq, Default, Serialize)]
 pub enum VariantField {
     #[default]
     Enum,
@@ -21,12 +23,13 @@ pub enum VariantField {
 
 pub type VariantFields = BTreeMap<Ident, VariantField>;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
 pub struct VariantAttributes {
     pub publicity: Publicity,
+    pub meta: Vec<Attribute>,
 }
 
-#[derive(Clone, Debug, PartialEq, Default)]
+#[derive(Clone, Debug, PartialEq, Default, Serialize)]
 pub struct VariantDef {
     pub location: Location,
     pub attributes: VariantAttributes,
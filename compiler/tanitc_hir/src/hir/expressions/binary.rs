@@ -1,10 +1,11 @@
+use serde::Serialize;
 use std::fmt::Display;
 
 use tanitc_lexer::location::Location;
 
 use crate::hir::expressions::Expression;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum BinaryOperation {
     Add,        // +
     Sub,        // -
@@ -29,7 +30,7 @@ pub enum BinaryOperation {
     Access,     // .
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct BinaryExpr {
     pub location: Location,
     pub operation: BinaryOperation,
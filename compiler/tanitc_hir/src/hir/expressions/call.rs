@@ -1,29 +1,30 @@
+use serde::Serialize;
 use tanitc_ident::Ident;
 use tanitc_lexer::location::Location;
 
 use crate::hir::expressions::Expression;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct NamedCallArg {
     pub location: Location,
     pub id: Ident,
     pub expr: Box<Expression>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct PositionalCallArg {
     pub location: Location,
     pub id: usize,
     pub expr: Box<Expression>,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum CallArg {
     Notified(NamedCallArg),
     Positional(PositionalCallArg),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct CallExpr {
     pub location: Location,
     pub expr: Box<Expression>,
@@ -1,8 +1,9 @@
+use serde::Serialize;
 use tanitc_lexer::location::Location;
 
 use crate::hir::{expressions::Expression, type_spec::TypeSpec};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ConversionExpr {
     pub location: Location,
     pub expr: Box<Expression>,
@@ -1,8 +1,9 @@
+use serde::Serialize;
 use tanitc_lexer::location::Location;
 
 use crate::hir::expressions::Expression;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct IndexingExpr {
     pub location: Location,
     pub lhs: Box<Expression>,
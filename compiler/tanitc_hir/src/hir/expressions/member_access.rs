@@ -1,9 +1,10 @@
+use serde::Serialize;
 use tanitc_ident::Ident;
 use tanitc_lexer::location::Location;
 
 use crate::hir::expressions::Expression;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct MemberAccessExpr {
     pub location: Location,
     pub lhs: Box<Expression>,
@@ -1,3 +1,4 @@
+use serde::Serialize;
 use tanitc_lexer::location::Location;
 
 use crate::hir::{
@@ -17,7 +18,7 @@ pub mod member_access;
 pub mod unary;
 pub mod variable;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum Expression {
     Unary(UnaryExpr),
     Binary(BinaryExpr),
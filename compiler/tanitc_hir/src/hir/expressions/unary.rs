@@ -1,10 +1,11 @@
+use serde::Serialize;
 use std::fmt::Display;
 
 use tanitc_lexer::{location::Location, token::lexeme::Lexeme};
 
 use crate::hir::expressions::Expression;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum UnaryOperation {
     Add,    // +
     Sub,    // -
@@ -14,7 +15,7 @@ pub enum UnaryOperation {
     Not,    // !
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct UnaryExpr {
     pub location: Location,
     pub operation: UnaryOperation,
@@ -1,8 +1,10 @@
+use serde::Serialize;
 use tanitc_lexer::location::Location;
 use tanitc_name::NameSpec;
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct Variable {
     pub location: Location,
     pub name: NameSpec,
+    pub item_name: Option<NameSpec>, // absolute path of the referenced function, method or enum unit
 }
@@ -1,3 +1,4 @@
+use serde::Serialize;
 use tanitc_lexer::location::Location;
 use tanitc_messages::Message;
 
@@ -19,7 +20,7 @@ use crate::{
     visitor::{Visitor, VisitorMut},
 };
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum Hir {
     Definition(Definition),
     Expression(Expression),
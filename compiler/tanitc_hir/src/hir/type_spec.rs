@@ -1,12 +1,16 @@
+use serde::Serialize;
 use std::str::FromStr;
-use tanitc_attributes::{Mutability, Safety};
+use tanitc_attributes::{Abi, Mutability, Safety};
 use tanitc_ident::Ident;
 use tanitc_lexer::location::Location;
-use tanitc_name::NameSpec;
+use tanitc_name::{
+    mangling::{mangle, mangle_name, ItemKind},
+    NameSpec,
+};
 
 use crate::hir::Hir;
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct TypeSpec {
     pub location: Location,
     pub ty: Type,
@@ -28,44 +32,66 @@ impl From<TypeSpec> for Hir {
     }
 }
 
-#[derive(Default, Debug, Clone, Copy, PartialEq)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Serialize)]
 pub enum ArraySize {
     #[default]
     Unknown,
     Fixed(usize),
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Serialize)]
 pub struct RefType {
     pub ref_to: Box<Type>,
     pub mutability: Mutability,
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Serialize)]
 pub struct PtrType {
     pub ptr_to: Box<Type>,
     pub mutability: Mutability,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct FuncTypeParam {
     pub ty: Box<Type>,
     pub id: Option<Ident>,
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct FuncType {
     pub parameters: Vec<FuncTypeParam>,
     pub return_type: Box<Type>,
     pub safety: Safety,
+    pub abi: Abi,
+    pub is_variadic: bool, // accepts extra C arguments after parameters
 }
 
-#[derive(Default, Clone, PartialEq)]
+impl FuncType {
+    // Name of the C function pointer typedef. It depends only on the C signature,
+    // so function types which differ by ABI or safety share the same typedef
+    pub fn get_c_typedef_name(&self) -> String {
+        let mut path: Vec<String> = self
+            .parameters
+            .iter()
+            .map(|param| param.ty.get_c_typedef_fragment())
+            .collect();
+
+        if self.is_variadic {
+            path.push("va".to_string());
+        }
+
+        path.push(self.return_type.get_c_typedef_fragment());
+
+        mangle(ItemKind::FuncType, &path)
+    }
+}
+
+#[derive(Default, Clone, PartialEq, Serialize)]
 pub struct TupleType {
     pub units: Vec<Type>,
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Serialize)]
 pub enum Type {
     Ref(RefType),
     Ptr(PtrType),
@@ -163,6 +189,21 @@ impl Type {
         )
     }
 
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128
+        )
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self, Self::F32 | Self::F64)
+    }
+
+    pub fn is_numeric(&self) -> bool {
+        self.is_integer() || self.is_float()
+    }
+
     pub fn is_reference(&self) -> bool {
         matches!(self, Self::Ref { .. })
     }
@@ -175,7 +216,16 @@ impl Type {
         matches!(self, Self::Array { .. })
     }
 
+    pub fn is_func(&self) -> bool {
+        matches!(self, Self::Func(_))
+    }
+
     pub fn get_c_type(&self) -> String {
+        self.get_c_type_with(&|name| mangle_name(ItemKind::Type, name))
+    }
+
+    // C type, where custom types are named by "type_name"
+    pub fn get_c_type_with(&self, type_name: &dyn Fn(&NameSpec) -> String) -> String {
         match self {
             Self::Auto => unreachable!("automatic type is not eliminated"),
             Self::Bool | Self::U8 => "unsigned char".to_string(),
@@ -191,16 +241,25 @@ impl Type {
             Self::F32 => "float".to_string(),
             Self::F64 => "double".to_string(),
             Self::Str => "char".to_string(),
-            Self::Custom(id) => id.to_string(),
+            Self::Custom(name) => type_name(name),
             Self::Ref(ref_type) => format!(
                 "{}{}*",
-                ref_type.ref_to.get_c_type(),
+                ref_type.ref_to.get_c_type_with(type_name),
                 if ref_type.mutability.is_const() {
                     " const "
                 } else {
                     " "
                 }
             ),
+            Self::Ptr(ptr_type) => format!(
+                "{}{}*",
+                ptr_type.ptr_to.get_c_type_with(type_name),
+                if ptr_type.mutability.is_const() {
+                    " const "
+                } else {
+                    " "
+                }
+            ),
             Self::Tuple(tuple_type) => {
                 if tuple_type.units.is_empty() {
                     "void".to_string()
@@ -214,7 +273,10 @@ impl Type {
                         .iter()
                         .enumerate()
                         .for_each(|(c_idx, c_type)| {
-                            res.push_str(&format!("{} _{c_idx}; ", c_type.get_c_type()));
+                            res.push_str(&format!(
+                                "{} _{c_idx}; ",
+                                c_type.get_c_type_with(type_name)
+                            ));
                         });
 
                     res.push('}');
@@ -222,10 +284,50 @@ impl Type {
                     res
                 }
             }
-            Self::Array { value_type, .. } => value_type.get_c_type(),
+            Self::Array { value_type, .. } => value_type.get_c_type_with(type_name),
+            Self::Func(func_type) => func_type.get_c_typedef_name(),
             _ => unimplemented!(),
         }
     }
+
+    // Part of the function typedef name, that identifies the type
+    fn get_c_typedef_fragment(&self) -> String {
+        let mutability_str =
+            |mutability: &Mutability| if mutability.is_const() { "c" } else { "m" };
+
+        match self {
+            Self::Ref(ref_type) => format!(
+                "r{}{}",
+                mutability_str(&ref_type.mutability),
+                ref_type.ref_to.get_c_typedef_fragment()
+            ),
+            Self::Ptr(ptr_type) => format!(
+                "p{}{}",
+                mutability_str(&ptr_type.mutability),
+                ptr_type.ptr_to.get_c_typedef_fragment()
+            ),
+            Self::Tuple(tuple_type) if tuple_type.units.is_empty() => "v".to_string(),
+            Self::Tuple(tuple_type) => {
+                let mut res = format!("t{}", tuple_type.units.len());
+                for unit in tuple_type.units.iter() {
+                    res.push_str(&unit.get_c_typedef_fragment());
+                }
+                res
+            }
+            Self::Array { value_type, .. } => format!("a{}", value_type.get_c_typedef_fragment()),
+            Self::Custom(name) => {
+                let mut res = "n".to_string();
+                for segment in name.path.iter() {
+                    let segment = segment.to_string();
+                    res.push_str(&format!("{}{segment}", segment.len()));
+                }
+                res
+            }
+            Self::Func(func_type) => func_type.get_c_typedef_name(),
+            Self::Never => "never".to_string(),
+            _ => self.to_string(),
+        }
+    }
 }
 
 impl std::str::FromStr for Type {
@@ -317,6 +419,10 @@ impl std::fmt::Display for FuncTypeParam {
 
 impl std::fmt::Display for FuncType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.abi.is_c() {
+            write!(f, "extern \"C\" ")?;
+        }
+
         write!(f, "func (")?;
 
         if let Some(first_param) = self.parameters.first() {
@@ -326,6 +432,13 @@ impl std::fmt::Display for FuncType {
             write!(f, ", {p}")?;
         }
 
+        if self.is_variadic {
+            if !self.parameters.is_empty() {
+                write!(f, ", ")?;
+            }
+            write!(f, "...")?;
+        }
+
         write!(f, ") -> {}", self.return_type)
     }
 }
@@ -1,12 +1,33 @@
+use serde::Serialize;
+use tanitc_attributes::Publicity;
+use tanitc_ident::Ident;
 use tanitc_lexer::location::Location;
-use tanitc_name::NameSpec;
+use tanitc_name::{NamePathSegment, NameSpec};
 
 use crate::hir::Hir;
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct UseAttributes {
+    pub publicity: Publicity,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
+pub struct UseItem {
+    pub name: NameSpec, // glob imports end with "*"
+    pub alias: Option<Ident>,
+}
+
+impl UseItem {
+    pub fn is_glob(&self) -> bool {
+        matches!(self.name.path.last(), Some(NamePathSegment::AllIdents))
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct Use {
     pub location: Location,
-    pub name: NameSpec,
+    pub attributes: UseAttributes,
+    pub items: Vec<UseItem>,
 }
 
 impl From<Use> for Hir {
@@ -0,0 +1,430 @@
+use tanitc_hir::{
+    attributes::{Attribute, MetaItem},
+    hir::type_spec::Type,
+};
+use tanitc_lexer::location::Location;
+use tanitc_lints::{find_lint, get_lint_names, LintLevel, DEPRECATED};
+use tanitc_messages::Message;
+use tanitc_name::NameSpec;
+
+use crate::{
+    hir::definitions::enums::FLAGS_ATTR,
+    symbol_table::entry::{Deprecation, Entry},
+    AnalyzeResult, Analyzer,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AttributeTarget {
+    Function,
+    Struct,
+    Union,
+    Enum,
+    Variant,
+    Alias,
+    Module,
+}
+
+impl AttributeTarget {
+    fn to_str(self) -> &'static str {
+        match self {
+            Self::Function => "function",
+            Self::Struct => "struct",
+            Self::Union => "union",
+            Self::Enum => "enum",
+            Self::Variant => "variant",
+            Self::Alias => "alias",
+            Self::Module => "module",
+        }
+    }
+}
+
+const INLINE_ATTR: &str = "inline";
+const NO_MANGLE_ATTR: &str = "no_mangle";
+const COLD_ATTR: &str = "cold";
+const DEPRECATED_ATTR: &str = "deprecated";
+
+impl Analyzer {
+    // Validates attributes of the definition and returns its deprecation, if any
+    pub(crate) fn analyze_attributes(
+        &mut self,
+        attributes: &[Attribute],
+        target: AttributeTarget,
+    ) -> AnalyzeResult<Option<Deprecation>> {
+        let mut deprecation = None;
+        let mut seen = Vec::<String>::new();
+
+        for attribute in attributes.iter() {
+            let Some(name) = attribute.name() else {
+                self.error(Message::malformed_attribute(
+                    attribute.location,
+                    format!("Malformed attribute \"{attribute}\": expected attribute name"),
+                ));
+                continue;
+            };
+
+            let name = name.to_string();
+
+            // Lint attributes can be repeated, e.g. #[allow(a)] #[deny(b)]
+            if let Some(level) = LintLevel::from_attribute_name(&name) {
+                if let Err(err) = Self::check_lint_attribute(attribute, level) {
+                    self.error(err);
+                }
+                continue;
+            }
+
+            if seen.contains(&name) {
+                self.error(Message::conflicting_attributes(
+                    attribute.location,
+                    format!("Attribute \"{name}\" is used more than once"),
+                ));
+                continue;
+            }
+            seen.push(name.clone());
+
+            let res = match name.as_str() {
+                INLINE_ATTR | NO_MANGLE_ATTR | COLD_ATTR => {
+                    Self::check_word_attribute(attribute, &name, target, AttributeTarget::Function)
+                }
+                FLAGS_ATTR => {
+                    Self::check_word_attribute(attribute, &name, target, AttributeTarget::Enum)
+                }
+                DEPRECATED_ATTR => Self::get_deprecation(attribute).map(|res| {
+                    deprecation = Some(res);
+                }),
+                _ => Err(Message::unknown_attribute(
+                    attribute.location,
+                    format!("Unknown attribute \"{name}\""),
+                )),
+            };
+
+            if let Err(err) = res {
+                self.error(err);
+            }
+        }
+
+        if seen.iter().any(|name| name == INLINE_ATTR)
+            && seen.iter().any(|name| name == NO_MANGLE_ATTR)
+        {
+            let location = attributes.first().map(|attr| attr.location).unwrap();
+            self.error(Message::conflicting_attributes(
+                location,
+                format!("Attributes \"{INLINE_ATTR}\" and \"{NO_MANGLE_ATTR}\" can not be used together"),
+            ));
+        }
+
+        Ok(deprecation)
+    }
+
+    fn check_word_attribute(
+        attribute: &Attribute,
+        name: &str,
+        target: AttributeTarget,
+        allowed_target: AttributeTarget,
+    ) -> AnalyzeResult<()> {
+        if target != allowed_target {
+            return Err(Message::misplaced_attribute(
+                attribute.location,
+                format!(
+                    "Attribute \"{name}\" is allowed only on {}s, not on {}",
+                    allowed_target.to_str(),
+                    target.to_str()
+                ),
+            ));
+        }
+
+        if !matches!(attribute.meta, MetaItem::Word(_)) {
+            return Err(Message::malformed_attribute(
+                attribute.location,
+                format!("Malformed attribute \"{attribute}\": \"{name}\" takes no arguments"),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn check_lint_attribute(attribute: &Attribute, level: LintLevel) -> AnalyzeResult<()> {
+        let malformed = || {
+            Message::malformed_attribute(
+                attribute.location,
+                format!(
+                    "Malformed attribute \"{attribute}\": expected \"#[{level}(lint_name, ...)]\""
+                ),
+            )
+        };
+
+        let MetaItem::List(_, items) = &attribute.meta else {
+            return Err(malformed());
+        };
+
+        if items.is_empty() {
+            return Err(malformed());
+        }
+
+        for item in items.iter() {
+            let MetaItem::Word(lint_name) = item else {
+                return Err(malformed());
+            };
+
+            let lint_name = lint_name.to_string();
+            if find_lint(&lint_name).is_none() {
+                return Err(Message::unknown_lint(
+                    attribute.location,
+                    format!(
+                        "Unknown lint \"{lint_name}\", known lints: {}",
+                        get_lint_names()
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn get_deprecation(attribute: &Attribute) -> AnalyzeResult<Deprecation> {
+        let note = match &attribute.meta {
+            MetaItem::Word(_) => Some(None),
+            MetaItem::NameValue(_, note) => Some(Some(note.clone())),
+            MetaItem::List(_, items) => match items.as_slice() {
+                [MetaItem::Literal(note)] => Some(Some(note.clone())),
+                [MetaItem::NameValue(key, note)] if key.to_string() == "note" => {
+                    Some(Some(note.clone()))
+                }
+                _ => None,
+            },
+            MetaItem::Literal(_) => None,
+        };
+
+        note.map(|note| Deprecation { note }).ok_or_else(|| {
+            Message::malformed_attribute(
+                attribute.location,
+                format!("Malformed attribute \"{attribute}\": expected \"#[deprecated]\", \"#[deprecated = \"note\"]\" or \"#[deprecated(note = \"note\")]\""),
+            )
+        })
+    }
+
+    pub(crate) fn deprecation_warning(
+        entry: &Entry,
+        name: &NameSpec,
+        location: Location,
+    ) -> Option<Message> {
+        let entry = entry.resolve_import();
+        let deprecation = entry.deprecation.as_ref()?;
+
+        let note = deprecation
+            .note
+            .as_ref()
+            .map(|note| format!(": {note}"))
+            .unwrap_or_default();
+
+        Some(Message::new(
+            location,
+            format!(
+                "Use of deprecated {} \"{name}\"{note}",
+                entry.kind.kind_str()
+            ),
+        ))
+    }
+
+    pub(crate) fn check_deprecated_name(&mut self, name: &NameSpec, location: Location) {
+        let warning = self
+            .table
+            .lookup_name_spec(name)
+            .ok()
+            .and_then(|entry| Self::deprecation_warning(entry, name, location));
+
+        if let Some(warning) = warning {
+            self.lint(&DEPRECATED, warning);
+        }
+    }
+
+    pub(crate) fn check_deprecated_type(&mut self, ty: &Type, location: Location) {
+        match ty {
+            Type::Custom(name) => self.check_deprecated_name(name, location),
+            Type::Ref(ref_type) => self.check_deprecated_type(&ref_type.ref_to, location),
+            Type::Ptr(ptr_type) => self.check_deprecated_type(&ptr_type.ptr_to, location),
+            Type::Array { value_type, .. } => self.check_deprecated_type(value_type, location),
+            Type::Tuple(tuple_type) => {
+                for unit in tuple_type.units.iter() {
+                    self.check_deprecated_type(unit, location);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_hir::{attributes::MetaItem, hir::type_spec::Type};
+    use tanitc_hir_test::{
+        create_attribute, create_call_expr, create_func_def, create_main_func_def, create_program,
+        create_struct_def, create_word_attribute,
+    };
+    use tanitc_ident::Ident;
+
+    use crate::{symbol_table::entry::SymbolKind, Analyzer};
+
+    #[test]
+    fn inline_func_good_test() {
+        // Given
+        let mut func_def = create_func_def("foo", vec![], Type::unit(), vec![]);
+        func_def.attributes.meta = vec![create_word_attribute("inline")];
+
+        let main_func = create_main_func_def(vec![create_call_expr(&["foo"], vec![]).into()]);
+
+        /* #[inline]
+         * func foo() { }
+         * func main() {
+         *     foo()
+         * }
+         */
+        let mut program = create_program(vec![func_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+
+        let entry = analyzer
+            .get_table()
+            .lookup(Ident::from("foo".to_string()))
+            .unwrap();
+        let SymbolKind::FuncDef(data) = &entry.kind else {
+            panic!("Expected function, actually: {:?}", entry.kind);
+        };
+        assert!(data.is_inline);
+    }
+
+    #[test]
+    fn deprecated_func_call_warning_test() {
+        // Given
+        let mut func_def = create_func_def("foo", vec![], Type::unit(), vec![]);
+        func_def.attributes.meta = vec![create_attribute(MetaItem::List(
+            Ident::from("deprecated".to_string()),
+            vec![MetaItem::NameValue(
+                Ident::from("note".to_string()),
+                "use bar instead".to_string(),
+            )],
+        ))];
+
+        let main_func = create_main_func_def(vec![create_call_expr(&["foo"], vec![]).into()]);
+
+        /* #[deprecated(note = "use bar instead")]
+         * func foo() { }
+         * func main() {
+         *     foo()
+         * }
+         */
+        let mut program = create_program(vec![func_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_WARN: &str =
+            "Semantic warning: Use of deprecated function \"foo\": use bar instead";
+
+        res.expect("Expected no errors");
+
+        let warnings = analyzer.messages_ref().warnings_ref();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].text, EXPECTED_WARN);
+    }
+
+    #[test]
+    fn unknown_attribute_bad_test() {
+        // Given
+        let mut func_def = create_main_func_def(vec![]);
+        func_def.attributes.meta = vec![create_word_attribute("hot")];
+
+        /* #[hot]
+         * func main() { }
+         */
+        let mut program = create_program(vec![func_def.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: Unknown attribute \"hot\"";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+
+    #[test]
+    fn attribute_target_bad_test() {
+        // Given
+        let mut struct_def = create_struct_def("S", vec![("x", Type::I32)]);
+        struct_def.attributes.meta = vec![create_word_attribute("cold")];
+
+        let main_func = create_main_func_def(vec![]);
+
+        /* #[cold]
+         * struct S {
+         *     x: i32
+         * }
+         * func main() { }
+         */
+        let mut program = create_program(vec![struct_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str =
+            "Semantic error: Attribute \"cold\" is allowed only on functions, not on struct";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+
+    #[test]
+    fn inline_and_no_mangle_bad_test() {
+        // Given
+        let mut func_def = create_func_def("foo", vec![], Type::unit(), vec![]);
+        func_def.attributes.meta = vec![
+            create_word_attribute("inline"),
+            create_word_attribute("no_mangle"),
+        ];
+
+        let main_func = create_main_func_def(vec![]);
+
+        /* #[inline]
+         * #[no_mangle]
+         * func foo() { }
+         * func main() { }
+         */
+        let mut program = create_program(vec![func_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str =
+            "Semantic error: Attributes \"inline\" and \"no_mangle\" can not be used together";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+}
@@ -15,7 +15,7 @@ impl Analyzer {
         Ok(())
     }
 
-    fn analyze_global_block(&mut self, block: &mut Block) -> AnalyzeResult<()> {
+    pub(crate) fn analyze_global_block(&mut self, block: &mut Block) -> AnalyzeResult<()> {
         self.table.set_safety(Safety::Safe);
 
         for stmt in block.statements.iter_mut() {
@@ -29,7 +29,7 @@ impl Analyzer {
             );
 
             if is_denied {
-                self.error(Message::new(
+                self.error(Message::misplaced_statement(
                     stmt.location(),
                     format!(
                         "Node \"{}\" is not allowed in global scope",
@@ -45,6 +45,8 @@ impl Analyzer {
             }
         }
 
+        self.check_unused_entries();
+
         Ok(())
     }
 
@@ -60,6 +62,8 @@ impl Analyzer {
             }
         }
 
+        self.check_unused_entries();
+
         self.table.exit_scope();
 
         Ok(())
@@ -20,7 +20,7 @@ impl Analyzer {
         let is_ret = matches!(cf.kind, ControlFlowKind::Return { .. });
 
         if (!is_ret && !is_in_loop) || (is_ret && !is_in_func) {
-            return Err(Message::new(
+            return Err(Message::misplaced_statement(
                 cf.location,
                 format!("Unexpected {} statement", cf.kind.to_str()),
             ));
@@ -2,7 +2,8 @@ use tanitc_hir::hir::{definitions::aliases::AliasDef, type_spec::Type};
 use tanitc_messages::Message;
 
 use crate::{
-    symbol_table::entry::{AliasDefData, Entry, SymbolKind},
+    hir::attributes::AttributeTarget,
+    symbol_table::entry::{AliasDefData, Entry, SymbolKind, Usage},
     AnalyzeResult, Analyzer,
 };
 
@@ -14,19 +15,26 @@ impl Analyzer {
             .ok_or(Message::empty_name_spec(alias_def.location))?;
 
         if self.has_symbol(alias_id) {
-            return Err(Message::multiple_ids(alias_def.location, alias_id));
+            return Err(self.multiple_ids_error(alias_def.location, alias_id));
         }
 
         // Copies table.table_path to start of alias_def.name.path
         alias_def.name.path.splice(0..0, self.table.get_path());
 
+        let deprecation =
+            self.analyze_attributes(&alias_def.attributes.meta, AttributeTarget::Alias)?;
+
         self.add_symbol(Entry {
             id: alias_id,
             is_static: true,
+            publicity: alias_def.attributes.publicity,
+            deprecation,
+            location: alias_def.location,
             kind: AliasDefData {
                 ty: alias_def.value.get_type(),
             }
             .into(),
+            usage: Usage::default(),
         });
 
         Ok(())
@@ -227,7 +235,7 @@ mod tests {
         let res = analyzer.analyze_program(&mut program);
 
         // Then
-        const EXPECTED_ERR: &str = "Semantic error: Cannot perform operation on objects with different types: A (aka: i32) and f32";
+        const EXPECTED_ERR: &str = "Semantic error: Cannot perform operation on objects with different types: A (aka: i32) and f64";
 
         let messages = res.expect_err("Expected errors");
         let errors = messages.errors_ref();
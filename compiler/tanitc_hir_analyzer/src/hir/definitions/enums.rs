@@ -1,11 +1,21 @@
 use crate::{
-    symbol_table::entry::{Entry, EnumData, EnumDefData, EnumDefEntries, SymbolKind},
+    hir::{attributes::AttributeTarget, expressions::conversion::get_numeric_size},
+    symbol_table::entry::{Entry, EnumData, EnumDefData, EnumDefEntries, SymbolKind, Usage},
     AnalyzeResult, Analyzer,
 };
-use tanitc_hir::hir::definitions::enums::{EnumDef, EnumUnits};
+use tanitc_attributes::Publicity;
+use tanitc_hir::{
+    attributes::find_attribute,
+    hir::{
+        definitions::enums::{EnumDef, EnumUnits},
+        type_spec::Type,
+    },
+};
 use tanitc_messages::Message;
 use tanitc_name::NameSpec;
 
+pub(crate) const FLAGS_ATTR: &str = "flags";
+
 impl Analyzer {
     pub(crate) fn analyze_enum_def(&mut self, enum_def: &mut EnumDef) -> AnalyzeResult<()> {
         let enum_id = enum_def
@@ -14,41 +24,101 @@ impl Analyzer {
             .ok_or(Message::empty_name_spec(enum_def.location))?;
 
         if self.has_symbol(enum_id) {
-            return Err(Message::multiple_ids(enum_def.location, enum_id));
+            return Err(self.multiple_ids_error(enum_def.location, enum_id));
         }
 
         // Copies table.table_path to start of enum_def.name.path
         enum_def.name.path.splice(0..0, self.table.get_path());
 
-        let units = self.analyze_enum_def_units(&enum_def.name, &mut enum_def.units)?;
+        let deprecation =
+            self.analyze_attributes(&enum_def.attributes.meta, AttributeTarget::Enum)?;
+
+        let repr = self.analyze_enum_repr(enum_def)?;
+        let is_flags = find_attribute(&enum_def.attributes.meta, FLAGS_ATTR).is_some();
+
+        let units = self.analyze_enum_def_units(
+            &enum_def.name,
+            &mut enum_def.units,
+            repr.as_ref(),
+            is_flags,
+        )?;
 
         self.add_symbol(Entry {
             id: enum_id,
             is_static: true,
+            publicity: enum_def.attributes.publicity,
+            deprecation,
+            location: enum_def.location,
             kind: SymbolKind::from(EnumDefData {
                 name: enum_def.name.clone(),
                 units,
+                repr,
+                is_flags,
             }),
+            usage: Usage::default(),
         });
 
         Ok(())
     }
 
+    // Returns the integer type the enum is represented by
+    fn analyze_enum_repr(&mut self, enum_def: &EnumDef) -> AnalyzeResult<Option<Type>> {
+        let Some(repr) = &enum_def.repr else {
+            return Ok(None);
+        };
+
+        self.check_deprecated_type(repr, enum_def.location);
+
+        let Some(type_info) = self.table.lookup_type(repr) else {
+            return Err(Message::undefined_type(enum_def.location, repr.to_string()));
+        };
+
+        let repr_value = self.find_alias_value(&type_info.ty).unwrap_or(type_info.ty);
+
+        if !repr_value.is_integer() {
+            return Err(Message::invalid_enum_repr(
+                enum_def.location,
+                format!(
+                    "Representation type of enum \"{}\" must be integer, found \"{repr}\"",
+                    enum_def.name
+                ),
+            ));
+        }
+
+        Ok(Some(repr_value))
+    }
+
     fn analyze_enum_def_units(
         &mut self,
         enum_name: &NameSpec,
         enum_units: &mut EnumUnits,
+        repr: Option<&Type>,
+        is_flags: bool,
     ) -> AnalyzeResult<EnumDefEntries> {
-        let mut counter = 0usize;
+        // Units of flags enum default to the next power of two
+        let mut counter = if is_flags { 1usize } else { 0usize };
         let mut enums_entries = EnumDefEntries::new();
 
-        for (unit_id, unit_value) in enum_units.iter_mut() {
-            if let Some(value) = unit_value {
-                counter = *value;
+        let max_value = repr.and_then(get_integer_max);
+
+        for (unit_id, unit) in enum_units.iter_mut() {
+            if let Some(value) = unit.value {
+                counter = value;
+            }
+
+            if let (Some(repr), Some(max_value)) = (repr, max_value) {
+                if counter as u128 > max_value {
+                    self.error(Message::invalid_enum_repr(
+                        enum_name.location,
+                        format!(
+                            "Value {counter} of unit \"{enum_name}::{unit_id}\" doesn't fit into \"{repr}\""
+                        ),
+                    ));
+                }
             }
 
             // mark unmarked enum fields
-            *unit_value = Some(counter);
+            unit.value = Some(counter);
 
             let unit_data = EnumData {
                 name: enum_name.clone(),
@@ -57,25 +127,46 @@ impl Analyzer {
             let entry = Entry {
                 id: *unit_id,
                 is_static: true,
+                publicity: Publicity::Public,
+                deprecation: None,
+                location: unit.location,
                 kind: unit_data.into(),
+                usage: Usage::default(),
             };
 
             enums_entries.insert(*unit_id, entry);
 
-            counter += 1;
+            counter = if is_flags {
+                counter.saturating_add(1).next_power_of_two()
+            } else {
+                counter.saturating_add(1)
+            };
         }
 
         Ok(enums_entries)
     }
 }
 
+fn get_integer_max(ty: &Type) -> Option<u128> {
+    let size = get_numeric_size(ty)?;
+    let is_signed = matches!(
+        ty,
+        Type::I8 | Type::I16 | Type::I32 | Type::I64 | Type::I128
+    );
+
+    Some(u128::MAX >> (128 - size + is_signed as usize))
+}
+
 #[cfg(test)]
 mod tests {
-    use tanitc_attributes::Mutability;
-    use tanitc_hir::hir::type_spec::Type;
+    use tanitc_attributes::{Mutability, Publicity};
+    use tanitc_hir::hir::{
+        definitions::Definition, expressions::binary::BinaryOperation, type_spec::Type, Hir,
+    };
     use tanitc_hir_test::{
-        create_enum_def, create_main_func_def, create_module_def, create_program,
-        create_scope_resolutions_expr, create_var_def,
+        create_binary_expr, create_custom_type, create_enum_def, create_main_func_def,
+        create_module_def, create_program, create_scope_resolutions_expr, create_var_def,
+        create_word_attribute,
     };
 
     use crate::Analyzer;
@@ -132,7 +223,7 @@ mod tests {
         const FIRST_UNIT_NAME: &str = "First";
         const SECOND_UNIT_NAME: &str = "Second";
         const MAX_UNIT_NAME: &str = "Max";
-        let enum_def = create_enum_def(
+        let mut enum_def = create_enum_def(
             ENUM_NAME,
             vec![
                 (FIRST_UNIT_NAME, Some(1)),
@@ -140,6 +231,7 @@ mod tests {
                 (MAX_UNIT_NAME, None),
             ],
         );
+        enum_def.attributes.publicity = Publicity::Public;
 
         const MODULE_NAME: &str = "MyModule";
         let module_def = create_module_def(MODULE_NAME, vec![enum_def.into()]);
@@ -155,7 +247,7 @@ mod tests {
 
         /*
          * module MyModule
-         *     enum MyEnum {
+         *     pub enum MyEnum {
          *         One: 1
          *         Second
          *         Max
@@ -289,7 +381,7 @@ mod tests {
         const FIRST_UNIT_NAME: &str = "First";
         const SECOND_UNIT_NAME: &str = "Second";
         const MAX_UNIT_NAME: &str = "Max";
-        let enum_def = create_enum_def(
+        let mut enum_def = create_enum_def(
             ENUM_NAME,
             vec![
                 (FIRST_UNIT_NAME, Some(1)),
@@ -297,6 +389,7 @@ mod tests {
                 (MAX_UNIT_NAME, None),
             ],
         );
+        enum_def.attributes.publicity = Publicity::Public;
 
         const MODULE_NAME: &str = "MyModule";
         let module_def = create_module_def(MODULE_NAME, vec![enum_def.into()]);
@@ -312,7 +405,7 @@ mod tests {
 
         /*
          * module MyModule
-         *     enum MyEnum {
+         *     pub enum MyEnum {
          *         One: 1
          *         Second
          *         Max
@@ -340,4 +433,163 @@ mod tests {
         assert_eq!(errors.len(), 1);
         assert_eq!(errors[0].text, EXPECTED_ERR);
     }
+
+    #[test]
+    fn flags_enum_test() {
+        // Given
+        const ENUM_NAME: &str = "Access";
+        let mut enum_def = create_enum_def(
+            ENUM_NAME,
+            vec![
+                ("Read", None),
+                ("Write", None),
+                ("Exec", Some(16)),
+                ("Sync", None),
+            ],
+        );
+        enum_def.attributes.meta = vec![create_word_attribute("flags")];
+        enum_def.repr = Some(Type::U8);
+
+        let var_value = create_binary_expr(
+            create_scope_resolutions_expr(&[ENUM_NAME, "Read"]),
+            BinaryOperation::BitwiseOr,
+            create_scope_resolutions_expr(&[ENUM_NAME, "Sync"]),
+        );
+        let var_def = create_var_def(
+            "access",
+            Mutability::Immutable,
+            create_custom_type(&[ENUM_NAME]),
+            Some(var_value),
+        );
+
+        let main_func = create_main_func_def(vec![var_def.into()]);
+
+        /* #[flags]
+         * enum Access: u8 {
+         *     Read
+         *     Write
+         *     Exec: 16
+         *     Sync
+         * }
+         *
+         * func main() {
+         *     var access: Access = Access::Read | Access::Sync
+         * }
+         */
+        let mut program = create_program(vec![enum_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+
+        let Hir::Block(block) = &program else {
+            panic!("Expected block");
+        };
+        let Hir::Definition(Definition::Enum(enum_def)) = &block.statements[0] else {
+            panic!("Expected enum");
+        };
+
+        let values: Vec<usize> = enum_def
+            .units
+            .values()
+            .filter_map(|unit| unit.value)
+            .collect();
+        assert_eq!(values, vec![1, 2, 16, 32]);
+    }
+
+    #[test]
+    fn bitwise_operation_on_enum_bad_test() {
+        // Given
+        const ENUM_NAME: &str = "Color";
+        let enum_def = create_enum_def(ENUM_NAME, vec![("Red", None), ("Green", None)]);
+
+        let var_value = create_binary_expr(
+            create_scope_resolutions_expr(&[ENUM_NAME, "Red"]),
+            BinaryOperation::BitwiseOr,
+            create_scope_resolutions_expr(&[ENUM_NAME, "Green"]),
+        );
+        let var_def = create_var_def(
+            "color",
+            Mutability::Immutable,
+            create_custom_type(&[ENUM_NAME]),
+            Some(var_value),
+        );
+
+        let main_func = create_main_func_def(vec![var_def.into()]);
+
+        /* enum Color {
+         *     Red
+         *     Green
+         * }
+         *
+         * func main() {
+         *     var color: Color = Color::Red | Color::Green
+         * }
+         */
+        let mut program = create_program(vec![enum_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str =
+            "Semantic error: Operation \"|\" requires flags enum, but \"Color\" is not marked as #[flags]";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+
+    #[test]
+    fn enum_repr_bad_test() {
+        // Given
+        let mut opcode_def = create_enum_def("Opcode", vec![("Nop", None), ("Wide", Some(256))]);
+        opcode_def.repr = Some(Type::U8);
+
+        let mut ratio_def = create_enum_def("Ratio", vec![("Half", None)]);
+        ratio_def.repr = Some(Type::F32);
+
+        /* enum Opcode: u8 {
+         *     Nop
+         *     Wide: 256
+         * }
+         *
+         * enum Ratio: f32 {
+         *     Half
+         * }
+         *
+         * func main() { }
+         */
+        let mut program = create_program(vec![
+            opcode_def.into(),
+            ratio_def.into(),
+            create_main_func_def(vec![]).into(),
+        ]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR_1: &str =
+            "Semantic error: Value 256 of unit \"Opcode::Wide\" doesn't fit into \"u8\"";
+        const EXPECTED_ERR_2: &str =
+            "Semantic error: Representation type of enum \"Ratio\" must be integer, found \"f32\"";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].text, EXPECTED_ERR_1);
+        assert_eq!(errors[1].text, EXPECTED_ERR_2);
+    }
 }
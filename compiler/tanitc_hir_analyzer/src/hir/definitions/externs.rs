@@ -1,6 +1,13 @@
-use tanitc_hir::{hir::definitions::externs::ExternDef, visitor::VisitorMut};
+use tanitc_hir::{
+    hir::definitions::{externs::ExternDef, variables::VariableDef},
+    visitor::VisitorMut,
+};
+use tanitc_messages::Message;
 
-use crate::{AnalyzeResult, Analyzer};
+use crate::{
+    symbol_table::entry::{Entry, Usage, VarDefData, VarStorageType},
+    AnalyzeResult, Analyzer,
+};
 
 impl Analyzer {
     pub(crate) fn analyze_extern_def(&mut self, extern_def: &mut ExternDef) -> AnalyzeResult<()> {
@@ -10,12 +17,154 @@ impl Analyzer {
             }
         }
 
+        for var_def in extern_def.statics.iter_mut() {
+            if let Err(err) = self.analyze_extern_static_def(var_def) {
+                self.error(err);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn analyze_extern_static_def(&mut self, var_def: &mut VariableDef) -> AnalyzeResult<()> {
+        if self.has_symbol(var_def.identifier) {
+            return Err(self.multiple_ids_error(var_def.location, var_def.identifier));
+        }
+
+        self.check_deprecated_type(&var_def.var_type, var_def.location);
+
+        let Some(type_info) = self.table.lookup_type(&var_def.var_type) else {
+            return Err(Message::undefined_type(
+                var_def.location,
+                var_def.var_type.to_string(),
+            ));
+        };
+        var_def.var_type = type_info.ty;
+
+        let var_def_data = VarDefData {
+            storage: VarStorageType::Extern,
+            var_type: var_def.var_type.clone(),
+            mutability: var_def.mutability,
+            is_initialization: false,
+        };
+        let entry = Entry {
+            id: var_def.identifier,
+            is_static: true,
+            publicity: var_def.attributes.publicity,
+            deprecation: None,
+            location: var_def.location,
+            kind: var_def_data.into(),
+            usage: Usage::default(),
+        };
+
+        self.add_symbol(entry);
+
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use tanitc_attributes::{Abi, Mutability, Safety};
+    use tanitc_hir::hir::{definitions::externs::ExternDef, type_spec::Type};
+    use tanitc_hir_test::{
+        create_func_def, create_main_func_def, create_program, create_var, create_var_def,
+    };
+
+    use crate::Analyzer;
+
+    fn create_errno_extern_def() -> ExternDef {
+        ExternDef {
+            abi: Abi::C,
+            statics: vec![
+                create_var_def("errno", Mutability::Mutable, Type::I32, None),
+                create_var_def("limit", Mutability::Immutable, Type::I32, None),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn extern_static_use_good_test() {
+        // Given
+        let safe_main = create_main_func_def(vec![create_var_def(
+            "a",
+            Mutability::Immutable,
+            Type::I32,
+            Some(create_var(&["limit"])),
+        )
+        .into()]);
+
+        /* extern "C" {
+         *     static mut errno: i32
+         *     static limit: i32
+         * }
+         * func main() {
+         *     var a: i32 = limit
+         * }
+         */
+        let mut program = create_program(vec![create_errno_extern_def().into(), safe_main.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+    }
+
+    #[test]
+    fn extern_mut_static_use_test() {
+        // Given
+        let var_def = create_var_def(
+            "a",
+            Mutability::Immutable,
+            Type::I32,
+            Some(create_var(&["errno"])),
+        );
+
+        let mut unsafe_func = create_func_def(
+            "unsafe_func",
+            vec![],
+            Type::unit(),
+            vec![var_def.clone().into()],
+        );
+        unsafe_func.attributes.safety = Safety::Unsafe;
+
+        let safe_main = create_main_func_def(vec![var_def.into()]);
+
+        /* extern "C" {
+         *     static mut errno: i32
+         *     static limit: i32
+         * }
+         * unsafe func unsafe_func() {
+         *     var a: i32 = errno
+         * }
+         * func main() {
+         *     var a: i32 = errno
+         * }
+         */
+        let mut program = create_program(vec![
+            create_errno_extern_def().into(),
+            unsafe_func.into(),
+            safe_main.into(),
+        ]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: Use of extern mutable static \"errno\" requires an unsafe function or block";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
 
     /*
     #[test]
@@ -1,13 +1,18 @@
-use tanitc_hir::hir::{
-    definitions::functions::{FunctionDef, FunctionParam},
-    type_spec::{FuncType, FuncTypeParam, Type},
+use tanitc_hir::{
+    attributes::find_attribute,
+    hir::{
+        definitions::functions::{FunctionDef, FunctionParam},
+        type_spec::{FuncType, FuncTypeParam, Type},
+    },
 };
 use tanitc_ident::Ident;
 use tanitc_lexer::location::Location;
 use tanitc_messages::Message;
+use tanitc_name::NameSpec;
 
 use crate::{
-    symbol_table::entry::{Entry, FuncDefData},
+    hir::attributes::AttributeTarget,
+    symbol_table::entry::{Entry, FuncDefData, Usage},
     AnalyzeResult, Analyzer,
 };
 
@@ -15,20 +20,41 @@ impl Analyzer {
     pub(crate) fn analyze_func_def(
         &mut self,
         func_def: &mut FunctionDef,
-        is_method: bool,
+        owner: Option<&NameSpec>,
     ) -> AnalyzeResult<()> {
+        let is_method = owner.is_some();
+
         let func_id = func_def
             .name
             .get_id()
             .ok_or(Message::empty_name_spec(func_def.location))?;
 
         if self.has_symbol(func_id) {
-            return Err(Message::multiple_ids(func_def.location, func_id));
+            return Err(self.multiple_ids_error(func_def.location, func_id));
         }
 
         // Copies table.table_path to start of enum_def.name.path
         func_def.name.path.splice(0..0, self.table.get_path());
 
+        let deprecation =
+            self.analyze_attributes(&func_def.attributes.meta, AttributeTarget::Function)?;
+        let is_inline = find_attribute(&func_def.attributes.meta, "inline").is_some();
+
+        if is_inline && func_def.body.is_none() {
+            self.error(Message::misplaced_attribute(
+                func_def.location,
+                format!("Function \"{func_id}\" without body can not be \"inline\""),
+            ));
+        }
+
+        if func_def.is_variadic {
+            self.check_variadic_func_def(func_id, func_def);
+        }
+
+        if func_def.attributes.abi.is_c() && func_def.body.is_some() {
+            self.check_exported_func_def(func_id, func_def, is_inline, is_method);
+        }
+
         let mut scope_info = self.table.get_scope_info();
         scope_info.safety = func_def.attributes.safety;
         scope_info.is_in_func = true;
@@ -44,10 +70,12 @@ impl Analyzer {
 
         if let Some(body) = &mut func_def.body {
             self.analyze_block(body)?;
+            self.check_unused_params();
         }
 
         self.table.exit_scope();
 
+        self.check_deprecated_type(&func_def.return_type, func_def.location);
         self.analyze_return_type(&mut func_def.return_type, func_def.location)?;
 
         let func_def_data = FuncDefData {
@@ -55,17 +83,24 @@ impl Analyzer {
                 parameters,
                 return_type: Box::new(func_def.return_type.clone()),
                 safety: func_def.attributes.safety,
+                abi: func_def.attributes.abi,
+                is_variadic: func_def.is_variadic,
             },
             name: func_def.name.clone(),
             is_virtual: false,
-            is_inline: false,
+            is_inline,
+            owner: owner.cloned(),
             no_return: func_def.return_type == Type::unit(),
         };
 
         self.add_symbol(Entry {
             id: func_id,
             is_static: false,
+            publicity: func_def.attributes.publicity,
+            deprecation,
+            location: func_def.location,
             kind: func_def_data.into(),
+            usage: Usage::default(),
         });
 
         Ok(())
@@ -97,7 +132,7 @@ impl Analyzer {
                 | FunctionParam::SelfRef(_)
                 | FunctionParam::SelfVal(_) => {
                     if !is_method {
-                        self.error(Message::new(
+                        self.error(Message::misplaced_self(
                             location,
                             format!(
                                 "In definition of function \"{func_id}\": \"self\" parameter is allowed only in associated functions")
@@ -105,7 +140,7 @@ impl Analyzer {
                     }
 
                     if index > 0 {
-                        self.error(Message::new(
+                        self.error(Message::misplaced_self(
                             location,
                             format!(
                                 "In definition of function \"{func_id}\": Unexpected \"self\" parameter. Must be the first parameter of the associated function"
@@ -118,6 +153,44 @@ impl Analyzer {
         Ok(parameters)
     }
 
+    fn check_variadic_func_def(&mut self, func_id: Ident, func_def: &FunctionDef) {
+        // Tanit can't define variadic functions, it only calls foreign ones
+        if !func_def.attributes.abi.is_c() || func_def.body.is_some() {
+            self.error(Message::invalid_variadic(
+                func_def.location,
+                format!("Function \"{func_id}\" can not be variadic: only extern functions are allowed to have \"...\""),
+            ));
+        } else if func_def.parameters.is_empty() {
+            self.error(Message::invalid_variadic(
+                func_def.location,
+                format!("Variadic function \"{func_id}\" must have at least one parameter before \"...\""),
+            ));
+        }
+    }
+
+    // Exported functions are called from C by their unmangled names
+    fn check_exported_func_def(
+        &mut self,
+        func_id: Ident,
+        func_def: &FunctionDef,
+        is_inline: bool,
+        is_method: bool,
+    ) {
+        if is_method {
+            self.error(Message::unsupported_abi(
+                func_def.location,
+                format!("Associated function \"{func_id}\" can not have \"C\" ABI"),
+            ));
+        }
+
+        if is_inline {
+            self.error(Message::conflicting_attributes(
+                func_def.location,
+                format!("Function \"{func_id}\" with \"C\" ABI can not be \"inline\""),
+            ));
+        }
+    }
+
     fn analyze_return_type(
         &mut self,
         return_type: &mut Type,
@@ -136,12 +209,14 @@ impl Analyzer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tanitc_attributes::{Mutability, Safety};
+    use tanitc_attributes::{Abi, Mutability, Publicity, Safety};
     use tanitc_hir::hir::Hir;
     use tanitc_hir_test::{
-        create_block, create_call_expr, create_func_def, create_integer_lit, create_main_func_def,
-        create_module_def, create_program, create_var_def,
+        create_block, create_call_expr, create_common_func_param, create_func_def,
+        create_integer_lit, create_main_func_def, create_module_def, create_program,
+        create_var_def, create_word_attribute,
     };
+    use tanitc_messages::codes::ErrorCode;
 
     #[test]
     fn good_func_access_test() {
@@ -172,7 +247,8 @@ mod tests {
     fn good_func_in_module_access_test() {
         // Given
         const FUNC_NAME: &str = "foo";
-        let func_def = create_func_def(FUNC_NAME, vec![], Type::unit(), vec![]);
+        let mut func_def = create_func_def(FUNC_NAME, vec![], Type::unit(), vec![]);
+        func_def.attributes.publicity = Publicity::Public;
 
         const MODULE_NAME: &str = "MyModule";
         let module_def = create_module_def(MODULE_NAME, vec![func_def.into()]);
@@ -181,7 +257,7 @@ mod tests {
         let main_func = create_main_func_def(vec![call_expr.into()]);
 
         /* module MyModule {
-         *     func foo() { }
+         *     pub func foo() { }
          * }
          * func main() {
          *     MyModule::foo()
@@ -203,6 +279,7 @@ mod tests {
         // Given
         const FUNC_NAME: &str = "foo";
         let mut func_def = create_func_def(FUNC_NAME, vec![], Type::unit(), vec![]);
+        func_def.attributes.publicity = Publicity::Public;
         func_def.attributes.safety = Safety::Unsafe;
 
         const MODULE_NAME: &str = "MyModule";
@@ -213,7 +290,7 @@ mod tests {
         main_func.attributes.safety = Safety::Unsafe;
 
         /* module MyModule {
-         *     unsafe func foo() { }
+         *     pub unsafe func foo() { }
          * }
          * unsafe func main() {
          *     MyModule::foo()
@@ -234,7 +311,8 @@ mod tests {
     fn good_func_return_type_from_module_call_test() {
         // Given
         const FUNC_NAME: &str = "foo";
-        let func_def = create_func_def(FUNC_NAME, vec![], Type::I32, vec![]);
+        let mut func_def = create_func_def(FUNC_NAME, vec![], Type::I32, vec![]);
+        func_def.attributes.publicity = Publicity::Public;
 
         const MODULE_NAME: &str = "MyModule";
         let module_def = create_module_def(MODULE_NAME, vec![func_def.into()]);
@@ -245,7 +323,7 @@ mod tests {
         let main_func = create_main_func_def(vec![var_def.into()]);
 
         /* module MyModule {
-         *     func foo() -> i32 { }
+         *     pub func foo() -> i32 { }
          * }
          * func main() {
          *     func var_name: i32 = MyModule::foo()
@@ -368,6 +446,7 @@ mod tests {
         // Given
         const FUNC_NAME: &str = "foo";
         let mut func_def = create_func_def(FUNC_NAME, vec![], Type::unit(), vec![]);
+        func_def.attributes.publicity = Publicity::Public;
         func_def.attributes.safety = Safety::Unsafe;
 
         const MODULE_NAME: &str = "MyModule";
@@ -377,7 +456,7 @@ mod tests {
         let main_func = create_main_func_def(vec![call_expr.into()]);
 
         /* module MyModule {
-         *     unsafe func foo() { }
+         *     pub unsafe func foo() { }
          * }
          * unsafe func main() {
          *     MyModule::foo()
@@ -405,7 +484,8 @@ mod tests {
     fn bad_func_param_from_module_call_test() {
         // Given
         const FUNC_NAME: &str = "foo";
-        let func_def = create_func_def(FUNC_NAME, vec![], Type::unit(), vec![]);
+        let mut func_def = create_func_def(FUNC_NAME, vec![], Type::unit(), vec![]);
+        func_def.attributes.publicity = Publicity::Public;
 
         const MODULE_NAME: &str = "MyModule";
         let module_def = create_module_def(MODULE_NAME, vec![func_def.into()]);
@@ -414,7 +494,7 @@ mod tests {
         let main_func = create_main_func_def(vec![call_expr.into()]);
 
         /* module MyModule {
-         *     func foo() { }
+         *     pub func foo() { }
          * }
          * func main() {
          *     MyModule::foo(45)
@@ -442,7 +522,8 @@ mod tests {
     fn bad_func_return_type_from_module_call_test() {
         // Given
         const FUNC_NAME: &str = "foo";
-        let func_def = create_func_def(FUNC_NAME, vec![], Type::F32, vec![]);
+        let mut func_def = create_func_def(FUNC_NAME, vec![], Type::F32, vec![]);
+        func_def.attributes.publicity = Publicity::Public;
 
         const MODULE_NAME: &str = "MyModule";
         let module_def = create_module_def(MODULE_NAME, vec![func_def.into()]);
@@ -458,7 +539,7 @@ mod tests {
         let main_func = create_main_func_def(vec![var_def.into()]);
 
         /* module MyModule {
-         *     func foo() -> f32 { }
+         *     pub func foo() -> f32 { }
          * }
          * func main() {
          *     var var_name: u16 = MyModule::foo()
@@ -506,6 +587,8 @@ mod tests {
 
         assert_eq!(errors.len(), 1);
         assert_eq!(errors[0].text, EXPECTED_ERR);
+        assert_eq!(errors[0].code, Some(ErrorCode::InvalidEntryPoint));
+        assert!(errors[0].location.is_none());
     }
 
     #[test]
@@ -591,4 +674,73 @@ mod tests {
         // Then
         res.expect("Expected no errors");
     }
+
+    #[test]
+    fn variadic_func_bad_test() {
+        // Given
+        let mut func_def = create_func_def(
+            "log",
+            vec![create_common_func_param(
+                "level",
+                Mutability::Immutable,
+                Type::I32,
+            )],
+            Type::unit(),
+            vec![],
+        );
+        func_def.is_variadic = true;
+
+        let main_func = create_main_func_def(vec![]);
+
+        /* func log(level: i32, ...) { }
+         * func main() { }
+         */
+        let mut program = create_program(vec![func_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: Function \"log\" can not be variadic: only extern functions are allowed to have \"...\"";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+
+    #[test]
+    fn exported_func_bad_test() {
+        // Given
+        let mut func_def = create_func_def("fast", vec![], Type::unit(), vec![]);
+        func_def.attributes.publicity = Publicity::Public;
+        func_def.attributes.abi = Abi::C;
+        func_def.attributes.meta = vec![create_word_attribute("inline")];
+
+        let main_func = create_main_func_def(vec![]);
+
+        /* #[inline]
+         * pub extern "C" func fast() { }
+         * func main() { }
+         */
+        let mut program = create_program(vec![func_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str =
+            "Semantic error: Function \"fast\" with \"C\" ABI can not be \"inline\"";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
 }
@@ -1,7 +1,11 @@
-use tanitc_hir::hir::definitions::{functions::FunctionDef, methods::ImplDef};
-use tanitc_messages::Message;
+use tanitc_hir::hir::{
+    definitions::{functions::FunctionDef, methods::ImplDef},
+    type_spec::Type,
+};
+use tanitc_messages::{codes::ErrorCode, Message};
+use tanitc_name::NameSpec;
 
-use crate::Analyzer;
+use crate::{symbol_table::type_info::TypeInfo, Analyzer};
 
 impl Analyzer {
     pub(crate) fn analyze_impl_def(&mut self, impl_def: &mut ImplDef) -> Result<(), Message> {
@@ -9,19 +13,37 @@ impl Analyzer {
             return Err(Message::new(
                 impl_def.location,
                 format!("Cannot find object named \"{}\"", impl_def.name),
-            ));
+            )
+            .with_code(ErrorCode::UndefinedType));
+        };
+
+        // Methods are bound to the absolute name of their type
+        let owner = match self.table.lookup_type(&Type::Custom(impl_def.name.clone())) {
+            Some(TypeInfo {
+                ty: Type::Custom(type_name),
+                ..
+            }) => type_name,
+            _ => impl_def.name.clone(),
         };
 
-        self.analyze_impl_methods(&mut impl_def.methods)?;
+        self.analyze_impl_methods(&owner, &mut impl_def.methods)?;
+        impl_def.type_name = Some(owner);
 
         Ok(())
     }
 
-    fn analyze_impl_methods(&mut self, methods: &mut [FunctionDef]) -> Result<(), Message> {
+    fn analyze_impl_methods(
+        &mut self,
+        owner: &NameSpec,
+        methods: &mut [FunctionDef],
+    ) -> Result<(), Message> {
         for method in methods.iter_mut() {
-            const IS_METHOD: bool = true;
+            let lint_scope = Self::get_lint_scope(&method.attributes.meta);
+            let res = self.with_lint_scope(lint_scope, |this| {
+                this.analyze_func_def(method, Some(owner))
+            });
 
-            match self.analyze_func_def(method, IS_METHOD) {
+            match res {
                 Ok(_) => {}
                 Err(err) => self.error(err),
             }
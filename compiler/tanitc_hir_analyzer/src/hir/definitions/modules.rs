@@ -3,8 +3,9 @@ use tanitc_ident::Ident;
 use tanitc_messages::Message;
 
 use crate::{
+    hir::attributes::AttributeTarget,
     symbol_table::{
-        entry::{Entry, ModuleDefData, SymbolKind},
+        entry::{Entry, ModuleDefData, SymbolKind, Usage},
         table::Table,
     },
     AnalyzeResult, Analyzer,
@@ -18,20 +19,30 @@ impl Analyzer {
             .ok_or(Message::empty_name_spec(module_def.location))?;
 
         if self.has_symbol(module_id) {
-            return Err(Message::multiple_ids(module_def.location, module_id));
+            return Err(self.multiple_ids_error(module_def.location, module_id));
         }
 
         // Copies table.table_path to start of struct_def.name.path
         module_def.name.path.splice(0..0, self.table.get_path());
 
-        self.table.insert(Entry {
+        let deprecation =
+            self.analyze_attributes(&module_def.attributes.meta, AttributeTarget::Module)?;
+
+        let entry = Entry {
             id: module_id,
             is_static: true,
+            publicity: module_def.attributes.publicity,
+            deprecation,
+            location: module_def.location,
             kind: SymbolKind::from(ModuleDefData {
                 name: module_def.name.clone(),
                 table: Box::new(Table::new()),
             }),
-        });
+            usage: Usage::default(),
+        };
+
+        self.index_definition(&entry);
+        self.table.insert(entry);
 
         self.analyze_module_def_body(module_id, &mut module_def.body)?;
 
@@ -47,12 +58,21 @@ impl Analyzer {
 
         let mut analyzer = Analyzer::new();
         analyzer.set_compile_options(self.compile_options.clone());
+        analyzer.lint_levels = self.lint_levels.clone();
         analyzer.table.set_path(joined_path);
+        analyzer
+            .table
+            .set_outer_tables(self.table.get_nested_outer_tables());
 
-        match body {
-            ModuleDefBody::External(body) => body.accept_mut(&mut analyzer)?,
-            ModuleDefBody::Internal(body) => analyzer.analyze_block(body)?,
-        }
+        let res = match body {
+            ModuleDefBody::External(body) => body.accept_mut(&mut analyzer),
+            ModuleDefBody::Internal(body) => analyzer.analyze_global_block(body),
+        };
+
+        self.messages
+            .append(std::mem::take(analyzer.messages_mut()));
+        self.index.append(std::mem::take(&mut analyzer.index));
+        res?;
 
         let entry = self.table.lookup_mut(module_id).unwrap();
         let SymbolKind::ModuleDef(ref mut data) = &mut entry.kind else {
@@ -134,4 +154,101 @@ mod tests {
         assert_eq!(errors.len(), 1);
         assert_eq!(errors[0].text, EXPECTED_ERR);
     }
+
+    #[test]
+    fn private_func_in_module_access_bad_test() {
+        use tanitc_hir_test::{create_call_expr, create_func_def};
+
+        // Given
+        const FUNC_NAME: &str = "foo";
+        let func_def = create_func_def(FUNC_NAME, vec![], Type::unit(), vec![]);
+
+        const MODULE_NAME: &str = "MyModule";
+        let module_def = create_module_def(MODULE_NAME, vec![func_def.into()]);
+
+        let call_expr = create_call_expr(&[MODULE_NAME, FUNC_NAME], vec![]);
+        let main_func = create_main_func_def(vec![call_expr.into()]);
+
+        /*
+         * module MyModule {
+         *     func foo() { }
+         * }
+         *
+         * func main() {
+         *     MyModule::foo() # foo is private
+         * }
+         */
+        let mut program = create_program(vec![module_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: function \"foo\" is private in module \"MyModule\" (defined at TestLocation:1:1)";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+
+    #[test]
+    fn private_module_access_bad_test() {
+        use tanitc_attributes::Publicity;
+
+        // Given
+        const ENUM_NAME: &str = "MyEnum";
+        let mut enum_def = create_enum_def(ENUM_NAME, vec![("First", None)]);
+        enum_def.attributes.publicity = Publicity::Public;
+
+        const INNER_MODULE_NAME: &str = "Inner";
+        let inner_module_def = create_module_def(INNER_MODULE_NAME, vec![enum_def.into()]);
+
+        const OUTER_MODULE_NAME: &str = "Outer";
+        let mut outer_module_def =
+            create_module_def(OUTER_MODULE_NAME, vec![inner_module_def.into()]);
+        outer_module_def.attributes.publicity = Publicity::Public;
+
+        let var_value = Some(create_scope_resolutions_expr(&[
+            OUTER_MODULE_NAME,
+            INNER_MODULE_NAME,
+            ENUM_NAME,
+            "First",
+        ]));
+        let var_def = create_var_def("first", Mutability::Immutable, Type::Auto, var_value);
+
+        let main_func = create_main_func_def(vec![var_def.into()]);
+
+        /*
+         * pub module Outer {
+         *     module Inner {
+         *         pub enum MyEnum {
+         *             First
+         *         }
+         *     }
+         * }
+         *
+         * func main() {
+         *     var first = Outer::Inner::MyEnum::First # Inner is private
+         * }
+         */
+        let mut program = create_program(vec![outer_module_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: module \"Inner\" is private in module \"Outer\" (defined at TestLocation:1:1)";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
 }
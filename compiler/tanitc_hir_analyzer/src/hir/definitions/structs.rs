@@ -3,7 +3,8 @@ use tanitc_messages::Message;
 use tanitc_name::NameSpec;
 
 use crate::{
-    symbol_table::entry::{Entry, StructDefData, StructFieldData, StructFieldsData},
+    hir::attributes::AttributeTarget,
+    symbol_table::entry::{Entry, StructDefData, StructFieldData, StructFieldsData, Usage},
     AnalyzeResult, Analyzer,
 };
 
@@ -15,12 +16,15 @@ impl Analyzer {
             .ok_or(Message::empty_name_spec(struct_def.location))?;
 
         if self.has_symbol(struct_id) {
-            return Err(Message::multiple_ids(struct_def.location, struct_id));
+            return Err(self.multiple_ids_error(struct_def.location, struct_id));
         }
 
         // Copies table.table_path to start of struct_def.name.path
         struct_def.name.path.splice(0..0, self.table.get_path());
 
+        let deprecation =
+            self.analyze_attributes(&struct_def.attributes.meta, AttributeTarget::Struct)?;
+
         let fields = self.analyze_struct_def_fields(&struct_def.name, &struct_def.fields)?;
         let struct_def_data = StructDefData {
             name: struct_def.name.clone(),
@@ -29,7 +33,11 @@ impl Analyzer {
         let entry = Entry {
             id: struct_id,
             is_static: true,
+            publicity: struct_def.attributes.publicity,
+            deprecation,
+            location: struct_def.location,
             kind: struct_def_data.into(),
+            usage: Usage::default(),
         };
 
         self.add_symbol(entry);
@@ -44,6 +52,8 @@ impl Analyzer {
     ) -> AnalyzeResult<StructFieldsData> {
         let mut fields = StructFieldsData::new();
         for (field_id, field_info) in struct_fields.iter() {
+            self.check_deprecated_type(&field_info.ty.ty, field_info.ty.location);
+
             let Some(type_info) = self.table.lookup_type(&field_info.ty.ty) else {
                 self.error(Message::undefined_type(
                     field_info.ty.location,
@@ -55,8 +65,11 @@ impl Analyzer {
             fields.insert(
                 *field_id,
                 StructFieldData {
+                    location: field_info.location,
                     name: struct_name.clone(),
                     ty: type_info.ty,
+                    publicity: field_info.attributes.publicity,
+                    usage: Usage::default(),
                 },
             );
         }
@@ -69,7 +82,7 @@ impl Analyzer {
 mod tests {
     use super::*;
 
-    use tanitc_attributes::Mutability;
+    use tanitc_attributes::{Mutability, Publicity};
     use tanitc_hir::hir::{
         type_spec::{ArraySize, Type},
         Hir,
@@ -79,6 +92,7 @@ mod tests {
         create_main_func_def, create_module_def, create_program, create_struct_def,
         create_struct_lit, create_var_def,
     };
+    use tanitc_ident::Ident;
 
     const STRUCT_NAME: &str = "Vector2";
     const STRUCT_FIELD_X_NAME: &str = "x";
@@ -87,13 +101,20 @@ mod tests {
     const STRUCT_FIELD_Y_TYPE: Type = Type::F32;
 
     fn create_vector2_struct_def() -> StructDef {
-        create_struct_def(
+        let mut struct_def = create_struct_def(
             STRUCT_NAME,
             vec![
                 (STRUCT_FIELD_X_NAME, STRUCT_FIELD_X_TYPE),
                 (STRUCT_FIELD_Y_NAME, STRUCT_FIELD_Y_TYPE),
             ],
-        )
+        );
+
+        struct_def.attributes.publicity = Publicity::Public;
+        for field in struct_def.fields.values_mut() {
+            field.attributes.publicity = Publicity::Public;
+        }
+
+        struct_def
     }
 
     #[test]
@@ -182,9 +203,9 @@ mod tests {
 
         /*
          * module math {
-         *     struct Vector2 {
-         *         x: f32
-         *         y: f32
+         *     pub struct Vector2 {
+         *         pub x: f32
+         *         pub y: f32
          *     }
          * }
          * func main() {
@@ -228,9 +249,9 @@ mod tests {
 
         /*
          * module math {
-         *     struct Vector2 {
-         *         x: f32
-         *         y: f32
+         *     pub struct Vector2 {
+         *         pub x: f32
+         *         pub y: f32
          *     }
          * }
          * func main() {
@@ -507,4 +528,61 @@ mod tests {
     }
 
      */
+
+    #[test]
+    fn private_struct_field_init_bad_test() {
+        // Given
+        const MODULE_NAME: &str = "math";
+        let mut struct_def = create_vector2_struct_def();
+        struct_def
+            .fields
+            .get_mut(&Ident::from(STRUCT_FIELD_Y_NAME.to_string()))
+            .unwrap()
+            .attributes
+            .publicity = Publicity::Private;
+
+        let module_math = create_module_def(MODULE_NAME, vec![struct_def.into()]);
+
+        let var_value = Some(create_struct_lit(
+            &[MODULE_NAME, STRUCT_NAME],
+            vec![
+                (STRUCT_FIELD_X_NAME, create_decimal_lit(0.0)),
+                (STRUCT_FIELD_Y_NAME, create_decimal_lit(2.0)),
+            ],
+        ));
+        let var_def_vec = create_var_def("vec", Mutability::Mutable, Type::Auto, var_value);
+
+        let main_func = create_main_func_def(vec![var_def_vec.into()]);
+
+        /*
+         * module math {
+         *     pub struct Vector2 {
+         *         pub x: f32
+         *         y: f32
+         *     }
+         * }
+         * func main() {
+         *     var mut vec = math::Vector2 {
+         *                       x: 0.0,
+         *                       y: 2.0, # y is private
+         *                   }
+         * }";
+         */
+        let mut program = create_program(vec![module_math.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str =
+            "Semantic error: Struct field \"y\" of \"math::Vector2\" is private";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
 }
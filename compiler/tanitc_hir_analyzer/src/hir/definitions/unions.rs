@@ -3,7 +3,8 @@ use tanitc_messages::Message;
 use tanitc_name::NameSpec;
 
 use crate::{
-    symbol_table::entry::{Entry, StructFieldData, StructFieldsData, UnionDefData},
+    hir::attributes::AttributeTarget,
+    symbol_table::entry::{Entry, StructFieldData, StructFieldsData, UnionDefData, Usage},
     AnalyzeResult, Analyzer,
 };
 
@@ -15,12 +16,15 @@ impl Analyzer {
             .ok_or(Message::empty_name_spec(union_def.location))?;
 
         if self.has_symbol(union_id) {
-            return Err(Message::multiple_ids(union_def.location, union_id));
+            return Err(self.multiple_ids_error(union_def.location, union_id));
         }
 
         // Copies table.table_path to start of union_def.name.path
         union_def.name.path.splice(0..0, self.table.get_path());
 
+        let deprecation =
+            self.analyze_attributes(&union_def.attributes.meta, AttributeTarget::Union)?;
+
         let fields = self.analyze_union_def_fields(&union_def.name, &union_def.fields)?;
         let union_def_data = UnionDefData {
             name: union_def.name.clone(),
@@ -29,7 +33,11 @@ impl Analyzer {
         let entry = Entry {
             id: union_id,
             is_static: true,
+            publicity: union_def.attributes.publicity,
+            deprecation,
+            location: union_def.location,
             kind: union_def_data.into(),
+            usage: Usage::default(),
         };
 
         self.add_symbol(entry);
@@ -45,6 +53,8 @@ impl Analyzer {
         let mut fields = StructFieldsData::new();
 
         for (field_id, field_info) in union_fields.iter() {
+            self.check_deprecated_type(&field_info.ty.ty, field_info.ty.location);
+
             let Some(ty) = self.table.lookup_type(&field_info.ty.ty) else {
                 self.error(Message::undefined_type(
                     field_info.ty.location,
@@ -56,8 +66,11 @@ impl Analyzer {
             fields.insert(
                 *field_id,
                 StructFieldData {
+                    location: field_info.location,
                     name: union_name.clone(),
                     ty: ty.ty,
+                    publicity: field_info.attributes.publicity,
+                    usage: Usage::default(),
                 },
             );
         }
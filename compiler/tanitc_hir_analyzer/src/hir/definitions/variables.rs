@@ -3,18 +3,18 @@ use tanitc_lexer::location::Location;
 use tanitc_messages::Message;
 
 use crate::{
-    symbol_table::entry::{Entry, VarDefData, VarStorageType},
+    symbol_table::entry::{Entry, Usage, VarDefData, VarStorageType},
     AnalyzeResult, Analyzer,
 };
 
 impl Analyzer {
     pub(crate) fn analyze_variable_def(&mut self, var_def: &mut VariableDef) -> AnalyzeResult<()> {
         if self.has_symbol(var_def.identifier) {
-            return Err(Message::multiple_ids(var_def.location, var_def.identifier));
+            return Err(self.multiple_ids_error(var_def.location, var_def.identifier));
         }
 
         if Type::Auto == var_def.var_type && var_def.value.is_none() {
-            return Err(Message::new(
+            return Err(Message::type_annotation_needed(
                 var_def.location,
                 format!(
                     "Type annotation needed for variable named \"{}\"",
@@ -23,20 +23,23 @@ impl Analyzer {
             ));
         }
 
-        let rhs_type_info = if let Some(rhs) = &mut var_def.value {
+        if Type::Auto != var_def.var_type {
+            // Analyze specified type
+            self.check_deprecated_type(&var_def.var_type, var_def.location);
+            self.analyze_variable_type(&mut var_def.var_type, var_def.location)?;
+        }
+
+        if let Some(rhs) = &mut var_def.value {
             self.analyze_expression(rhs)?;
-            Some(self.get_expr_type(rhs))
-        } else {
-            None
-        };
 
-        if let Some(rhs_type) = rhs_type_info {
+            // Specified type is expected from rhs
+            let rhs_type = self.get_expected_expr_type(rhs, &var_def.var_type);
+
             if Type::Auto == var_def.var_type {
                 // Use rhs type
-                var_def.var_type = rhs_type.ty.clone();
+                var_def.var_type = rhs_type.ty;
             } else {
-                // Analyze specified type
-                self.analyze_variable_type(&mut var_def.var_type, var_def.location)?;
+                self.check_literal_range(rhs, &var_def.var_type, rhs.location());
                 self.compare_types(&var_def.var_type, &rhs_type.ty, var_def.location)?;
             }
         }
@@ -50,11 +53,19 @@ impl Analyzer {
         let entry = Entry {
             id: var_def.identifier,
             is_static: false,
+            publicity: var_def.attributes.publicity,
+            deprecation: None,
+            location: var_def.location,
             kind: var_def_data.into(),
+            usage: Usage::default(),
         };
 
         self.add_symbol(entry);
 
+        if let Some(rhs) = &var_def.value {
+            self.remember_union_value(var_def.identifier, rhs);
+        }
+
         Ok(())
     }
 
@@ -73,10 +84,16 @@ impl Analyzer {
 mod tests {
     use super::*;
 
-    use tanitc_hir::hir::{blocks::Block, Hir};
-    use tanitc_hir_test::create_main_func_def;
+    use tanitc_attributes::Mutability;
+    use tanitc_hir::hir::{blocks::Block, definitions::Definition, Hir};
+    use tanitc_hir_test::{
+        create_decimal_lit, create_integer_lit, create_main_func_def, create_program, create_var,
+        create_var_def,
+    };
     use tanitc_ident::Ident;
 
+    use crate::lower_source;
+
     #[test]
     fn var_without_type_and_rhs_bad_test() {
         const VAR_NAME: &str = "var";
@@ -106,4 +123,230 @@ mod tests {
         assert_eq!(errors.len(), 1);
         assert_eq!(errors[0].text, EXPECTED_ERR);
     }
+
+    #[test]
+    fn literals_adopt_specified_type_good_test() {
+        // Given
+        let var_1_def = create_var_def(
+            "a",
+            Mutability::Immutable,
+            Type::U8,
+            Some(create_integer_lit(5)),
+        );
+        let var_2_def = create_var_def(
+            "b",
+            Mutability::Immutable,
+            Type::F32,
+            Some(create_decimal_lit(1.5)),
+        );
+        let var_3_def = create_var_def(
+            "c",
+            Mutability::Immutable,
+            Type::F64,
+            Some(create_integer_lit(2)),
+        );
+
+        let main_func =
+            create_main_func_def(vec![var_1_def.into(), var_2_def.into(), var_3_def.into()]);
+
+        /* func main() {
+         *     var a: u8 = 5
+         *     var b: f32 = 1.5
+         *     var c: f64 = 2
+         * }
+         */
+        let mut program = create_program(vec![main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+    }
+
+    #[test]
+    fn literals_default_type_test() {
+        // Given
+        let var_1_def = create_var_def(
+            "a",
+            Mutability::Immutable,
+            Type::Auto,
+            Some(create_integer_lit(5)),
+        );
+        let var_2_def = create_var_def(
+            "b",
+            Mutability::Immutable,
+            Type::Auto,
+            Some(create_decimal_lit(1.5)),
+        );
+        let var_3_def = create_var_def(
+            "c",
+            Mutability::Immutable,
+            Type::I32,
+            Some(create_var(&["a"])),
+        );
+        let var_4_def = create_var_def(
+            "d",
+            Mutability::Immutable,
+            Type::F32,
+            Some(create_var(&["b"])),
+        );
+
+        let main_func = create_main_func_def(vec![
+            var_1_def.into(),
+            var_2_def.into(),
+            var_3_def.into(),
+            var_4_def.into(),
+        ]);
+
+        /* func main() {
+         *     var a = 5
+         *     var b = 1.5
+         *     var c: i32 = a
+         *     var d: f32 = b # b is f64
+         * }
+         */
+        let mut program = create_program(vec![main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str =
+            "Semantic error: Cannot perform operation on objects with different types: f32 and f64";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+
+    #[test]
+    fn decimal_lit_to_integer_bad_test() {
+        // Given
+        let var_def = create_var_def(
+            "a",
+            Mutability::Immutable,
+            Type::U8,
+            Some(create_decimal_lit(1.5)),
+        );
+
+        let main_func = create_main_func_def(vec![var_def.into()]);
+
+        /* func main() {
+         *     var a: u8 = 1.5
+         * }
+         */
+        let mut program = create_program(vec![main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str =
+            "Semantic error: Cannot perform operation on objects with different types: u8 and f64";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+
+    // Returns types of variables defined in "main" after analysis
+    fn get_main_var_types(program: &Hir) -> Vec<Type> {
+        let Hir::Block(block) = program else {
+            panic!("Expected block");
+        };
+
+        let Some(Hir::Definition(Definition::Func(main_func))) = block.statements.first() else {
+            panic!("Expected main function");
+        };
+
+        let body = main_func.body.as_ref().expect("Expected body");
+        body.statements
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Hir::Definition(Definition::Variable(var_def)) => Some(var_def.var_type.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn literals_inference_from_source_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                                \n    var a = 5\
+                                \n    var b: u64 = 1 + 2\
+                                \n    var c = 1 + 2 * 3\
+                                \n    var x: f32 = 2\
+                                \n    var d = -1 + b\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+        assert_eq!(
+            get_main_var_types(&program),
+            vec![Type::I32, Type::U64, Type::I32, Type::F32, Type::U64]
+        );
+    }
+
+    #[test]
+    fn struct_literal_inference_from_source_test() {
+        // Given
+        const SRC_TEXT: &str = "struct U {\
+                                \n    a: i32\
+                                \n}\
+                                \nfunc main() {\
+                                \n    var mut u = U { a: 5 }\
+                                \n    u.a = 6\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+    }
+
+    #[test]
+    fn literal_assignment_bad_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                                \n    1 = 2\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        let messages = res.expect_err("Expected errors");
+        assert_eq!(
+            messages.errors_ref()[0].text,
+            "Semantic error: Cannot perform operation with integer-literal in this context"
+        );
+    }
 }
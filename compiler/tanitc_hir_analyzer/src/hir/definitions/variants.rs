@@ -1,9 +1,10 @@
 use std::collections::BTreeMap;
 
 use crate::symbol_table::entry::{
-    Entry, StructFieldData, StructFieldsData, VariantData, VariantDefData, VariantKind,
+    Entry, StructFieldData, StructFieldsData, Usage, VariantData, VariantDefData, VariantKind,
     VariantStruct, VariantTuple,
 };
+use tanitc_attributes::Publicity;
 use tanitc_hir::hir::{
     definitions::{
         structs::StructFieldsInfo,
@@ -16,7 +17,7 @@ use tanitc_lexer::location::Location;
 use tanitc_messages::Message;
 use tanitc_name::NameSpec;
 
-use crate::{AnalyzeResult, Analyzer};
+use crate::{hir::attributes::AttributeTarget, AnalyzeResult, Analyzer};
 
 impl Analyzer {
     pub(crate) fn analyze_variant_def(
@@ -31,11 +32,14 @@ impl Analyzer {
             .ok_or(Message::empty_name_spec(variant_def.location))?;
 
         if self.has_symbol(variant_id) {
-            return Err(Message::multiple_ids(variant_def.location, variant_id));
+            return Err(self.multiple_ids_error(variant_def.location, variant_id));
         }
 
         variant_def.name.path.splice(0..0, self.table.get_path());
 
+        let deprecation =
+            self.analyze_attributes(&variant_def.attributes.meta, AttributeTarget::Variant)?;
+
         let variants = self.get_variants_from_definition(variant_def)?;
         let variant_def_data = VariantDefData {
             name: variant_def.name.clone(),
@@ -44,7 +48,11 @@ impl Analyzer {
         let entry = Entry {
             id: variant_id,
             is_static: true,
+            publicity: variant_def.attributes.publicity,
+            deprecation,
+            location: variant_def.location,
             kind: variant_def_data.into(),
+            usage: Usage::default(),
         };
 
         self.add_symbol(entry);
@@ -54,7 +62,7 @@ impl Analyzer {
 
     fn check_variants_are_allowed(&self, location: Location) -> AnalyzeResult<()> {
         if !self.compile_options.allow_variants {
-            return Err(Message::new(
+            return Err(Message::disabled_feature(
                 location,
                 "Variants are not supported in 0.1.0 (use \"--variants\" to enable variants)",
             ));
@@ -73,8 +81,11 @@ impl Analyzer {
             variant_fields.insert(
                 *field_name,
                 StructFieldData {
+                    location: field_ty.location,
                     name: NameSpec::default(),
                     ty: field_ty.ty.get_type(),
+                    publicity: Publicity::Public,
+                    usage: Usage::default(),
                 },
             );
         }
@@ -85,14 +96,21 @@ impl Analyzer {
         })
     }
 
-    fn get_tuple_variant_kind(&self, variant_tuple_components: &[Type]) -> VariantKind {
+    fn get_tuple_variant_kind(
+        &self,
+        variant_tuple_components: &[Type],
+        location: Location,
+    ) -> VariantKind {
         let mut variant_fields = BTreeMap::<usize, StructFieldData>::new();
         for (field_num, field_ty) in variant_tuple_components.iter().enumerate() {
             variant_fields.insert(
                 field_num,
                 StructFieldData {
+                    location,
                     name: NameSpec::default(),
                     ty: field_ty.clone(),
+                    publicity: Publicity::Public,
+                    usage: Usage::default(),
                 },
             );
         }
@@ -115,7 +133,9 @@ impl Analyzer {
             let variant_data = VariantData {
                 variant_kind: match variant {
                     VariantField::Enum => VariantKind::Enum,
-                    VariantField::Tuple(fields) => self.get_tuple_variant_kind(fields),
+                    VariantField::Tuple(fields) => {
+                        self.get_tuple_variant_kind(fields, variant_def.location)
+                    }
                     VariantField::Struct(fields) => {
                         self.get_struct_variant_kind(&variant_name, fields)
                     }
@@ -130,7 +150,11 @@ impl Analyzer {
                 Entry {
                     id: *variant_unit_id,
                     is_static: true,
+                    publicity: Publicity::Public,
+                    deprecation: None,
+                    location: variant_def.location,
                     kind: variant_data.into(),
+                    usage: Usage::default(),
                 },
             );
         }
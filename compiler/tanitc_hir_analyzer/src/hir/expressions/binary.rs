@@ -10,6 +10,10 @@ use tanitc_hir::hir::{
 use tanitc_messages::Message;
 
 use crate::{
+    hir::{
+        definitions::enums::FLAGS_ATTR,
+        expressions::{is_untyped_literal, member_access::get_local_var_id},
+    },
     symbol_table::{entry::SymbolKind, type_info::TypeInfo},
     AnalyzeResult, Analyzer,
 };
@@ -18,31 +22,63 @@ impl Analyzer {
     pub(crate) fn analyze_binary_expr(&mut self, expr: &mut BinaryExpr) -> AnalyzeResult<()> {
         self.analyze_expression(&mut expr.rhs)?;
 
-        let rhs_type = self.get_expr_type(&expr.rhs);
         let does_mutate = expr.operation.does_mutate();
 
         let lhs_type = match expr.lhs.as_mut() {
+            // In expressions like "1 + a" literal takes the type of "a"
+            lhs if !does_mutate && is_untyped_literal(lhs) => {
+                let rhs_type = self.get_expr_type(&expr.rhs).ty;
+                self.check_literal_range(lhs, &rhs_type, lhs.location());
+                self.get_expected_expr_type(lhs, &rhs_type).ty
+            }
             Expression::Variable(var) => {
+                self.analyze_variable_usage(var)?;
+
                 let entry = self
                     .table
                     .lookup_name_spec(&var.name)
-                    .map_err(|err| Message::new(var.location, err))?;
+                    .map_err(|err| err.into_message(var.location))?;
 
-                let SymbolKind::VarDef(var_data) = &entry.kind else {
-                    return Err(Message::undefined_variable(var.location, &var.name));
-                };
+                let entry = entry.resolve_import();
+                match &entry.kind {
+                    SymbolKind::VarDef(var_data) => {
+                        if var_data.mutability.is_const() && does_mutate {
+                            return Err(Message::const_var_mutation(var.location, &var.name));
+                        }
 
-                if var_data.mutability.is_const() && does_mutate {
-                    return Err(Message::const_var_mutation(var.location, &var.name));
-                }
+                        if let Type::Ref(ref_type) = &var_data.var_type {
+                            if ref_type.mutability.is_const() && does_mutate {
+                                return Err(Message::const_ref_mutation(var.location, &var.name));
+                            }
+                        }
+
+                        if does_mutate {
+                            entry.usage.mark_mutated();
+                        }
+
+                        let var_type = var_data.var_type.clone();
 
-                if let Type::Ref(ref_type) = &var_data.var_type {
-                    if ref_type.mutability.is_const() && does_mutate {
-                        return Err(Message::const_ref_mutation(var.location, &var.name));
+                        if let (true, Some(var_id)) = (does_mutate, get_local_var_id(&expr.lhs)) {
+                            self.remember_union_value(var_id, &expr.rhs);
+                        }
+
+                        var_type
+                    }
+                    // Units of enums are constants, so they can only be read
+                    SymbolKind::Enum(unit_data) if !does_mutate => {
+                        Type::Custom(unit_data.name.clone())
                     }
+                    _ => return Err(Message::undefined_variable(var.location, &var.name)),
+                }
+            }
+            Expression::MemberAccess(member_access) => {
+                if does_mutate {
+                    self.analyze_member_write(member_access)?;
+                } else {
+                    self.analyze_member_access_expr(member_access)?;
                 }
 
-                var_data.var_type.clone()
+                self.get_member_access_expr_type(member_access).ty
             }
             Expression::Unary(UnaryExpr {
                 node,
@@ -50,7 +86,7 @@ impl Analyzer {
                 location,
             }) => {
                 if !matches!(operation, UnaryOperation::Deref) {
-                    return Err(Message::new(
+                    return Err(Message::invalid_operand(
                         *location,
                         "Cannot perform operation on rvalue",
                     ));
@@ -58,7 +94,7 @@ impl Analyzer {
                 self.get_expr_type(node.as_ref()).ty
             }
             Expression::Literal(lit) => {
-                return Err(Message::new(
+                return Err(Message::invalid_operand(
                     lit.location(),
                     format!(
                         "Cannot perform operation with {} in this context",
@@ -67,7 +103,7 @@ impl Analyzer {
                 ))
             }
             expr => {
-                return Err(Message::new(
+                return Err(Message::invalid_operand(
                     expr.location(),
                     format!(
                         "Cannot perform operation with {} in this context",
@@ -77,8 +113,11 @@ impl Analyzer {
             }
         };
 
+        self.check_literal_range(&expr.rhs, &lhs_type, expr.rhs.location());
+        let rhs_type = self.get_expected_expr_type(&expr.rhs, &lhs_type);
+
         if lhs_type != rhs_type.ty {
-            self.error(Message::new(
+            self.error(Message::mismatched_types(
                 expr.rhs.location(),
                 format!(
                     "Cannot perform operation on objects with different types: {} and {rhs_type}",
@@ -87,10 +126,42 @@ impl Analyzer {
             ));
         }
 
+        self.check_enum_operation(expr, &lhs_type);
+
         Ok(())
     }
 
-    pub(crate) fn get_binary_expr_type(&self, expr: &BinaryExpr) -> TypeInfo {
+    // Bitwise operations are allowed only on flags enums
+    fn check_enum_operation(&mut self, expr: &BinaryExpr, lhs_type: &Type) {
+        if !matches!(
+            expr.operation,
+            BinaryOperation::BitwiseOr | BinaryOperation::BitwiseXor | BinaryOperation::BitwiseAnd
+        ) {
+            return;
+        }
+
+        let Type::Custom(name) = lhs_type else {
+            return;
+        };
+
+        let Ok(entry) = self.table.lookup_name_spec(name) else {
+            return;
+        };
+
+        if let SymbolKind::EnumDef(data) = &entry.resolve_import().kind {
+            if !data.is_flags {
+                self.error(Message::invalid_operand(
+                    expr.location,
+                    format!(
+                        "Operation \"{}\" requires flags enum, but \"{name}\" is not marked as #[{FLAGS_ATTR}]",
+                        expr.operation
+                    ),
+                ));
+            }
+        }
+    }
+
+    pub(crate) fn get_binary_expr_type(&self, expr: &BinaryExpr, expected: &Type) -> TypeInfo {
         match expr.operation {
             BinaryOperation::LogicalNe
             | BinaryOperation::LogicalEq
@@ -103,7 +174,16 @@ impl Analyzer {
                 ..Default::default()
             },
 
-            _ => self.get_expr_type(&expr.lhs),
+            _ => {
+                // In expressions like "1 + a" literal takes the type of "a"
+                let lhs_expected = if *expected == Type::Auto && is_untyped_literal(&expr.lhs) {
+                    self.get_expr_type(&expr.rhs).ty
+                } else {
+                    expected.clone()
+                };
+
+                self.get_expected_expr_type(&expr.lhs, &lhs_expected)
+            }
         }
     }
 }
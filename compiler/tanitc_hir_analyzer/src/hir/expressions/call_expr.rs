@@ -4,25 +4,30 @@ use tanitc_attributes::Mutability;
 use tanitc_hir::hir::{
     expressions::{
         call::{CallArg, CallExpr, NamedCallArg, PositionalCallArg},
+        conversion::ConversionExpr,
+        literal::Literal,
         Expression,
     },
-    type_spec::{FuncType, FuncTypeParam, Type},
+    type_spec::{FuncType, FuncTypeParam, PtrType, Type, TypeSpec},
 };
 
 use tanitc_lexer::location::Location;
 use tanitc_messages::Message;
 
-use crate::{symbol_table::type_info::TypeInfo, AnalyzeResult, Analyzer};
+use crate::{
+    symbol_table::{entry::SymbolKind, type_info::TypeInfo},
+    AnalyzeResult, Analyzer,
+};
 
 impl Analyzer {
     pub(crate) fn analyze_call_expr(&mut self, expr: &mut CallExpr) -> AnalyzeResult<()> {
-        if let Expression::Variable(var) = expr.expr.as_ref() {
+        if let Expression::Variable(var) = expr.expr.as_mut() {
             self.analyze_variable_usage(var)?;
         }
 
         let expr_type = self.get_expr_type(&expr.expr);
         let Type::Func(func_type) = &expr_type.ty else {
-            return Err(Message::new(
+            return Err(Message::not_callable(
                 expr.location,
                 format!("call something that is not a function: \"{expr_type}\""),
             ));
@@ -81,22 +86,31 @@ impl Analyzer {
         };
 
         if *positional_skipped {
-            return Err(Message::new(
+            return Err(Message::argument_mismatch(
                 *location,
                 format!("Call: positional parameter \"{arg_idx}\" must be passed before notified",),
             ));
         }
 
         let Some(func_param) = func_type.parameters.get(*arg_idx) else {
-            return Err(Message::new(
+            if func_type.is_variadic {
+                self.check_variadic_arg(func_type, arg_value, *arg_idx, *location)?;
+                return Ok(*arg_idx);
+            }
+
+            return Err(Message::argument_mismatch(
                 *location,
                 format!("Mismatched parameters: type \"{func_type}\" has no parameter {arg_idx}"),
             ));
         };
 
-        let expr_type = self.get_expr_type(arg_value);
-        if expr_type.ty != *func_param.ty {
-            return Err(Message::new(
+        let expr_type = self.get_arg_type(func_type, arg_value, &func_param.ty);
+        if let (Type::Func(arg_func_type), Type::Func(param_func_type)) =
+            (&expr_type.ty, func_param.ty.as_ref())
+        {
+            self.check_func_type_coerce(arg_func_type, param_func_type, *location)?;
+        } else if expr_type.ty != *func_param.ty {
+            return Err(Message::mismatched_types(
                 *location,
                 format!("Mismatched types. Call: positional parameter \"{arg_idx}\" has type \"{}\" but expected \"{}\"",
                     expr_type.ty, func_param.ty),
@@ -137,9 +151,13 @@ impl Analyzer {
         ) in func_type.parameters.iter().enumerate()
         {
             if *param_name == Some(*arg_id) {
-                let arg_type = self.get_expr_type(arg_value);
-                if **param_type != arg_type.ty {
-                    return Err(Message::new(
+                let arg_type = self.get_arg_type(func_type, arg_value, param_type);
+                if let (Type::Func(arg_func_type), Type::Func(param_func_type)) =
+                    (&arg_type.ty, param_type.as_ref())
+                {
+                    self.check_func_type_coerce(arg_func_type, param_func_type, *location)?;
+                } else if **param_type != arg_type.ty {
+                    return Err(Message::mismatched_types(
                         *location,
                         format!("Mismatched types. Notified parameter \"{arg_id}\" has type \"{arg_type}\" but expected \"{param_type}\"", ),
                     ));
@@ -149,7 +167,7 @@ impl Analyzer {
             }
         }
 
-        Err(Message::new(
+        Err(Message::argument_mismatch(
             *location,
             format!("No parameter named \"{arg_id}\" in function \"{func_type}\""),
         ))
@@ -163,6 +181,10 @@ impl Analyzer {
     ) -> AnalyzeResult<()> {
         let location = arg.location();
 
+        let (CallArg::Notified(NamedCallArg { expr, .. })
+        | CallArg::Positional(PositionalCallArg { expr, .. })) = arg;
+        self.analyze_expression(expr)?;
+
         let res = match arg {
             CallArg::Notified(_) => self.check_notified_arg(func_type, arg, positional_skipped),
             CallArg::Positional(_) => self.check_positional_arg(func_type, arg, positional_skipped),
@@ -170,15 +192,23 @@ impl Analyzer {
 
         match res {
             Ok(arg_position) => {
-                let arg_value = match arg {
-                    CallArg::Notified(NamedCallArg { expr, .. }) => Box::new(expr.clone()),
-                    CallArg::Positional(PositionalCallArg { expr, .. }) => Box::new(expr.clone()),
+                let mut arg_value = match arg {
+                    CallArg::Notified(NamedCallArg { expr, .. }) => expr.clone(),
+                    CallArg::Positional(PositionalCallArg { expr, .. }) => expr.clone(),
                 };
 
+                if let Some(param) = func_type.parameters.get(arg_position) {
+                    self.check_literal_range(&arg_value, &param.ty, arg_value.location());
+                }
+
+                if func_type.abi.is_c() && Self::is_text_literal(&arg_value) {
+                    arg_value = Box::new(Self::convert_to_c_string(*arg_value));
+                }
+
                 *arg = CallArg::Positional(PositionalCallArg {
                     location,
                     id: arg_position,
-                    expr: *arg_value,
+                    expr: arg_value,
                 });
             }
             Err(err) => self.error(err),
@@ -196,6 +226,10 @@ impl Analyzer {
         let actual_len = arguments.len();
         let expected_len = func_type.parameters.len();
 
+        if func_type.is_variadic && actual_len >= expected_len {
+            return Ok(());
+        }
+
         let many_or_few = match actual_len.cmp(&expected_len) {
             Ordering::Greater => "many",
             Ordering::Less => "few",
@@ -203,7 +237,7 @@ impl Analyzer {
         };
 
         if actual_len != expected_len {
-            return Err(Message::new(
+            return Err(Message::argument_mismatch(
                 location,
                 format!(
                     "Too {many_or_few} arguments passed in function, expected: {expected_len}, actually: {actual_len}",
@@ -214,9 +248,90 @@ impl Analyzer {
         Ok(())
     }
 
+    // Text literals are passed to C functions as C strings
+    fn get_arg_type(
+        &self,
+        func_type: &FuncType,
+        arg_value: &Expression,
+        expected: &Type,
+    ) -> TypeInfo {
+        if func_type.abi.is_c()
+            && Self::is_text_literal(arg_value)
+            && *expected == Self::c_string_type()
+        {
+            return TypeInfo {
+                ty: Self::c_string_type(),
+                ..Default::default()
+            };
+        }
+
+        self.get_expected_expr_type(arg_value, expected)
+    }
+
+    fn check_variadic_arg(
+        &self,
+        func_type: &FuncType,
+        arg_value: &Expression,
+        arg_idx: usize,
+        location: Location,
+    ) -> AnalyzeResult<()> {
+        let arg_type = self.get_arg_type(func_type, arg_value, &Type::Auto);
+
+        if Self::is_text_literal(arg_value) || self.is_c_promotable_type(&arg_type.ty) {
+            return Ok(());
+        }
+
+        Err(Message::not_c_compatible(
+            location,
+            format!("Call: variadic argument \"{arg_idx}\" has type \"{arg_type}\" that can not be passed to C function, expected number, \"bool\", enum or pointer"),
+        ))
+    }
+
+    pub(crate) fn is_c_promotable_type(&self, ty: &Type) -> bool {
+        if ty.is_common() || ty.is_pointer() {
+            return true;
+        }
+
+        if let Some(alias_to) = self.find_alias_value(ty) {
+            return self.is_c_promotable_type(&alias_to);
+        }
+
+        let Type::Custom(name) = ty else {
+            return false;
+        };
+
+        self.table
+            .lookup_name_spec(name)
+            .is_ok_and(|entry| matches!(entry.resolve_import().kind, SymbolKind::EnumDef(_)))
+    }
+
+    fn is_text_literal(expr: &Expression) -> bool {
+        matches!(expr, Expression::Literal(Literal::Text(_)))
+    }
+
+    fn c_string_type() -> Type {
+        Type::Ptr(PtrType {
+            ptr_to: Box::new(Type::U8),
+            mutability: Mutability::Immutable,
+        })
+    }
+
+    fn convert_to_c_string(expr: Expression) -> Expression {
+        let location = expr.location();
+
+        Expression::Conversion(ConversionExpr {
+            location,
+            expr: Box::new(expr),
+            ty: TypeSpec {
+                location,
+                ty: Self::c_string_type(),
+            },
+        })
+    }
+
     fn check_call_safety(&mut self, expr: &CallExpr, func_type: &FuncType) -> AnalyzeResult<()> {
         if func_type.safety.is_unsafe() && self.get_current_safety().is_safe() {
-            self.error(Message::new(
+            self.error(Message::unsafe_required(
                 expr.location,
                 "Call unsafe function requires an unsafe function or block",
             ));
@@ -230,16 +345,22 @@ impl Analyzer {
 mod tests {
     use super::*;
 
-    use tanitc_attributes::{Mutability, Safety};
+    use tanitc_attributes::{Abi, Mutability, Publicity, Safety};
     use tanitc_hir::hir::{
         blocks::{Block, BlockAttributes},
+        definitions::{externs::ExternDef, modules::ModuleDefBody, Definition},
+        type_spec::RefType,
         Hir,
     };
     use tanitc_hir_test::{
-        create_block, create_call_expr, create_decimal_lit, create_func_def, create_main_func_def,
-        create_var, create_var_def,
+        create_block, create_call_expr, create_common_func_param, create_decimal_lit,
+        create_func_def, create_integer_lit, create_main_func_def, create_module_def,
+        create_name_spec, create_program, create_text_lit, create_tuple_lit, create_var,
+        create_var_def,
     };
 
+    use crate::lower_source;
+
     #[test]
     fn unsafe_call_bad_test() {
         // Given
@@ -276,6 +397,101 @@ mod tests {
         assert_eq!(errors[0].text, EXPECTED_ERR);
     }
 
+    #[test]
+    fn call_resolves_item_name_test() {
+        // Given
+        let mut origin_def = create_func_def("origin", vec![], Type::unit(), vec![]);
+        origin_def.attributes.publicity = Publicity::Public;
+
+        let module_def = create_module_def("geo", vec![origin_def.into()]);
+        let main_func =
+            create_main_func_def(vec![create_call_expr(&["geo", "origin"], vec![]).into()]);
+
+        /*
+         * module geo {
+         *     pub func origin() { }
+         * }
+         * func main() {
+         *     geo::origin()
+         * }
+         */
+        let mut program = create_program(vec![module_def.into(), main_func.into()]);
+
+        // When
+        let mut analyzer = Analyzer::new();
+        analyzer.analyze_program(&mut program).unwrap();
+
+        // Then
+        let Hir::Block(block) = &program else {
+            panic!("Expected block");
+        };
+        let Hir::Definition(Definition::Func(main_func)) = &block.statements[1] else {
+            panic!("Expected main function");
+        };
+        let Hir::Expression(Expression::Call(call)) =
+            &main_func.body.as_ref().unwrap().statements[0]
+        else {
+            panic!("Expected call");
+        };
+        let Expression::Variable(var) = call.expr.as_ref() else {
+            panic!("Expected variable");
+        };
+
+        assert_eq!(var.item_name, Some(create_name_spec(&["geo", "origin"])));
+    }
+
+    #[test]
+    fn method_call_resolves_item_name_test() {
+        // Given
+        const SRC_TEXT: &str = "module geo {\
+                                \n    pub struct Point { }\
+                                \n    impl Point {\
+                                \n        pub func create() { }\
+                                \n    }\
+                                \n}\
+                                \nuse geo::Point\
+                                \nfunc main() {\
+                                \n    Point::create()\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        // When
+        let mut analyzer = Analyzer::new();
+        analyzer.analyze_program(&mut program).unwrap();
+
+        // Then
+        let Hir::Block(block) = program.as_ref() else {
+            panic!("Expected block");
+        };
+        let Hir::Definition(Definition::Module(module_def)) = &block.statements[0] else {
+            panic!("Expected module");
+        };
+        let ModuleDefBody::Internal(module_block) = &module_def.body else {
+            panic!("Expected internal module");
+        };
+        let Hir::Definition(Definition::Impl(impl_def)) = &module_block.statements[1] else {
+            panic!("Expected impl");
+        };
+        let Hir::Definition(Definition::Func(main_func)) = &block.statements[2] else {
+            panic!("Expected main function");
+        };
+        let Hir::Expression(Expression::Call(call)) =
+            &main_func.body.as_ref().unwrap().statements[0]
+        else {
+            panic!("Expected call");
+        };
+        let Expression::Variable(var) = call.expr.as_ref() else {
+            panic!("Expected variable");
+        };
+
+        assert_eq!(impl_def.get_type_name().to_string(), "geo::Point");
+        assert_eq!(
+            var.item_name.as_ref().map(ToString::to_string).as_deref(),
+            Some("geo::Point::create")
+        );
+    }
+
     #[test]
     fn unsafe_call_good_test() {
         // Given
@@ -369,7 +585,7 @@ mod tests {
         let res = analyzer.analyze_program(&mut program);
 
         // Then
-        const EXPECTED_ERR: &str = "Semantic error: call something that is not a function: \"f32\"";
+        const EXPECTED_ERR: &str = "Semantic error: call something that is not a function: \"f64\"";
 
         let messages = res.expect_err("Expected errors");
         let errors = messages.errors_ref();
@@ -377,4 +593,311 @@ mod tests {
         assert_eq!(errors.len(), 1);
         assert_eq!(errors[0].text, EXPECTED_ERR);
     }
+
+    #[test]
+    fn literal_arg_adopts_param_type_test() {
+        // Given
+        const FUNC_NAME: &str = "foo";
+        let func_def = create_func_def(
+            FUNC_NAME,
+            vec![
+                create_common_func_param("a", Mutability::Immutable, Type::U16),
+                create_common_func_param("b", Mutability::Immutable, Type::F32),
+            ],
+            Type::unit(),
+            vec![],
+        );
+
+        let call_expr = create_call_expr(
+            &[FUNC_NAME],
+            vec![create_integer_lit(1), create_decimal_lit(2.0)],
+        );
+        let main_func = create_main_func_def(vec![call_expr.into()]);
+
+        /*
+         * func foo(a: u16, b: f32) { }
+         * func main() {
+         *     foo(1, 2.0)
+         * }
+         */
+        let mut program = Hir::from(create_block(vec![func_def.into(), main_func.into()]));
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+    }
+
+    fn create_printf_def() -> ExternDef {
+        let mut printf_def = create_func_def(
+            "printf",
+            vec![create_common_func_param(
+                "fmt",
+                Mutability::Immutable,
+                Type::Ptr(PtrType {
+                    ptr_to: Box::new(Type::U8),
+                    mutability: Mutability::Immutable,
+                }),
+            )],
+            Type::I32,
+            vec![],
+        );
+        printf_def.attributes.abi = Abi::C;
+        printf_def.is_variadic = true;
+        printf_def.body = None;
+
+        ExternDef {
+            abi: Abi::C,
+            functions: vec![printf_def],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn variadic_extern_call_good_test() {
+        // Given
+        let call_expr = create_call_expr(
+            &["printf"],
+            vec![
+                create_text_lit("%d %f\\n"),
+                create_integer_lit(1),
+                create_decimal_lit(2.0),
+            ],
+        );
+        let main_func = create_main_func_def(vec![call_expr.into()]);
+
+        /*
+         * extern "C" {
+         *     func printf(fmt: *const u8, ...): i32
+         * }
+         * func main() {
+         *     printf("%d %f\\n", 1, 2.0)
+         * }
+         */
+        let mut program = Hir::from(create_block(vec![
+            create_printf_def().into(),
+            main_func.into(),
+        ]));
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+    }
+
+    #[test]
+    fn variadic_extern_call_bad_test() {
+        // Given
+        let call_expr = create_call_expr(
+            &["printf"],
+            vec![
+                create_text_lit("%d"),
+                create_tuple_lit(vec![create_integer_lit(1), create_integer_lit(2)]),
+            ],
+        );
+        let few_args_call_expr = create_call_expr(&["printf"], vec![]);
+        let main_func = create_main_func_def(vec![call_expr.into(), few_args_call_expr.into()]);
+
+        /*
+         * extern "C" {
+         *     func printf(fmt: *const u8, ...): i32
+         * }
+         * func main() {
+         *     printf("%d", (1, 2))
+         *     printf()
+         * }
+         */
+        let mut program = Hir::from(create_block(vec![
+            create_printf_def().into(),
+            main_func.into(),
+        ]));
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR_1: &str = "Semantic error: Call: variadic argument \"1\" has type \"( i32 i32 )\" that can not be passed to C function, expected number, \"bool\", enum or pointer";
+        const EXPECTED_ERR_2: &str =
+            "Semantic error: Too few arguments passed in function, expected: 1, actually: 0";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].text, EXPECTED_ERR_1);
+        assert_eq!(errors[1].text, EXPECTED_ERR_2);
+    }
+
+    fn create_atexit_def(callback_param_type: Type) -> ExternDef {
+        let callback_type = Type::Func(FuncType {
+            parameters: vec![FuncTypeParam {
+                ty: Box::new(callback_param_type),
+                id: None,
+            }],
+            return_type: Box::new(Type::unit()),
+            safety: Safety::Safe,
+            abi: Abi::C,
+            is_variadic: false,
+        });
+
+        let mut atexit_def = create_func_def(
+            "atexit",
+            vec![create_common_func_param(
+                "cb",
+                Mutability::Immutable,
+                callback_type,
+            )],
+            Type::I32,
+            vec![],
+        );
+        atexit_def.attributes.abi = Abi::C;
+        atexit_def.body = None;
+
+        ExternDef {
+            abi: Abi::C,
+            functions: vec![atexit_def],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn c_callback_good_test() {
+        // Given
+        let param_type = Type::Ptr(PtrType {
+            ptr_to: Box::new(Type::I32),
+            mutability: Mutability::Mutable,
+        });
+
+        let callback_def = create_func_def(
+            "bye",
+            vec![create_common_func_param(
+                "code",
+                Mutability::Immutable,
+                param_type.clone(),
+            )],
+            Type::unit(),
+            vec![],
+        );
+
+        let call_expr = create_call_expr(&["atexit"], vec![create_var(&["bye"])]);
+        let main_func = create_main_func_def(vec![call_expr.into()]);
+
+        /*
+         * extern "C" {
+         *     func atexit(cb: extern "C" func(*mut i32)): i32
+         * }
+         * func bye(code: *mut i32) { }
+         * func main() {
+         *     atexit(bye)
+         * }
+         */
+        let mut program = Hir::from(create_block(vec![
+            create_atexit_def(param_type).into(),
+            callback_def.into(),
+            main_func.into(),
+        ]));
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+    }
+
+    #[test]
+    fn c_callback_bad_test() {
+        // Given
+        let param_type = Type::Ref(RefType {
+            ref_to: Box::new(Type::I32),
+            mutability: Mutability::Immutable,
+        });
+
+        let callback_def = create_func_def(
+            "bye",
+            vec![create_common_func_param(
+                "code",
+                Mutability::Immutable,
+                param_type.clone(),
+            )],
+            Type::unit(),
+            vec![],
+        );
+        let other_def = create_func_def("other", vec![], Type::unit(), vec![]);
+
+        let call_expr = create_call_expr(&["atexit"], vec![create_var(&["bye"])]);
+        let other_call_expr = create_call_expr(&["atexit"], vec![create_var(&["other"])]);
+        let main_func = create_main_func_def(vec![call_expr.into(), other_call_expr.into()]);
+
+        /*
+         * extern "C" {
+         *     func atexit(cb: extern "C" func(&i32)): i32
+         * }
+         * func bye(code: &i32) { }
+         * func other() { }
+         * func main() {
+         *     atexit(bye)
+         *     atexit(other)
+         * }
+         */
+        let mut program = Hir::from(create_block(vec![
+            create_atexit_def(param_type).into(),
+            callback_def.into(),
+            other_def.into(),
+            main_func.into(),
+        ]));
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR_1: &str = "Semantic error: Function \"func (code:&i32) -> ( )\" can not be used as \"C\" callback: parameter 0 has type \"&i32\" that is not C-compatible";
+        const EXPECTED_ERR_2: &str = "Semantic error: Mismatched function types: \"func () -> ( )\" can not be used as \"extern \"C\" func (&i32) -> ( )\"";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].text, EXPECTED_ERR_1);
+        assert_eq!(errors[1].text, EXPECTED_ERR_2);
+    }
+
+    #[test]
+    fn undefined_name_in_call_arg_bad_test() {
+        // Given
+        const SRC_TEXT: &str = "func foo(_a: i32) { }\
+                                \nfunc main() {\
+                                \n    foo(nothere)\
+                                \n    foo(1 + nothere2)\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR_1: &str = "Semantic error: undefined id: \"nothere\"";
+        const EXPECTED_ERR_2: &str = "Semantic error: undefined id: \"nothere2\"";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].text, EXPECTED_ERR_1);
+        assert_eq!(errors[1].text, EXPECTED_ERR_2);
+    }
 }
@@ -1,14 +1,86 @@
-use tanitc_attributes::Mutability;
-use tanitc_hir::hir::expressions::conversion::ConversionExpr;
+use tanitc_attributes::{Mutability, Safety};
+use tanitc_hir::hir::{
+    expressions::{conversion::ConversionExpr, literal::Literal, Expression},
+    type_spec::Type,
+};
+use tanitc_lexer::location::Location;
+use tanitc_lints::LOSSY_CONVERSIONS;
+use tanitc_messages::Message;
 
-use crate::{symbol_table::type_info::TypeInfo, AnalyzeResult, Analyzer};
+use crate::{
+    hir::expressions::is_untyped_literal,
+    symbol_table::{
+        entry::{EnumDefData, SymbolKind},
+        type_info::TypeInfo,
+    },
+    AnalyzeResult, Analyzer,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ConversionKind {
+    Integer,
+    Float,
+    Bool,
+    Enum,
+    Pointer,
+    Reference,
+    Struct,
+    Union,
+    Tuple,
+    Array,
+    Other,
+}
+
+impl ConversionKind {
+    fn is_numeric(&self) -> bool {
+        matches!(self, Self::Integer | Self::Float)
+    }
+
+    fn is_aggregate(&self) -> bool {
+        matches!(self, Self::Struct | Self::Union | Self::Tuple | Self::Array)
+    }
+
+    fn to_str(self) -> &'static str {
+        match self {
+            Self::Integer => "integer",
+            Self::Float => "float",
+            Self::Bool => "bool",
+            Self::Enum => "enum",
+            Self::Pointer => "pointer",
+            Self::Reference => "reference",
+            Self::Struct => "struct",
+            Self::Union => "union",
+            Self::Tuple => "tuple",
+            Self::Array => "array",
+            Self::Other => "type",
+        }
+    }
+}
 
 impl Analyzer {
     pub(crate) fn analyze_conversion_expr(
         &mut self,
-        _expr: &mut ConversionExpr,
+        expr: &mut ConversionExpr,
     ) -> AnalyzeResult<()> {
-        todo!()
+        let location = expr.location;
+
+        self.analyze_expression(&mut expr.expr)?;
+        self.check_deprecated_type(&expr.ty.ty, location);
+
+        let Some(to_type_info) = self.table.lookup_type(&expr.ty.ty) else {
+            return Err(Message::undefined_type(location, expr.ty.ty.to_string()));
+        };
+        expr.ty.ty = to_type_info.ty;
+
+        let to = expr.ty.get_type();
+        let from = if is_untyped_literal(&expr.expr) {
+            self.check_literal_range(&expr.expr, &to, location);
+            self.get_expected_expr_type(&expr.expr, &to).ty
+        } else {
+            self.get_expr_type(&expr.expr).ty
+        };
+
+        self.check_conversion(&expr.expr, &from, &to, location)
     }
 
     pub(crate) fn get_conversion_expr_type(&self, expr: &ConversionExpr) -> TypeInfo {
@@ -18,4 +90,904 @@ impl Analyzer {
             ..Default::default()
         }
     }
+
+    fn check_conversion(
+        &mut self,
+        expr: &Expression,
+        from: &Type,
+        to: &Type,
+        location: Location,
+    ) -> AnalyzeResult<()> {
+        let from_value = self.find_alias_value(from).unwrap_or_else(|| from.clone());
+        let to_value = self.find_alias_value(to).unwrap_or_else(|| to.clone());
+
+        if from_value == to_value {
+            return Ok(());
+        }
+
+        let from_kind = self.get_conversion_kind(&from_value);
+        let to_kind = self.get_conversion_kind(&to_value);
+
+        if from_kind.is_aggregate() || to_kind.is_aggregate() {
+            let (kind, ty) = if from_kind.is_aggregate() {
+                (from_kind, from)
+            } else {
+                (to_kind, to)
+            };
+
+            return Err(Message::invalid_conversion(
+                location,
+                format!(
+                    "Non-primitive conversion from \"{from}\" to \"{to}\": {} \"{ty}\" cannot be converted",
+                    kind.to_str()
+                ),
+            ));
+        }
+
+        match (from_kind, to_kind) {
+            (from_kind, to_kind) if from_kind.is_numeric() && to_kind.is_numeric() => {
+                self.check_lossy_conversion(&from_value, &to_value, location);
+                Ok(())
+            }
+            (ConversionKind::Enum, ConversionKind::Integer) => {
+                if let Some(repr) = self
+                    .get_enum_def_data(&from_value)
+                    .and_then(|data| data.repr)
+                {
+                    self.check_lossy_conversion(&repr, &to_value, location);
+                }
+                Ok(())
+            }
+            (ConversionKind::Integer, ConversionKind::Enum) => {
+                self.check_integer_to_enum_conversion(expr, from, to, location)
+            }
+            (ConversionKind::Bool, ConversionKind::Integer) => Ok(()),
+            (ConversionKind::Reference, ConversionKind::Pointer) => Ok(()),
+            (ConversionKind::Pointer, ConversionKind::Pointer)
+            | (ConversionKind::Pointer, ConversionKind::Integer)
+            | (ConversionKind::Integer, ConversionKind::Pointer) => {
+                if self.get_current_safety() != Safety::Unsafe {
+                    return Err(Message::unsafe_required(
+                        location,
+                        format!("Conversion from \"{from}\" to \"{to}\" requires unsafe function or block"),
+                    ));
+                }
+
+                Ok(())
+            }
+            (from_kind, to_kind) => Err(Message::invalid_conversion(
+                location,
+                format!(
+                    "Cannot convert {} \"{from}\" to {} \"{to}\"",
+                    from_kind.to_str(),
+                    to_kind.to_str()
+                ),
+            )),
+        }
+    }
+
+    fn check_integer_to_enum_conversion(
+        &mut self,
+        expr: &Expression,
+        from: &Type,
+        to: &Type,
+        location: Location,
+    ) -> AnalyzeResult<()> {
+        let Some(data) = self.get_enum_def_data(to) else {
+            return Ok(());
+        };
+
+        let values: Vec<usize> = data
+            .units
+            .values()
+            .filter_map(|entry| match &entry.kind {
+                SymbolKind::Enum(unit_data) => Some(unit_data.value),
+                _ => None,
+            })
+            .collect();
+
+        // Literals are checked in place, other values only at run-time
+        if let Expression::Literal(Literal::Integer(lit)) = expr {
+            if data.is_flags {
+                let all_bits = values.iter().fold(0, |bits, value| bits | value);
+                if lit.value & !all_bits != 0 {
+                    return Err(Message::invalid_enum_value(
+                        location,
+                        format!(
+                            "Value {} contains bits which are not units of enum \"{to}\"",
+                            lit.value
+                        ),
+                    ));
+                }
+            } else if !values.contains(&lit.value) {
+                return Err(Message::invalid_enum_value(
+                    location,
+                    format!("Value {} is not a unit of enum \"{to}\"", lit.value),
+                ));
+            }
+
+            return Ok(());
+        }
+
+        if !data.is_flags && self.get_current_safety() != Safety::Unsafe {
+            return Err(Message::unsafe_required(
+                location,
+                format!(
+                    "Conversion from \"{from}\" to enum \"{to}\" requires unsafe function or block, \
+                     the value may not be a unit of the enum"
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn get_enum_def_data(&self, ty: &Type) -> Option<EnumDefData> {
+        let Type::Custom(name) = ty else {
+            return None;
+        };
+
+        match &self
+            .table
+            .lookup_name_spec(name)
+            .ok()?
+            .resolve_import()
+            .kind
+        {
+            SymbolKind::EnumDef(data) => Some(data.clone()),
+            _ => None,
+        }
+    }
+
+    fn check_lossy_conversion(&mut self, from: &Type, to: &Type, location: Location) {
+        if from.is_float() && to.is_integer() {
+            self.lint(
+                &LOSSY_CONVERSIONS,
+                Message::new(
+                    location,
+                    format!("Conversion from \"{from}\" to \"{to}\" loses the fractional part"),
+                ),
+            );
+            return;
+        }
+
+        let (Some(from_size), Some(to_size)) = (get_numeric_size(from), get_numeric_size(to))
+        else {
+            return;
+        };
+
+        if from.is_float() == to.is_float() && to_size < from_size {
+            self.lint(
+                &LOSSY_CONVERSIONS,
+                Message::new(
+                    location,
+                    format!("Narrowing conversion from \"{from}\" to \"{to}\" may lose data"),
+                ),
+            );
+            return;
+        }
+
+        // Only unsigned values fit into wider signed types unchanged
+        let changes_sign =
+            from.is_signed() != to.is_signed() && (from.is_signed() || to_size == from_size);
+        if from.is_integer() && to.is_integer() && changes_sign {
+            self.lint(
+                &LOSSY_CONVERSIONS,
+                Message::new(
+                    location,
+                    format!("Conversion from \"{from}\" to \"{to}\" may change the sign"),
+                ),
+            );
+        }
+    }
+
+    // Untyped literals take the target type, so their value must fit into it
+    pub(crate) fn check_literal_range(&mut self, expr: &Expression, to: &Type, location: Location) {
+        let to_value = self.find_alias_value(to).unwrap_or_else(|| to.clone());
+
+        let (Some(value), Some((min, max))) =
+            (get_literal_value(expr), get_integer_range(&to_value))
+        else {
+            return;
+        };
+
+        if value < min || value > max {
+            self.lint(
+                &LOSSY_CONVERSIONS,
+                Message::new(
+                    location,
+                    format!("Literal {value} is out of range for \"{to}\""),
+                ),
+            );
+        }
+    }
+
+    fn get_conversion_kind(&self, ty: &Type) -> ConversionKind {
+        match ty {
+            ty if ty.is_integer() => ConversionKind::Integer,
+            ty if ty.is_float() => ConversionKind::Float,
+            Type::Bool => ConversionKind::Bool,
+            Type::Ptr(_) => ConversionKind::Pointer,
+            Type::Ref(_) => ConversionKind::Reference,
+            Type::Tuple(_) => ConversionKind::Tuple,
+            Type::Array { .. } => ConversionKind::Array,
+            Type::Custom(name) => match self
+                .table
+                .lookup_name_spec(name)
+                .map(|entry| &entry.resolve_import().kind)
+            {
+                Ok(SymbolKind::EnumDef(_)) => ConversionKind::Enum,
+                Ok(SymbolKind::StructDef(_)) => ConversionKind::Struct,
+                Ok(SymbolKind::UnionDef(_)) => ConversionKind::Union,
+                _ => ConversionKind::Other,
+            },
+            _ => ConversionKind::Other,
+        }
+    }
+}
+
+// Returns size of the numeric type in bits
+pub(crate) fn get_numeric_size(ty: &Type) -> Option<usize> {
+    match ty {
+        Type::I8 | Type::U8 => Some(8),
+        Type::I16 | Type::U16 => Some(16),
+        Type::I32 | Type::U32 | Type::F32 => Some(32),
+        Type::I64 | Type::U64 | Type::F64 => Some(64),
+        Type::I128 | Type::U128 => Some(128),
+        _ => None,
+    }
+}
+
+// Returns value of the integer literal, signs are applied
+fn get_literal_value(expr: &Expression) -> Option<i128> {
+    use tanitc_hir::hir::expressions::unary::{UnaryExpr, UnaryOperation};
+
+    match expr {
+        Expression::Literal(Literal::Integer(lit)) => Some(lit.value as i128),
+        Expression::Unary(UnaryExpr {
+            operation: UnaryOperation::Sub,
+            node,
+            ..
+        }) => get_literal_value(node).map(|value| -value),
+        Expression::Unary(UnaryExpr {
+            operation: UnaryOperation::Add,
+            node,
+            ..
+        }) => get_literal_value(node),
+        _ => None,
+    }
+}
+
+// Returns the smallest and the largest values of the integer type
+fn get_integer_range(ty: &Type) -> Option<(i128, i128)> {
+    if !ty.is_integer() {
+        return None;
+    }
+
+    let size = get_numeric_size(ty)?;
+    if ty.is_signed() {
+        let max = i128::MAX >> (128 - size);
+        Some((-max - 1, max))
+    } else {
+        Some((
+            0,
+            (u128::MAX >> (128 - size)).min(i128::MAX as u128) as i128,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lower_source;
+
+    use tanitc_hir::hir::type_spec::PtrType;
+    use tanitc_hir_test::{
+        create_conversion_expr, create_custom_type, create_decimal_lit, create_enum_def,
+        create_integer_lit, create_main_func_def, create_program, create_scope_resolutions_expr,
+        create_struct_def, create_var, create_var_def, create_word_attribute,
+    };
+
+    #[test]
+    fn numeric_conversion_good_test() {
+        // Given
+        let var_a_def = create_var_def(
+            "a",
+            Mutability::Immutable,
+            Type::I32,
+            Some(create_integer_lit(5)),
+        );
+        let var_b_def = create_var_def(
+            "_b",
+            Mutability::Immutable,
+            Type::Auto,
+            Some(create_conversion_expr(create_var(&["a"]), Type::F64)),
+        );
+        let var_c_def = create_var_def(
+            "_c",
+            Mutability::Immutable,
+            Type::Auto,
+            Some(create_conversion_expr(create_integer_lit(5), Type::U8)),
+        );
+
+        let main_func =
+            create_main_func_def(vec![var_a_def.into(), var_b_def.into(), var_c_def.into()]);
+
+        /* func main() {
+         *     var a: i32 = 5
+         *     var _b = a as f64
+         *     var _c = 5 as u8
+         * }
+         */
+        let mut program = create_program(vec![main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+        assert!(!analyzer.messages_ref().has_warnings());
+    }
+
+    #[test]
+    fn lossy_conversion_warn_test() {
+        // Given
+        let var_a_def = create_var_def(
+            "a",
+            Mutability::Immutable,
+            Type::Auto,
+            Some(create_decimal_lit(1.5)),
+        );
+        let var_b_def = create_var_def(
+            "_b",
+            Mutability::Immutable,
+            Type::Auto,
+            Some(create_conversion_expr(create_var(&["a"]), Type::I32)),
+        );
+        let var_c_def = create_var_def(
+            "c",
+            Mutability::Immutable,
+            Type::I64,
+            Some(create_integer_lit(5)),
+        );
+        let var_d_def = create_var_def(
+            "_d",
+            Mutability::Immutable,
+            Type::Auto,
+            Some(create_conversion_expr(create_var(&["c"]), Type::I16)),
+        );
+
+        let main_func = create_main_func_def(vec![
+            var_a_def.into(),
+            var_b_def.into(),
+            var_c_def.into(),
+            var_d_def.into(),
+        ]);
+
+        /* func main() {
+         *     var a = 1.5
+         *     var _b = a as i32
+         *     var c: i64 = 5
+         *     var _d = c as i16
+         * }
+         */
+        let mut program = create_program(vec![main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_WARN_1: &str =
+            "Semantic warning: Conversion from \"f64\" to \"i32\" loses the fractional part";
+        const EXPECTED_WARN_2: &str =
+            "Semantic warning: Narrowing conversion from \"i64\" to \"i16\" may lose data";
+
+        res.expect("Expected no errors");
+
+        let warnings = analyzer.messages_ref().warnings_ref();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].text, EXPECTED_WARN_1);
+        assert_eq!(warnings[1].text, EXPECTED_WARN_2);
+    }
+
+    #[test]
+    fn sign_changing_conversion_warn_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                                \n    var a: u32 = 1\
+                                \n    var _b = a as i32\
+                                \n    var c: i64 = 1\
+                                \n    var _d = c as u64\
+                                \n    var e: i8 = 1\
+                                \n    var _f = e as u8\
+                                \n    var _g = e as u64\
+                                \n    var h: u8 = 1\
+                                \n    var _i = h as i32\
+                                \n    var _j = e as i64\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+
+        let warnings: Vec<&str> = analyzer
+            .messages_ref()
+            .warnings_ref()
+            .iter()
+            .map(|warn| warn.text.as_str())
+            .collect();
+        assert_eq!(
+            warnings,
+            [
+                "Semantic warning: Conversion from \"u32\" to \"i32\" may change the sign",
+                "Semantic warning: Conversion from \"i64\" to \"u64\" may change the sign",
+                "Semantic warning: Conversion from \"i8\" to \"u8\" may change the sign",
+                "Semantic warning: Conversion from \"i8\" to \"u64\" may change the sign",
+            ]
+        );
+    }
+
+    #[test]
+    fn literal_out_of_range_conversion_warn_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                                \n    var _a = 300 as u8\
+                                \n    var _b = 255 as u8\
+                                \n    var _c = 128 as i8\
+                                \n    var _d = 127 as i8\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+
+        let warnings: Vec<&str> = analyzer
+            .messages_ref()
+            .warnings_ref()
+            .iter()
+            .map(|warn| warn.text.as_str())
+            .collect();
+        assert_eq!(
+            warnings,
+            [
+                "Semantic warning: Literal 300 is out of range for \"u8\"",
+                "Semantic warning: Literal 128 is out of range for \"i8\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn literal_out_of_range_initializer_warn_test() {
+        // Given
+        const SRC_TEXT: &str = "pub struct Pixel {\
+                                \n    pub value: u8\
+                                \n}\
+                                \nfunc main() {\
+                                \n    var _a: u8 = 300\
+                                \n    var _b: u8 = 255\
+                                \n    var _c: u32 = -1\
+                                \n    var mut d: i8 = 0\
+                                \n    d = -129\
+                                \n    var _p = Pixel {\
+                                \n        value: 256\
+                                \n    }\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+
+        let warnings: Vec<&str> = analyzer
+            .messages_ref()
+            .warnings_ref()
+            .iter()
+            .map(|warn| warn.text.as_str())
+            .collect();
+        assert_eq!(
+            warnings,
+            [
+                "Semantic warning: Literal 300 is out of range for \"u8\"",
+                "Semantic warning: Literal -1 is out of range for \"u32\"",
+                "Semantic warning: Literal -129 is out of range for \"i8\"",
+                "Semantic warning: Literal 256 is out of range for \"u8\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn imported_types_conversion_test() {
+        // Given
+        const SRC_TEXT: &str = "module m {\
+                                \n    pub enum Color {\
+                                \n        Red\
+                                \n    }\
+                                \n    pub struct Point {\
+                                \n        pub x: i32\
+                                \n    }\
+                                \n}\
+                                \nuse m::Color\
+                                \nuse m::Point\
+                                \nfunc main() {\
+                                \n    var c: Color = Color::Red\
+                                \n    var _i = c as i32\
+                                \n    var p: Point\
+                                \n    var _j = p as i32\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: Non-primitive conversion from \"m::Point\" to \"i32\": struct \"m::Point\" cannot be converted";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+
+    #[test]
+    fn negative_literal_out_of_range_conversion_warn_test() {
+        use tanitc_hir::hir::expressions::unary::{UnaryExpr, UnaryOperation};
+
+        // Given
+        let negate = |value: usize| {
+            Expression::Unary(UnaryExpr {
+                location: Location::default(),
+                operation: UnaryOperation::Sub,
+                node: Box::new(create_integer_lit(value)),
+            })
+        };
+
+        let var_a_def = create_var_def(
+            "_a",
+            Mutability::Immutable,
+            Type::Auto,
+            Some(create_conversion_expr(negate(1), Type::U8)),
+        );
+        let var_b_def = create_var_def(
+            "_b",
+            Mutability::Immutable,
+            Type::Auto,
+            Some(create_conversion_expr(negate(128), Type::I8)),
+        );
+        let var_c_def = create_var_def(
+            "_c",
+            Mutability::Immutable,
+            Type::Auto,
+            Some(create_conversion_expr(negate(129), Type::I8)),
+        );
+
+        let main_func =
+            create_main_func_def(vec![var_a_def.into(), var_b_def.into(), var_c_def.into()]);
+
+        /* func main() {
+         *     var _a = (-1) as u8
+         *     var _b = (-128) as i8
+         *     var _c = (-129) as i8
+         * }
+         */
+        let mut program = create_program(vec![main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+
+        let warnings: Vec<&str> = analyzer
+            .messages_ref()
+            .warnings_ref()
+            .iter()
+            .map(|warn| warn.text.as_str())
+            .collect();
+        assert_eq!(
+            warnings,
+            [
+                "Semantic warning: Literal -1 is out of range for \"u8\"",
+                "Semantic warning: Literal -129 is out of range for \"i8\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn enum_to_integer_conversion_good_test() {
+        // Given
+        const ENUM_NAME: &str = "MyEnum";
+        let enum_def = create_enum_def(ENUM_NAME, vec![("First", None), ("Second", None)]);
+
+        let var_def = create_var_def(
+            "a",
+            Mutability::Immutable,
+            Type::U32,
+            Some(create_conversion_expr(
+                create_scope_resolutions_expr(&[ENUM_NAME, "Second"]),
+                Type::U32,
+            )),
+        );
+
+        let main_func = create_main_func_def(vec![var_def.into()]);
+
+        /* enum MyEnum {
+         *     First
+         *     Second
+         * }
+         * func main() {
+         *     var a: u32 = MyEnum::Second as u32
+         * }
+         */
+        let mut program = create_program(vec![enum_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+    }
+
+    #[test]
+    fn integer_to_enum_conversion_test() {
+        // Given
+        const ENUM_NAME: &str = "MyEnum";
+        let enum_def = create_enum_def(ENUM_NAME, vec![("First", None), ("Second", Some(5))]);
+
+        let var_a_def = create_var_def(
+            "a",
+            Mutability::Immutable,
+            create_custom_type(&[ENUM_NAME]),
+            Some(create_conversion_expr(
+                create_integer_lit(5),
+                create_custom_type(&[ENUM_NAME]),
+            )),
+        );
+        let var_b_def = create_var_def(
+            "b",
+            Mutability::Immutable,
+            create_custom_type(&[ENUM_NAME]),
+            Some(create_conversion_expr(
+                create_integer_lit(3),
+                create_custom_type(&[ENUM_NAME]),
+            )),
+        );
+        let var_c_def = create_var_def("c", Mutability::Immutable, Type::I32, None);
+        let var_d_def = create_var_def(
+            "d",
+            Mutability::Immutable,
+            create_custom_type(&[ENUM_NAME]),
+            Some(create_conversion_expr(
+                create_var(&["c"]),
+                create_custom_type(&[ENUM_NAME]),
+            )),
+        );
+
+        let main_func = create_main_func_def(vec![
+            var_a_def.into(),
+            var_b_def.into(),
+            var_c_def.into(),
+            var_d_def.into(),
+        ]);
+
+        /* enum MyEnum {
+         *     First
+         *     Second: 5
+         * }
+         * func main() {
+         *     var a: MyEnum = 5 as MyEnum # Ok
+         *     var b: MyEnum = 3 as MyEnum # Error: not a unit
+         *     var c: i32
+         *     var d: MyEnum = c as MyEnum # Error: requires unsafe
+         * }
+         */
+        let mut program = create_program(vec![enum_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR_1: &str = "Semantic error: Value 3 is not a unit of enum \"MyEnum\"";
+        const EXPECTED_ERR_2: &str = "Semantic error: Conversion from \"i32\" to enum \"MyEnum\" \
+                                      requires unsafe function or block, \
+                                      the value may not be a unit of the enum";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].text, EXPECTED_ERR_1);
+        assert_eq!(errors[1].text, EXPECTED_ERR_2);
+    }
+
+    #[test]
+    fn integer_to_flags_enum_conversion_test() {
+        // Given
+        const ENUM_NAME: &str = "Access";
+        let mut enum_def = create_enum_def(ENUM_NAME, vec![("Read", None), ("Write", None)]);
+        enum_def.attributes.meta = vec![create_word_attribute("flags")];
+        enum_def.repr = Some(Type::U8);
+
+        let var_a_def = create_var_def(
+            "a",
+            Mutability::Immutable,
+            create_custom_type(&[ENUM_NAME]),
+            Some(create_conversion_expr(
+                create_integer_lit(3),
+                create_custom_type(&[ENUM_NAME]),
+            )),
+        );
+        let var_b_def = create_var_def(
+            "b",
+            Mutability::Immutable,
+            create_custom_type(&[ENUM_NAME]),
+            Some(create_conversion_expr(
+                create_integer_lit(4),
+                create_custom_type(&[ENUM_NAME]),
+            )),
+        );
+        let var_c_def = create_var_def(
+            "c",
+            Mutability::Immutable,
+            Type::I8,
+            Some(create_conversion_expr(create_var(&["a"]), Type::I8)),
+        );
+
+        let main_func =
+            create_main_func_def(vec![var_a_def.into(), var_b_def.into(), var_c_def.into()]);
+
+        /* #[flags]
+         * enum Access: u8 {
+         *     Read
+         *     Write
+         * }
+         * func main() {
+         *     var a: Access = 3 as Access # Ok: Read | Write
+         *     var b: Access = 4 as Access # Error: unknown bit
+         *     var c: i8 = a as i8
+         * }
+         */
+        let mut program = create_program(vec![enum_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str =
+            "Semantic error: Value 4 contains bits which are not units of enum \"Access\"";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+
+    #[test]
+    fn struct_conversion_bad_test() {
+        // Given
+        const STRUCT_NAME: &str = "MyStruct";
+        let struct_def = create_struct_def(STRUCT_NAME, vec![("f", Type::I32)]);
+
+        let var_a_def = create_var_def(
+            "a",
+            Mutability::Immutable,
+            create_custom_type(&[STRUCT_NAME]),
+            None,
+        );
+        let var_b_def = create_var_def(
+            "b",
+            Mutability::Immutable,
+            Type::Auto,
+            Some(create_conversion_expr(create_var(&["a"]), Type::I32)),
+        );
+
+        let main_func = create_main_func_def(vec![var_a_def.into(), var_b_def.into()]);
+
+        /* struct MyStruct {
+         *     f: i32
+         * }
+         * func main() {
+         *     var a: MyStruct
+         *     var b = a as i32
+         * }
+         */
+        let mut program = create_program(vec![struct_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: Non-primitive conversion from \"MyStruct\" to \"i32\": struct \"MyStruct\" cannot be converted";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+
+    #[test]
+    fn ptr_to_integer_conversion_test() {
+        // Given
+        let ptr_type = Type::Ptr(PtrType {
+            ptr_to: Box::new(Type::I32),
+            mutability: Mutability::Immutable,
+        });
+        let var_a_def = create_var_def("a", Mutability::Immutable, ptr_type, None);
+        let var_b_def = create_var_def(
+            "b",
+            Mutability::Immutable,
+            Type::Auto,
+            Some(create_conversion_expr(create_var(&["a"]), Type::U64)),
+        );
+
+        let safe_main_func = create_main_func_def(vec![var_a_def.into(), var_b_def.into()]);
+        let mut unsafe_main_func = safe_main_func.clone();
+        unsafe_main_func.attributes.safety = Safety::Unsafe;
+
+        /* func main() {
+         *     var a: *const i32
+         *     var b = a as u64
+         * }
+         */
+        let mut safe_program = create_program(vec![safe_main_func.into()]);
+
+        /* unsafe func main() {
+         *     var a: *const i32
+         *     var b = a as u64
+         * }
+         */
+        let mut unsafe_program = create_program(vec![unsafe_main_func.into()]);
+
+        // When
+        let safe_res = Analyzer::new().analyze_program(&mut safe_program);
+        let unsafe_res = Analyzer::new().analyze_program(&mut unsafe_program);
+
+        // Then
+        const EXPECTED_ERR: &str =
+            "Semantic error: Conversion from \"*const i32\" to \"u64\" requires unsafe function or block";
+
+        let messages = safe_res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+
+        unsafe_res.expect("Expected no errors");
+    }
 }
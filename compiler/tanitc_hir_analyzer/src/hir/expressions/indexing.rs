@@ -21,24 +21,24 @@ impl Analyzer {
                 let var_entry = self
                     .table
                     .lookup_name_spec(var_name)
-                    .map_err(|err| Message::new(location, err))?;
+                    .map_err(|err| err.into_message(location))?;
 
                 let SymbolKind::VarDef(var_data) = &var_entry.kind else {
-                    return Err(Message::new(
+                    return Err(Message::not_indexable(
                         location,
                         format!("{var_name} is not an variable"),
                     ));
                 };
 
                 let Type::Array { .. } = &var_data.var_type else {
-                    return Err(Message::new(
+                    return Err(Message::not_indexable(
                         location,
                         format!("{var_name} is not an array"),
                     ));
                 };
             }
             _ => {
-                return Err(Message::new(
+                return Err(Message::not_indexable(
                     location,
                     format!("Can't index {}", expr.lhs.kind_str()),
                 ));
@@ -47,7 +47,7 @@ impl Analyzer {
 
         let index_ty = self.get_expr_type(&expr.index);
         if !index_ty.ty.is_integer() {
-            return Err(Message::new(
+            return Err(Message::mismatched_types(
                 expr.index.location(),
                 format!("Invalid index type: {}", index_ty.ty),
             ));
@@ -1,6 +1,6 @@
 use std::collections::BTreeMap;
 
-use tanitc_attributes::Mutability;
+use tanitc_attributes::{Mutability, Publicity};
 use tanitc_hir::hir::{
     expressions::{
         literal::{ArrayLiteral, Literal, StructLiteral, TupleLiteral},
@@ -10,12 +10,15 @@ use tanitc_hir::hir::{
 };
 use tanitc_ident::Ident;
 use tanitc_lexer::location::Location;
-use tanitc_messages::Message;
+use tanitc_lints::DEPRECATED;
+use tanitc_messages::{codes::ErrorCode, Message};
+use tanitc_name::NameSpec;
 
 use crate::{
     hir::expressions::get_ordinal_number_suffix,
     symbol_table::{
-        entry::{StructDefData, StructFieldsData, SymbolKind, UnionDefData},
+        entry::{StructDefData, StructFieldsData, SymbolKind, UnionDefData, Usage},
+        suggestions::get_similar_field,
         type_info::{MemberInfo, TypeInfo},
     },
     AnalyzeResult, Analyzer,
@@ -33,7 +36,11 @@ impl Analyzer {
         }
     }
 
-    pub(crate) fn get_literal_type(&self, lit: &Literal) -> TypeInfo {
+    pub(crate) fn get_literal_type(&self, lit: &Literal, expected: &Type) -> TypeInfo {
+        let expected_value = self
+            .find_alias_value(expected)
+            .unwrap_or_else(|| expected.clone());
+
         match lit {
             Literal::Text(_) => TypeInfo {
                 ty: Type::Ref(RefType {
@@ -44,12 +51,20 @@ impl Analyzer {
                 ..Default::default()
             },
             Literal::Decimal(_) => TypeInfo {
-                ty: Type::F32,
+                ty: if expected_value.is_float() {
+                    expected.clone()
+                } else {
+                    Type::F64
+                },
                 mutability: Mutability::Mutable,
                 ..Default::default()
             },
             Literal::Integer(_) => TypeInfo {
-                ty: Type::I32,
+                ty: if expected_value.is_numeric() {
+                    expected.clone()
+                } else {
+                    Type::I32
+                },
                 mutability: Mutability::Mutable,
                 ..Default::default()
             },
@@ -67,9 +82,16 @@ impl Analyzer {
                 type_info
             }
             Literal::Tuple(TupleLiteral { units, .. }) => {
+                let expected_units = match &expected_value {
+                    Type::Tuple(TupleType { units: expected }) if expected.len() == units.len() => {
+                        expected.clone()
+                    }
+                    _ => vec![Type::Auto; units.len()],
+                };
+
                 let mut comp_vec = Vec::<Type>::new();
-                for comp in units.iter() {
-                    comp_vec.push(self.get_expr_type(comp).ty);
+                for (comp, comp_expected) in units.iter().zip(expected_units.iter()) {
+                    comp_vec.push(self.get_expected_expr_type(comp, comp_expected).ty);
                 }
                 TypeInfo {
                     ty: Type::Tuple(TupleType {
@@ -84,6 +106,7 @@ impl Analyzer {
                                 MemberInfo {
                                     is_public: true,
                                     ty: comp_type.clone(),
+                                    usage: Usage::default(),
                                 },
                             );
                         }
@@ -93,6 +116,11 @@ impl Analyzer {
                 }
             }
             Literal::Array(ArrayLiteral { elements, .. }) => {
+                let expected_value_type = match &expected_value {
+                    Type::Array { value_type, .. } => value_type.as_ref().clone(),
+                    _ => Type::Auto,
+                };
+
                 let len = elements.len();
                 if len == 0 {
                     return TypeInfo {
@@ -109,7 +137,10 @@ impl Analyzer {
                 TypeInfo {
                     ty: Type::Array {
                         size: ArraySize::Fixed(len),
-                        value_type: Box::new(self.get_expr_type(&elements[0]).ty),
+                        value_type: Box::new(
+                            self.get_expected_expr_type(&elements[0], &expected_value_type)
+                                .ty,
+                        ),
                     },
                     mutability: Mutability::Mutable,
                     ..Default::default()
@@ -126,7 +157,7 @@ impl Analyzer {
     ) -> AnalyzeResult<()> {
         let struct_comps = &struct_data.fields;
         if value_comps.len() != struct_comps.len() {
-            return Err(Message::new(
+            return Err(Message::invalid_struct_literal(
                 location,
                 format!(
                     "Struct \"{}\" consists of {} fields, but {} were supplied",
@@ -138,9 +169,12 @@ impl Analyzer {
         }
 
         for comp_id in 0..value_comps.len() {
-            if let Err(mut msg) =
-                self.check_struct_component(comp_id, value_comps, &struct_data.fields)
-            {
+            if let Err(mut msg) = self.check_struct_component(
+                comp_id,
+                value_comps,
+                &struct_data.name,
+                &struct_data.fields,
+            ) {
                 msg.text = format!("Struct {}", msg.text);
                 self.error(msg);
             }
@@ -159,7 +193,7 @@ impl Analyzer {
         let initialized_comp_size = value_comps.len();
 
         if union_comp_size == 0 && initialized_comp_size > 0 {
-            return Err(Message::new(
+            return Err(Message::invalid_struct_literal(
                 location,
                 format!(
                     "Union \"{}\" has no fields, but were supplied {initialized_comp_size} fields",
@@ -169,7 +203,7 @@ impl Analyzer {
         }
 
         if union_comp_size > 0 && initialized_comp_size > 1 {
-            return Err(Message::new(
+            return Err(Message::invalid_struct_literal(
                 location,
                 format!(
                     "Only one union field must be initialized, but {initialized_comp_size} were initialized",
@@ -178,9 +212,12 @@ impl Analyzer {
         }
 
         for comp_id in 0..initialized_comp_size {
-            if let Err(mut msg) =
-                self.check_struct_component(comp_id, value_comps, &union_data.fields)
-            {
+            if let Err(mut msg) = self.check_struct_component(
+                comp_id,
+                value_comps,
+                &union_data.name,
+                &union_data.fields,
+            ) {
                 msg.text = format!("Union {}", msg.text);
                 self.error(msg);
             }
@@ -193,9 +230,16 @@ impl Analyzer {
         let mut entry = self
             .table
             .lookup_name_spec(&literal.name)
-            .map_err(|err| Message::new(literal.location, err))?
+            .map_err(|err| err.into_message(literal.location))?
             .clone();
 
+        self.index
+            .add_reference(literal.location, &literal.name, entry.resolve_import());
+
+        if let Some(warning) = Self::deprecation_warning(&entry, &literal.name, literal.location) {
+            self.lint(&DEPRECATED, warning);
+        }
+
         if let SymbolKind::AliasDef(alias_data) = &entry.kind {
             let ty = &alias_data.ty;
             match ty {
@@ -203,11 +247,11 @@ impl Analyzer {
                     entry = self
                         .table
                         .lookup_name_spec(alias_to_name)
-                        .map_err(|err| Message::new(literal.location, err))?
+                        .map_err(|err| err.into_message(literal.location))?
                         .clone();
                 }
                 ty if ty.is_common() => {
-                    return Err(Message::new(
+                    return Err(Message::invalid_struct_literal(
                         literal.location,
                         format!("Common type \"{ty}\" does not have any fields"),
                     ))
@@ -240,7 +284,8 @@ impl Analyzer {
                         "Cannot find struct or union named \"{}\" in this scope",
                         literal.name
                     ),
-                ));
+                )
+                .with_code(ErrorCode::UndefinedStruct));
             }
         }
 
@@ -265,11 +310,12 @@ impl Analyzer {
         let comp_type = self.get_expr_type(&literal.elements[0]);
 
         for comp in literal.elements.iter().enumerate() {
-            let current_comp_type = self.get_expr_type(comp.1);
+            self.check_literal_range(comp.1, &comp_type.ty, comp.1.location());
+            let current_comp_type = self.get_expected_expr_type(comp.1, &comp_type.ty);
             if comp_type.ty != current_comp_type.ty {
                 let comp_index = comp.0 + 1;
                 let suffix = get_ordinal_number_suffix(comp.0);
-                return Err(Message::new(
+                return Err(Message::mismatched_types(
                     comp.1.location(),
                     format!(
                         "Array type is declared like {}, but {comp_index}{suffix} element has type {}",
@@ -286,12 +332,39 @@ impl Analyzer {
         &mut self,
         comp_id: usize,
         value_comps: &mut [(Ident, Expression)],
+        struct_name: &NameSpec,
         struct_fields: &StructFieldsData,
     ) -> AnalyzeResult<()> {
         let value_comp = value_comps.get_mut(comp_id).unwrap();
         let value_comp_name = &value_comp.0;
-        let value_comp_type = self.get_expr_type(&value_comp.1);
-        let struct_comp_type = &struct_fields.get(value_comp_name).unwrap().ty;
+        let Some(struct_comp) = struct_fields.get(value_comp_name) else {
+            let can_access_private = self.table.can_access_private_members(struct_name);
+
+            return Err(Message::undefined_field(
+                value_comp.1.location(),
+                format!("\"{struct_name}\" has no field named \"{value_comp_name}\""),
+            )
+            .with_suggestion(get_similar_field(
+                struct_fields,
+                *value_comp_name,
+                can_access_private,
+            )));
+        };
+        let struct_comp_type = &struct_comp.ty;
+
+        if struct_comp.publicity != Publicity::Public
+            && !self.table.can_access_private_members(&struct_comp.name)
+        {
+            return Err(Message::private_item(
+                value_comp.1.location(),
+                format!(
+                    "field \"{value_comp_name}\" of \"{}\" is private",
+                    struct_comp.name
+                ),
+            ));
+        }
+        self.check_literal_range(&value_comp.1, struct_comp_type, value_comp.1.location());
+        let value_comp_type = self.get_expected_expr_type(&value_comp.1, struct_comp_type);
 
         if let Err(err) = self.analyze_expression(&mut value_comp.1) {
             self.error(err);
@@ -305,7 +378,7 @@ impl Analyzer {
             )
             .is_err()
         {
-            return Err(Message::new(
+            return Err(Message::mismatched_types(
                 value_comp.1.location(),
                 format!("field named \"{value_comp_name}\" is {struct_comp_type}, but initialized like {value_comp_type}"),
             ));
@@ -1,13 +1,535 @@
-use tanitc_hir::hir::expressions::member_access::MemberAccessExpr;
+use tanitc_hir::hir::{
+    expressions::{literal::Literal, member_access::MemberAccessExpr, Expression},
+    type_spec::Type,
+};
+use tanitc_ident::Ident;
+use tanitc_lints::UNION_FIELD_MISMATCH;
+use tanitc_messages::{codes::ErrorCode, Message};
 
-use crate::{symbol_table::type_info::TypeInfo, AnalyzeResult, Analyzer};
+use crate::{
+    symbol_table::{suggestions::get_similar_member, type_info::TypeInfo},
+    AnalyzeResult, Analyzer,
+};
 
 impl Analyzer {
-    pub(crate) fn analyze_member_access_expr(&self, _expr: &MemberAccessExpr) -> AnalyzeResult<()> {
+    pub(crate) fn analyze_member_access_expr(
+        &mut self,
+        expr: &mut MemberAccessExpr,
+    ) -> AnalyzeResult<()> {
+        self.analyze_member(expr)?;
+
+        let lhs_type = self.get_expr_type(&expr.lhs);
+        if !lhs_type.is_union {
+            return Ok(());
+        }
+
+        // Reading of union field reinterprets memory of the union
+        if self.get_current_safety().is_safe() {
+            return Err(Message::unsafe_required(
+                expr.location,
+                "Access to union field is unsafe and requires an unsafe function or block",
+            ));
+        }
+
+        let Some(var_id) = get_local_var_id(&expr.lhs) else {
+            return Ok(());
+        };
+
+        if let Some(written_id) = self.table.get_union_write(var_id) {
+            if written_id != expr.id {
+                self.lint(
+                    &UNION_FIELD_MISMATCH,
+                    Message::new(
+                        expr.location,
+                        format!(
+                            "Field \"{}\" of union \"{}\" (variable \"{var_id}\") is read, but field \"{written_id}\" was written last",
+                            expr.id, lhs_type.ty
+                        ),
+                    ),
+                );
+            }
+        }
+
         Ok(())
     }
 
-    pub(crate) fn get_member_access_expr_type(&self, _expr: &MemberAccessExpr) -> TypeInfo {
-        TypeInfo::default()
+    // Writing of union field is safe, so lhs of assignment is analyzed separately
+    pub(crate) fn analyze_member_write(
+        &mut self,
+        expr: &mut MemberAccessExpr,
+    ) -> AnalyzeResult<()> {
+        self.analyze_member(expr)?;
+
+        let lhs_type = self.get_expr_type(&expr.lhs);
+        if lhs_type.mutability.is_const() {
+            return Err(Message::new(
+                expr.location,
+                format!(
+                    "Field \"{}\" of \"{}\" is immutable in current scope",
+                    expr.id, lhs_type.ty
+                ),
+            )
+            .with_code(ErrorCode::ConstMutation));
+        }
+
+        if let (true, Some(var_id)) = (lhs_type.is_union, get_local_var_id(&expr.lhs)) {
+            self.table.set_union_write(var_id, Some(expr.id));
+        }
+
+        self.mark_mutated_root(&expr.lhs);
+
+        Ok(())
+    }
+
+    // Remembers which field of the union is initialized by the whole value
+    pub(crate) fn remember_union_value(&mut self, var_id: Ident, value: &Expression) {
+        if !self.get_expr_type(value).is_union {
+            return;
+        }
+
+        let field_id = match value {
+            Expression::Literal(Literal::Struct(lit)) if lit.fields.len() == 1 => {
+                lit.fields.first().map(|(field_id, _)| *field_id)
+            }
+            _ => None,
+        };
+
+        self.table.set_union_write(var_id, field_id);
+    }
+
+    fn analyze_member(&mut self, expr: &mut MemberAccessExpr) -> AnalyzeResult<()> {
+        self.analyze_expression(&mut expr.lhs)?;
+
+        let lhs_type = self.get_expr_type(&expr.lhs);
+        let Some(member) = lhs_type.members.get(&expr.id) else {
+            let (can_access_private, type_name) = match &lhs_type.ty {
+                Type::Custom(type_name) => (
+                    self.table.can_access_private_members(type_name),
+                    Some(type_name),
+                ),
+                _ => (true, None),
+            };
+
+            let methods = type_name
+                .into_iter()
+                .flat_map(|type_name| self.table.type_methods(type_name));
+
+            return Err(Message::undefined_field(
+                expr.location,
+                format!("\"{}\" has no member named \"{}\"", lhs_type.ty, expr.id),
+            )
+            .with_suggestion(get_similar_member(
+                &lhs_type.members,
+                methods,
+                expr.id,
+                can_access_private,
+            )));
+        };
+
+        member.usage.mark_read();
+
+        if let Type::Custom(type_name) = &lhs_type.ty {
+            if !member.is_public && !self.table.can_access_private_members(type_name) {
+                return Err(Message::private_item(
+                    expr.location,
+                    format!("field \"{}\" of \"{type_name}\" is private", expr.id),
+                ));
+            }
+
+            if let Ok(owner) = self.table.lookup_name_spec(type_name) {
+                self.index.add_member_reference(
+                    expr.location,
+                    expr.id,
+                    member.ty.clone(),
+                    owner.resolve_import().location,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    // Writing of a field mutates the variable that holds the object
+    fn mark_mutated_root(&self, expr: &Expression) {
+        match expr {
+            Expression::MemberAccess(member) => self.mark_mutated_root(&member.lhs),
+            Expression::Variable(var) => {
+                if let Ok(entry) = self.table.lookup_name_spec(&var.name) {
+                    entry.resolve_import().usage.mark_mutated();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    pub(crate) fn get_member_access_expr_type(&self, expr: &MemberAccessExpr) -> TypeInfo {
+        let lhs_type = self.get_expr_type(&expr.lhs);
+
+        let Some(member) = lhs_type.members.get(&expr.id) else {
+            return TypeInfo::default();
+        };
+
+        member.usage.mark_read();
+
+        let mut type_info = self.table.lookup_type(&member.ty).unwrap_or(TypeInfo {
+            ty: member.ty.clone(),
+            ..Default::default()
+        });
+        type_info.mutability = lhs_type.mutability;
+
+        type_info
+    }
+}
+
+// Returns id of the variable if expression is just a name of local variable
+pub(crate) fn get_local_var_id(expr: &Expression) -> Option<Ident> {
+    match expr {
+        Expression::Variable(var) if var.name.path.len() == 1 => var.name.get_id(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use tanitc_attributes::{Mutability, Publicity, Safety};
+    use tanitc_hir::hir::{
+        blocks::Block,
+        expressions::{binary::BinaryOperation, Expression},
+        Hir,
+    };
+    use tanitc_hir_test::{
+        create_binary_expr, create_custom_type, create_decimal_lit, create_integer_lit,
+        create_main_func_def, create_module_def, create_program, create_struct_def,
+        create_struct_lit, create_union_def, create_var, create_var_def,
+    };
+    use tanitc_ident::Ident;
+    use tanitc_lexer::location::Location;
+
+    use crate::lower_source;
+
+    fn create_member_access_expr(var_name: &str, member: &str) -> Expression {
+        Expression::MemberAccess(MemberAccessExpr {
+            location: Location::default(),
+            lhs: Box::new(create_var(&[var_name])),
+            id: Ident::from(member.to_string()),
+        })
+    }
+
+    #[test]
+    fn private_field_access_bad_test() {
+        // Given
+        const MODULE_NAME: &str = "math";
+        const STRUCT_NAME: &str = "Vector2";
+        let mut struct_def =
+            create_struct_def(STRUCT_NAME, vec![("x", Type::F32), ("y", Type::F32)]);
+        struct_def.attributes.publicity = Publicity::Public;
+        struct_def
+            .fields
+            .get_mut(&Ident::from("x".to_string()))
+            .unwrap()
+            .attributes
+            .publicity = Publicity::Public;
+
+        let module_math = create_module_def(MODULE_NAME, vec![struct_def.into()]);
+
+        let var_def = create_var_def(
+            "v",
+            Mutability::Immutable,
+            create_custom_type(&[MODULE_NAME, STRUCT_NAME]),
+            None,
+        );
+        let x_def = create_var_def(
+            "x",
+            Mutability::Immutable,
+            Type::F32,
+            Some(create_member_access_expr("v", "x")),
+        );
+        let y_def = create_var_def(
+            "y",
+            Mutability::Immutable,
+            Type::F32,
+            Some(create_member_access_expr("v", "y")),
+        );
+
+        let main_func = create_main_func_def(vec![var_def.into(), x_def.into(), y_def.into()]);
+
+        /*
+         * module math {
+         *     pub struct Vector2 {
+         *         pub x: f32
+         *         y: f32
+         *     }
+         * }
+         * func main() {
+         *     var v: math::Vector2
+         *     var x: f32 = v.x
+         *     var y: f32 = v.y # y is private
+         * }
+         */
+        let mut program = create_program(vec![module_math.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: field \"y\" of \"math::Vector2\" is private";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+
+    #[test]
+    fn private_field_access_from_source_bad_test() {
+        // Given
+        const SRC_TEXT: &str = "module math {\
+                                \n    pub struct Vector2 {\
+                                \n        pub x: f32\
+                                \n        y: f32\
+                                \n    }\
+                                \n}\
+                                \nuse math::Vector2\
+                                \nfunc main() {\
+                                \n    var v: Vector2\
+                                \n    var _x: f32 = v.x\
+                                \n    var _y: f32 = v.y\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: field \"y\" of \"math::Vector2\" is private";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+    }
+
+    fn create_unsafe_block(statements: Vec<Hir>) -> Block {
+        let mut block = Block {
+            statements,
+            ..Default::default()
+        };
+        block.attributes.safety = Safety::Unsafe;
+        block
+    }
+
+    #[test]
+    fn union_field_read_bad_test() {
+        // Given
+        const UNION_NAME: &str = "Bits";
+        let union_def = create_union_def(UNION_NAME, vec![("i", Type::I32), ("f", Type::F32)]);
+
+        let var_def = create_var_def(
+            "u",
+            Mutability::Mutable,
+            create_custom_type(&[UNION_NAME]),
+            None,
+        );
+        let write_expr = create_binary_expr(
+            create_member_access_expr("u", "f"),
+            BinaryOperation::Assign,
+            create_decimal_lit(2.0),
+        );
+        let safe_read_def = create_var_def(
+            "_x",
+            Mutability::Immutable,
+            Type::F32,
+            Some(create_member_access_expr("u", "f")),
+        );
+        let unsafe_read_def = create_var_def(
+            "_y",
+            Mutability::Immutable,
+            Type::F32,
+            Some(create_member_access_expr("u", "f")),
+        );
+
+        let main_func = create_main_func_def(vec![
+            var_def.into(),
+            write_expr.into(),
+            safe_read_def.into(),
+            create_unsafe_block(vec![unsafe_read_def.into()]).into(),
+        ]);
+
+        /*
+         * union Bits {
+         *     i: i32
+         *     f: f32
+         * }
+         * func main() {
+         *     var mut u: Bits
+         *     u.f = 2.0 # Ok: writes are safe
+         *     var _x: f32 = u.f # Error: read outside of unsafe block
+         *     unsafe {
+         *         var _y: f32 = u.f
+         *     }
+         * }
+         */
+        let mut program = create_program(vec![union_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: Access to union field is unsafe and requires an unsafe function or block";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+        assert!(messages.warnings_ref().is_empty());
+    }
+
+    #[test]
+    fn union_field_read_after_other_write_test() {
+        // Given
+        const UNION_NAME: &str = "Bits";
+        let union_def = create_union_def(UNION_NAME, vec![("i", Type::I32), ("f", Type::F32)]);
+
+        let var_def = create_var_def(
+            "u",
+            Mutability::Mutable,
+            create_custom_type(&[UNION_NAME]),
+            Some(create_struct_lit(
+                &[UNION_NAME],
+                vec![("i", create_integer_lit(1))],
+            )),
+        );
+        let first_read_def = create_var_def(
+            "_x",
+            Mutability::Immutable,
+            Type::F32,
+            Some(create_member_access_expr("u", "f")),
+        );
+        let write_expr = create_binary_expr(
+            create_member_access_expr("u", "f"),
+            BinaryOperation::Assign,
+            create_decimal_lit(2.0),
+        );
+        let second_read_def = create_var_def(
+            "_y",
+            Mutability::Immutable,
+            Type::F32,
+            Some(create_member_access_expr("u", "f")),
+        );
+
+        let main_func = create_main_func_def(vec![create_unsafe_block(vec![
+            var_def.into(),
+            first_read_def.into(),
+            write_expr.into(),
+            second_read_def.into(),
+        ])
+        .into()]);
+
+        /*
+         * union Bits {
+         *     i: i32
+         *     f: f32
+         * }
+         * func main() {
+         *     unsafe {
+         *         var mut u = Bits { i: 1 }
+         *         var _x: f32 = u.f # Warning: "i" was written last
+         *         u.f = 2.0
+         *         var _y: f32 = u.f
+         *     }
+         * }
+         */
+        let mut program = create_program(vec![union_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_WARN: &str = "Semantic warning: Field \"f\" of union \"Bits\" (variable \"u\") is read, but field \"i\" was written last";
+
+        res.expect("Expected no errors");
+
+        let warnings = analyzer.messages_ref().warnings_ref();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].text, EXPECTED_WARN);
+    }
+
+    #[test]
+    fn union_field_read_in_call_arg_bad_test() {
+        // Given
+        const SRC_TEXT: &str = "union Bits {\
+                                \n    i: i32\
+                                \n    f: f32\
+                                \n}\
+                                \nfunc foo(_a: i32) { }\
+                                \nfunc main() {\
+                                \n    var u = Bits { i: 1 }\
+                                \n    foo(u.i)\
+                                \n    foo(u.f as i32)\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: Access to union field is unsafe and requires an unsafe function or block";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+        assert_eq!(errors[1].text, EXPECTED_ERR);
+    }
+
+    #[test]
+    fn union_field_read_in_call_arg_after_other_write_test() {
+        // Given
+        const SRC_TEXT: &str = "union Bits {\
+                                \n    i: i32\
+                                \n    f: f32\
+                                \n}\
+                                \nfunc foo(_a: i32) { }\
+                                \nfunc main() {\
+                                \n    var mut u = Bits { i: 1 }\
+                                \n    unsafe {\
+                                \n        u.f = 2.0\
+                                \n        foo(u.i)\
+                                \n    }\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_WARN: &str = "Semantic warning: Field \"i\" of union \"Bits\" (variable \"u\") is read, but field \"f\" was written last";
+
+        res.expect("Expected no errors");
+
+        let warnings = analyzer.messages_ref().warnings_ref();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].text, EXPECTED_WARN);
     }
 }
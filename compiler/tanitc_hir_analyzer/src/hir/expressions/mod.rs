@@ -6,7 +6,12 @@ use tanitc_hir::hir::{
 use tanitc_messages::Message;
 
 use crate::{
-    symbol_table::{entry::SymbolKind, type_info::TypeInfo},
+    symbol_table::{
+        entry::{FuncDefData, SymbolKind, VarDefData, VarStorageType},
+        suggestions::get_similar_value,
+        table::LookupError,
+        type_info::TypeInfo,
+    },
     AnalyzeResult, Analyzer,
 };
 
@@ -33,22 +38,81 @@ impl Analyzer {
     }
 
     pub(crate) fn get_expr_type(&self, expr: &Expression) -> TypeInfo {
+        self.get_expected_expr_type(expr, &Type::Auto)
+    }
+
+    // Returns type of the expression. Untyped literals adopt the expected type
+    // if it suits them, Type::Auto means that context expects nothing
+    pub(crate) fn get_expected_expr_type(&self, expr: &Expression, expected: &Type) -> TypeInfo {
         match expr {
-            Expression::Unary(expr) => self.get_unary_expr_type(expr),
-            Expression::Binary(expr) => self.get_binary_expr_type(expr),
+            Expression::Unary(expr) => self.get_unary_expr_type(expr, expected),
+            Expression::Binary(expr) => self.get_binary_expr_type(expr, expected),
             Expression::MemberAccess(expr) => self.get_member_access_expr_type(expr),
             Expression::Conversion(expr) => self.get_conversion_expr_type(expr),
             Expression::Indexing(expr) => self.get_indexing_expr_type(expr),
             Expression::Call(call_expr) => self.get_call_expr_type(call_expr),
             Expression::Variable(var) => self.get_variable_type(var),
-            Expression::Literal(lit) => self.get_literal_type(lit),
+            Expression::Literal(lit) => self.get_literal_type(lit, expected),
         }
     }
 
-    pub(crate) fn analyze_variable_usage(&mut self, var: &Variable) -> AnalyzeResult<()> {
-        self.table
+    pub(crate) fn analyze_variable_usage(&mut self, var: &mut Variable) -> AnalyzeResult<()> {
+        let entry = self
+            .table
             .lookup_name_spec(&var.name)
-            .map_err(|err| Message::new(var.location, err))?;
+            .map_err(|err| match err {
+                // Only a value can be named by a single identifier here
+                LookupError::UndefinedId { id, .. } if var.name.path.len() == 1 => {
+                    LookupError::UndefinedId {
+                        id,
+                        suggestion: get_similar_value(self.table.all_entries(), id),
+                    }
+                }
+                err => err,
+            })
+            .map_err(|err| err.into_message(var.location))?;
+
+        // Backend refers to functions and enum units by their absolute paths,
+        // and to methods by the absolute path of their type
+        let item = entry.resolve_import();
+        item.usage.mark_read();
+        self.index.add_reference(var.location, &var.name, item);
+        match &item.kind {
+            SymbolKind::FuncDef(FuncDefData {
+                owner: Some(owner), ..
+            }) => {
+                let mut item_name = owner.clone();
+                item_name.path.push(item.id.into());
+                var.item_name = Some(item_name);
+            }
+            SymbolKind::FuncDef(func_data) => var.item_name = Some(func_data.name.clone()),
+            SymbolKind::Enum(unit_data) => {
+                let mut item_name = unit_data.name.clone();
+                item_name.path.push(item.id.into());
+                var.item_name = Some(item_name);
+            }
+            _ => {}
+        }
+
+        // Extern mutable statics may be changed by foreign code at any moment
+        if let SymbolKind::VarDef(VarDefData {
+            storage: VarStorageType::Extern,
+            mutability: Mutability::Mutable,
+            ..
+        }) = &entry.resolve_import().kind
+        {
+            if self.get_current_safety().is_safe() {
+                return Err(Message::unsafe_required(
+                    var.location,
+                    format!(
+                        "Use of extern mutable static \"{}\" requires an unsafe function or block",
+                        var.name
+                    ),
+                ));
+            }
+        }
+
+        self.check_deprecated_name(&var.name, var.location);
 
         Ok(())
     }
@@ -65,6 +129,8 @@ impl Analyzer {
             return type_info;
         };
 
+        entry.resolve_import().usage.mark_read();
+
         match &entry.kind {
             SymbolKind::VarDef(data) => {
                 type_info.ty = data.var_type.clone();
@@ -83,6 +149,11 @@ impl Analyzer {
                 type_info.ty = Type::Func(data.ty.clone());
                 type_info
             }
+            SymbolKind::Enum(data) => {
+                type_info.ty = Type::Custom(data.name.clone());
+                type_info.mutability = Mutability::Immutable;
+                type_info
+            }
             _ => type_info,
         }
     }
@@ -96,3 +167,21 @@ pub(crate) fn get_ordinal_number_suffix(num: usize) -> &'static str {
         _ => "th",
     }
 }
+
+// Returns true if type of the expression depends only on the context
+pub(crate) fn is_untyped_literal(expr: &Expression) -> bool {
+    use tanitc_hir::hir::expressions::{
+        literal::Literal,
+        unary::{UnaryExpr, UnaryOperation},
+    };
+
+    match expr {
+        Expression::Literal(Literal::Integer(_) | Literal::Decimal(_)) => true,
+        Expression::Unary(UnaryExpr {
+            operation: UnaryOperation::Add | UnaryOperation::Sub,
+            node,
+            ..
+        }) => is_untyped_literal(node),
+        _ => false,
+    }
+}
@@ -6,7 +6,7 @@ use tanitc_hir::hir::{
     },
     type_spec::{RefType, Type},
 };
-use tanitc_messages::Message;
+use tanitc_messages::{codes::ErrorCode, Message};
 
 use crate::{
     symbol_table::{entry::SymbolKind, type_info::TypeInfo},
@@ -26,14 +26,19 @@ impl Analyzer {
             let entry = self
                 .table
                 .lookup_name_spec(&var.name)
-                .map_err(|err| Message::new(location, err))?;
+                .map_err(|err| err.into_message(location))?;
 
             if let SymbolKind::VarDef(var_data) = &entry.kind {
                 if var_data.mutability.is_const() && does_mutate {
                     return Err(Message::new(
                         location,
                         format!("Mutable reference to immutable variable \"{}\"", var.name),
-                    ));
+                    )
+                    .with_code(ErrorCode::ConstVarMutation));
+                }
+
+                if does_mutate {
+                    entry.usage.mark_mutated();
                 }
             }
         }
@@ -42,7 +47,7 @@ impl Analyzer {
             && UnaryOperation::Deref == expr.operation
             && self.get_current_safety() != Safety::Unsafe
         {
-            return Err(Message::new(
+            return Err(Message::unsafe_required(
                 location,
                 "Dereferencing raw pointer require unsafe function or block",
             ));
@@ -51,8 +56,16 @@ impl Analyzer {
         Ok(())
     }
 
-    pub(crate) fn get_unary_expr_type(&self, expr: &UnaryExpr) -> TypeInfo {
-        let node_type = self.get_expr_type(&expr.node);
+    pub(crate) fn get_unary_expr_type(&self, expr: &UnaryExpr, expected: &Type) -> TypeInfo {
+        let node_expected = match (&expr.operation, expected) {
+            (UnaryOperation::Ref | UnaryOperation::RefMut, Type::Ref(ref_type)) => {
+                ref_type.ref_to.as_ref().clone()
+            }
+            (UnaryOperation::Ref | UnaryOperation::RefMut | UnaryOperation::Deref, _) => Type::Auto,
+            _ => expected.clone(),
+        };
+
+        let node_type = self.get_expected_expr_type(&expr.node, &node_expected);
 
         let (is_ref, mutability) = match &expr.operation {
             UnaryOperation::Ref => (true, Mutability::Immutable),
@@ -18,32 +18,40 @@ use tanitc_messages::Message;
 
 use crate::Analyzer;
 
+pub(crate) mod attributes;
 pub(crate) mod blocks;
 pub(crate) mod branches;
 pub(crate) mod control_flows;
 pub(crate) mod definitions;
 pub(crate) mod expressions;
 pub(crate) mod types;
+pub(crate) mod unused;
+pub(crate) mod uses;
 
 impl VisitorMut for Analyzer {
     fn visit_module_def(&mut self, module_def: &mut ModuleDef) -> Result<(), Message> {
-        self.analyze_module_def(module_def)
+        let lint_scope = Self::get_lint_scope(&module_def.attributes.meta);
+        self.with_lint_scope(lint_scope, |this| this.analyze_module_def(module_def))
     }
 
     fn visit_struct_def(&mut self, struct_def: &mut StructDef) -> Result<(), Message> {
-        self.analyze_struct_def(struct_def)
+        let lint_scope = Self::get_lint_scope(&struct_def.attributes.meta);
+        self.with_lint_scope(lint_scope, |this| this.analyze_struct_def(struct_def))
     }
 
     fn visit_union_def(&mut self, union_def: &mut UnionDef) -> Result<(), Message> {
-        self.analyze_union_def(union_def)
+        let lint_scope = Self::get_lint_scope(&union_def.attributes.meta);
+        self.with_lint_scope(lint_scope, |this| this.analyze_union_def(union_def))
     }
 
     fn visit_variant_def(&mut self, variant_def: &mut VariantDef) -> Result<(), Message> {
-        self.analyze_variant_def(variant_def)
+        let lint_scope = Self::get_lint_scope(&variant_def.attributes.meta);
+        self.with_lint_scope(lint_scope, |this| this.analyze_variant_def(variant_def))
     }
 
     fn visit_enum_def(&mut self, enum_def: &mut EnumDef) -> Result<(), Message> {
-        self.analyze_enum_def(enum_def)
+        let lint_scope = Self::get_lint_scope(&enum_def.attributes.meta);
+        self.with_lint_scope(lint_scope, |this| this.analyze_enum_def(enum_def))
     }
 
     fn visit_impl_def(&mut self, impl_def: &mut ImplDef) -> Result<(), Message> {
@@ -51,9 +59,8 @@ impl VisitorMut for Analyzer {
     }
 
     fn visit_func_def(&mut self, func_def: &mut FunctionDef) -> Result<(), Message> {
-        const NOT_METHOD: bool = false;
-
-        self.analyze_func_def(func_def, NOT_METHOD)
+        let lint_scope = Self::get_lint_scope(&func_def.attributes.meta);
+        self.with_lint_scope(lint_scope, |this| this.analyze_func_def(func_def, None))
     }
 
     fn visit_extern_def(&mut self, extern_def: &mut ExternDef) -> Result<(), Message> {
@@ -65,7 +72,8 @@ impl VisitorMut for Analyzer {
     }
 
     fn visit_alias_def(&mut self, alias_def: &mut AliasDef) -> Result<(), Message> {
-        self.analyze_alias_def(alias_def)
+        let lint_scope = Self::get_lint_scope(&alias_def.attributes.meta);
+        self.with_lint_scope(lint_scope, |this| this.analyze_alias_def(alias_def))
     }
 
     fn visit_expression(&mut self, expr: &mut Expression) -> Result<(), Message> {
@@ -84,8 +92,8 @@ impl VisitorMut for Analyzer {
         Ok(())
     }
 
-    fn visit_use(&mut self, _u: &mut Use) -> Result<(), Message> {
-        Ok(())
+    fn visit_use(&mut self, u: &mut Use) -> Result<(), Message> {
+        self.analyze_use(u)
     }
 
     fn visit_block(&mut self, block: &mut Block) -> Result<(), Message> {
@@ -1,4 +1,4 @@
-use tanitc_hir::hir::type_spec::Type;
+use tanitc_hir::hir::type_spec::{FuncType, Type};
 use tanitc_lexer::location::Location;
 use tanitc_messages::Message;
 
@@ -11,7 +11,6 @@ impl Analyzer {
         rhs_type: &Type,
         location: Location,
     ) -> AnalyzeResult<()> {
-        println!("Compare: {lhs_type} vs {rhs_type}");
         let mut alias_to = self.find_alias_value(lhs_type);
 
         if lhs_type == rhs_type {
@@ -22,7 +21,7 @@ impl Analyzer {
             && lhs_type != rhs_type
             && !self.try_coerce(rhs_type, lhs_type, location)
         {
-            return Err(Message::new(
+            return Err(Message::mismatched_types(
                     location,
                     format!(
                         "Cannot perform operation on objects with different types: {lhs_type} and {rhs_type}",
@@ -31,7 +30,7 @@ impl Analyzer {
         } else if alias_to.as_ref().is_some_and(|ty| rhs_type != ty)
             && !self.try_coerce(rhs_type, lhs_type, location)
         {
-            return Err(Message::new(
+            return Err(Message::mismatched_types(
                     location,
                     format!(
                         "Cannot perform operation on objects with different types: {lhs_type} (aka: {}) and {rhs_type}",
@@ -97,8 +96,78 @@ impl Analyzer {
         self.compare_types(dst_type, src_type, location)
     }
 
+    // Function is passed as a pointer, so only its signature matters, not names of parameters.
+    // Tanit functions are plain C functions, so they can be used as "C" callbacks
+    // as long as their signatures are expressible in C
+    pub(crate) fn check_func_type_coerce(
+        &self,
+        src_type: &FuncType,
+        dst_type: &FuncType,
+        location: Location,
+    ) -> Result<(), Message> {
+        let same_signature = src_type.parameters.len() == dst_type.parameters.len()
+            && src_type.is_variadic == dst_type.is_variadic
+            && src_type.return_type == dst_type.return_type
+            && src_type
+                .parameters
+                .iter()
+                .zip(dst_type.parameters.iter())
+                .all(|(src_param, dst_param)| src_param.ty == dst_param.ty);
+
+        if !same_signature {
+            return Err(Message::mismatched_types(
+                location,
+                format!(
+                    "Mismatched function types: \"{src_type}\" can not be used as \"{dst_type}\""
+                ),
+            ));
+        }
+
+        if src_type.safety.is_unsafe() && dst_type.safety.is_safe() {
+            return Err(Message::unsafe_required(
+                location,
+                format!("Unsafe function \"{src_type}\" can not be used as safe \"{dst_type}\""),
+            ));
+        }
+
+        if !dst_type.abi.is_c() || src_type.abi.is_c() {
+            return Ok(());
+        }
+
+        for (param_idx, param) in src_type.parameters.iter().enumerate() {
+            if !self.is_c_compatible_type(&param.ty) {
+                return Err(Message::not_c_compatible(
+                    location,
+                    format!("Function \"{src_type}\" can not be used as \"C\" callback: parameter {param_idx} has type \"{}\" that is not C-compatible", param.ty),
+                ));
+            }
+        }
+
+        if !src_type.return_type.is_unit() && !self.is_c_compatible_type(&src_type.return_type) {
+            return Err(Message::not_c_compatible(
+                location,
+                format!("Function \"{src_type}\" can not be used as \"C\" callback: return type \"{}\" is not C-compatible", src_type.return_type),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn is_c_compatible_type(&self, ty: &Type) -> bool {
+        match ty {
+            Type::Func(func_type) => func_type.abi.is_c(),
+            _ => self.is_c_promotable_type(ty),
+        }
+    }
+
     // Returns true if src_type can be coerced to dst_type, otherwise - false
     fn try_coerce(&self, src_type: &Type, dst_type: &Type, location: Location) -> bool {
+        if let (Type::Func(src_type), Type::Func(dst_type)) = (src_type, dst_type) {
+            return self
+                .check_func_type_coerce(src_type, dst_type, location)
+                .is_ok();
+        }
+
         if src_type.is_reference() && dst_type.is_pointer() {
             return self
                 .check_ref_coerce_to_ptr(src_type, dst_type, location)
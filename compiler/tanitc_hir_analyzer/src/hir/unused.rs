@@ -0,0 +1,552 @@
+use tanitc_attributes::{Mutability, Publicity};
+use tanitc_ident::Ident;
+use tanitc_lints::{Lint, DEAD_CODE, UNUSED_IMPORTS, UNUSED_MUT, UNUSED_VARIABLES};
+use tanitc_messages::Message;
+
+use crate::{
+    lints::LintScope,
+    symbol_table::entry::{
+        Entry, EnumDefData, FuncDefData, StructDefData, SymbolKind, UseItemData, VarDefData,
+        VarStorageType,
+    },
+    Analyzer,
+};
+
+type UnusedWarning = (&'static Lint, Message, LintScope);
+
+impl Analyzer {
+    // Reports definitions of the current scope that are never used
+    pub(crate) fn check_unused_entries(&mut self) {
+        // Static definitions of blocks are moved to the enclosing scope and checked there
+        let is_local = self.table.get_scope_info().is_in_func;
+
+        let entries: Vec<&Entry> = self
+            .table
+            .scope_entries()
+            .filter(|entry| !(is_local && entry.is_static))
+            .collect();
+
+        let mut warnings: Vec<UnusedWarning> = entries
+            .iter()
+            .flat_map(|entry| get_unused_warnings(entry, "variable"))
+            .collect();
+        warnings.extend(get_unused_import_warnings(self.table.scope_use_items()));
+
+        self.report_unused(warnings);
+    }
+
+    // Parameters are defined in the scope of the function, not in its body
+    pub(crate) fn check_unused_params(&mut self) {
+        let warnings = self
+            .table
+            .scope_entries()
+            .flat_map(|entry| get_unused_warnings(entry, "parameter"))
+            .collect();
+
+        self.report_unused(warnings);
+    }
+
+    fn report_unused(&mut self, mut warnings: Vec<UnusedWarning>) {
+        warnings.sort_by_key(|(_, msg, _)| msg.location);
+
+        for (lint, msg, lint_scope) in warnings {
+            self.with_lint_scope(lint_scope, |this| this.lint(lint, msg));
+        }
+    }
+}
+
+// Names starting with "_" are unused intentionally
+fn is_intentionally_unused(id: Ident) -> bool {
+    id.to_string().starts_with('_')
+}
+
+fn get_unused_warnings(entry: &Entry, var_kind: &str) -> Vec<UnusedWarning> {
+    if is_intentionally_unused(entry.id) {
+        return vec![];
+    }
+
+    let messages = match &entry.kind {
+        SymbolKind::VarDef(data) => get_unused_var_messages(entry, data, var_kind),
+        SymbolKind::FuncDef(data) => get_unused_func_messages(entry, data),
+        SymbolKind::StructDef(data) => get_unused_field_messages(entry, data),
+        SymbolKind::EnumDef(data) => get_unused_unit_messages(entry, data),
+        _ => vec![],
+    };
+
+    messages
+        .into_iter()
+        .map(|(lint, msg)| (lint, msg, entry.usage.lint_scope.clone()))
+        .collect()
+}
+
+fn get_unused_var_messages(
+    entry: &Entry,
+    data: &VarDefData,
+    var_kind: &str,
+) -> Vec<(&'static Lint, Message)> {
+    if entry.publicity == Publicity::Public || matches!(data.storage, VarStorageType::Extern) {
+        return vec![];
+    }
+
+    let id = entry.id;
+
+    if !entry.usage.is_read() {
+        return vec![(
+            &UNUSED_VARIABLES,
+            Message::new(entry.location, format!("Unused {var_kind} \"{id}\"")).with_help(format!(
+                "if this is intentional, prefix it with an underscore: \"_{id}\""
+            )),
+        )];
+    }
+
+    if data.mutability == Mutability::Mutable && !entry.usage.is_mutated() {
+        return vec![(
+            &UNUSED_MUT,
+            Message::new(
+                entry.location,
+                format!("Mutable {var_kind} \"{id}\" is never mutated"),
+            )
+            .with_help("remove \"mut\""),
+        )];
+    }
+
+    vec![]
+}
+
+fn get_unused_func_messages(entry: &Entry, data: &FuncDefData) -> Vec<(&'static Lint, Message)> {
+    // Methods are called through objects and exported functions are called from C
+    if entry.publicity == Publicity::Public
+        || entry.usage.is_read()
+        || entry.id.to_string() == "main"
+        || data.is_method()
+        || data.ty.abi.is_c()
+    {
+        return vec![];
+    }
+
+    vec![(
+        &DEAD_CODE,
+        Message::new(
+            entry.location,
+            format!("Function \"{}\" is never used", entry.id),
+        ),
+    )]
+}
+
+fn get_unused_field_messages(entry: &Entry, data: &StructDefData) -> Vec<(&'static Lint, Message)> {
+    data.fields
+        .iter()
+        .filter(|(field_id, field_data)| {
+            let is_visible =
+                entry.publicity == Publicity::Public && field_data.publicity == Publicity::Public;
+
+            !is_visible && !field_data.usage.is_read() && !is_intentionally_unused(**field_id)
+        })
+        .map(|(field_id, field_data)| {
+            (
+                &DEAD_CODE,
+                Message::new(
+                    field_data.location,
+                    format!(
+                        "Field \"{field_id}\" of struct \"{}\" is never read",
+                        entry.id
+                    ),
+                ),
+            )
+        })
+        .collect()
+}
+
+fn get_unused_unit_messages(entry: &Entry, data: &EnumDefData) -> Vec<(&'static Lint, Message)> {
+    if entry.publicity == Publicity::Public {
+        return vec![];
+    }
+
+    data.units
+        .values()
+        .filter(|unit| !unit.usage.is_read() && !is_intentionally_unused(unit.id))
+        .map(|unit| {
+            (
+                &DEAD_CODE,
+                Message::new(
+                    unit.location,
+                    format!(
+                        "Unit \"{}\" of enum \"{}\" is never used",
+                        unit.id, entry.id
+                    ),
+                ),
+            )
+        })
+        .collect()
+}
+
+// Use declaration is used if any of its imported names is used
+fn get_unused_import_warnings<'a>(
+    use_items: impl Iterator<Item = &'a UseItemData>,
+) -> Vec<UnusedWarning> {
+    use_items
+        .filter(|item| {
+            item.publicity != Publicity::Public
+                && !item.usage.is_read()
+                && !item.id.is_some_and(is_intentionally_unused)
+        })
+        .map(|item| {
+            (
+                &UNUSED_IMPORTS,
+                Message::new(
+                    item.source.location,
+                    format!("Unused import \"{}\"", item.source),
+                ),
+                item.usage.lint_scope.clone(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_attributes::Mutability;
+    use tanitc_hir::{
+        attributes::{Attribute, MetaItem},
+        hir::{expressions::binary::BinaryOperation, type_spec::Type},
+    };
+    use tanitc_hir_test::{
+        create_attribute, create_binary_expr, create_call_expr, create_common_func_param,
+        create_custom_type, create_enum_def, create_func_def, create_integer_lit,
+        create_main_func_def, create_program, create_scope_resolutions_expr, create_struct_def,
+        create_var, create_var_def,
+    };
+    use tanitc_ident::Ident;
+
+    use crate::{lower_source, Analyzer};
+
+    fn create_allow_attribute(lint: &str) -> Attribute {
+        create_attribute(MetaItem::List(
+            Ident::from("allow".to_string()),
+            vec![MetaItem::Word(Ident::from(lint.to_string()))],
+        ))
+    }
+
+    fn get_warnings(analyzer: &Analyzer) -> Vec<String> {
+        let mut warnings: Vec<String> = analyzer
+            .messages_ref()
+            .warnings_ref()
+            .iter()
+            .map(|msg| msg.text.clone())
+            .collect();
+        warnings.sort();
+        warnings
+    }
+
+    #[test]
+    fn unused_variables_test() {
+        // Given
+        let foo_def = create_func_def(
+            "foo",
+            vec![
+                create_common_func_param("a", Mutability::Immutable, Type::I32),
+                create_common_func_param("_b", Mutability::Immutable, Type::I32),
+            ],
+            Type::unit(),
+            vec![],
+        );
+
+        let main_func = create_main_func_def(vec![
+            create_var_def("x", Mutability::Immutable, Type::I32, None).into(),
+            create_var_def("_y", Mutability::Immutable, Type::I32, None).into(),
+            create_var_def("z", Mutability::Immutable, Type::I32, None).into(),
+            create_call_expr(&["foo"], vec![create_var(&["z"]), create_integer_lit(1)]).into(),
+        ]);
+
+        /* func foo(a: i32, _b: i32) { }
+         * func main() {
+         *     var x: i32
+         *     var _y: i32
+         *     var z: i32
+         *     foo(z, 1)
+         * }
+         */
+        let mut program = create_program(vec![foo_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+        assert_eq!(
+            get_warnings(&analyzer),
+            vec![
+                "Semantic warning: Unused parameter \"a\"",
+                "Semantic warning: Unused variable \"x\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn unused_mut_test() {
+        // Given
+        let main_func = create_main_func_def(vec![
+            create_var_def(
+                "a",
+                Mutability::Mutable,
+                Type::I32,
+                Some(create_integer_lit(1)),
+            )
+            .into(),
+            create_var_def(
+                "b",
+                Mutability::Mutable,
+                Type::I32,
+                Some(create_integer_lit(2)),
+            )
+            .into(),
+            create_binary_expr(
+                create_var(&["b"]),
+                BinaryOperation::Assign,
+                create_var(&["a"]),
+            )
+            .into(),
+            create_var_def(
+                "_c",
+                Mutability::Immutable,
+                Type::I32,
+                Some(create_var(&["b"])),
+            )
+            .into(),
+        ]);
+
+        /* func main() {
+         *     var mut a: i32 = 1
+         *     var mut b: i32 = 2
+         *     b = a
+         *     var _c: i32 = b
+         * }
+         */
+        let mut program = create_program(vec![main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+        assert_eq!(
+            get_warnings(&analyzer),
+            vec!["Semantic warning: Mutable variable \"a\" is never mutated"]
+        );
+    }
+
+    #[test]
+    fn dead_code_test() {
+        // Given
+        let used_def = create_func_def("used", vec![], Type::unit(), vec![]);
+        let unused_def = create_func_def("unused", vec![], Type::unit(), vec![]);
+        let mut allowed_def = create_func_def("allowed", vec![], Type::unit(), vec![]);
+        allowed_def.attributes.meta = vec![create_allow_attribute("dead_code")];
+
+        let struct_def = create_struct_def("Point", vec![("x", Type::I32), ("_y", Type::I32)]);
+        let enum_def = create_enum_def("Color", vec![("Red", None), ("Green", None)]);
+
+        let main_func = create_main_func_def(vec![
+            create_call_expr(&["used"], vec![]).into(),
+            create_var_def(
+                "_c",
+                Mutability::Immutable,
+                create_custom_type(&["Color"]),
+                Some(create_scope_resolutions_expr(&["Color", "Red"])),
+            )
+            .into(),
+        ]);
+
+        /* func used() { }
+         * func unused() { }
+         * #[allow(dead_code)]
+         * func allowed() { }
+         * struct Point {
+         *     x: i32
+         *     _y: i32
+         * }
+         * enum Color {
+         *     Red
+         *     Green
+         * }
+         * func main() {
+         *     used()
+         *     var _c: Color = Color::Red
+         * }
+         */
+        let mut program = create_program(vec![
+            used_def.into(),
+            unused_def.into(),
+            allowed_def.into(),
+            struct_def.into(),
+            enum_def.into(),
+            main_func.into(),
+        ]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+        assert_eq!(
+            get_warnings(&analyzer),
+            vec![
+                "Semantic warning: Field \"x\" of struct \"Point\" is never read",
+                "Semantic warning: Function \"unused\" is never used",
+                "Semantic warning: Unit \"Green\" of enum \"Color\" is never used",
+            ]
+        );
+    }
+
+    #[test]
+    fn unused_imports_test() {
+        // Given
+        const SRC_TEXT: &str = "module math {\
+                                \n    pub func sin(): f32 { return 0.0 }\
+                                \n    pub func cos(): f32 { return 1.0 }\
+                                \n    pub func tan(): f32 { return 2.0 }\
+                                \n}\
+                                \nmodule units {\
+                                \n    pub func metre(): f32 { return 1.0 }\
+                                \n}\
+                                \nuse math::sin\
+                                \nuse math::cos\
+                                \nuse math::tan as _tan\
+                                \nuse units::*\
+                                \nfunc main() {\
+                                \n    var _x = sin()\
+                                \n    var _y = metre()\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+        assert_eq!(
+            get_warnings(&analyzer),
+            vec!["Semantic warning: Unused import \"math::cos\""]
+        );
+    }
+
+    #[test]
+    fn unused_glob_imports_test() {
+        // Given
+        const SRC_TEXT: &str = "module a {\
+                                \n    pub func f1() { }\
+                                \n    pub func f2() { }\
+                                \n}\
+                                \nmodule c {\
+                                \n    pub func f1() { }\
+                                \n}\
+                                \nmodule empty {\
+                                \n}\
+                                \nuse a::*\
+                                \nuse c::*\
+                                \nuse empty::*\
+                                \nfunc main() {\
+                                \n    f2()\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+        assert_eq!(
+            get_warnings(&analyzer),
+            vec![
+                "Semantic warning: Unused import \"c::*\"",
+                "Semantic warning: Unused import \"empty::*\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn colliding_glob_imports_unused_test() {
+        // Given
+        const SRC_TEXT: &str = "module a {\
+                                \n    pub func f1() { }\
+                                \n}\
+                                \nmodule c {\
+                                \n    pub func f1() { }\
+                                \n}\
+                                \nuse a::*\
+                                \nuse c::*\
+                                \nfunc main() { }";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+        assert_eq!(
+            get_warnings(&analyzer),
+            vec![
+                "Semantic warning: Unused import \"a::*\"",
+                "Semantic warning: Unused import \"c::*\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn dead_members_location_test() {
+        // Given
+        const SRC_TEXT: &str = "struct Point {\
+                                \n    x: i32\
+                                \n    _y: i32\
+                                \n}\
+                                \nenum Color {\
+                                \n    Red\
+                                \n    Green\
+                                \n}\
+                                \nfunc main() {\
+                                \n    var _p: Point\
+                                \n    var _c = Color::Red\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+        let warnings = analyzer.messages_ref().warnings_ref();
+        let field_warning = warnings
+            .iter()
+            .find(|msg| msg.text.contains("Field \"x\""))
+            .expect("Expected warning for field");
+        let unit_warning = warnings
+            .iter()
+            .find(|msg| msg.text.contains("Unit \"Green\""))
+            .expect("Expected warning for unit");
+
+        // Rows and columns start from zero
+        let field_location = field_warning.location.expect("Expected location");
+        assert_eq!((field_location.row, field_location.col), (1, 4));
+
+        let unit_location = unit_warning.location.expect("Expected location");
+        assert_eq!((unit_location.row, unit_location.col), (6, 4));
+    }
+}
@@ -0,0 +1,573 @@
+use tanitc_attributes::Publicity;
+use tanitc_hir::hir::uses::{Use, UseItem};
+use tanitc_ident::Ident;
+use tanitc_messages::Message;
+use tanitc_name::NameSpec;
+
+use crate::{
+    symbol_table::{
+        entry::{Entry, ImportData, SymbolKind, Usage, UseItemData},
+        table::LookupError,
+    },
+    AnalyzeResult, Analyzer,
+};
+
+impl Analyzer {
+    pub(crate) fn analyze_use(&mut self, u: &mut Use) -> AnalyzeResult<()> {
+        for item in u.items.iter() {
+            let mut usage = Usage::default();
+            usage.lint_scope = self.lint_levels.get_scopes();
+
+            let res = if item.is_glob() {
+                self.import_glob(item, u.attributes.publicity, &usage)
+            } else {
+                self.import_item(item, u.attributes.publicity, &usage)
+            };
+
+            match res {
+                Ok(id) => self.table.add_use_item(UseItemData {
+                    source: item.name.clone(),
+                    id,
+                    publicity: u.attributes.publicity,
+                    usage,
+                }),
+                Err(err) => self.error(err),
+            }
+        }
+
+        Ok(())
+    }
+
+    // Imports single item: "use a::b" or "use a::b as c"
+    fn import_item(
+        &mut self,
+        item: &UseItem,
+        publicity: Publicity,
+        usage: &Usage,
+    ) -> AnalyzeResult<Option<Ident>> {
+        let location = item.name.location;
+
+        let target = self
+            .table
+            .lookup_name_spec(&item.name)
+            .map_err(|err| Self::import_error(&item.name, err))?
+            .clone();
+
+        let Some(id) = item.alias.or(item.name.get_id()) else {
+            return Err(Message::invalid_import(
+                location,
+                format!(
+                    "Cannot import \"{}\" without a name, use \"as\" to specify it",
+                    item.name
+                ),
+            ));
+        };
+
+        if publicity == Publicity::Public && target.publicity != Publicity::Public {
+            return Err(Message::private_item(
+                location,
+                format!(
+                    "{} \"{}\" is private and cannot be re-exported",
+                    target.kind.kind_str(),
+                    item.name
+                ),
+            ));
+        }
+
+        if self
+            .table
+            .lookup_in_scope(id)
+            .is_some_and(|entry| !entry.is_glob_import())
+        {
+            return Err(self.multiple_ids_error(location, id));
+        }
+
+        self.add_symbol(Entry {
+            id,
+            is_static: false,
+            publicity,
+            deprecation: None,
+            location,
+            kind: ImportData {
+                source: item.name.clone(),
+                target: Box::new(target),
+                is_glob: false,
+                ambiguity: None,
+            }
+            .into(),
+            usage: usage.clone(),
+        });
+
+        Ok(Some(id))
+    }
+
+    // Imports all visible items: "use a::*"
+    fn import_glob(
+        &mut self,
+        item: &UseItem,
+        publicity: Publicity,
+        usage: &Usage,
+    ) -> AnalyzeResult<Option<Ident>> {
+        let location = item.name.location;
+        let namespace = NameSpec {
+            location,
+            path: item.name.path[..item.name.path.len() - 1].to_vec(),
+        };
+
+        // Path is split with the trailing "*", which stays in the rest of it
+        let (table, path) = self
+            .table
+            .split_path_root(&item.name.path)
+            .map_err(|err| Self::import_error(&item.name, err))?;
+
+        let imported: Vec<(Ident, Entry)> = if path.len() == 1 {
+            // "use super::*" imports everything from the enclosing module
+            table
+                .all_entries()
+                .map(|entry| (entry.id, entry.resolve_import().clone()))
+                .collect()
+        } else {
+            let entry = self
+                .table
+                .lookup_name_spec(&namespace)
+                .map_err(|err| Self::import_error(&item.name, err))?;
+
+            match &entry.kind {
+                SymbolKind::ModuleDef(data) => {
+                    let is_inner = self.table.get_path().starts_with(&data.name.path);
+
+                    data.table
+                        .all_entries()
+                        .filter(|entry| is_inner || entry.publicity == Publicity::Public)
+                        .map(|entry| (entry.id, entry.resolve_import().clone()))
+                        .collect()
+                }
+                SymbolKind::EnumDef(data) => data
+                    .units
+                    .iter()
+                    .map(|(id, entry)| (*id, entry.clone()))
+                    .collect(),
+                SymbolKind::VariantDef(data) => data
+                    .variants
+                    .iter()
+                    .map(|(id, entry)| (*id, entry.clone()))
+                    .collect(),
+                kind => {
+                    return Err(Message::invalid_import(
+                        location,
+                        format!(
+                            "Cannot import \"{}\": {} \"{namespace}\" is not a module or enum",
+                            item.name,
+                            kind.kind_str()
+                        ),
+                    ))
+                }
+            }
+        };
+
+        for (id, target) in imported {
+            if let Some(existing) = self.table.lookup_in_scope(id) {
+                let SymbolKind::Import(data) = &existing.kind else {
+                    // Explicitly defined names shadow glob imports
+                    continue;
+                };
+
+                if !data.is_glob || data.source == item.name || data.ambiguity.is_some() {
+                    continue;
+                }
+
+                let mut existing = existing.clone();
+                if let SymbolKind::Import(data) = &mut existing.kind {
+                    data.ambiguity = Some(item.name.clone());
+                }
+
+                self.add_symbol(existing);
+                continue;
+            }
+
+            self.add_symbol(Entry {
+                id,
+                is_static: false,
+                publicity,
+                deprecation: None,
+                location,
+                kind: ImportData {
+                    source: item.name.clone(),
+                    target: Box::new(target),
+                    is_glob: true,
+                    ambiguity: None,
+                }
+                .into(),
+                usage: usage.clone(),
+            });
+        }
+
+        Ok(None)
+    }
+
+    fn import_error(name: &NameSpec, err: LookupError) -> Message {
+        let code = err.get_error_code();
+        Message::new(name.location, format!("Cannot import \"{name}\": {err}")).with_code(code)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_hir::hir::{definitions::functions::FunctionDef, type_spec::Type};
+    use tanitc_hir_test::{
+        create_call_expr, create_func_def, create_main_func_def, create_module_def, create_program,
+        create_use, create_use_item,
+    };
+
+    use tanitc_messages::codes::ErrorCode;
+
+    use super::*;
+    use crate::lower_source;
+
+    fn create_pub_func_def(name: &str) -> FunctionDef {
+        let mut func_def = create_func_def(name, vec![], Type::unit(), vec![]);
+        func_def.attributes.publicity = Publicity::Public;
+        func_def
+    }
+
+    #[test]
+    fn use_alias_and_group_good_test() {
+        // Given
+        let module_math = create_module_def(
+            "math",
+            vec![
+                create_pub_func_def("sin").into(),
+                create_pub_func_def("cos").into(),
+            ],
+        );
+
+        let use_math = create_use(vec![
+            create_use_item(&["math", "sin"], None),
+            create_use_item(&["math", "cos"], Some("cosine")),
+        ]);
+
+        let main_func = create_main_func_def(vec![
+            create_call_expr(&["sin"], vec![]).into(),
+            create_call_expr(&["cosine"], vec![]).into(),
+        ]);
+
+        /*
+         * module math {
+         *     pub func sin() { }
+         *     pub func cos() { }
+         * }
+         * use math::{sin, cos as cosine}
+         * func main() {
+         *     sin()
+         *     cosine()
+         * }
+         */
+        let mut program =
+            create_program(vec![module_math.into(), use_math.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        assert!(res.is_ok());
+        assert!(analyzer.messages_ref().warnings_ref().is_empty());
+    }
+
+    #[test]
+    fn use_super_and_crate_good_test() {
+        // Given
+        let module_a = create_module_def("a", vec![create_pub_func_def("f").into()]);
+
+        let mut func_h = create_func_def(
+            "h",
+            vec![],
+            Type::unit(),
+            vec![
+                create_call_expr(&["f"], vec![]).into(),
+                create_call_expr(&["g"], vec![]).into(),
+            ],
+        );
+        func_h.attributes.publicity = Publicity::Public;
+
+        let module_b = create_module_def(
+            "b",
+            vec![
+                create_use(vec![create_use_item(&["super", "a", "*"], None)]).into(),
+                create_use(vec![create_use_item(&["crate", "a", "f"], Some("g"))]).into(),
+                func_h.into(),
+            ],
+        );
+
+        let main_func = create_main_func_def(vec![]);
+
+        /*
+         * module a {
+         *     pub func f() { }
+         * }
+         * module b {
+         *     use super::a::*
+         *     use crate::a::f as g
+         *     pub func h() {
+         *         f()
+         *         g()
+         *     }
+         * }
+         * func main() { }
+         */
+        let mut program = create_program(vec![module_a.into(), module_b.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        assert!(res.is_ok());
+        assert!(analyzer.messages_ref().warnings_ref().is_empty());
+    }
+
+    #[test]
+    fn use_crate_inside_nested_module_good_test() {
+        // Given
+        const SRC_TEXT: &str = "module a {\
+                                \n    func f() { }\
+                                \n    func k() { }\
+                                \n    pub module b {\
+                                \n        use crate::a::f\
+                                \n        use crate::a::*\
+                                \n        pub func h() {\
+                                \n            f()\
+                                \n            k()\
+                                \n        }\
+                                \n    }\
+                                \n}\
+                                \nfunc main() { }";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        assert!(res.is_ok());
+        assert!(analyzer.messages_ref().warnings_ref().is_empty());
+    }
+
+    #[test]
+    fn use_type_with_static_method_good_test() {
+        // Given
+        const SRC_TEXT: &str = "module m {\
+                                \n    pub struct Point {\
+                                \n        pub x: i32\
+                                \n    }\
+                                \n    impl Point {\
+                                \n        pub func create(): i32 {\
+                                \n            return 1\
+                                \n        }\
+                                \n    }\
+                                \n}\
+                                \nuse m::Point\
+                                \nfunc main() {\
+                                \n    var _a = Point::create()\
+                                \n    var _b = m::Point::create()\
+                                \n}";
+
+        let mut program = lower_source(SRC_TEXT);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        assert!(res.is_ok(), "{res:?}");
+        assert!(analyzer.messages_ref().warnings_ref().is_empty());
+    }
+
+    #[test]
+    fn pub_use_reexport_test() {
+        // Given
+        let module_inner = create_module_def("inner", vec![create_pub_func_def("f").into()]);
+
+        let mut pub_use = create_use(vec![create_use_item(&["super", "inner", "f"], None)]);
+        pub_use.attributes.publicity = Publicity::Public;
+        let module_outer = create_module_def("outer", vec![pub_use.into()]);
+
+        let private_use = create_use(vec![create_use_item(&["super", "inner", "f"], None)]);
+        let module_hidden = create_module_def("hidden", vec![private_use.into()]);
+
+        let main_func = create_main_func_def(vec![
+            create_call_expr(&["outer", "f"], vec![]).into(),
+            create_call_expr(&["hidden", "f"], vec![]).into(),
+        ]);
+
+        /*
+         * module inner {
+         *     pub func f() { }
+         * }
+         * module outer {
+         *     pub use super::inner::f
+         * }
+         * module hidden {
+         *     use super::inner::f
+         * }
+         * func main() {
+         *     outer::f()
+         *     hidden::f() # import is not re-exported
+         * }
+         */
+        let mut program = create_program(vec![
+            module_inner.into(),
+            module_outer.into(),
+            module_hidden.into(),
+            main_func.into(),
+        ]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: function \"f\" is private in module \"hidden\" (defined at TestLocation:1:1)";
+        const EXPECTED_WARN: &str = "Semantic warning: Unused import \"super::inner::f\"";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+        let warnings = messages.warnings_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].text, EXPECTED_WARN);
+    }
+
+    #[test]
+    fn ambiguous_glob_import_bad_test() {
+        // Given
+        let module_a = create_module_def("a", vec![create_pub_func_def("f").into()]);
+        let module_b = create_module_def("b", vec![create_pub_func_def("f").into()]);
+
+        let use_globs = create_use(vec![
+            create_use_item(&["a", "*"], None),
+            create_use_item(&["b", "*"], None),
+        ]);
+
+        let main_func = create_main_func_def(vec![create_call_expr(&["f"], vec![]).into()]);
+
+        /*
+         * module a {
+         *     pub func f() { }
+         * }
+         * module b {
+         *     pub func f() { }
+         * }
+         * use {a::*, b::*}
+         * func main() {
+         *     f() # ambiguous
+         * }
+         */
+        let mut program = create_program(vec![
+            module_a.into(),
+            module_b.into(),
+            use_globs.into(),
+            main_func.into(),
+        ]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str =
+            "Semantic error: \"f\" is ambiguous: imported by both \"a::*\" and \"b::*\"";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+        assert_eq!(errors[0].code, Some(ErrorCode::AmbiguousImport));
+    }
+
+    #[test]
+    fn unused_import_warning_test() {
+        // Given
+        let module_math = create_module_def(
+            "math",
+            vec![
+                create_pub_func_def("sin").into(),
+                create_pub_func_def("cos").into(),
+            ],
+        );
+
+        let use_math = create_use(vec![
+            create_use_item(&["math", "sin"], None),
+            create_use_item(&["math", "cos"], None),
+        ]);
+
+        let main_func = create_main_func_def(vec![create_call_expr(&["sin"], vec![]).into()]);
+
+        /*
+         * module math {
+         *     pub func sin() { }
+         *     pub func cos() { }
+         * }
+         * use math::{sin, cos}
+         * func main() {
+         *     sin()
+         * }
+         */
+        let mut program =
+            create_program(vec![module_math.into(), use_math.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_WARN: &str = "Semantic warning: Unused import \"math::cos\"";
+
+        assert!(res.is_ok());
+
+        let warnings = analyzer.messages_ref().warnings_ref();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].text, EXPECTED_WARN);
+    }
+
+    #[test]
+    fn use_super_outside_module_bad_test() {
+        // Given
+        let use_super = create_use(vec![create_use_item(&["super", "f"], None)]);
+        let main_func = create_main_func_def(vec![]);
+
+        /*
+         * use super::f
+         * func main() { }
+         */
+        let mut program = create_program(vec![use_super.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str =
+            "Semantic error: Cannot import \"super::f\": \"super\" is used outside of any module";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+        assert_eq!(errors[0].code, Some(ErrorCode::SuperOfRoot));
+    }
+}
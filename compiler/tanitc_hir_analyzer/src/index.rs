@@ -0,0 +1,183 @@
+use tanitc_hir::hir::type_spec::Type;
+use tanitc_ident::Ident;
+use tanitc_lexer::location::Location;
+use tanitc_name::{NamePathSegment, NameSpec};
+
+use crate::symbol_table::entry::{Entry, SymbolKind};
+
+// Definitions and resolved names of the analyzed program.
+// Editors use it to show types of names and to jump to definitions
+#[derive(Default, Debug, Clone)]
+pub struct SymbolIndex {
+    definitions: Vec<SymbolDefinition>,
+    references: Vec<SymbolReference>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SymbolDefinition {
+    pub id: Ident,
+    pub kind: &'static str,
+    pub location: Location,
+    pub ty: Option<Type>,
+    pub module: Vec<NamePathSegment>, // path of the module where the name is defined
+    pub is_local: bool,               // defined inside of a function
+}
+
+#[derive(Debug, Clone)]
+pub struct SymbolReference {
+    pub location: Location,
+    pub name: String,
+    pub width: usize, // number of characters of the name in the source
+    pub kind: &'static str,
+    pub definition: Location,
+    pub ty: Option<Type>,
+}
+
+impl SymbolIndex {
+    pub fn definitions(&self) -> &[SymbolDefinition] {
+        &self.definitions
+    }
+
+    pub fn references(&self) -> &[SymbolReference] {
+        &self.references
+    }
+
+    // Returns the reference whose name covers the location
+    pub fn find_reference(&self, location: Location) -> Option<&SymbolReference> {
+        self.references.iter().find(|reference| {
+            reference.location.path == location.path
+                && reference.location.row == location.row
+                && (reference.location.col..reference.location.col + reference.width)
+                    .contains(&location.col)
+        })
+    }
+
+    pub(crate) fn add_definition(
+        &mut self,
+        entry: &Entry,
+        module: Vec<NamePathSegment>,
+        is_local: bool,
+    ) {
+        if matches!(entry.kind, SymbolKind::Import(_) | SymbolKind::None) {
+            return;
+        }
+
+        self.definitions.push(SymbolDefinition {
+            id: entry.id,
+            kind: entry.kind.kind_str(),
+            location: entry.location,
+            ty: get_entry_type(entry),
+            module,
+            is_local,
+        });
+    }
+
+    pub(crate) fn add_reference(&mut self, location: Location, name: &NameSpec, entry: &Entry) {
+        let name = name.to_string();
+
+        self.references.push(SymbolReference {
+            location,
+            width: name.chars().count(),
+            name,
+            kind: entry.kind.kind_str(),
+            definition: entry.location,
+            ty: get_entry_type(entry),
+        });
+    }
+
+    // Fields are not stored as entries, so the reference leads to the owner
+    pub(crate) fn add_member_reference(
+        &mut self,
+        location: Location,
+        id: Ident,
+        ty: Type,
+        owner_location: Location,
+    ) {
+        let name = id.to_string();
+
+        self.references.push(SymbolReference {
+            location,
+            width: name.chars().count(),
+            name,
+            kind: "field",
+            definition: owner_location,
+            ty: Some(ty),
+        });
+    }
+
+    pub(crate) fn append(&mut self, mut other: Self) {
+        self.definitions.append(&mut other.definitions);
+        self.references.append(&mut other.references);
+    }
+}
+
+fn get_entry_type(entry: &Entry) -> Option<Type> {
+    match &entry.kind {
+        SymbolKind::AliasDef(data) => Some(data.ty.clone()),
+        SymbolKind::VarDef(data) => Some(data.var_type.clone()),
+        SymbolKind::FuncDef(data) => Some(Type::Func(data.ty.clone())),
+        SymbolKind::Enum(data) => Some(Type::Custom(data.name.clone())),
+        SymbolKind::Import(data) => get_entry_type(&data.target),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_attributes::Mutability;
+    use tanitc_hir::hir::type_spec::Type;
+    use tanitc_hir_test::{create_main_func_def, create_program, create_var, create_var_def};
+
+    use crate::Analyzer;
+
+    #[test]
+    fn index_test() {
+        // Given
+        let main_func = create_main_func_def(vec![
+            create_var_def("x", Mutability::Immutable, Type::I32, None).into(),
+            create_var_def(
+                "_y",
+                Mutability::Immutable,
+                Type::I32,
+                Some(create_var(&["x"])),
+            )
+            .into(),
+        ]);
+
+        /* func main() {
+         *     var x: i32
+         *     var _y: i32 = x
+         * }
+         */
+        let mut program = create_program(vec![main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+
+        let index = analyzer.index_ref();
+        let definitions: Vec<(String, &str, bool)> = index
+            .definitions()
+            .iter()
+            .map(|def| (def.id.to_string(), def.kind, def.is_local))
+            .collect();
+        assert_eq!(
+            definitions,
+            vec![
+                ("x".to_string(), "variable", true),
+                ("_y".to_string(), "variable", true),
+                ("main".to_string(), "function", false),
+            ]
+        );
+
+        let references = index.references();
+        assert_eq!(references.len(), 1);
+        assert_eq!(references[0].name, "x");
+        assert_eq!(references[0].kind, "variable");
+        assert_eq!(references[0].ty, Some(Type::I32));
+    }
+}
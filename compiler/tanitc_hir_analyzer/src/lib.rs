@@ -1,3 +1,5 @@
+use crate::index::SymbolIndex;
+use crate::lints::LintLevels;
 use crate::symbol_table::{
     entry::{Entry, SymbolKind},
     table::Table,
@@ -10,6 +12,8 @@ use tanitc_messages::{listener::MessageListener, Message};
 use tanitc_options::{CompileOptions, CrateType};
 
 pub(crate) mod hir;
+pub mod index;
+pub(crate) mod lints;
 pub(crate) mod symbol_table;
 
 pub type AnalyzeResult<T> = Result<T, Message>;
@@ -22,6 +26,8 @@ pub struct Analyzer {
     compile_options: CompileOptions,
     counter: Counter,
     messages: MessageListener,
+    lint_levels: LintLevels,
+    index: SymbolIndex,
 }
 
 impl Analyzer {
@@ -54,6 +60,7 @@ impl Analyzer {
     }
 
     pub fn set_compile_options(&mut self, compile_options: CompileOptions) {
+        self.lint_levels = LintLevels::new(&compile_options);
         self.compile_options = compile_options;
     }
 
@@ -76,38 +83,63 @@ impl Analyzer {
     }
 
     pub fn has_symbol(&self, id: Ident) -> bool {
-        self.table.lookup(id).is_some()
+        // Names imported by glob can be shadowed by definitions
+        self.table
+            .lookup_entry(id)
+            .is_some_and(|entry| !entry.is_glob_import())
     }
 
-    pub fn add_symbol(&mut self, entry: Entry) {
+    // Points to the previous definition of the name
+    pub fn multiple_ids_error(&self, location: Location, id: Ident) -> Message {
+        let msg = Message::multiple_ids(location, id);
+
+        match self.table.lookup_entry(id) {
+            Some(entry) => msg.with_label(entry.location, "previous definition is here"),
+            None => msg,
+        }
+    }
+
+    pub fn add_symbol(&mut self, mut entry: Entry) {
+        // Unused definitions are reported after their attributes are out of scope
+        entry.usage.lint_scope = self.lint_levels.get_scopes();
+        self.index_definition(&entry);
         self.table.insert(entry);
     }
 
+    pub(crate) fn index_definition(&mut self, entry: &Entry) {
+        let is_local = self.table.get_scope_info().is_in_func;
+        self.index
+            .add_definition(entry, self.table.get_path(), is_local);
+    }
+
     pub fn check_entry_point(&self) -> Result<(), Message> {
         const ENTRY_POINT: &str = "main";
         let main_func_id = Ident::from(ENTRY_POINT.to_string());
 
         let Some(entry) = self.table.lookup(main_func_id) else {
-            return Err(Message::new(Location::default(), "No entry point!"));
+            return Err(Message::invalid_entry_point("No entry point!"));
         };
 
         let SymbolKind::FuncDef(data) = &entry.kind else {
-            return Err(Message::new(
-                Location::default(),
-                "No entry point function!",
-            ));
+            return Err(Message::invalid_entry_point("No entry point function!")
+                .map_location(entry.location));
         };
 
         if *data.ty.return_type != Type::I32 && !data.ty.return_type.is_unit() {
-            return Err(Message::new(
-                Location::default(),
-                format!("Bad type of main function: {}", data.ty.return_type),
-            ));
+            return Err(Message::invalid_entry_point(format!(
+                "Bad type of main function: {}",
+                data.ty.return_type
+            ))
+            .map_location(entry.location));
         }
 
         Ok(())
     }
 
+    pub fn index_ref(&self) -> &SymbolIndex {
+        &self.index
+    }
+
     pub fn messages_ref(&self) -> &MessageListener {
         &self.messages
     }
@@ -126,3 +158,14 @@ impl Analyzer {
         self.messages.warn(warn);
     }
 }
+
+// Lets tests analyze programs written as source text
+#[cfg(test)]
+pub(crate) fn lower_source(src: &str) -> Box<Hir> {
+    let mut parser = tanitc_parser::Parser::from_text(src);
+    let program_ctx = parser.parse_program().unwrap();
+
+    tanitc_ast_lowering::AstLowering::new()
+        .low(program_ctx.as_ref())
+        .unwrap()
+}
@@ -0,0 +1,263 @@
+use std::collections::BTreeMap;
+
+use tanitc_hir::attributes::{Attribute, MetaItem};
+use tanitc_lints::{find_lint, Lint, LintLevel};
+use tanitc_messages::Message;
+use tanitc_options::CompileOptions;
+
+use crate::Analyzer;
+
+pub(crate) type LintScope = Vec<(&'static str, LintLevel)>;
+
+#[derive(Default, Debug, Clone)]
+pub(crate) struct LintLevels {
+    command_line: BTreeMap<&'static str, LintLevel>,
+    deny_warnings: bool,
+    scopes: LintScope, // levels set by attributes, innermost definition last
+}
+
+impl LintLevels {
+    pub(crate) fn new(compile_options: &CompileOptions) -> Self {
+        let mut command_line = BTreeMap::new();
+
+        for option in compile_options.lint_options.iter() {
+            if let Some(lint) = find_lint(&option.name) {
+                command_line.insert(lint.name, option.level);
+            }
+        }
+
+        Self {
+            command_line,
+            deny_warnings: compile_options.deny_warnings,
+            scopes: LintScope::new(),
+        }
+    }
+
+    pub(crate) fn get_scopes(&self) -> LintScope {
+        self.scopes.clone()
+    }
+
+    // Attributes override command line, which overrides defaults
+    pub(crate) fn get_level(&self, lint: &Lint) -> LintLevel {
+        let level = self
+            .scopes
+            .iter()
+            .rev()
+            .find(|(name, _)| *name == lint.name)
+            .map(|(_, level)| *level)
+            .or_else(|| self.command_line.get(lint.name).copied())
+            .unwrap_or(lint.default_level);
+
+        if self.deny_warnings && level == LintLevel::Warn {
+            LintLevel::Deny
+        } else {
+            level
+        }
+    }
+}
+
+impl Analyzer {
+    pub(crate) fn lint(&mut self, lint: &Lint, msg: Message) {
+        let level = self.lint_levels.get_level(lint);
+        let msg = msg
+            .with_code(lint.code)
+            .with_note(format!("lint \"{}\" is set to \"{level}\"", lint.name));
+
+        match level {
+            LintLevel::Allow => {}
+            LintLevel::Warn => self.warning(msg),
+            LintLevel::Deny => self.error(msg),
+        }
+    }
+
+    // Levels from attributes of the definition are used while it is analyzed
+    pub(crate) fn with_lint_scope<T>(
+        &mut self,
+        scope: LintScope,
+        analyze: impl FnOnce(&mut Self) -> T,
+    ) -> T {
+        let depth = self.lint_levels.scopes.len();
+        self.lint_levels.scopes.extend(scope);
+
+        let res = analyze(self);
+
+        self.lint_levels.scopes.truncate(depth);
+        res
+    }
+
+    // Unknown lints are skipped, they are reported by attributes analysis
+    pub(crate) fn get_lint_scope(attributes: &[Attribute]) -> LintScope {
+        let mut scope = LintScope::new();
+
+        for attribute in attributes.iter() {
+            let MetaItem::List(name, items) = &attribute.meta else {
+                continue;
+            };
+
+            let Some(level) = LintLevel::from_attribute_name(&name.to_string()) else {
+                continue;
+            };
+
+            for item in items.iter() {
+                if let Some(lint) = item.name().and_then(|id| find_lint(&id.to_string())) {
+                    scope.push((lint.name, level));
+                }
+            }
+        }
+
+        scope
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_hir::{
+        attributes::MetaItem,
+        hir::{definitions::functions::FunctionDef, type_spec::Type, Hir},
+    };
+    use tanitc_hir_test::{
+        create_attribute, create_call_expr, create_func_def, create_main_func_def, create_program,
+        create_word_attribute,
+    };
+    use tanitc_ident::Ident;
+    use tanitc_lints::LintLevel;
+    use tanitc_options::{CompileOptions, LintOption};
+
+    use crate::Analyzer;
+
+    fn create_lint_attribute(level: &str, lint: &str) -> tanitc_hir::attributes::Attribute {
+        create_attribute(MetaItem::List(
+            Ident::from(level.to_string()),
+            vec![MetaItem::Word(Ident::from(lint.to_string()))],
+        ))
+    }
+
+    /* #[deprecated]
+     * func foo() { }
+     * func main() {
+     *     foo()
+     * }
+     */
+    fn create_deprecated_call_program(main_func: impl FnOnce(FunctionDef) -> FunctionDef) -> Hir {
+        let mut func_def = create_func_def("foo", vec![], Type::unit(), vec![]);
+        func_def.attributes.meta = vec![create_word_attribute("deprecated")];
+
+        let main_func = main_func(create_main_func_def(vec![create_call_expr(
+            &["foo"],
+            vec![],
+        )
+        .into()]));
+
+        create_program(vec![func_def.into(), main_func.into()])
+    }
+
+    #[test]
+    fn allow_lint_attribute_test() {
+        // Given
+        /* #[allow(deprecated)]
+         * func main() {
+         *     foo()
+         * }
+         */
+        let mut program = create_deprecated_call_program(|mut main_func| {
+            main_func.attributes.meta = vec![create_lint_attribute("allow", "deprecated")];
+            main_func
+        });
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        res.expect("Expected no errors");
+        assert!(analyzer.messages_ref().warnings_ref().is_empty());
+    }
+
+    #[test]
+    fn deny_lint_option_test() {
+        // Given
+        let mut program = create_deprecated_call_program(|main_func| main_func);
+
+        let mut analyzer = Analyzer::with_compile_options(CompileOptions {
+            lint_options: vec![LintOption {
+                name: "deprecated".to_string(),
+                level: LintLevel::Deny,
+            }],
+            ..Default::default()
+        });
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        const EXPECTED_ERR: &str = "Semantic error: Use of deprecated function \"foo\"";
+
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, EXPECTED_ERR);
+        assert_eq!(
+            errors[0].notes[0].text,
+            "lint \"deprecated\" is set to \"deny\""
+        );
+    }
+
+    #[test]
+    fn deny_warnings_test() {
+        // Given
+        /* #[warn(deprecated)]
+         * func main() {
+         *     foo()
+         * }
+         */
+        let mut program = create_deprecated_call_program(|mut main_func| {
+            main_func.attributes.meta = vec![create_lint_attribute("warn", "deprecated")];
+            main_func
+        });
+
+        let mut analyzer = Analyzer::with_compile_options(CompileOptions {
+            lint_options: vec![LintOption {
+                name: "deprecated".to_string(),
+                level: LintLevel::Allow,
+            }],
+            deny_warnings: true,
+            ..Default::default()
+        });
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        let messages = res.expect_err("Expected errors");
+        assert_eq!(messages.errors_ref().len(), 1);
+        assert!(messages.warnings_ref().is_empty());
+    }
+
+    #[test]
+    fn unknown_lint_attribute_bad_test() {
+        // Given
+        let mut func_def = create_main_func_def(vec![]);
+        func_def.attributes.meta = vec![create_lint_attribute("allow", "unused_everything")];
+
+        /* #[allow(unused_everything)]
+         * func main() { }
+         */
+        let mut program = create_program(vec![func_def.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let res = analyzer.analyze_program(&mut program);
+
+        // Then
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0]
+            .text
+            .starts_with("Semantic error: Unknown lint \"unused_everything\""));
+    }
+}
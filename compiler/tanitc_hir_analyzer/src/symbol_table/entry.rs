@@ -1,11 +1,15 @@
-use std::collections::BTreeMap;
+use std::{cell::Cell, collections::BTreeMap, rc::Rc};
 
-use tanitc_attributes::Mutability;
+use tanitc_attributes::{Mutability, Publicity};
 use tanitc_hir::hir::type_spec::{FuncType, Type};
 use tanitc_ident::Ident;
+use tanitc_lexer::location::Location;
 use tanitc_name::NameSpec;
 
-use crate::symbol_table::table::{Table, TableEntries};
+use crate::{
+    lints::LintScope,
+    symbol_table::table::{Table, TableEntries},
+};
 
 #[derive(Debug, Clone)]
 pub struct AliasDefData {
@@ -40,13 +44,23 @@ pub struct FuncDefData {
     pub ty: FuncType,
     pub is_virtual: bool,
     pub is_inline: bool,
+    pub owner: Option<NameSpec>, // type of the impl block, if it is a method
     pub no_return: bool,
 }
 
+impl FuncDefData {
+    pub fn is_method(&self) -> bool {
+        self.owner.is_some()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct StructFieldData {
+    pub location: Location,
     pub name: NameSpec,
     pub ty: Type,
+    pub publicity: Publicity,
+    pub usage: Usage,
 }
 
 pub type StructFieldsData = BTreeMap<Ident, StructFieldData>;
@@ -75,6 +89,8 @@ pub type EnumDefEntries = std::collections::BTreeMap<Ident, Entry>;
 pub struct EnumDefData {
     pub name: NameSpec,
     pub units: EnumDefEntries,
+    pub repr: Option<Type>, // underlying integer type
+    pub is_flags: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -109,6 +125,23 @@ pub struct VariantDefData {
     pub variants: TableEntries,
 }
 
+#[derive(Debug, Clone)]
+pub struct ImportData {
+    pub source: NameSpec,            // path written in the use declaration
+    pub target: Box<Entry>,          // imported entry
+    pub is_glob: bool,               // imported by "use a::*"
+    pub ambiguity: Option<NameSpec>, // another glob import of the same name
+}
+
+// Item of the use declaration, shares usage with all names it imports
+#[derive(Debug, Clone)]
+pub struct UseItemData {
+    pub source: NameSpec,  // path written in the use declaration
+    pub id: Option<Ident>, // imported name, not set for glob imports
+    pub publicity: Publicity,
+    pub usage: Usage,
+}
+
 #[derive(Default, Debug, Clone)]
 pub enum SymbolKind {
     #[default]
@@ -123,13 +156,87 @@ pub enum SymbolKind {
     Enum(EnumData),
     VariantDef(VariantDefData),
     Variant(VariantData),
+    Import(ImportData),
+}
+
+impl SymbolKind {
+    pub fn kind_str(&self) -> &'static str {
+        match self {
+            Self::None => "symbol",
+            Self::AliasDef(_) => "alias",
+            Self::ModuleDef(_) => "module",
+            Self::VarDef(_) => "variable",
+            Self::FuncDef(data) if data.is_method() => "method",
+            Self::FuncDef(_) => "function",
+            Self::StructDef(_) => "struct",
+            Self::UnionDef(_) => "union",
+            Self::EnumDef(_) => "enum",
+            Self::Enum(_) => "enum unit",
+            Self::VariantDef(_) => "variant",
+            Self::Variant(_) => "variant unit",
+            Self::Import(data) => data.target.kind.kind_str(),
+        }
+    }
+}
+
+// Flags are shared by all copies of the definition, so a use through
+// an import or through a copied table marks the definition itself
+#[derive(Default, Debug, Clone)]
+pub struct Usage {
+    is_read: Rc<Cell<bool>>,
+    is_mutated: Rc<Cell<bool>>,
+    pub lint_scope: LintScope, // lint levels set by attributes around the definition
+}
+
+impl Usage {
+    pub fn mark_read(&self) {
+        self.is_read.set(true);
+    }
+
+    pub fn mark_mutated(&self) {
+        self.is_mutated.set(true);
+    }
+
+    pub fn is_read(&self) -> bool {
+        self.is_read.get()
+    }
+
+    pub fn is_mutated(&self) -> bool {
+        self.is_mutated.get()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deprecation {
+    pub note: Option<String>,
 }
 
 #[derive(Default, Debug, Clone)]
 pub struct Entry {
     pub id: Ident,
     pub is_static: bool,
+    pub publicity: Publicity,
+    pub deprecation: Option<Deprecation>,
+    pub location: Location,
     pub kind: SymbolKind,
+    pub usage: Usage,
+}
+
+impl Entry {
+    // Returns the imported entry if this entry is an import, otherwise self
+    pub fn resolve_import(&self) -> &Entry {
+        match &self.kind {
+            SymbolKind::Import(data) => {
+                self.usage.mark_read();
+                data.target.resolve_import()
+            }
+            _ => self,
+        }
+    }
+
+    pub fn is_glob_import(&self) -> bool {
+        matches!(&self.kind, SymbolKind::Import(data) if data.is_glob)
+    }
 }
 
 impl From<AliasDefData> for SymbolKind {
@@ -191,3 +298,9 @@ impl From<VariantData> for SymbolKind {
         Self::Variant(value)
     }
 }
+
+impl From<ImportData> for SymbolKind {
+    fn from(value: ImportData) -> Self {
+        Self::Import(value)
+    }
+}
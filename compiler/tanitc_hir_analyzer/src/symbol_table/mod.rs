@@ -1,17 +1,18 @@
 pub(crate) mod entry;
+pub(crate) mod suggestions;
 pub(crate) mod table;
 pub(crate) mod type_info;
 
 #[cfg(test)]
 mod tests {
-    use tanitc_attributes::{Mutability, Safety};
+    use tanitc_attributes::{Abi, Mutability, Publicity, Safety};
     use tanitc_hir::hir::type_spec::{FuncType, Type};
     use tanitc_ident::Ident;
     use tanitc_lexer::location::Location;
     use tanitc_name::NameSpec;
 
     use crate::symbol_table::{
-        entry::{Entry, StructFieldsData, SymbolKind},
+        entry::{Entry, StructFieldsData, SymbolKind, Usage},
         table::{ScopeInfo, Table},
     };
 
@@ -44,6 +45,9 @@ mod tests {
         table.insert(Entry {
             id: main_mod_id,
             is_static: true,
+            publicity: Publicity::Public,
+            deprecation: None,
+            location: Location::default(),
             kind: ModuleDefData {
                 name: {
                     let mut name = name_spec.clone();
@@ -54,6 +58,7 @@ mod tests {
                 table: Box::new(Table::new()),
             }
             .into(),
+            usage: Usage::default(),
         });
 
         let main_mod = table.lookup_mut(main_mod_id).unwrap();
@@ -74,17 +79,24 @@ mod tests {
             table.insert(Entry {
                 id: bar_id,
                 is_static: false,
+                publicity: Publicity::Public,
+                deprecation: None,
+                location: Location::default(),
                 kind: SymbolKind::from(FuncDefData {
                     name: name_spec.clone(),
                     ty: FuncType {
                         parameters: vec![],
                         return_type: Box::new(Type::unit()),
                         safety: Safety::Safe,
+                        abi: Abi::Tanit,
+                        is_variadic: false,
                     },
                     is_virtual: false,
                     is_inline: false,
+                    owner: None,
                     no_return: true,
                 }),
+                usage: Usage::default(),
             });
 
             {
@@ -108,17 +120,24 @@ mod tests {
             table.insert(Entry {
                 id: main_fn_id,
                 is_static: false,
+                publicity: Publicity::Public,
+                deprecation: None,
+                location: Location::default(),
                 kind: SymbolKind::from(FuncDefData {
                     name: name_spec.clone(),
                     ty: FuncType {
                         parameters: vec![],
                         return_type: Box::new(Type::unit()),
                         safety: Safety::Safe,
+                        abi: Abi::Tanit,
+                        is_variadic: false,
                     },
                     is_virtual: false,
                     is_inline: false,
+                    owner: None,
                     no_return: true,
                 }),
+                usage: Usage::default(),
             });
 
             {
@@ -131,12 +150,16 @@ mod tests {
                 table.insert(Entry {
                     id: var_id,
                     is_static: false,
+                    publicity: Publicity::Public,
+                    deprecation: None,
+                    location: Location::default(),
                     kind: SymbolKind::from(VarDefData {
                         storage: VarStorageType::Auto,
                         var_type: Type::I32,
                         mutability: Mutability::default(),
                         is_initialization: true,
                     }),
+                    usage: Usage::default(),
                 });
 
                 // check if var visible in main
@@ -186,6 +209,9 @@ mod tests {
         table.insert(Entry {
             id: m1_id,
             is_static: true,
+            publicity: Publicity::Public,
+            deprecation: None,
+            location: Location::default(),
             kind: ModuleDefData {
                 name: {
                     let mut name = name_spec.clone();
@@ -203,18 +229,25 @@ mod tests {
                     table.insert(Entry {
                         id: f1_id,
                         is_static: false,
+                        publicity: Publicity::Public,
+                        deprecation: None,
+                        location: Location::default(),
                         kind: FuncDefData {
                             name: name_spec.clone(),
                             ty: FuncType {
                                 parameters: vec![],
                                 return_type: Box::new(Type::unit()),
                                 safety: Safety::Safe,
+                                abi: Abi::Tanit,
+                                is_variadic: false,
                             },
                             is_virtual: false,
                             is_inline: false,
+                            owner: None,
                             no_return: true,
                         }
                         .into(),
+                        usage: Usage::default(),
                     });
 
                     name_spec.path = table.get_joined_path(f2_id);
@@ -222,30 +255,41 @@ mod tests {
                     table.insert(Entry {
                         id: f2_id,
                         is_static: false,
+                        publicity: Publicity::Public,
+                        deprecation: None,
+                        location: Location::default(),
                         kind: FuncDefData {
                             name: name_spec.clone(),
                             ty: FuncType {
                                 parameters: vec![],
                                 return_type: Box::new(Type::unit()),
                                 safety: Safety::Safe,
+                                abi: Abi::Tanit,
+                                is_variadic: false,
                             },
                             is_virtual: false,
                             is_inline: false,
+                            owner: None,
                             no_return: true,
                         }
                         .into(),
+                        usage: Usage::default(),
                     });
 
                     table
                 },
             }
             .into(),
+            usage: Usage::default(),
         });
 
         // INSERT M2
         table.insert(Entry {
             id: m2_id,
             is_static: true,
+            publicity: Publicity::Public,
+            deprecation: None,
+            location: Location::default(),
             kind: ModuleDefData {
                 name: {
                     let mut name = name_spec.clone();
@@ -262,24 +306,32 @@ mod tests {
                     table.insert(Entry {
                         id: f2_id,
                         is_static: false,
+                        publicity: Publicity::Public,
+                        deprecation: None,
+                        location: Location::default(),
                         kind: FuncDefData {
                             name: name_spec.clone(),
                             ty: FuncType {
                                 parameters: vec![],
                                 return_type: Box::new(Type::unit()),
                                 safety: Safety::Safe,
+                                abi: Abi::Tanit,
+                                is_variadic: false,
                             },
                             is_virtual: false,
                             is_inline: false,
+                            owner: None,
                             no_return: true,
                         }
                         .into(),
+                        usage: Usage::default(),
                     });
 
                     table
                 },
             }
             .into(),
+            usage: Usage::default(),
         });
 
         // Then
@@ -339,6 +391,9 @@ mod tests {
         table.insert(Entry {
             id: m1_id,
             is_static: true,
+            publicity: Publicity::Public,
+            deprecation: None,
+            location: Location::default(),
             kind: ModuleDefData {
                 name: {
                     name_spec.path.push(m1_id.into());
@@ -347,6 +402,7 @@ mod tests {
                 table: Box::new(Table::new()),
             }
             .into(),
+            usage: Usage::default(),
         });
 
         {
@@ -358,6 +414,9 @@ mod tests {
             data.table.insert(Entry {
                 id: m2_id,
                 is_static: true,
+                publicity: Publicity::Public,
+                deprecation: None,
+                location: Location::default(),
                 kind: ModuleDefData {
                     name: {
                         name_spec.path.push(m2_id.into());
@@ -366,6 +425,7 @@ mod tests {
                     table: Box::new(Table::new()),
                 }
                 .into(),
+                usage: Usage::default(),
             });
 
             {
@@ -382,6 +442,9 @@ mod tests {
                 data.table.insert(Entry {
                     id: s1_id,
                     is_static: false,
+                    publicity: Publicity::Public,
+                    deprecation: None,
+                    location: Location::default(),
                     kind: StructDefData {
                         name: name_spec.clone(),
                         fields: {
@@ -390,15 +453,21 @@ mod tests {
                             field.insert(
                                 f1_id,
                                 StructFieldData {
+                                    location: Location::default(),
                                     name: name_spec.clone(),
                                     ty: Type::I32,
+                                    publicity: Publicity::Public,
+                                    usage: Usage::default(),
                                 },
                             );
                             field.insert(
                                 f2_id,
                                 StructFieldData {
+                                    location: Location::default(),
                                     name: name_spec.clone(),
                                     ty: Type::F32,
+                                    publicity: Publicity::Public,
+                                    usage: Usage::default(),
                                 },
                             );
 
@@ -406,6 +475,7 @@ mod tests {
                         },
                     }
                     .into(),
+                    usage: Usage::default(),
                 });
             }
         }
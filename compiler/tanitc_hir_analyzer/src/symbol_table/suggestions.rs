@@ -0,0 +1,375 @@
+use tanitc_attributes::Publicity;
+use tanitc_ident::Ident;
+use tanitc_messages::suggestions::Suggestion;
+
+use super::{
+    entry::{Entry, StructFieldsData, SymbolKind},
+    type_info::TypeMembersInfo,
+};
+
+// Private entries are suggested only for lookups from outside of the module,
+// inside of it they are accessible
+pub fn get_similar_entry<'a>(
+    entries: impl Iterator<Item = &'a Entry>,
+    id: Ident,
+    is_outer: bool,
+) -> Option<Suggestion> {
+    let candidates = entries.map(|entry| {
+        Suggestion::new(
+            entry.id,
+            entry.kind.kind_str(),
+            is_outer && entry.publicity != Publicity::Public,
+        )
+    });
+
+    Suggestion::find_closest(&id.to_string(), candidates)
+}
+
+// Types and modules can not be used where a value is expected
+pub fn get_similar_value<'a>(
+    entries: impl Iterator<Item = &'a Entry>,
+    id: Ident,
+) -> Option<Suggestion> {
+    get_similar_entry(entries.filter(|entry| is_value(entry)), id, false)
+}
+
+fn is_value(entry: &Entry) -> bool {
+    match &entry.kind {
+        SymbolKind::VarDef(_) => true,
+        SymbolKind::FuncDef(data) => !data.is_method(),
+        SymbolKind::Import(data) => is_value(&data.target),
+        _ => false,
+    }
+}
+
+pub fn get_similar_field(
+    fields: &StructFieldsData,
+    id: Ident,
+    can_access_private: bool,
+) -> Option<Suggestion> {
+    let candidates = fields.iter().map(|(field_id, field_data)| {
+        Suggestion::new(
+            field_id,
+            "field",
+            !can_access_private && field_data.publicity != Publicity::Public,
+        )
+    });
+
+    Suggestion::find_closest(&id.to_string(), candidates)
+}
+
+pub fn get_similar_member<'a>(
+    members: &TypeMembersInfo,
+    methods: impl Iterator<Item = &'a Entry>,
+    id: Ident,
+    can_access_private: bool,
+) -> Option<Suggestion> {
+    let fields = members.iter().map(|(member_id, member)| {
+        Suggestion::new(member_id, "field", !can_access_private && !member.is_public)
+    });
+
+    let methods = methods.map(|method| {
+        Suggestion::new(
+            method.id,
+            method.kind.kind_str(),
+            !can_access_private && method.publicity != Publicity::Public,
+        )
+    });
+
+    Suggestion::find_closest(&id.to_string(), fields.chain(methods))
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_attributes::{Mutability, Publicity};
+    use tanitc_hir::hir::{
+        expressions::{member_access::MemberAccessExpr, Expression},
+        type_spec::Type,
+        Hir,
+    };
+    use tanitc_hir_test::{
+        create_call_expr, create_custom_type, create_enum_def, create_func_def, create_impl_def,
+        create_integer_lit, create_main_func_def, create_module_def, create_program,
+        create_scope_resolutions_expr, create_struct_def, create_struct_lit, create_union_def,
+        create_var, create_var_def,
+    };
+    use tanitc_ident::Ident;
+    use tanitc_lexer::location::Location;
+
+    use crate::Analyzer;
+
+    fn get_helps(analyzer: &mut Analyzer, program: &mut Hir) -> Vec<String> {
+        let messages = analyzer
+            .analyze_program(program)
+            .expect_err("Expected errors");
+
+        messages
+            .errors_ref()
+            .iter()
+            .flat_map(|msg| msg.notes.iter().map(|note| note.text.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn similar_variable_test() {
+        // Given
+        let main_func = create_main_func_def(vec![
+            create_var_def(
+                "count",
+                Mutability::Immutable,
+                Type::I32,
+                Some(create_integer_lit(1)),
+            )
+            .into(),
+            create_var_def(
+                "_c",
+                Mutability::Immutable,
+                Type::I32,
+                Some(create_var(&["cont"])),
+            )
+            .into(),
+        ]);
+
+        /* func main() {
+         *     var count: i32 = 1
+         *     var _c: i32 = cont
+         * }
+         */
+        let mut program = create_program(vec![main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let helps = get_helps(&mut analyzer, &mut program);
+
+        // Then
+        assert_eq!(
+            helps,
+            vec!["a variable with a similar name exists: \"count\""]
+        );
+    }
+
+    #[test]
+    fn similar_private_func_test() {
+        // Given
+        let helper_def = create_func_def("helper", vec![], Type::unit(), vec![]);
+        let module_def = create_module_def("MyModule", vec![helper_def.into()]);
+
+        let main_func =
+            create_main_func_def(vec![create_call_expr(&["MyModule", "helpr"], vec![]).into()]);
+
+        /* module MyModule {
+         *     func helper() { }
+         * }
+         * func main() {
+         *     MyModule::helpr()
+         * }
+         */
+        let mut program = create_program(vec![module_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let helps = get_helps(&mut analyzer, &mut program);
+
+        // Then
+        const EXPECTED_HELP: &str = "function \"helper\" has a similar name, but it is private: mark it with \"pub\" to use it here";
+
+        assert_eq!(helps, vec![EXPECTED_HELP]);
+    }
+
+    #[test]
+    fn similar_field_test() {
+        // Given
+        let mut struct_def = create_struct_def("Point", vec![("x", Type::I32), ("y", Type::I32)]);
+        struct_def.attributes.publicity = Publicity::Public;
+
+        let main_func = create_main_func_def(vec![
+            create_var_def(
+                "p",
+                Mutability::Immutable,
+                create_custom_type(&["Point"]),
+                Some(create_struct_lit(
+                    &["Point"],
+                    vec![("x", create_integer_lit(1)), ("yy", create_integer_lit(2))],
+                )),
+            )
+            .into(),
+            create_var_def(
+                "q",
+                Mutability::Immutable,
+                create_custom_type(&["Point"]),
+                None,
+            )
+            .into(),
+            create_var_def(
+                "_x",
+                Mutability::Immutable,
+                Type::I32,
+                Some(Expression::MemberAccess(MemberAccessExpr {
+                    location: Location::default(),
+                    lhs: Box::new(create_var(&["q"])),
+                    id: Ident::from("xx".to_string()),
+                })),
+            )
+            .into(),
+        ]);
+
+        /* pub struct Point {
+         *     x: i32
+         *     y: i32
+         * }
+         * func main() {
+         *     var p: Point = Point {
+         *         x: 1
+         *         yy: 2
+         *     }
+         *     var q: Point
+         *     var _x: i32 = q.xx
+         * }
+         */
+        let mut program = create_program(vec![struct_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let helps = get_helps(&mut analyzer, &mut program);
+
+        // Then
+        assert_eq!(
+            helps,
+            vec![
+                "a field with a similar name exists: \"y\"",
+                "a field with a similar name exists: \"x\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn similar_enum_unit_test() {
+        // Given
+        let enum_def = create_enum_def("Color", vec![("Red", None), ("Green", None)]);
+
+        let main_func = create_main_func_def(vec![create_var_def(
+            "_c",
+            Mutability::Immutable,
+            create_custom_type(&["Color"]),
+            Some(create_scope_resolutions_expr(&["Color", "Gren"])),
+        )
+        .into()]);
+
+        /* enum Color {
+         *     Red
+         *     Green
+         * }
+         * func main() {
+         *     var _c: Color = Color::Gren
+         * }
+         */
+        let mut program = create_program(vec![enum_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let helps = get_helps(&mut analyzer, &mut program);
+
+        // Then
+        assert_eq!(
+            helps,
+            vec!["an enum unit with a similar name exists: \"Green\""]
+        );
+    }
+
+    #[test]
+    fn similar_method_test() {
+        // Given
+        let struct_def = create_struct_def("Point", vec![("x", Type::I32)]);
+        let impl_def = create_impl_def(
+            "Point",
+            vec![create_func_def("make", vec![], Type::unit(), vec![])],
+        );
+
+        let main_func = create_main_func_def(vec![
+            create_call_expr(&["Point", "mak"], vec![]).into(),
+            create_var_def(
+                "p",
+                Mutability::Immutable,
+                create_custom_type(&["Point"]),
+                None,
+            )
+            .into(),
+            create_var_def(
+                "_m",
+                Mutability::Immutable,
+                Type::I32,
+                Some(Expression::MemberAccess(MemberAccessExpr {
+                    location: Location::default(),
+                    lhs: Box::new(create_var(&["p"])),
+                    id: Ident::from("mak".to_string()),
+                })),
+            )
+            .into(),
+        ]);
+
+        /* struct Point {
+         *     x: i32
+         * }
+         * impl Point {
+         *     func make() { }
+         * }
+         * func main() {
+         *     Point::mak()
+         *     var p: Point
+         *     var _m: i32 = p.mak
+         * }
+         */
+        let mut program =
+            create_program(vec![struct_def.into(), impl_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let helps = get_helps(&mut analyzer, &mut program);
+
+        // Then
+        assert_eq!(
+            helps,
+            vec![
+                "a method with a similar name exists: \"make\"",
+                "a method with a similar name exists: \"make\"",
+            ]
+        );
+    }
+
+    #[test]
+    fn similar_type_for_value_test() {
+        // Given
+        let union_def = create_union_def("U", vec![("a", Type::I32), ("b", Type::F32)]);
+
+        let main_func = create_main_func_def(vec![create_var_def(
+            "_x",
+            Mutability::Immutable,
+            Type::I32,
+            Some(create_var(&["u"])),
+        )
+        .into()]);
+
+        /* union U {
+         *     a: i32
+         *     b: f32
+         * }
+         * func main() {
+         *     var _x: i32 = u
+         * }
+         */
+        let mut program = create_program(vec![union_def.into(), main_func.into()]);
+
+        let mut analyzer = Analyzer::new();
+
+        // When
+        let helps = get_helps(&mut analyzer, &mut program);
+
+        // Then
+        assert!(helps.is_empty(), "{helps:?}");
+    }
+}
@@ -1,14 +1,17 @@
-use std::{fmt::Display, iter::Peekable, slice::Iter};
+use std::{fmt::Display, iter::Peekable, rc::Rc, slice::Iter};
 
-use tanitc_attributes::{Mutability, Safety};
+use tanitc_attributes::{Mutability, Publicity, Safety};
 use tanitc_hir::hir::type_spec::{PtrType, RefType, Type};
 use tanitc_ident::Ident;
+use tanitc_lexer::location::Location;
+use tanitc_messages::{codes::ErrorCode, suggestions::Suggestion, Message};
 use tanitc_name::{NamePathSegment, NameSpec};
 
 use crate::symbol_table::type_info::TypeMembersInfo;
 
 use super::{
-    entry::{Entry, SymbolKind},
+    entry::{Entry, StructDefData, SymbolKind, UnionDefData, UseItemData},
+    suggestions::get_similar_entry,
     type_info::{MemberInfo, TypeInfo},
 };
 
@@ -21,6 +24,9 @@ pub struct ScopeInfo {
 
 pub type TableEntries = std::collections::BTreeMap<Ident, Entry>;
 pub type TableStack = std::collections::LinkedList<Table>;
+pub type OuterTables = Vec<Rc<Table>>;
+pub type UnionWrites = std::collections::BTreeMap<Ident, Option<Ident>>;
+pub type UseItems = Vec<UseItemData>;
 
 #[derive(Default, Debug, Clone)]
 pub struct Table {
@@ -28,27 +34,48 @@ pub struct Table {
     entries: TableEntries,
     stack: TableStack,
     scope_info: ScopeInfo,
+    outer_tables: OuterTables, // tables of the enclosing modules, crate root first
+    union_writes: UnionWrites, // last written field of union variables
+    use_items: UseItems,       // use declarations of the scope
 }
 
 pub enum LookupError {
     UndefinedInModule {
         namespace: NameSpec,
         id: Ident,
+        suggestion: Option<Suggestion>,
     },
     UndefinedInEnum {
         namespace: NameSpec,
         id: Ident,
+        suggestion: Option<Suggestion>,
     },
     UndefinedInVariant {
         namespace: NameSpec,
         id: Ident,
+        suggestion: Option<Suggestion>,
+    },
+    PrivateInModule {
+        namespace: NameSpec,
+        id: Ident,
+        kind: &'static str,
+        location: Location,
     },
     RedundantNames {
         namespace: NameSpec,
         tail: NamePathSegment,
     },
+    AmbiguousImport {
+        id: Ident,
+        first: NameSpec,
+        second: NameSpec,
+    },
+    SuperOfRoot,
     UnexpectedId(NamePathSegment),
-    UndefinedId(Ident),
+    UndefinedId {
+        id: Ident,
+        suggestion: Option<Suggestion>,
+    },
     EmptyNamespec,
 }
 
@@ -59,6 +86,9 @@ impl Table {
             entries: TableEntries::new(),
             stack: TableStack::new(),
             scope_info: ScopeInfo::default(),
+            outer_tables: OuterTables::new(),
+            union_writes: UnionWrites::new(),
+            use_items: UseItems::new(),
         }
     }
 }
@@ -79,6 +109,18 @@ impl Table {
         new_path
     }
 
+    pub fn set_outer_tables(&mut self, outer_tables: OuterTables) {
+        self.outer_tables = outer_tables;
+    }
+
+    // Returns tables visible through "super" and "crate" from a nested module
+    pub fn get_nested_outer_tables(&self) -> OuterTables {
+        let mut outer_tables = self.outer_tables.clone();
+        outer_tables.push(Rc::new(self.clone()));
+
+        outer_tables
+    }
+
     pub fn enter_scope(&mut self, scope_info: ScopeInfo) {
         let mut table = Table::new();
         table.scope_info = scope_info;
@@ -111,6 +153,11 @@ impl Table {
     }
 
     pub fn lookup(&self, name: Ident) -> Option<&Entry> {
+        self.lookup_entry(name).map(Entry::resolve_import)
+    }
+
+    // Searches entry by name without resolving imports
+    pub fn lookup_entry(&self, name: Ident) -> Option<&Entry> {
         let mut res: Option<&Entry> = self.entries.get(&name);
 
         for scope in self.stack.iter().rev() {
@@ -123,6 +170,38 @@ impl Table {
         res
     }
 
+    // Searches entry by name only in the innermost scope without resolving imports
+    pub fn lookup_in_scope(&self, name: Ident) -> Option<&Entry> {
+        self.stack
+            .back()
+            .map_or(&self.entries, |scope| &scope.entries)
+            .get(&name)
+    }
+
+    pub fn scope_entries(&self) -> impl Iterator<Item = &Entry> {
+        self.stack
+            .back()
+            .map_or(&self.entries, |scope| &scope.entries)
+            .values()
+    }
+
+    pub fn all_entries(&self) -> impl Iterator<Item = &Entry> {
+        self.entries
+            .values()
+            .chain(self.stack.iter().flat_map(|scope| scope.entries.values()))
+    }
+
+    // Returns methods defined in impl blocks of the type. They are stored
+    // in the table of the module that defines the type
+    pub fn type_methods<'a>(&'a self, type_name: &'a NameSpec) -> impl Iterator<Item = &'a Entry> {
+        let type_module = &type_name.path[..type_name.path.len().saturating_sub(1)];
+        let table = self.get_module_table(type_module).unwrap_or(self);
+
+        table.all_entries().filter(move |entry| {
+            matches!(&entry.kind, SymbolKind::FuncDef(data) if data.owner.as_ref() == Some(type_name))
+        })
+    }
+
     pub fn lookup_mut(&mut self, name: Ident) -> Option<&mut Entry> {
         let mut res: Option<&mut Entry> = self.entries.get_mut(&name);
 
@@ -136,9 +215,12 @@ impl Table {
         res
     }
 
+    // Searches entry by name segments. If lookup is performed from outside of
+    // the module, outer_module is a name of that module
     fn lookup_name_spec_segments(
         &self,
         mut names: Peekable<Iter<NamePathSegment>>,
+        outer_module: Option<&NameSpec>,
     ) -> Result<&Entry, LookupError> {
         let next = names.next().cloned().ok_or(LookupError::EmptyNamespec)?;
         let NamePathSegment::Id(next_id) = next else {
@@ -146,8 +228,34 @@ impl Table {
         };
 
         let entry = self
-            .lookup(next_id)
-            .ok_or(LookupError::UndefinedId(next_id))?;
+            .lookup_entry(next_id)
+            .ok_or_else(|| LookupError::UndefinedId {
+                id: next_id,
+                suggestion: get_similar_entry(self.all_entries(), next_id, outer_module.is_some()),
+            })?;
+
+        if let Some(namespace) = outer_module {
+            if entry.publicity != Publicity::Public {
+                return Err(LookupError::PrivateInModule {
+                    namespace: namespace.clone(),
+                    id: next_id,
+                    kind: entry.kind.kind_str(),
+                    location: entry.location,
+                });
+            }
+        }
+
+        if let SymbolKind::Import(data) = &entry.kind {
+            if let Some(second) = &data.ambiguity {
+                return Err(LookupError::AmbiguousImport {
+                    id: next_id,
+                    first: data.source.clone(),
+                    second: second.clone(),
+                });
+            }
+        }
+
+        let entry = entry.resolve_import();
 
         if names.peek().is_none() {
             // Return if last
@@ -156,16 +264,17 @@ impl Table {
 
         // lookup in module
         if let SymbolKind::ModuleDef(data) = &entry.kind {
-            return data.table.lookup_name_spec_segments(names).map_err(|err| {
-                println!("ERR: {err}");
-                match err {
-                    LookupError::UndefinedId(id) => LookupError::UndefinedInModule {
+            return data
+                .table
+                .lookup_name_spec_segments(names, Some(&data.name))
+                .map_err(|err| match err {
+                    LookupError::UndefinedId { id, suggestion } => LookupError::UndefinedInModule {
                         namespace: data.name.clone(),
                         id,
+                        suggestion,
                     },
                     err => err,
-                }
-            });
+                });
         };
 
         let next = names.next().cloned().unwrap();
@@ -185,9 +294,10 @@ impl Table {
 
                 data.units
                     .get(&next_id)
-                    .ok_or(LookupError::UndefinedInEnum {
+                    .ok_or_else(|| LookupError::UndefinedInEnum {
                         namespace: data.name.clone(),
                         id: next_id,
+                        suggestion: get_similar_entry(data.units.values(), next_id, false),
                     })
             }
 
@@ -202,21 +312,117 @@ impl Table {
 
                 data.variants
                     .get(&next_id)
-                    .ok_or(LookupError::UndefinedInVariant {
+                    .ok_or_else(|| LookupError::UndefinedInVariant {
                         namespace: data.name.clone(),
                         id: next_id,
+                        suggestion: get_similar_entry(data.variants.values(), next_id, false),
                     })
             }
 
+            // lookup of methods
+            SymbolKind::StructDef(StructDefData { name, .. })
+            | SymbolKind::UnionDef(UnionDefData { name, .. }) => self
+                .type_methods(name)
+                .find(|method| method.id == next_id)
+                .ok_or_else(|| LookupError::UndefinedId {
+                    id: next_id,
+                    suggestion: get_similar_entry(self.type_methods(name), next_id, false),
+                }),
+
             // lookup in self
             _ => self
                 .lookup(next_id)
-                .ok_or(LookupError::UndefinedId(next_id)),
+                .ok_or_else(|| LookupError::UndefinedId {
+                    id: next_id,
+                    suggestion: get_similar_entry(self.all_entries(), next_id, false),
+                }),
         }
     }
 
     pub fn lookup_name_spec(&self, name: &NameSpec) -> Result<&Entry, LookupError> {
-        self.lookup_name_spec_segments(name.path.iter().peekable())
+        let (table, path) = self.split_path_root(&name.path)?;
+        table.lookup_name_spec_segments(path.iter().peekable(), None)
+    }
+
+    // Resolves leading "crate", "super" and "self" segments of the path.
+    // Returns the table where the rest of the path must be searched
+    pub fn split_path_root<'t, 'p>(
+        &'t self,
+        path: &'p [NamePathSegment],
+    ) -> Result<(&'t Table, &'p [NamePathSegment]), LookupError> {
+        match path.first() {
+            Some(NamePathSegment::CrateNameSpace) => {
+                // Enclosing modules are filled in the root only after their analysis,
+                // so the path continues from the deepest enclosing module it passes.
+                // The last segment is kept, since it names the searched item
+                let path = &path[1..];
+                let depth = path
+                    .iter()
+                    .zip(self.table_path.iter())
+                    .take(path.len().saturating_sub(1))
+                    .take_while(|(segment, module)| segment == module)
+                    .count();
+
+                let table = self
+                    .outer_tables
+                    .get(depth)
+                    .map_or(self, |table| table.as_ref());
+                Ok((table, &path[depth..]))
+            }
+            Some(NamePathSegment::SelfNameSpace) => Ok((self, &path[1..])),
+            Some(NamePathSegment::SuperNameSpace) => {
+                let depth = path
+                    .iter()
+                    .take_while(|segment| **segment == NamePathSegment::SuperNameSpace)
+                    .count();
+
+                let index = self
+                    .outer_tables
+                    .len()
+                    .checked_sub(depth)
+                    .ok_or(LookupError::SuperOfRoot)?;
+
+                Ok((self.outer_tables[index].as_ref(), &path[depth..]))
+            }
+            _ => Ok((self, path)),
+        }
+    }
+
+    // Searches the table of the module by its absolute path
+    fn get_module_table(&self, module_path: &[NamePathSegment]) -> Option<&Table> {
+        // Enclosing modules are reached through their tables, since they are
+        // filled in the root only after their analysis
+        let depth = module_path
+            .iter()
+            .zip(self.table_path.iter())
+            .take_while(|(segment, module)| segment == module)
+            .count();
+
+        let mut table = if depth == self.table_path.len() {
+            self
+        } else {
+            self.outer_tables.get(depth)?.as_ref()
+        };
+
+        for segment in &module_path[depth..] {
+            let NamePathSegment::Id(id) = segment else {
+                return None;
+            };
+
+            let SymbolKind::ModuleDef(data) = &table.lookup_entry(*id)?.kind else {
+                return None;
+            };
+
+            table = data.table.as_ref();
+        }
+
+        Some(table)
+    }
+
+    // Returns true if private members of the item are accessible from this table
+    pub fn can_access_private_members(&self, item_name: &NameSpec) -> bool {
+        let item_module = &item_name.path[..item_name.path.len().saturating_sub(1)];
+        self.table_path.starts_with(item_module)
     }
 
     pub fn lookup_type(&self, initial_ty: &Type) -> Option<TypeInfo> {
@@ -255,10 +461,29 @@ impl Table {
                 });
                 return Some(internal);
             }
+            Type::Func(func_type) => {
+                let mut resolved = func_type.clone();
+                for param in resolved.parameters.iter_mut() {
+                    *param.ty = self.lookup_type(&param.ty)?.ty;
+                }
+                *resolved.return_type = self.lookup_type(&func_type.return_type)?.ty;
+
+                return Some(TypeInfo {
+                    ty: Type::Func(resolved),
+                    mutability: Mutability::default(),
+                    members: TypeMembersInfo::new(),
+                    is_union: false,
+                });
+            }
             _ => {}
         }
 
-        let Some(entry) = self.lookup(Ident::from(initial_ty.to_string())) else {
+        let entry = match initial_ty {
+            Type::Custom(name) => self.lookup_name_spec(name).ok(),
+            _ => self.lookup(Ident::from(initial_ty.to_string())),
+        };
+
+        let Some(entry) = entry else {
             for (_, entry) in self.entries.iter() {
                 let SymbolKind::ModuleDef(data) = &entry.kind else {
                     continue;
@@ -281,8 +506,9 @@ impl Table {
                     members.insert(
                         *field_name,
                         MemberInfo {
-                            is_public: true,
+                            is_public: field_data.publicity == Publicity::Public,
                             ty: field_data.ty.clone(),
+                            usage: field_data.usage.clone(),
                         },
                     );
                 }
@@ -300,8 +526,9 @@ impl Table {
                     members.insert(
                         *field_name,
                         MemberInfo {
-                            is_public: true,
+                            is_public: field_data.publicity == Publicity::Public,
                             ty: field_data.ty.clone(),
+                            usage: field_data.usage.clone(),
                         },
                     );
                 }
@@ -351,6 +578,42 @@ impl Table {
         self.get_scope_info().safety
     }
 
+    // Remembers the field of the union variable written last in the current scope,
+    // None means that the written field is unknown
+    pub fn set_union_write(&mut self, var_id: Ident, field_id: Option<Ident>) {
+        if let Some(back) = self.stack.back_mut() {
+            back.union_writes.insert(var_id, field_id);
+        } else {
+            self.union_writes.insert(var_id, field_id);
+        }
+    }
+
+    // Writes of nested scopes are forgotten on exit, since they may be conditional
+    pub fn get_union_write(&self, var_id: Ident) -> Option<Ident> {
+        self.stack
+            .iter()
+            .rev()
+            .chain(std::iter::once(self))
+            .find_map(|scope| scope.union_writes.get(&var_id))
+            .copied()
+            .flatten()
+    }
+
+    pub fn add_use_item(&mut self, item: UseItemData) {
+        if let Some(back) = self.stack.back_mut() {
+            back.use_items.push(item);
+        } else {
+            self.use_items.push(item);
+        }
+    }
+
+    pub fn scope_use_items(&self) -> impl Iterator<Item = &UseItemData> {
+        self.stack
+            .back()
+            .map_or(&self.use_items, |scope| &scope.use_items)
+            .iter()
+    }
+
     pub fn set_safety(&mut self, safety: Safety) {
         if let Some(back) = self.stack.back_mut() {
             back.scope_info.safety = safety;
@@ -360,22 +623,75 @@ impl Table {
     }
 }
 
+impl LookupError {
+    pub fn get_error_code(&self) -> ErrorCode {
+        match self {
+            Self::UndefinedId { .. } => ErrorCode::UndefinedName,
+            Self::UndefinedInModule { .. }
+            | Self::UndefinedInEnum { .. }
+            | Self::UndefinedInVariant { .. }
+            | Self::RedundantNames { .. } => ErrorCode::NoIdInNamespace,
+            Self::PrivateInModule { .. } => ErrorCode::PrivateItem,
+            Self::AmbiguousImport { .. } => ErrorCode::AmbiguousImport,
+            Self::SuperOfRoot => ErrorCode::SuperOfRoot,
+            Self::EmptyNamespec => ErrorCode::EmptyNameSpec,
+            Self::UnexpectedId(_) => ErrorCode::InvalidPath,
+        }
+    }
+
+    pub fn get_suggestion(&self) -> Option<&Suggestion> {
+        match self {
+            Self::UndefinedId { suggestion, .. }
+            | Self::UndefinedInModule { suggestion, .. }
+            | Self::UndefinedInEnum { suggestion, .. }
+            | Self::UndefinedInVariant { suggestion, .. } => suggestion.as_ref(),
+            _ => None,
+        }
+    }
+
+    pub fn into_message(self, location: Location) -> Message {
+        let code = self.get_error_code();
+        let suggestion = self.get_suggestion().cloned();
+        Message::new(location, self)
+            .with_code(code)
+            .with_suggestion(suggestion)
+    }
+}
+
 impl Display for LookupError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::EmptyNamespec => write!(f, "empty name specifier"),
-            Self::UndefinedId(id) => write!(f, "undefined id: \"{id}\""),
+            Self::UndefinedId { id, .. } => write!(f, "undefined id: \"{id}\""),
             Self::UnexpectedId(id) => write!(f, "unexpected id: \"{id}\""),
+            Self::SuperOfRoot => write!(f, "\"super\" is used outside of any module"),
+            Self::AmbiguousImport { id, first, second } => {
+                write!(
+                    f,
+                    "\"{id}\" is ambiguous: imported by both \"{first}\" and \"{second}\""
+                )
+            }
             Self::RedundantNames { namespace, tail } => {
                 write!(f, "\"{namespace}\" doesn't contain \"{tail}\"")
             }
-            Self::UndefinedInEnum { namespace, id } => {
+            Self::UndefinedInEnum { namespace, id, .. } => {
                 write!(f, "enum \"{namespace}\" doesn't contain \"{id}\"")
             }
-            Self::UndefinedInVariant { namespace, id } => {
+            Self::UndefinedInVariant { namespace, id, .. } => {
                 write!(f, "variant \"{namespace}\" doesn't contain \"{id}\"")
             }
-            Self::UndefinedInModule { namespace, id } => {
+            Self::PrivateInModule {
+                namespace,
+                id,
+                kind,
+                location,
+            } => {
+                write!(
+                    f,
+                    "{kind} \"{id}\" is private in module \"{namespace}\" (defined at {location})"
+                )
+            }
+            Self::UndefinedInModule { namespace, id, .. } => {
                 write!(f, "module \"{namespace}\" doesn't contain \"{id}\"")
             }
         }
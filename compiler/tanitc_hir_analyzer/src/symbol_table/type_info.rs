@@ -4,10 +4,13 @@ use tanitc_attributes::Mutability;
 use tanitc_hir::hir::type_spec::Type;
 use tanitc_ident::Ident;
 
+use crate::symbol_table::entry::Usage;
+
 #[derive(Debug, Clone)]
 pub struct MemberInfo {
     pub is_public: bool,
     pub ty: Type,
+    pub usage: Usage,
 }
 
 pub type TypeMembersInfo = std::collections::BTreeMap<Ident, MemberInfo>;
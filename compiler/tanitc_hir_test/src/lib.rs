@@ -1,9 +1,10 @@
 use tanitc_attributes::Mutability;
+use tanitc_hir::attributes::{Attribute, MetaItem};
 use tanitc_hir::hir::{
     blocks::Block,
     definitions::{
         aliases::AliasDef,
-        enums::{EnumDef, EnumUnits},
+        enums::{EnumDef, EnumUnitInfo, EnumUnits},
         functions::{FunctionDef, FunctionParam},
         methods::ImplDef,
         modules::ModuleDef,
@@ -12,8 +13,9 @@ use tanitc_hir::hir::{
         variables::VariableDef,
         variants::{VariantAttributes, VariantDef, VariantField, VariantFields},
     },
-    expressions::{variable::Variable, Expression},
+    expressions::{binary::BinaryOperation, variable::Variable, Expression},
     type_spec::{Type, TypeSpec},
+    uses::{Use, UseItem},
     Hir,
 };
 use tanitc_ident::Ident;
@@ -56,7 +58,15 @@ pub fn create_module_def(name: &str, definitions: Vec<Hir>) -> ModuleDef {
 pub fn create_enum_def_units(units: Vec<(&str, Option<usize>)>) -> EnumUnits {
     units
         .into_iter()
-        .map(|(unit_name, unit_value)| (unit_name.to_string().into(), unit_value))
+        .map(|(unit_name, value)| {
+            (
+                unit_name.to_string().into(),
+                EnumUnitInfo {
+                    value,
+                    ..Default::default()
+                },
+            )
+        })
         .collect::<EnumUnits>()
 }
 
@@ -345,6 +355,7 @@ pub fn create_var(var_name: &[&str]) -> Expression {
     Expression::Variable(Variable {
         location: Location::default(),
         name: create_name_spec(var_name),
+        item_name: None,
     })
 }
 
@@ -373,6 +384,40 @@ pub fn create_call_expr(func_name: &[&str], args: Vec<Expression>) -> Expression
     })
 }
 
+/* Creates:
+ * expr as ty
+ */
+pub fn create_conversion_expr(expr: Expression, ty: Type) -> Expression {
+    use tanitc_hir::hir::expressions::conversion::ConversionExpr;
+
+    Expression::Conversion(ConversionExpr {
+        location: Location::default(),
+        expr: Box::new(expr),
+        ty: TypeSpec {
+            ty,
+            ..Default::default()
+        },
+    })
+}
+
+/* Creates:
+ * lhs <operation> rhs
+ */
+pub fn create_binary_expr(
+    lhs: Expression,
+    operation: BinaryOperation,
+    rhs: Expression,
+) -> Expression {
+    use tanitc_hir::hir::expressions::binary::BinaryExpr;
+
+    Expression::Binary(BinaryExpr {
+        location: Location::default(),
+        operation,
+        lhs: Box::new(lhs),
+        rhs: Box::new(rhs),
+    })
+}
+
 /* Creates:
  * impl struct_name {
  *     methods[0]
@@ -414,9 +459,48 @@ pub fn create_scope_resolutions_expr(ids: &[&str]) -> Expression {
     Expression::Variable(Variable {
         name: create_name_spec(ids),
         location: Location::default(),
+        item_name: None,
     })
 }
 
 pub fn create_custom_type(ids: &[&str]) -> Type {
     Type::Custom(create_name_spec(ids))
 }
+
+/* Creates:
+ * name as alias
+ */
+pub fn create_use_item(name: &[&str], alias: Option<&str>) -> UseItem {
+    UseItem {
+        name: create_name_spec(name),
+        alias: alias.map(|alias| Ident::from(alias.to_string())),
+    }
+}
+
+/* Creates:
+ * use { items[0], items[1], ... items[N] }
+ */
+pub fn create_use(items: Vec<UseItem>) -> Use {
+    Use {
+        location: Location::default(),
+        items,
+        ..Default::default()
+    }
+}
+
+/* Creates:
+ * #[name]
+ */
+pub fn create_word_attribute(name: &str) -> Attribute {
+    create_attribute(MetaItem::Word(Ident::from(name.to_string())))
+}
+
+/* Creates:
+ * #[meta]
+ */
+pub fn create_attribute(meta: MetaItem) -> Attribute {
+    Attribute {
+        location: Location::default(),
+        meta,
+    }
+}
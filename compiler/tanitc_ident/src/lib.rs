@@ -1,4 +1,5 @@
 use lazy_static::lazy_static;
+use serde::{Serialize, Serializer};
 use std::{
     fmt::{Debug, Display},
     sync::Mutex,
@@ -90,6 +91,13 @@ impl Debug for Ident {
     }
 }
 
+// Identifiers are interned, so they are written as their text
+impl Serialize for Ident {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&String::from(*self))
+    }
+}
+
 lazy_static! {
     static ref IDENTIFIERS: Mutex<Vec<String>> = Mutex::new(vec![]);
 }
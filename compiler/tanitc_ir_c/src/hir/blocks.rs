@@ -1,6 +1,6 @@
 use tanitc_hir::hir::{blocks::Block, definitions::Definition, Hir};
 
-use crate::CodeGenStream;
+use crate::{hir::types::collect_func_types, CodeGenStream};
 
 impl CodeGenStream<'_> {
     pub fn generate_block(&mut self, block: &Block) -> std::io::Result<()> {
@@ -14,6 +14,12 @@ impl CodeGenStream<'_> {
         }
 
         for stmt in block.statements.iter() {
+            if block.is_global {
+                let mut func_types = Vec::new();
+                collect_func_types(stmt, &mut func_types);
+                self.generate_func_typedefs(&func_types)?;
+            }
+
             if !matches!(stmt, Hir::Block(_)) {
                 write!(self, "{indentation}    ")?;
             }
@@ -78,8 +84,8 @@ mod tests {
         node.accept(&mut writer).unwrap();
 
         // Then
-        const HEADER_EXPECTED: &str = "void hello();\n";
-        const SOURCE_EXPECTED: &str = "void hello()\
+        const HEADER_EXPECTED: &str = "void _TF5helloE();\n";
+        const SOURCE_EXPECTED: &str = "void _TF5helloE()\
                                      \n{\
                                      \n    {\
                                      \n    }\
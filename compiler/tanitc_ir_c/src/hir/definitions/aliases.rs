@@ -14,7 +14,7 @@ impl CodeGenStream<'_> {
 
         write!(self, " ")?;
 
-        self.generate_name_spec(&alias_def.name)?;
+        self.generate_type_name(&alias_def.name)?;
         writeln!(self, ";")?;
 
         self.mode = old_mode;
@@ -54,7 +54,7 @@ mod tests {
         node.accept(&mut writer).unwrap();
 
         // Then
-        const HEADER_EXPECTED: &str = "typedef float MyAlias;\n";
+        const HEADER_EXPECTED: &str = "typedef float _TT7MyAliasE;\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -93,9 +93,9 @@ mod tests {
 
         // Then
         const HEADER_EXPECTED: &str = "typedef struct {\
-                                     \n} EmptyStruct;\
-                                     \ntypedef EmptyStruct FirstAlias;\
-                                     \ntypedef FirstAlias SecondAlias;\n";
+                                     \n} _TT11EmptyStructE;\
+                                     \ntypedef _TT11EmptyStructE _TT10FirstAliasE;\
+                                     \ntypedef _TT10FirstAliasE _TT11SecondAliasE;\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
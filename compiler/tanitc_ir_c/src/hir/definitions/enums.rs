@@ -11,19 +11,35 @@ impl CodeGenStream<'_> {
 
         let indentation = self.indentation();
 
-        writeln!(self, "{indentation}typedef enum {{")?;
-
-        for field in enum_def.units.iter() {
-            writeln!(
-                self,
-                "{indentation}    {} = {},",
-                field.0,
-                field.1.unwrap_or_default()
-            )?;
+        if let Some(repr) = &enum_def.repr {
+            // C enums can't have fixed underlying type, so units become typed constants
+            write!(self, "{indentation}typedef {} ", repr.get_c_type())?;
+            self.generate_type_name(&enum_def.name)?;
+            writeln!(self, ";")?;
+
+            for (unit_id, unit) in enum_def.units.iter() {
+                let symbol = self.get_enum_unit_name(&enum_def.name, *unit_id);
+                write!(self, "{indentation}#define {symbol} ((")?;
+                self.generate_type_name(&enum_def.name)?;
+                writeln!(self, "){})", unit.value.unwrap_or_default())?;
+            }
+        } else {
+            writeln!(self, "{indentation}typedef enum {{")?;
+
+            for (unit_id, unit) in enum_def.units.iter() {
+                writeln!(
+                    self,
+                    "{indentation}    {} = {},",
+                    self.get_enum_unit_name(&enum_def.name, *unit_id),
+                    unit.value.unwrap_or_default()
+                )?;
+            }
+
+            write!(self, "{indentation}}} ")?;
+            self.generate_type_name(&enum_def.name)?;
+            writeln!(self, ";")?;
         }
 
-        writeln!(self, "{indentation}}} {};", enum_def.name)?;
-
         self.mode = old_mode;
 
         Ok(())
@@ -35,6 +51,7 @@ mod tests {
     use super::*;
 
     use pretty_assertions::assert_str_eq;
+    use tanitc_hir::hir::type_spec::Type;
     use tanitc_hir_test::{create_enum_def, create_program};
     use tanitc_options::CompileOptions;
 
@@ -62,7 +79,7 @@ mod tests {
         // Then
         const SOURCE_EXPECTED: &str = "#include \"EnumsTest.tt.h\"\n\n";
         const HEADER_EXPECTED: &str = "typedef enum {\
-                                     \n} EmptyEnum;\n";
+                                     \n} _TT9EmptyEnumE;\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -94,8 +111,8 @@ mod tests {
         // Then
         const SOURCE_EXPECTED: &str = "#include \"EnumsTest.tt.h\"\n\n";
         const HEADER_EXPECTED: &str = "typedef enum {\
-                                     \n    A = 0,\
-                                     \n} MyEnum;\n";
+                                     \n    _TC6MyEnum1AE = 0,\
+                                     \n} _TT6MyEnumE;\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -127,10 +144,10 @@ mod tests {
         // Then
         const SOURCE_EXPECTED: &str = "#include \"EnumsTest.tt.h\"\n\n";
         const HEADER_EXPECTED: &str = "typedef enum {\
-                                     \n    A = 4,\
-                                     \n    B = 0,\
-                                     \n    C = 0,\
-                                     \n} MyEnum;\n";
+                                     \n    _TC6MyEnum1AE = 4,\
+                                     \n    _TC6MyEnum1BE = 0,\
+                                     \n    _TC6MyEnum1CE = 0,\
+                                     \n} _TT6MyEnumE;\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -138,4 +155,35 @@ mod tests {
         let source_res = String::from_utf8(source_buffer).unwrap();
         assert_str_eq!(source_res, SOURCE_EXPECTED);
     }
+
+    #[test]
+    fn enum_with_repr() {
+        // Given
+        let mut enum_def = create_enum_def("Opcode", vec![("Nop", Some(0)), ("Halt", Some(255))]);
+        enum_def.repr = Some(Type::U8);
+
+        let program = create_program(vec![enum_def.into()]);
+
+        let mut header_buffer = Vec::<u8>::new();
+        let mut source_buffer = Vec::<u8>::new();
+        let mut writer = CodeGenStream::with_compile_options(
+            &mut header_buffer,
+            &mut source_buffer,
+            CompileOptions {
+                crate_name: "EnumsTest".into(),
+                ..Default::default()
+            },
+        );
+
+        // When
+        writer.codegen_program(&program).unwrap();
+
+        // Then
+        const HEADER_EXPECTED: &str = "typedef unsigned char _TT6OpcodeE;\
+                                     \n#define _TC6Opcode3NopE ((_TT6OpcodeE)0)\
+                                     \n#define _TC6Opcode4HaltE ((_TT6OpcodeE)255)\n";
+
+        let header_res = String::from_utf8(header_buffer).unwrap();
+        assert_str_eq!(header_res, HEADER_EXPECTED);
+    }
 }
@@ -2,13 +2,21 @@ use tanitc_hir::hir::definitions::externs::ExternDef;
 
 use crate::{CodeGenMode, CodeGenStream};
 
+use std::io::Write;
+
 impl CodeGenStream<'_> {
     pub fn generate_extern_def(&mut self, extern_def: &ExternDef) -> std::io::Result<()> {
         let mode = self.mode;
         self.mode = CodeGenMode::HeaderOnly;
 
         for func_def in extern_def.functions.iter() {
-            self.generate_func_def(func_def, None)?;
+            self.generate_func_def(&extern_def.get_foreign_func(func_def), None)?;
+        }
+
+        for var_def in extern_def.statics.iter() {
+            write!(self, "extern ")?;
+            self.generate_variable_def(var_def)?;
+            writeln!(self, ";")?;
         }
 
         self.mode = mode;
@@ -21,8 +29,12 @@ impl CodeGenStream<'_> {
 mod tests {
     use super::*;
 
-    use tanitc_hir::hir::{type_spec::Type, Hir};
-    use tanitc_hir_test::create_func_def;
+    use tanitc_attributes::{Abi, Mutability};
+    use tanitc_hir::hir::{
+        type_spec::{PtrType, Type},
+        Hir,
+    };
+    use tanitc_hir_test::{create_common_func_param, create_func_def, create_var_def};
 
     use pretty_assertions::assert_str_eq;
     use tanitc_options::CompileOptions;
@@ -39,7 +51,7 @@ mod tests {
          * }
          */
         let program = Hir::from(ExternDef {
-            abi_name: "C".to_string(),
+            abi: Abi::C,
             functions: vec![func_def],
             ..Default::default()
         });
@@ -67,4 +79,97 @@ mod tests {
         let source_res = String::from_utf8(source_buffer).unwrap();
         assert_str_eq!(source_res, SOURCE_EXPECTED);
     }
+
+    #[test]
+    fn variadic_extern_test() {
+        // Given
+        let mut func_def = create_func_def(
+            "printf",
+            vec![create_common_func_param(
+                "fmt",
+                Mutability::Immutable,
+                Type::Ptr(PtrType {
+                    ptr_to: Box::new(Type::U8),
+                    mutability: Mutability::Immutable,
+                }),
+            )],
+            Type::I32,
+            vec![],
+        );
+        func_def.is_variadic = true;
+        func_def.body = None;
+
+        /*
+         * extern "C" {
+         *     func printf(fmt: *const u8, ...): i32
+         * }
+         */
+        let program = Hir::from(ExternDef {
+            abi: Abi::C,
+            functions: vec![func_def],
+            ..Default::default()
+        });
+
+        let mut header_buffer = Vec::<u8>::new();
+        let mut source_buffer = Vec::<u8>::new();
+        let mut writer = CodeGenStream::with_compile_options(
+            &mut header_buffer,
+            &mut source_buffer,
+            CompileOptions {
+                crate_name: "variadic_extern_test".into(),
+                ..Default::default()
+            },
+        );
+
+        writer.codegen_program(&program).unwrap();
+
+        // Then
+        const HEADER_EXPECTED: &str = "signed int printf(unsigned char const * const fmt, ...);\n";
+
+        let header_res = String::from_utf8(header_buffer).unwrap();
+        assert_str_eq!(header_res, HEADER_EXPECTED);
+    }
+
+    #[test]
+    fn extern_statics_test() {
+        // Given
+        /*
+         * extern "C" {
+         *     static mut errno: i32
+         *     static limit: u8
+         * }
+         */
+        let program = Hir::from(ExternDef {
+            abi: Abi::C,
+            statics: vec![
+                create_var_def("errno", Mutability::Mutable, Type::I32, None),
+                create_var_def("limit", Mutability::Immutable, Type::U8, None),
+            ],
+            ..Default::default()
+        });
+
+        let mut header_buffer = Vec::<u8>::new();
+        let mut source_buffer = Vec::<u8>::new();
+        let mut writer = CodeGenStream::with_compile_options(
+            &mut header_buffer,
+            &mut source_buffer,
+            CompileOptions {
+                crate_name: "extern_statics_test".into(),
+                ..Default::default()
+            },
+        );
+
+        writer.codegen_program(&program).unwrap();
+
+        // Then
+        const HEADER_EXPECTED: &str = "extern signed int errno;\n\
+                                       extern unsigned char const limit;\n";
+        const SOURCE_EXPECTED: &str = "#include \"extern_statics_test.tt.h\"\n\n";
+
+        let header_res = String::from_utf8(header_buffer).unwrap();
+        assert_str_eq!(header_res, HEADER_EXPECTED);
+
+        let source_res = String::from_utf8(source_buffer).unwrap();
+        assert_str_eq!(source_res, SOURCE_EXPECTED);
+    }
 }
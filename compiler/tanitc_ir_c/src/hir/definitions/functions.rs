@@ -1,5 +1,12 @@
-use tanitc_hir::hir::definitions::functions::{FunctionDef, FunctionParam};
-use tanitc_name::NameSpec;
+use tanitc_attributes::Publicity;
+use tanitc_hir::{
+    attributes::find_attribute,
+    hir::definitions::functions::{FunctionDef, FunctionParam},
+};
+use tanitc_name::{
+    mangling::{mangle_name, ItemKind},
+    NameSpec,
+};
 
 use crate::{CodeGenMode, CodeGenStream};
 
@@ -11,10 +18,14 @@ impl CodeGenStream<'_> {
         func_def: &FunctionDef,
         struct_name: Option<&NameSpec>,
     ) -> std::io::Result<()> {
+        let meta = &func_def.attributes.meta;
+        let is_inline = find_attribute(meta, "inline").is_some();
+
         let old_mode = self.mode;
-        self.mode = if func_def.body.is_some() {
+        self.mode = if func_def.body.is_some() && !is_inline {
             CodeGenMode::Both
         } else {
+            // Inline functions are fully defined in the header
             CodeGenMode::HeaderOnly
         };
 
@@ -22,23 +33,34 @@ impl CodeGenStream<'_> {
 
         write!(self, "{indentation}")?;
 
-        self.generate_type(&func_def.return_type)?;
+        if find_attribute(meta, "cold").is_some() {
+            write!(self, "__attribute__((cold)) ")?;
+        }
 
-        let full_name = if let Some(struct_name) = struct_name {
-            format!("{struct_name}__{}", func_def.name)
-        } else {
-            format!("{}", func_def.name)
-        };
+        if is_inline {
+            write!(self, "static inline ")?;
+        } else if func_def.attributes.abi.is_c()
+            && func_def.attributes.publicity != Publicity::Public
+            && func_def.body.is_some()
+        {
+            // Unmangled symbols of private functions must not clash with ones of other objects
+            write!(self, "static ")?;
+        }
+
+        self.generate_type(&func_def.return_type)?;
 
-        write!(self, " {full_name}")?;
+        let symbol = self.get_func_symbol(func_def, struct_name);
+        write!(self, " {symbol}")?;
 
         self.generate_func_def_params(func_def, struct_name)?;
 
-        self.mode = CodeGenMode::HeaderOnly;
-        writeln!(self, ";")?;
+        if !is_inline {
+            self.mode = CodeGenMode::HeaderOnly;
+            writeln!(self, ";")?;
+            self.mode = CodeGenMode::SourceOnly;
+        }
 
         if let Some(body) = &func_def.body {
-            self.mode = CodeGenMode::SourceOnly;
             if body.statements.is_empty() {
                 writeln!(self, " {{ }}")?;
             } else {
@@ -64,7 +86,8 @@ impl CodeGenStream<'_> {
 
                 write!(
                     self,
-                    "{struct_name} {}self",
+                    "{} {}self",
+                    mangle_name(ItemKind::Type, struct_name),
                     if mutability.is_const() { "const " } else { "" }
                 )
             }
@@ -75,7 +98,8 @@ impl CodeGenStream<'_> {
 
                 write!(
                     self,
-                    "{struct_name} {}* const self",
+                    "{} {}* const self",
+                    mangle_name(ItemKind::Type, struct_name),
                     if mutability.is_const() { "const " } else { "" }
                 )
             }
@@ -98,6 +122,10 @@ impl CodeGenStream<'_> {
             write!(self, ", ")?;
             self.generate_func_def_param(param, struct_name)?;
         }
+
+        if func_def.is_variadic {
+            write!(self, ", ...")?;
+        }
         write!(self, ")")?;
 
         Ok(())
@@ -107,9 +135,12 @@ impl CodeGenStream<'_> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tanitc_attributes::Mutability;
+    use tanitc_attributes::{Abi, Mutability};
     use tanitc_hir::hir::type_spec::{RefType, Type};
-    use tanitc_hir_test::{create_common_func_param, create_func_def, create_program};
+    use tanitc_hir_test::{
+        create_common_func_param, create_func_def, create_name_spec, create_program,
+        create_word_attribute,
+    };
 
     use pretty_assertions::assert_str_eq;
 
@@ -136,8 +167,8 @@ mod tests {
         node.accept(&mut writer).unwrap();
 
         // Then
-        const HEADER_EXPECTED: &str = "unsigned char hello(signed long long const a);\n";
-        const SOURCE_EXPECTED: &str = "unsigned char hello(signed long long const a) { }\n";
+        const HEADER_EXPECTED: &str = "unsigned char _TF5helloE(signed long long const a);\n";
+        const SOURCE_EXPECTED: &str = "unsigned char _TF5helloE(signed long long const a) { }\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -160,8 +191,8 @@ mod tests {
         node.accept(&mut writer).unwrap();
 
         // Then
-        const HEADER_EXPECTED: &str = "signed long empty_func();\n";
-        const SOURCE_EXPECTED: &str = "signed long empty_func() { }\n";
+        const HEADER_EXPECTED: &str = "signed long _TF10empty_funcE();\n";
+        const SOURCE_EXPECTED: &str = "signed long _TF10empty_funcE() { }\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -216,9 +247,81 @@ mod tests {
 
         // Then
         const HEADER_EXPECTED: &str =
-            "void full_func(signed int const * const ref, signed int * const mut_ref, unsigned int const integer, char const * const string);\n";
+            "void _TF9full_funcE(signed int const * const ref, signed int * const mut_ref, unsigned int const integer, char const * const string);\n";
         const SOURCE_EXPECTED: &str =
-            "void full_func(signed int const * const ref, signed int * const mut_ref, unsigned int const integer, char const * const string) { }\n";
+            "void _TF9full_funcE(signed int const * const ref, signed int * const mut_ref, unsigned int const integer, char const * const string) { }\n";
+
+        let header_res = String::from_utf8(header_buffer).unwrap();
+        assert_str_eq!(header_res, HEADER_EXPECTED);
+
+        let source_res = String::from_utf8(source_buffer).unwrap();
+        assert_str_eq!(source_res, SOURCE_EXPECTED);
+    }
+
+    #[test]
+    fn func_attributes_codegen_test() {
+        // Given
+        let mut inline_func = create_func_def("fast", vec![], Type::unit(), vec![]);
+        inline_func.attributes.meta = vec![create_word_attribute("inline")];
+
+        let mut cold_func = create_func_def("rare", vec![], Type::unit(), vec![]);
+        cold_func.attributes.meta = vec![create_word_attribute("cold")];
+
+        let mut exported_func = create_func_def("exported", vec![], Type::unit(), vec![]);
+        exported_func.name = create_name_spec(&["lib", "exported"]);
+        exported_func.attributes.meta = vec![create_word_attribute("no_mangle")];
+
+        let node = create_program(vec![
+            inline_func.into(),
+            cold_func.into(),
+            exported_func.into(),
+        ]);
+
+        let mut header_buffer = Vec::<u8>::new();
+        let mut source_buffer = Vec::<u8>::new();
+        let mut writer = CodeGenStream::new(&mut header_buffer, &mut source_buffer);
+
+        // When
+        node.accept(&mut writer).unwrap();
+
+        // Then
+        const HEADER_EXPECTED: &str = "static inline void _TF4fastE() { }\n\
+                                       __attribute__((cold)) void _TF4rareE();\n\
+                                       void exported();\n";
+        const SOURCE_EXPECTED: &str = "__attribute__((cold)) void _TF4rareE() { }\n\
+                                       void exported() { }\n";
+
+        let header_res = String::from_utf8(header_buffer).unwrap();
+        assert_str_eq!(header_res, HEADER_EXPECTED);
+
+        let source_res = String::from_utf8(source_buffer).unwrap();
+        assert_str_eq!(source_res, SOURCE_EXPECTED);
+    }
+
+    #[test]
+    fn c_abi_func_linkage_test() {
+        // Given
+        let mut public_func = create_func_def("public_c", vec![], Type::unit(), vec![]);
+        public_func.attributes.publicity = Publicity::Public;
+        public_func.attributes.abi = Abi::C;
+
+        let mut private_func = create_func_def("private_c", vec![], Type::unit(), vec![]);
+        private_func.attributes.abi = Abi::C;
+
+        let node = create_program(vec![public_func.into(), private_func.into()]);
+
+        let mut header_buffer = Vec::<u8>::new();
+        let mut source_buffer = Vec::<u8>::new();
+        let mut writer = CodeGenStream::new(&mut header_buffer, &mut source_buffer);
+
+        // When
+        node.accept(&mut writer).unwrap();
+
+        // Then
+        const HEADER_EXPECTED: &str = "void public_c();\n\
+                                       static void private_c();\n";
+        const SOURCE_EXPECTED: &str = "void public_c() { }\n\
+                                       static void private_c() { }\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -8,7 +8,7 @@ impl CodeGenStream<'_> {
         self.mode = CodeGenMode::HeaderOnly;
 
         for method in impl_def.methods.iter() {
-            self.generate_func_def(method, Some(&impl_def.name))?;
+            self.generate_func_def(method, Some(impl_def.get_type_name()))?;
         }
 
         self.mode = old_mode;
@@ -66,11 +66,11 @@ mod tests {
 
         // Then
         const HEADER_EXPECTED: &str = "typedef struct {\
-                                     \n} MyStruct;\
-                                     \nvoid MyStruct__by_self(MyStruct const self, signed int const hello);\n";
+                                     \n} _TT8MyStructE;\
+                                     \nvoid _TM8MyStruct7by_selfE(_TT8MyStructE const self, signed int const hello);\n";
 
         const SOURCE_EXPECTED: &str =
-            "void MyStruct__by_self(MyStruct const self, signed int const hello) { }\n";
+            "void _TM8MyStruct7by_selfE(_TT8MyStructE const self, signed int const hello) { }\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -104,10 +104,10 @@ mod tests {
 
         // Then
         const HEADER_EXPECTED: &str = "typedef struct {\
-                                     \n} MyStruct;\
-                                     \nvoid MyStruct__by_mut_self(MyStruct self);\n";
+                                     \n} _TT8MyStructE;\
+                                     \nvoid _TM8MyStruct11by_mut_selfE(_TT8MyStructE self);\n";
 
-        const SOURCE_EXPECTED: &str = "void MyStruct__by_mut_self(MyStruct self) { }\n";
+        const SOURCE_EXPECTED: &str = "void _TM8MyStruct11by_mut_selfE(_TT8MyStructE self) { }\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -141,11 +141,11 @@ mod tests {
 
         // Then
         const HEADER_EXPECTED: &str = "typedef struct {\
-                                     \n} MyStruct;\
-                                     \nvoid MyStruct__by_self_ref(MyStruct const * const self);\n";
+                                     \n} _TT8MyStructE;\
+                                     \nvoid _TM8MyStruct11by_self_refE(_TT8MyStructE const * const self);\n";
 
         const SOURCE_EXPECTED: &str =
-            "void MyStruct__by_self_ref(MyStruct const * const self) { }\n";
+            "void _TM8MyStruct11by_self_refE(_TT8MyStructE const * const self) { }\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -182,11 +182,11 @@ mod tests {
 
         // Then
         const HEADER_EXPECTED: &str = "typedef struct {\
-                                     \n} MyStruct;\
-                                     \nvoid MyStruct__by_mut_self_ref(MyStruct * const self, signed int const hello);\n";
+                                     \n} _TT8MyStructE;\
+                                     \nvoid _TM8MyStruct15by_mut_self_refE(_TT8MyStructE * const self, signed int const hello);\n";
 
         const SOURCE_EXPECTED: &str =
-            "void MyStruct__by_mut_self_ref(MyStruct * const self, signed int const hello) { }\n";
+            "void _TM8MyStruct15by_mut_self_refE(_TT8MyStructE * const self, signed int const hello) { }\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -16,7 +16,9 @@ impl CodeGenStream<'_> {
             self.generate_type_spec(&field_info.ty)?;
             writeln!(self, " {field_id};")?;
         }
-        writeln!(self, "{indentation}}} {};", struct_def.name)?;
+        write!(self, "{indentation}}} ")?;
+        self.generate_type_name(&struct_def.name)?;
+        writeln!(self, ";")?;
 
         self.mode = old_mode;
         Ok(())
@@ -36,7 +38,7 @@ mod tests {
     fn empty_struct() {
         const STRUCT_NAME: &str = "EmptyStruct";
         const HEADER_EXPECTED: &str = "typedef struct {\
-                                     \n} EmptyStruct;\n";
+                                     \n} _TT11EmptyStructE;\n";
 
         let node = Hir::from(create_struct_def(STRUCT_NAME, vec![]));
 
@@ -59,7 +61,7 @@ mod tests {
         const FIELD_1_NAME: &str = "a";
         const HEADER_EXPECTED: &str = "typedef struct {\
                                      \n    signed int a;\
-                                     \n} MyStruct;\n";
+                                     \n} _TT8MyStructE;\n";
 
         let node = Hir::from(create_struct_def(
             STRUCT_NAME,
@@ -89,8 +91,8 @@ mod tests {
         const HEADER_EXPECTED: &str = "typedef struct {\
                                      \n    signed int a;\
                                      \n    float b;\
-                                     \n    C c;\
-                                     \n} MyStruct;\n";
+                                     \n    _TT1CE c;\
+                                     \n} _TT8MyStructE;\n";
 
         let node = Hir::from(create_struct_def(
             STRUCT_NAME,
@@ -16,7 +16,9 @@ impl CodeGenStream<'_> {
             self.generate_type_spec(&field_info.ty)?;
             writeln!(self, " {field_id};")?;
         }
-        writeln!(self, "}} {};", union_def.name)?;
+        write!(self, "}} ")?;
+        self.generate_type_name(&union_def.name)?;
+        writeln!(self, ";")?;
 
         self.mode = old_mode;
         Ok(())
@@ -47,7 +49,7 @@ mod tests {
 
         // Then
         const HEADER_EXPECTED: &str = "typedef union {\
-                                     \n} EmptyUnion;\n";
+                                     \n} _TT10EmptyUnionE;\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -72,7 +74,7 @@ mod tests {
         // Then
         const HEADER_EXPECTED: &str = "typedef union {\
                                      \n    signed int a;\
-                                     \n} MyUnion;\n";
+                                     \n} _TT7MyUnionE;\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -105,8 +107,8 @@ mod tests {
         const HEADER_EXPECTED: &str = "typedef union {\
                                      \n    signed int a;\
                                      \n    float b;\
-                                     \n    C c;\
-                                     \n} MyUnion;\n";
+                                     \n    _TT1CE c;\
+                                     \n} _TT7MyUnionE;\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
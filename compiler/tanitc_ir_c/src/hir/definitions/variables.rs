@@ -39,7 +39,7 @@ impl CodeGenStream<'_> {
             unreachable!("Array size must be known at this point");
         };
 
-        let type_str = value_type.get_c_type();
+        let type_str = self.get_c_type(value_type);
         let var_name = var_def.identifier;
         let mutable_str = if var_def.mutability.is_mutable() {
             " "
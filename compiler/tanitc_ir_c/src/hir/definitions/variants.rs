@@ -44,7 +44,7 @@ impl CodeGenStream<'_> {
         self.generate_variant_data_field(&variant_def.name)?;
 
         write!(self, "}} ")?;
-        self.generate_name_spec(&variant_def.name)?;
+        self.generate_type_name(&variant_def.name)?;
         writeln!(self, ";\n")?;
 
         self.mode = old_mode;
@@ -60,14 +60,14 @@ impl CodeGenStream<'_> {
 
         writeln!(self, "typedef enum {{")?;
         for (field_id, _) in fields.iter() {
-            write!(self, "    __")?;
-            self.generate_name_spec(&enum_name)?;
-            writeln!(self, "__{field_id}__,")?;
+            write!(self, "    ")?;
+            self.generate_type_name(&VariantDef::get_variant_kind_name(variant_id, *field_id))?;
+            writeln!(self, ",")?;
         }
 
-        write!(self, "}} __")?;
-        self.generate_name_spec(&enum_name)?;
-        writeln!(self, "__;\n")?;
+        write!(self, "}} ")?;
+        self.generate_type_name(&enum_name)?;
+        writeln!(self, ";\n")?;
 
         Ok(())
     }
@@ -94,14 +94,14 @@ impl CodeGenStream<'_> {
         writeln!(self, "typedef union {{")?;
 
         for (field_id, _) in fields.iter() {
-            write!(self, "    __")?;
-            self.generate_name_spec(&union_name)?;
-            writeln!(self, "__{field_id}__ {field_id};")?;
+            write!(self, "    ")?;
+            self.generate_type_name(&VariantDef::get_variant_data_name(variant_name, *field_id))?;
+            writeln!(self, " {field_id};")?;
         }
 
-        write!(self, "}} __")?;
-        self.generate_name_spec(&union_name)?;
-        writeln!(self, "__;\n")?;
+        write!(self, "}} ")?;
+        self.generate_type_name(&union_name)?;
+        writeln!(self, ";\n")?;
 
         Ok(())
     }
@@ -114,9 +114,9 @@ impl CodeGenStream<'_> {
 
         writeln!(self, "    // Used to identifie variant kind in run-time")?;
 
-        write!(self, "    __")?;
-        self.generate_name_spec(&enum_name)?;
-        writeln!(self, "__ __kind__;")?;
+        write!(self, "    ")?;
+        self.generate_type_name(&enum_name)?;
+        writeln!(self, " __kind__;")?;
 
         Ok(())
     }
@@ -124,13 +124,13 @@ impl CodeGenStream<'_> {
     // Generates empty struct for variant unit of kind: enum
     fn generate_variant_enum_field(
         &mut self,
-        union_name: &NameSpec,
+        variant_name: &NameSpec,
         field_id: Ident,
     ) -> std::io::Result<()> {
         writeln!(self, "// Empty struct for enum-variant: {field_id}")?;
-        write!(self, "typedef struct {{ }} __")?;
-        self.generate_name_spec(union_name)?;
-        writeln!(self, "__{field_id}__;")?;
+        write!(self, "typedef struct {{ }} ")?;
+        self.generate_type_name(&VariantDef::get_variant_data_name(variant_name, field_id))?;
+        writeln!(self, ";")?;
 
         Ok(())
     }
@@ -138,7 +138,7 @@ impl CodeGenStream<'_> {
     // Generates struct for variant unit of kind: struct
     fn generate_variant_struct_field(
         &mut self,
-        union_name: &NameSpec,
+        variant_name: &NameSpec,
         field_id: Ident,
         subfields: &StructFieldsInfo,
     ) -> std::io::Result<()> {
@@ -146,13 +146,13 @@ impl CodeGenStream<'_> {
         writeln!(self, "typedef struct {{")?;
 
         for (subfield_id, subfield_type) in subfields.iter() {
-            let subfield_type = subfield_type.ty.get_type().get_c_type();
+            let subfield_type = self.get_c_type(&subfield_type.ty.get_type());
             writeln!(self, "    {subfield_type} {subfield_id};")?;
         }
 
-        write!(self, "}} __")?;
-        self.generate_name_spec(union_name)?;
-        writeln!(self, "__{field_id}__;")?;
+        write!(self, "}} ")?;
+        self.generate_type_name(&VariantDef::get_variant_data_name(variant_name, field_id))?;
+        writeln!(self, ";")?;
 
         Ok(())
     }
@@ -160,7 +160,7 @@ impl CodeGenStream<'_> {
     // Generates struct for variant unit of kind: tuple
     fn generate_variant_tuple_field(
         &mut self,
-        union_name: &NameSpec,
+        variant_name: &NameSpec,
         field_id: Ident,
         components: &[Type],
     ) -> std::io::Result<()> {
@@ -168,13 +168,13 @@ impl CodeGenStream<'_> {
         writeln!(self, "typedef struct {{")?;
 
         for (field_num, field_type) in components.iter().enumerate() {
-            let field_type = field_type.get_c_type();
+            let field_type = self.get_c_type(field_type);
             writeln!(self, "    {field_type} _{field_num};")?;
         }
 
-        write!(self, "}} __")?;
-        self.generate_name_spec(union_name)?;
-        writeln!(self, "__{field_id}__;")?;
+        write!(self, "}} ")?;
+        self.generate_type_name(&VariantDef::get_variant_data_name(variant_name, field_id))?;
+        writeln!(self, ";")?;
 
         Ok(())
     }
@@ -184,17 +184,15 @@ impl CodeGenStream<'_> {
         variant_name: &NameSpec,
         fields: &VariantFields,
     ) -> std::io::Result<()> {
-        let union_name = VariantDef::get_variant_data_type_name(variant_name);
-
         for (field_id, field_data) in fields.iter() {
             writeln!(self, "// FIELD {field_id}")?;
             match field_data {
-                VariantField::Enum => self.generate_variant_enum_field(&union_name, *field_id)?,
+                VariantField::Enum => self.generate_variant_enum_field(variant_name, *field_id)?,
                 VariantField::Struct(subfields) => {
-                    self.generate_variant_struct_field(&union_name, *field_id, subfields)?
+                    self.generate_variant_struct_field(variant_name, *field_id, subfields)?
                 }
                 VariantField::Tuple(components) => {
-                    self.generate_variant_tuple_field(&union_name, *field_id, components)?
+                    self.generate_variant_tuple_field(variant_name, *field_id, components)?
                 }
             }
             writeln!(self)?;
@@ -208,9 +206,9 @@ impl CodeGenStream<'_> {
 
         writeln!(self, "    // Used to store variant data in run-time")?;
 
-        write!(self, "    __")?;
-        self.generate_name_spec(&union_name)?;
-        writeln!(self, "__ __data__;")?;
+        write!(self, "    ")?;
+        self.generate_type_name(&union_name)?;
+        writeln!(self, " __data__;")?;
 
         Ok(())
     }
@@ -252,18 +250,18 @@ mod tests {
         const SOURCE_EXPECTED: &str = "#include \"VariantsTest.tt.h\"\n\n";
         const HEADER_EXPECTED: &str = "// Generated structs/enums for variant MyVariant\
                                      \ntypedef enum {\
-                                     \n} __MyVariant__kind__;\
+                                     \n} _TT9MyVariant4kindE;\
                                      \n\
                                      \n// union type that stores any data of variant fields\
                                      \ntypedef union {\
-                                     \n} __MyVariant__data__;\
+                                     \n} _TT9MyVariant4dataE;\
                                      \n\
                                      \ntypedef struct {\
                                      \n    // Used to identifie variant kind in run-time\
-                                     \n    __MyVariant__kind__ __kind__;\
+                                     \n    _TT9MyVariant4kindE __kind__;\
                                      \n    // Used to store variant data in run-time\
-                                     \n    __MyVariant__data__ __data__;\
-                                     \n} MyVariant;\n\n";
+                                     \n    _TT9MyVariant4dataE __data__;\
+                                     \n} _TT9MyVariantE;\n\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
@@ -317,47 +315,47 @@ mod tests {
         const SOURCE_EXPECTED: &str = "#include \"VariantsTest.tt.h\"\n\n";
         const HEADER_EXPECTED: &str = "// Generated structs/enums for variant MyVariant\
                                      \ntypedef enum {\
-                                     \n    __MyVariant__kind__A__,\
-                                     \n    __MyVariant__kind__B__,\
-                                     \n    __MyVariant__kind__C__,\
-                                     \n    __MyVariant__kind__D__,\
-                                     \n    __MyVariant__kind__E__,\
-                                     \n    __MyVariant__kind__F__,\
-                                     \n    __MyVariant__kind__G__,\
-                                     \n} __MyVariant__kind__;\
+                                     \n    _TT9MyVariant4kind1AE,\
+                                     \n    _TT9MyVariant4kind1BE,\
+                                     \n    _TT9MyVariant4kind1CE,\
+                                     \n    _TT9MyVariant4kind1DE,\
+                                     \n    _TT9MyVariant4kind1EE,\
+                                     \n    _TT9MyVariant4kind1FE,\
+                                     \n    _TT9MyVariant4kind1GE,\
+                                     \n} _TT9MyVariant4kindE;\
                                      \n\
                                      \n// FIELD A\
                                      \n// Empty struct for enum-variant: A\
-                                     \ntypedef struct { } __MyVariant__data__A__;\
+                                     \ntypedef struct { } _TT9MyVariant4data1AE;\
                                      \n\
                                      \n// FIELD B\
                                      \n// Struct for struct-variant: B\
                                      \ntypedef struct {\
-                                     \n} __MyVariant__data__B__;\
+                                     \n} _TT9MyVariant4data1BE;\
                                      \n\
                                      \n// FIELD C\
                                      \n// Struct for struct-variant: C\
                                      \ntypedef struct {\
                                      \n    float c;\
-                                     \n} __MyVariant__data__C__;\
+                                     \n} _TT9MyVariant4data1CE;\
                                      \n\
                                      \n// FIELD D\
                                      \n// Struct for struct-variant: D\
                                      \ntypedef struct {\
                                      \n    unsigned char d1;\
                                      \n    signed int d2;\
-                                     \n} __MyVariant__data__D__;\
+                                     \n} _TT9MyVariant4data1DE;\
                                      \n\
                                      \n// FIELD E\
                                      \n// Struct for tuple-variant: E\
                                      \ntypedef struct {\
-                                     \n} __MyVariant__data__E__;\
+                                     \n} _TT9MyVariant4data1EE;\
                                      \n\
                                      \n// FIELD F\
                                      \n// Struct for tuple-variant: F\
                                      \ntypedef struct {\
                                      \n    double _0;\
-                                     \n} __MyVariant__data__F__;\
+                                     \n} _TT9MyVariant4data1FE;\
                                      \n\
                                      \n// FIELD G\
                                      \n// Struct for tuple-variant: G\
@@ -365,25 +363,25 @@ mod tests {
                                      \n    unsigned int _0;\
                                      \n    signed short _1;\
                                      \n    signed int _2;\
-                                     \n} __MyVariant__data__G__;\
+                                     \n} _TT9MyVariant4data1GE;\
                                      \n\
                                      \n// union type that stores any data of variant fields\
                                      \ntypedef union {\
-                                     \n    __MyVariant__data__A__ A;\
-                                     \n    __MyVariant__data__B__ B;\
-                                     \n    __MyVariant__data__C__ C;\
-                                     \n    __MyVariant__data__D__ D;\
-                                     \n    __MyVariant__data__E__ E;\
-                                     \n    __MyVariant__data__F__ F;\
-                                     \n    __MyVariant__data__G__ G;\
-                                     \n} __MyVariant__data__;\
+                                     \n    _TT9MyVariant4data1AE A;\
+                                     \n    _TT9MyVariant4data1BE B;\
+                                     \n    _TT9MyVariant4data1CE C;\
+                                     \n    _TT9MyVariant4data1DE D;\
+                                     \n    _TT9MyVariant4data1EE E;\
+                                     \n    _TT9MyVariant4data1FE F;\
+                                     \n    _TT9MyVariant4data1GE G;\
+                                     \n} _TT9MyVariant4dataE;\
                                      \n\
                                      \ntypedef struct {\
                                      \n    // Used to identifie variant kind in run-time\
-                                     \n    __MyVariant__kind__ __kind__;\
+                                     \n    _TT9MyVariant4kindE __kind__;\
                                      \n    // Used to store variant data in run-time\
-                                     \n    __MyVariant__data__ __data__;\
-                                     \n} MyVariant;\n\n";
+                                     \n    _TT9MyVariant4dataE __data__;\
+                                     \n} _TT9MyVariantE;\n\n";
 
         let header_res = String::from_utf8(header_buffer).unwrap();
         assert_str_eq!(header_res, HEADER_EXPECTED);
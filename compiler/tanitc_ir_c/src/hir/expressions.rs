@@ -1,6 +1,7 @@
 use tanitc_hir::hir::expressions::{
     binary::BinaryExpr, call::CallArg, literal::Literal, unary::UnaryOperation, Expression,
 };
+use tanitc_name::mangling::{mangle_name, ItemKind};
 
 use crate::{CodeGenMode, CodeGenStream};
 
@@ -69,7 +70,13 @@ impl CodeGenStream<'_> {
                 write!(self, ")")?;
             }
             Expression::Literal(lit) => self.generate_literal(lit)?,
-            Expression::Variable(var) => write!(self, "{}", var.name)?,
+            Expression::Variable(var) => match &var.item_name {
+                Some(item_name) => {
+                    let symbol = self.get_item_symbol(item_name);
+                    write!(self, "{symbol}")?;
+                }
+                None => write!(self, "{}", var.name)?,
+            },
         }
 
         self.mode = old_mode;
@@ -85,7 +92,7 @@ impl CodeGenStream<'_> {
             Literal::Text(val) => write!(self, "\"{}\"", val.value)?,
             Literal::Struct(struct_lit) => {
                 // create anonimous variable
-                write!(self, "({})", struct_lit.name)?;
+                write!(self, "({})", mangle_name(ItemKind::Type, &struct_lit.name))?;
 
                 if struct_lit.fields.is_empty() {
                     write!(self, " {{ }}")?;
@@ -230,22 +237,22 @@ mod tests {
         writer.codegen_program(&program).unwrap();
 
         // Then
-        const HEADER_EXPECTED: &str = "void just_func();\n";
+        const HEADER_EXPECTED: &str = "void _TF8my_crate9just_funcE();\n";
         const SOURCE_EXPECTED: &str = "#include \"my_crate.tt.h\"\
                                      \n\
-                                     \nvoid just_func()\
+                                     \nvoid _TF8my_crate9just_funcE()\
                                      \n{\
                                      \n    \"text\";\
                                      \n    var_name;\
                                      \n    empty_func_name();\
                                      \n    func_with_1p(0.0);\
                                      \n    func_with_2p(0.0, 2.0);\
-                                     \n    (MyEmptyStruct) { };\
-                                     \n    (StructWith1F)\
+                                     \n    (_TT13MyEmptyStructE) { };\
+                                     \n    (_TT12StructWith1FE)\
                                      \n    {\
                                      \n        .f1=1.1,\
                                      \n    };\
-                                     \n    (StructWith2F)\
+                                     \n    (_TT12StructWith2FE)\
                                      \n    {\
                                      \n        .f1=0,\
                                      \n        .f2=2.2,\
@@ -16,7 +16,10 @@ use tanitc_hir::{
     visitor::Visitor,
 };
 use tanitc_messages::Message;
-use tanitc_name::{NamePathSegment, NameSpec};
+use tanitc_name::{
+    mangling::{mangle_name, ItemKind},
+    NameSpec,
+};
 
 use super::CodeGenStream;
 
@@ -123,7 +126,7 @@ impl CodeGenStream<'_> {
         }
     }
 
-    fn generate_name_spec(&mut self, name: &NameSpec) -> std::io::Result<()> {
+    fn generate_type_name(&mut self, name: &NameSpec) -> std::io::Result<()> {
         use std::io::Write;
 
         if name.path.is_empty() {
@@ -133,28 +136,9 @@ impl CodeGenStream<'_> {
             ));
         }
 
-        self.generate_name_path_segment(&name.path[0])?;
-        for segment in name.path.iter().skip(1) {
-            write!(self, "__")?;
-            self.generate_name_path_segment(segment)?;
-        }
-
-        Ok(())
-    }
-
-    fn generate_name_path_segment(&mut self, segm: &NamePathSegment) -> std::io::Result<()> {
-        use std::io::Write;
-
-        match segm {
-            NamePathSegment::Id(id) => write!(self, "{id}"),
-            NamePathSegment::CrateNameSpace => {
-                let crate_name = self.compile_options.crate_name.clone();
-                write!(self, "{crate_name}")
-            }
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                "NameSpec is empty",
-            )),
+        match self.get_public_type_name(name) {
+            Some(public_name) => write!(self, "{public_name}"),
+            None => write!(self, "{}", mangle_name(ItemKind::Type, name)),
         }
     }
 }
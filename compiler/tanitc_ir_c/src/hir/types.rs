@@ -1,6 +1,19 @@
-use tanitc_hir::hir::type_spec::{Type, TypeSpec};
+use tanitc_hir::hir::{
+    blocks::Block,
+    branches::{Branch, ElseBody},
+    definitions::{
+        functions::{FunctionDef, FunctionParam},
+        modules::ModuleDefBody,
+        variants::VariantField,
+        Definition,
+    },
+    type_spec::{FuncType, Type, TypeSpec},
+    Hir,
+};
 
-use crate::CodeGenStream;
+use tanitc_name::mangling::{mangle_name, ItemKind};
+
+use crate::{CodeGenMode, CodeGenStream};
 
 impl CodeGenStream<'_> {
     pub fn generate_type_spec(&mut self, type_spec: &TypeSpec) -> std::io::Result<()> {
@@ -10,6 +23,306 @@ impl CodeGenStream<'_> {
     pub fn generate_type(&mut self, ty: &Type) -> std::io::Result<()> {
         use std::io::Write;
 
-        write!(self, "{}", ty.get_c_type())
+        write!(self, "{}", self.get_c_type(ty))
+    }
+
+    pub(crate) fn get_c_type(&self, ty: &Type) -> String {
+        ty.get_c_type_with(&|name| {
+            self.get_public_type_name(name)
+                .unwrap_or_else(|| mangle_name(ItemKind::Type, name))
+        })
+    }
+
+    // Function pointers are declared through typedefs, which must be written
+    // to the header before the first statement that uses them
+    pub fn generate_func_typedefs(&mut self, func_types: &[FuncType]) -> std::io::Result<()> {
+        for func_type in func_types.iter() {
+            if self.func_typedefs.insert(func_type.get_c_typedef_name()) {
+                self.generate_func_typedef(func_type)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn generate_func_typedef(&mut self, func_type: &FuncType) -> std::io::Result<()> {
+        use std::io::Write;
+
+        let old_mode = self.mode;
+        self.mode = CodeGenMode::HeaderOnly;
+
+        write!(self, "typedef ")?;
+        self.generate_type(&func_type.return_type)?;
+        write!(self, " (*{})(", func_type.get_c_typedef_name())?;
+
+        if func_type.parameters.is_empty() && !func_type.is_variadic {
+            write!(self, "void")?;
+        }
+
+        for (param_idx, param) in func_type.parameters.iter().enumerate() {
+            if param_idx > 0 {
+                write!(self, ", ")?;
+            }
+            self.generate_type(&param.ty)?;
+        }
+
+        if func_type.is_variadic {
+            write!(self, ", ...")?;
+        }
+
+        writeln!(self, ");")?;
+
+        self.mode = old_mode;
+
+        Ok(())
+    }
+}
+
+// Function types are collected in the order of their typedefs:
+// types of parameters go before the function type itself
+pub(crate) fn collect_func_types(hir: &Hir, func_types: &mut Vec<FuncType>) {
+    match hir {
+        Hir::Block(block) => collect_block_func_types(block, func_types),
+        Hir::Definition(definition) => collect_definition_func_types(definition, func_types),
+        Hir::BranchStmt(branch) => collect_branch_func_types(branch, func_types),
+        Hir::TypeSpec(type_spec) => collect_type_func_types(&type_spec.ty, func_types),
+        _ => {}
+    }
+}
+
+pub(crate) fn collect_definition_func_types(
+    definition: &Definition,
+    func_types: &mut Vec<FuncType>,
+) {
+    match definition {
+        Definition::Module(module_def) => match &module_def.body {
+            ModuleDefBody::Internal(block) => collect_block_func_types(block, func_types),
+            ModuleDefBody::External(body) => collect_func_types(body, func_types),
+        },
+        Definition::Struct(struct_def) => {
+            for field_info in struct_def.fields.values() {
+                collect_type_func_types(&field_info.ty.ty, func_types);
+            }
+        }
+        Definition::Union(union_def) => {
+            for field_info in union_def.fields.values() {
+                collect_type_func_types(&field_info.ty.ty, func_types);
+            }
+        }
+        Definition::Variant(variant_def) => {
+            for field in variant_def.fields.values() {
+                match field {
+                    VariantField::Enum => {}
+                    VariantField::Tuple(units) => {
+                        for unit in units.iter() {
+                            collect_type_func_types(unit, func_types);
+                        }
+                    }
+                    VariantField::Struct(fields) => {
+                        for field_info in fields.values() {
+                            collect_type_func_types(&field_info.ty.ty, func_types);
+                        }
+                    }
+                }
+            }
+        }
+        Definition::Impl(impl_def) => {
+            for method in impl_def.methods.iter() {
+                collect_func_def_func_types(method, func_types);
+            }
+        }
+        Definition::Func(func_def) => collect_func_def_func_types(func_def, func_types),
+        Definition::Variable(var_def) => collect_type_func_types(&var_def.var_type, func_types),
+        Definition::Alias(alias_def) => collect_type_func_types(&alias_def.value.ty, func_types),
+        Definition::Extern(extern_def) => {
+            for func_def in extern_def.functions.iter() {
+                collect_func_def_func_types(func_def, func_types);
+            }
+            for var_def in extern_def.statics.iter() {
+                collect_type_func_types(&var_def.var_type, func_types);
+            }
+        }
+        Definition::Enum(_) => {}
+    }
+}
+
+pub(crate) fn collect_func_signature_func_types(
+    func_def: &FunctionDef,
+    func_types: &mut Vec<FuncType>,
+) {
+    for param in func_def.parameters.iter() {
+        if let FunctionParam::Common(var_def) = param {
+            collect_type_func_types(&var_def.var_type, func_types);
+        }
+    }
+
+    collect_type_func_types(&func_def.return_type, func_types);
+}
+
+fn collect_func_def_func_types(func_def: &FunctionDef, func_types: &mut Vec<FuncType>) {
+    collect_func_signature_func_types(func_def, func_types);
+
+    if let Some(body) = &func_def.body {
+        collect_block_func_types(body, func_types);
+    }
+}
+
+fn collect_block_func_types(block: &Block, func_types: &mut Vec<FuncType>) {
+    for statement in block.statements.iter() {
+        collect_func_types(statement, func_types);
+    }
+}
+
+fn collect_branch_func_types(branch: &Branch, func_types: &mut Vec<FuncType>) {
+    match branch {
+        Branch::Loop(node) => collect_block_func_types(&node.body, func_types),
+        Branch::While(node) => collect_block_func_types(&node.body, func_types),
+        Branch::If(node) => collect_block_func_types(&node.body, func_types),
+        Branch::Else(node) => match &node.body {
+            ElseBody::Block(block) => collect_block_func_types(block, func_types),
+            ElseBody::If(node) => collect_block_func_types(&node.body, func_types),
+        },
+    }
+}
+
+fn collect_type_func_types(ty: &Type, func_types: &mut Vec<FuncType>) {
+    match ty {
+        Type::Ref(ref_type) => collect_type_func_types(&ref_type.ref_to, func_types),
+        Type::Ptr(ptr_type) => collect_type_func_types(&ptr_type.ptr_to, func_types),
+        Type::Array { value_type, .. } => collect_type_func_types(value_type, func_types),
+        Type::Tuple(tuple_type) => {
+            for unit in tuple_type.units.iter() {
+                collect_type_func_types(unit, func_types);
+            }
+        }
+        Type::Func(func_type) => {
+            for param in func_type.parameters.iter() {
+                collect_type_func_types(&param.ty, func_types);
+            }
+            collect_type_func_types(&func_type.return_type, func_types);
+
+            func_types.push(func_type.clone());
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_attributes::{Abi, Mutability, Safety};
+    use tanitc_hir::hir::{
+        definitions::externs::ExternDef,
+        type_spec::{FuncTypeParam, PtrType},
+    };
+    use tanitc_hir_test::{
+        create_common_func_param, create_func_def, create_program, create_struct_def,
+    };
+    use tanitc_options::CompileOptions;
+
+    use super::*;
+
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn func_typedef_test() {
+        // Given
+        let elem_type = Type::Ptr(PtrType {
+            ptr_to: Box::new(Type::I32),
+            mutability: Mutability::Immutable,
+        });
+
+        let cmp_type = Type::Func(FuncType {
+            parameters: vec![
+                FuncTypeParam {
+                    ty: Box::new(elem_type.clone()),
+                    id: None,
+                },
+                FuncTypeParam {
+                    ty: Box::new(elem_type),
+                    id: None,
+                },
+            ],
+            return_type: Box::new(Type::I32),
+            safety: Safety::Safe,
+            abi: Abi::C,
+            is_variadic: false,
+        });
+
+        let handler_type = Type::Func(FuncType {
+            parameters: vec![],
+            return_type: Box::new(Type::unit()),
+            safety: Safety::Safe,
+            abi: Abi::Tanit,
+            is_variadic: false,
+        });
+
+        let mut sort_def = create_func_def(
+            "sort",
+            vec![create_common_func_param(
+                "cmp",
+                Mutability::Immutable,
+                cmp_type.clone(),
+            )],
+            Type::unit(),
+            vec![],
+        );
+        sort_def.attributes.abi = Abi::C;
+        sort_def.body = None;
+
+        let struct_def = create_struct_def("Handlers", vec![("on_exit", handler_type)]);
+
+        let set_cmp_def = create_func_def(
+            "set_cmp",
+            vec![create_common_func_param(
+                "cmp",
+                Mutability::Immutable,
+                cmp_type,
+            )],
+            Type::unit(),
+            vec![],
+        );
+
+        /* extern "C" {
+         *     func sort(cmp: extern "C" func(*const i32, *const i32): i32)
+         * }
+         * struct Handlers {
+         *     on_exit: func()
+         * }
+         * func set_cmp(cmp: extern "C" func(*const i32, *const i32): i32) { }
+         */
+        let program = create_program(vec![
+            ExternDef {
+                abi: Abi::C,
+                functions: vec![sort_def],
+                ..Default::default()
+            }
+            .into(),
+            struct_def.into(),
+            set_cmp_def.into(),
+        ]);
+
+        let mut header_buffer = Vec::<u8>::new();
+        let mut source_buffer = Vec::<u8>::new();
+        let mut writer = CodeGenStream::with_compile_options(
+            &mut header_buffer,
+            &mut source_buffer,
+            CompileOptions {
+                crate_name: "typedef_test".into(),
+                ..Default::default()
+            },
+        );
+
+        // When
+        writer.codegen_program(&program).unwrap();
+
+        // Then
+        const HEADER_EXPECTED: &str = "typedef signed int (*_TP5pci325pci323i32E)(signed int const *, signed int const *);\n\
+                                       void sort(_TP5pci325pci323i32E const cmp);\n\
+                                       typedef void (*_TP1vE)(void);\n\
+                                       typedef struct {\n    _TP1vE on_exit;\n} _TT8HandlersE;\n\
+                                       void _TF12typedef_test7set_cmpE(_TP5pci325pci323i32E const cmp);\n";
+
+        let header_res = String::from_utf8(header_buffer).unwrap();
+        assert_str_eq!(header_res, HEADER_EXPECTED);
     }
 }
@@ -1,7 +1,11 @@
+use std::collections::{HashMap, HashSet};
+
 use tanitc_hir::hir::Hir;
 use tanitc_options::CompileOptions;
 
 pub(crate) mod hir;
+pub(crate) mod public_header;
+pub(crate) mod symbols;
 
 #[derive(Default, Debug, PartialEq, Eq, Clone, Copy)]
 pub enum CodeGenMode {
@@ -16,6 +20,9 @@ pub struct CodeGenStream<'a> {
     header_stream: &'a mut dyn std::io::Write,
     source_stream: &'a mut dyn std::io::Write,
     compile_options: CompileOptions,
+    func_typedefs: HashSet<String>, // already written function pointer typedefs
+    item_symbols: HashMap<String, String>, // absolute paths of the functions to their symbols
+    public_type_names: HashMap<String, String>, // absolute paths of the types to their C names
     pub mode: CodeGenMode,
     pub indent: usize,
 }
@@ -29,6 +36,9 @@ impl<'a> CodeGenStream<'a> {
             header_stream,
             source_stream,
             compile_options: CompileOptions::default(),
+            func_typedefs: HashSet::new(),
+            item_symbols: HashMap::new(),
+            public_type_names: HashMap::new(),
             mode: CodeGenMode::Unset,
             indent: 0,
         }
@@ -43,6 +53,9 @@ impl<'a> CodeGenStream<'a> {
             header_stream,
             source_stream,
             compile_options,
+            func_typedefs: HashSet::new(),
+            item_symbols: HashMap::new(),
+            public_type_names: HashMap::new(),
             mode: CodeGenMode::Unset,
             indent: 0,
         }
@@ -59,6 +72,7 @@ impl<'a> CodeGenStream<'a> {
 
         self.mode = CodeGenMode::Unset;
 
+        self.collect_item_symbols(program_hir);
         program_hir.accept(self).map_err(|err| err.to_string())?;
 
         Ok(())
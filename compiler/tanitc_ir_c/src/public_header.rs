@@ -0,0 +1,349 @@
+use std::{collections::BTreeSet, io::Write};
+
+use tanitc_attributes::Publicity;
+use tanitc_hir::{
+    attributes::find_attribute,
+    hir::{
+        definitions::{
+            functions::{FunctionDef, FunctionParam},
+            modules::ModuleDefBody,
+            variants::VariantField,
+            Definition,
+        },
+        type_spec::Type,
+        Hir,
+    },
+};
+use tanitc_ident::Ident;
+use tanitc_name::NameSpec;
+
+use crate::{
+    hir::types::{collect_definition_func_types, collect_func_signature_func_types},
+    CodeGenMode, CodeGenStream,
+};
+
+impl CodeGenStream<'_> {
+    // Public header contains only exported "C" functions and the types they use,
+    // so it can be included by C code linked against the library
+    pub fn codegen_public_header(&mut self, program_hir: &Hir) -> Result<(), String> {
+        self.generate_public_header(program_hir)
+            .map_err(|err| err.to_string())
+    }
+
+    fn generate_public_header(&mut self, program_hir: &Hir) -> std::io::Result<()> {
+        self.collect_item_symbols(program_hir);
+
+        let mut definitions = Vec::<&Definition>::new();
+        collect_definitions(program_hir, &mut definitions);
+
+        let functions: Vec<&FunctionDef> = definitions
+            .iter()
+            .filter_map(|definition| match definition {
+                Definition::Func(func_def) if is_exported_func(func_def) => Some(func_def),
+                _ => None,
+            })
+            .collect();
+
+        let mut pending = Vec::<Ident>::new();
+        for definition in definitions.iter() {
+            if type_def_publicity(definition) == Some(Publicity::Public) {
+                pending.extend(type_def_id(definition));
+            }
+        }
+        for func_def in functions.iter() {
+            collect_func_type_ids(func_def, &mut pending);
+        }
+
+        // Types referenced by the required ones are required as well
+        let mut required = BTreeSet::<Ident>::new();
+        while let Some(id) = pending.pop() {
+            if !required.insert(id) {
+                continue;
+            }
+
+            let type_def = definitions
+                .iter()
+                .find(|definition| type_def_id(definition) == Some(id));
+            if let Some(type_def) = type_def {
+                collect_type_def_ids(type_def, &mut pending);
+            }
+        }
+
+        // C code refers to the types by their Tanit names instead of the mangled ones
+        for definition in definitions.iter() {
+            let Some(name) = type_def_name(definition) else {
+                continue;
+            };
+
+            if let Some(id) = name.get_id().filter(|id| required.contains(id)) {
+                self.public_type_names
+                    .insert(name.to_string(), id.to_string());
+            }
+        }
+
+        let old_mode = self.mode;
+        self.mode = CodeGenMode::HeaderOnly;
+
+        let guard = self.get_include_guard();
+        writeln!(self, "#ifndef {guard}")?;
+        writeln!(self, "#define {guard}\n")?;
+
+        for definition in definitions.iter() {
+            if type_def_id(definition).is_some_and(|id| required.contains(&id)) {
+                let mut func_types = Vec::new();
+                collect_definition_func_types(definition, &mut func_types);
+                self.generate_func_typedefs(&func_types)?;
+
+                self.generate_definition(definition)?;
+            }
+        }
+
+        for func_def in functions.iter() {
+            let mut func_types = Vec::new();
+            collect_func_signature_func_types(func_def, &mut func_types);
+            self.generate_func_typedefs(&func_types)?;
+
+            self.generate_func_def(func_def, None)?;
+        }
+
+        writeln!(self, "\n#endif // {guard}")?;
+
+        self.mode = old_mode;
+        self.public_type_names.clear();
+
+        Ok(())
+    }
+
+    fn get_include_guard(&self) -> String {
+        let crate_name: String = self
+            .compile_options
+            .crate_name
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        format!("{crate_name}_H")
+    }
+}
+
+// Extern crates are not part of the library, so their modules are skipped
+fn collect_definitions<'a>(hir: &'a Hir, definitions: &mut Vec<&'a Definition>) {
+    match hir {
+        Hir::Block(block) => {
+            for statement in block.statements.iter() {
+                collect_definitions(statement, definitions);
+            }
+        }
+        Hir::Definition(Definition::Module(module_def)) => {
+            if let ModuleDefBody::Internal(block) = &module_def.body {
+                for statement in block.statements.iter() {
+                    collect_definitions(statement, definitions);
+                }
+            }
+        }
+        Hir::Definition(definition) => definitions.push(definition),
+        _ => {}
+    }
+}
+
+fn is_exported_func(func_def: &FunctionDef) -> bool {
+    func_def.attributes.publicity == Publicity::Public
+        && func_def.attributes.abi.is_c()
+        && func_def.body.is_some()
+        && find_attribute(&func_def.attributes.meta, "inline").is_none()
+}
+
+fn type_def_name(definition: &Definition) -> Option<&NameSpec> {
+    match definition {
+        Definition::Struct(node) => Some(&node.name),
+        Definition::Union(node) => Some(&node.name),
+        Definition::Variant(node) => Some(&node.name),
+        Definition::Enum(node) => Some(&node.name),
+        Definition::Alias(node) => Some(&node.name),
+        _ => None,
+    }
+}
+
+fn type_def_id(definition: &Definition) -> Option<Ident> {
+    type_def_name(definition).and_then(NameSpec::get_id)
+}
+
+fn type_def_publicity(definition: &Definition) -> Option<Publicity> {
+    match definition {
+        Definition::Struct(node) => Some(node.attributes.publicity),
+        Definition::Union(node) => Some(node.attributes.publicity),
+        Definition::Variant(node) => Some(node.attributes.publicity),
+        Definition::Enum(node) => Some(node.attributes.publicity),
+        Definition::Alias(node) => Some(node.attributes.publicity),
+        _ => None,
+    }
+}
+
+fn collect_func_type_ids(func_def: &FunctionDef, ids: &mut Vec<Ident>) {
+    for param in func_def.parameters.iter() {
+        if let FunctionParam::Common(var_def) = param {
+            collect_type_ids(&var_def.var_type, ids);
+        }
+    }
+
+    collect_type_ids(&func_def.return_type, ids);
+}
+
+fn collect_type_def_ids(definition: &Definition, ids: &mut Vec<Ident>) {
+    match definition {
+        Definition::Struct(node) => {
+            for field_info in node.fields.values() {
+                collect_type_ids(&field_info.ty.ty, ids);
+            }
+        }
+        Definition::Union(node) => {
+            for field_info in node.fields.values() {
+                collect_type_ids(&field_info.ty.ty, ids);
+            }
+        }
+        Definition::Variant(node) => {
+            for field in node.fields.values() {
+                match field {
+                    VariantField::Enum => {}
+                    VariantField::Tuple(units) => {
+                        for unit in units.iter() {
+                            collect_type_ids(unit, ids);
+                        }
+                    }
+                    VariantField::Struct(fields) => {
+                        for field_info in fields.values() {
+                            collect_type_ids(&field_info.ty.ty, ids);
+                        }
+                    }
+                }
+            }
+        }
+        Definition::Alias(node) => collect_type_ids(&node.value.ty, ids),
+        _ => {}
+    }
+}
+
+fn collect_type_ids(ty: &Type, ids: &mut Vec<Ident>) {
+    match ty {
+        Type::Custom(name) => ids.extend(name.get_id()),
+        Type::Ref(ref_type) => collect_type_ids(&ref_type.ref_to, ids),
+        Type::Ptr(ptr_type) => collect_type_ids(&ptr_type.ptr_to, ids),
+        Type::Array { value_type, .. } => collect_type_ids(value_type, ids),
+        Type::Tuple(tuple_type) => {
+            for unit in tuple_type.units.iter() {
+                collect_type_ids(unit, ids);
+            }
+        }
+        Type::Func(func_type) => {
+            for param in func_type.parameters.iter() {
+                collect_type_ids(&param.ty, ids);
+            }
+            collect_type_ids(&func_type.return_type, ids);
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_attributes::{Abi, Mutability, Publicity};
+    use tanitc_hir::hir::type_spec::PtrType;
+    use tanitc_hir_test::{
+        create_common_func_param, create_custom_type, create_enum_def, create_func_def,
+        create_name_spec, create_program, create_struct_def,
+    };
+    use tanitc_options::CompileOptions;
+
+    use super::*;
+
+    use pretty_assertions::assert_str_eq;
+
+    #[test]
+    fn public_header_test() {
+        // Given
+        let hidden_def = create_struct_def("Hidden", vec![("a", Type::I32)]);
+        let point_def = create_struct_def("Point", vec![("x", Type::F32)]);
+
+        let mut color_def = create_enum_def("Color", vec![("Red", Some(0))]);
+        color_def.attributes.publicity = Publicity::Public;
+
+        let mut point_x_def = create_func_def(
+            "point_x",
+            vec![create_common_func_param(
+                "p",
+                Mutability::Immutable,
+                Type::Ptr(PtrType {
+                    ptr_to: Box::new(create_custom_type(&["Point"])),
+                    mutability: Mutability::Immutable,
+                }),
+            )],
+            Type::F32,
+            vec![],
+        );
+        point_x_def.name = create_name_spec(&["geo", "point_x"]);
+        point_x_def.attributes.publicity = Publicity::Public;
+        point_x_def.attributes.abi = Abi::C;
+
+        let mut internal_def = create_func_def("internal", vec![], Type::unit(), vec![]);
+        internal_def.attributes.publicity = Publicity::Public;
+
+        /* struct Hidden {
+         *     a: i32
+         * }
+         * struct Point {
+         *     x: f32
+         * }
+         * pub enum Color {
+         *     Red: 0
+         * }
+         * pub extern "C" func point_x(p: *const Point): f32 { }
+         * pub func internal() { }
+         */
+        let program = create_program(vec![
+            hidden_def.into(),
+            point_def.into(),
+            color_def.into(),
+            point_x_def.into(),
+            internal_def.into(),
+        ]);
+
+        let mut header_buffer = Vec::<u8>::new();
+        let mut source_buffer = Vec::<u8>::new();
+        let mut writer = CodeGenStream::with_compile_options(
+            &mut header_buffer,
+            &mut source_buffer,
+            CompileOptions {
+                crate_name: "my-geo".into(),
+                ..Default::default()
+            },
+        );
+
+        // When
+        writer.codegen_public_header(&program).unwrap();
+
+        // Then
+        const HEADER_EXPECTED: &str = "#ifndef MY_GEO_H\n\
+                                       #define MY_GEO_H\n\
+                                       \n\
+                                       typedef struct {\n    float x;\n} Point;\n\
+                                       typedef enum {\n    Color_Red = 0,\n} Color;\n\
+                                       float point_x(Point const * const p);\n\
+                                       \n\
+                                       #endif // MY_GEO_H\n";
+
+        let header_res = String::from_utf8(header_buffer).unwrap();
+        assert_str_eq!(header_res, HEADER_EXPECTED);
+
+        // Bodies go to the source stream, which is discarded for the public header
+        const SOURCE_EXPECTED: &str = "float point_x(Point const * const p) { }\n";
+
+        let source_res = String::from_utf8(source_buffer).unwrap();
+        assert_str_eq!(source_res, SOURCE_EXPECTED);
+    }
+}
@@ -0,0 +1,334 @@
+use tanitc_hir::{
+    attributes::find_attribute,
+    hir::{
+        definitions::{functions::FunctionDef, modules::ModuleDefBody, Definition},
+        Hir,
+    },
+};
+use tanitc_ident::Ident;
+use tanitc_name::{
+    mangling::{mangle, ItemKind},
+    NameSpec,
+};
+
+use crate::CodeGenStream;
+
+impl CodeGenStream<'_> {
+    // Remembers symbols of all functions and methods, so references to them can be written
+    // before their definitions
+    pub(crate) fn collect_item_symbols(&mut self, hir: &Hir) {
+        match hir {
+            Hir::Block(block) => {
+                for statement in block.statements.iter() {
+                    self.collect_item_symbols(statement);
+                }
+            }
+            Hir::Definition(Definition::Module(module_def)) => match &module_def.body {
+                ModuleDefBody::Internal(block) => {
+                    for statement in block.statements.iter() {
+                        self.collect_item_symbols(statement);
+                    }
+                }
+                ModuleDefBody::External(body) => self.collect_item_symbols(body),
+            },
+            Hir::Definition(Definition::Func(func_def)) => self.insert_func_symbol(func_def),
+            Hir::Definition(Definition::Enum(enum_def)) => {
+                for unit_id in enum_def.units.keys() {
+                    let symbol = get_enum_unit_symbol(&enum_def.name, *unit_id);
+                    self.item_symbols
+                        .insert(format!("{}::{unit_id}", enum_def.name), symbol);
+                }
+            }
+            Hir::Definition(Definition::Impl(impl_def)) => {
+                let type_name = impl_def.get_type_name();
+                for method in impl_def.methods.iter() {
+                    let Some(id) = method.name.get_id() else {
+                        continue;
+                    };
+                    let symbol = self.get_func_symbol(method, Some(type_name));
+                    self.item_symbols
+                        .insert(format!("{type_name}::{id}"), symbol);
+                }
+            }
+            Hir::Definition(Definition::Extern(extern_def)) => {
+                for func_def in extern_def.functions.iter() {
+                    self.insert_func_symbol(&extern_def.get_foreign_func(func_def));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn insert_func_symbol(&mut self, func_def: &FunctionDef) {
+        let symbol = self.get_func_symbol(func_def, None);
+        self.item_symbols.insert(func_def.name.to_string(), symbol);
+    }
+
+    pub(crate) fn get_func_symbol(
+        &self,
+        func_def: &FunctionDef,
+        struct_name: Option<&NameSpec>,
+    ) -> String {
+        // Functions with "C" ABI are called by C code, so their symbols must be stable
+        let no_mangle = find_attribute(&func_def.attributes.meta, "no_mangle").is_some()
+            || func_def.attributes.abi.is_c();
+
+        let Some(id) = func_def.name.get_id() else {
+            return func_def.name.to_string();
+        };
+
+        if no_mangle {
+            return id.to_string();
+        }
+
+        match struct_name {
+            Some(struct_name) => {
+                let mut path = self.get_crate_path(struct_name);
+                path.push(id.to_string());
+                mangle(ItemKind::Method, &path)
+            }
+            None if func_def.name.path.len() == 1 && id.to_string() == "main" => "main".to_string(),
+            None => mangle(ItemKind::Function, &self.get_crate_path(&func_def.name)),
+        }
+    }
+
+    pub(crate) fn get_item_symbol(&self, item_name: &NameSpec) -> String {
+        self.item_symbols
+            .get(&item_name.to_string())
+            .cloned()
+            .unwrap_or_else(|| mangle(ItemKind::Function, &self.get_crate_path(item_name)))
+    }
+
+    // Public header names the types like C code does: "Shape_kind_Dot" for "geo::Shape::kind::Dot"
+    pub(crate) fn get_public_type_name(&self, name: &NameSpec) -> Option<String> {
+        (1..=name.path.len()).rev().find_map(|len| {
+            let type_path = name.path[..len]
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join("::");
+
+            let public_name = self.public_type_names.get(&type_path)?;
+
+            Some(
+                std::iter::once(public_name.clone())
+                    .chain(name.path[len..].iter().map(ToString::to_string))
+                    .collect::<Vec<_>>()
+                    .join("_"),
+            )
+        })
+    }
+
+    pub(crate) fn get_enum_unit_name(&self, enum_name: &NameSpec, unit_id: Ident) -> String {
+        match self.get_public_type_name(enum_name) {
+            Some(public_name) => format!("{public_name}_{unit_id}"),
+            None => get_enum_unit_symbol(enum_name, unit_id),
+        }
+    }
+
+    // Items of extern crates already start with the name of their crate
+    fn get_crate_path(&self, name: &NameSpec) -> Vec<String> {
+        let mut path: Vec<String> = name.path.iter().map(ToString::to_string).collect();
+
+        let crate_name = &self.compile_options.crate_name;
+        let is_extern = path.first().is_some_and(|first| {
+            self.compile_options
+                .extern_crates
+                .iter()
+                .any(|extern_crate| extern_crate.name == *first)
+        });
+
+        if !crate_name.is_empty() && !is_extern {
+            // Crate names may come from file names, which are not always identifiers
+            let crate_name = crate_name
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+                .collect();
+            path.insert(0, crate_name);
+        }
+
+        path
+    }
+}
+
+// Units of enums are constants of the C header, like the enum type itself
+pub(crate) fn get_enum_unit_symbol(enum_name: &NameSpec, unit_id: Ident) -> String {
+    let mut path: Vec<String> = enum_name.path.iter().map(ToString::to_string).collect();
+    path.push(unit_id.to_string());
+    mangle(ItemKind::Constant, &path)
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_hir::hir::{
+        expressions::{call::CallExpr, Expression},
+        type_spec::Type,
+    };
+    use tanitc_hir_test::{
+        create_call_expr, create_func_def, create_impl_def, create_main_func_def,
+        create_module_def, create_name_spec, create_program, create_struct_def,
+    };
+    use tanitc_options::CompileOptions;
+
+    use super::*;
+
+    use pretty_assertions::assert_str_eq;
+
+    fn create_item_call(item_name: &[&str]) -> Hir {
+        let mut call = create_call_expr(&[item_name.last().unwrap()], vec![]);
+        if let Expression::Call(CallExpr { expr, .. }) = &mut call {
+            if let Expression::Variable(var) = expr.as_mut() {
+                var.item_name = Some(create_name_spec(item_name));
+            }
+        }
+        call.into()
+    }
+
+    #[test]
+    fn mangled_symbols_do_not_collide_test() {
+        // Given
+        let mut nested_def = create_func_def("b", vec![], Type::unit(), vec![]);
+        nested_def.name = create_name_spec(&["a", "b"]);
+
+        let joined_def = create_func_def("a__b", vec![], Type::unit(), vec![]);
+
+        /*
+         * module a {
+         *     func b() { }
+         * }
+         * func a__b() { }
+         * func main() {
+         *     a::b()
+         *     a__b()
+         * }
+         */
+        let program = create_program(vec![
+            create_module_def("a", vec![nested_def.into()]).into(),
+            joined_def.into(),
+            create_main_func_def(vec![
+                create_item_call(&["a", "b"]),
+                create_item_call(&["a__b"]),
+            ])
+            .into(),
+        ]);
+
+        let mut header_buffer = Vec::<u8>::new();
+        let mut source_buffer = Vec::<u8>::new();
+        let mut writer = CodeGenStream::with_compile_options(
+            &mut header_buffer,
+            &mut source_buffer,
+            CompileOptions {
+                crate_name: "my-app".into(),
+                ..Default::default()
+            },
+        );
+
+        // When
+        writer.codegen_program(&program).unwrap();
+
+        // Then
+        const HEADER_EXPECTED: &str = "void _TF6my_app1a1bE();\
+                                     \nvoid _TF6my_app4a__bE();\
+                                     \nsigned int main();\n";
+
+        const SOURCE_EXPECTED: &str = "#include \"my-app.tt.h\"\n\
+                                     \nvoid _TF6my_app1a1bE() { }\
+                                     \nvoid _TF6my_app4a__bE() { }\
+                                     \nsigned int main()\
+                                     \n{\
+                                     \n    _TF6my_app1a1bE();\
+                                     \n    _TF6my_app4a__bE();\
+                                     \n}\n";
+
+        let header_res = String::from_utf8(header_buffer).unwrap();
+        assert_str_eq!(header_res, HEADER_EXPECTED);
+
+        let source_res = String::from_utf8(source_buffer).unwrap();
+        assert_str_eq!(source_res, SOURCE_EXPECTED);
+    }
+
+    // Module "name" with struct "P" and its static method "make", as analyzer leaves them
+    fn create_module_with_method(name: &str) -> Hir {
+        let mut struct_def = create_struct_def("P", vec![]);
+        struct_def.name = create_name_spec(&[name, "P"]);
+
+        let mut make_def = create_func_def("make", vec![], Type::unit(), vec![]);
+        make_def.name = create_name_spec(&[name, "make"]);
+
+        let mut impl_def = create_impl_def("P", vec![make_def]);
+        impl_def.type_name = Some(create_name_spec(&[name, "P"]));
+
+        create_module_def(name, vec![struct_def.into(), impl_def.into()]).into()
+    }
+
+    #[test]
+    fn same_named_methods_do_not_collide_test() {
+        // Given
+
+        /*
+         * module a {
+         *     struct P { }
+         *     impl P {
+         *         func make() { }
+         *     }
+         * }
+         * module b {
+         *     struct P { }
+         *     impl P {
+         *         func make() { }
+         *     }
+         * }
+         * func main() {
+         *     a::P::make()
+         *     b::P::make()
+         * }
+         */
+        let program = create_program(vec![
+            create_module_with_method("a"),
+            create_module_with_method("b"),
+            create_main_func_def(vec![
+                create_item_call(&["a", "P", "make"]),
+                create_item_call(&["b", "P", "make"]),
+            ])
+            .into(),
+        ]);
+
+        let mut header_buffer = Vec::<u8>::new();
+        let mut source_buffer = Vec::<u8>::new();
+        let mut writer = CodeGenStream::with_compile_options(
+            &mut header_buffer,
+            &mut source_buffer,
+            CompileOptions {
+                crate_name: "app".into(),
+                ..Default::default()
+            },
+        );
+
+        // When
+        writer.codegen_program(&program).unwrap();
+
+        // Then
+        const HEADER_EXPECTED: &str = "typedef struct {\
+                                     \n} _TT1a1PE;\
+                                     \nvoid _TM3app1a1P4makeE();\
+                                     \ntypedef struct {\
+                                     \n} _TT1b1PE;\
+                                     \nvoid _TM3app1b1P4makeE();\
+                                     \nsigned int main();\n";
+
+        const SOURCE_EXPECTED: &str = "#include \"app.tt.h\"\n\
+                                     \nvoid _TM3app1a1P4makeE() { }\
+                                     \nvoid _TM3app1b1P4makeE() { }\
+                                     \nsigned int main()\
+                                     \n{\
+                                     \n    _TM3app1a1P4makeE();\
+                                     \n    _TM3app1b1P4makeE();\
+                                     \n}\n";
+
+        let header_res = String::from_utf8(header_buffer).unwrap();
+        assert_str_eq!(header_res, HEADER_EXPECTED);
+
+        let source_res = String::from_utf8(source_buffer).unwrap();
+        assert_str_eq!(source_res, SOURCE_EXPECTED);
+    }
+}
@@ -0,0 +1,8 @@
+use crate::location::Location;
+
+// Comments are not tokens, they are kept aside for tools printing the source back
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment {
+    pub text: String, // starts with '#'
+    pub location: Location,
+}
@@ -1,3 +1,4 @@
+pub mod comment;
 pub mod location;
 pub mod token;
 
@@ -7,6 +8,7 @@ use std::{
     str::Chars,
 };
 
+use comment::Comment;
 use location::Location;
 use tanitc_ident::Ident;
 
@@ -18,6 +20,7 @@ pub struct Lexer<'a> {
     location: Location,
     next_token: Option<Token>,
     input: Peekable<Chars<'a>>,
+    comments: Vec<Comment>,
     pub verbose_tokens: bool,
 }
 
@@ -28,6 +31,7 @@ impl<'a> Lexer<'a> {
             next_token: None,
             verbose_tokens: false,
             input,
+            comments: Vec::new(),
         }
     }
 
@@ -89,6 +93,14 @@ impl<'a> Lexer<'a> {
         self.next_token.clone()
     }
 
+    pub fn comments_ref(&self) -> &[Comment] {
+        &self.comments
+    }
+
+    pub fn take_comments(&mut self) -> Vec<Comment> {
+        std::mem::take(&mut self.comments)
+    }
+
     pub fn location_ref(&self) -> &Location {
         &self.location
     }
@@ -123,8 +135,8 @@ impl<'a> Lexer<'a> {
 
     fn skip_spaces(&mut self) {
         while self.peek_char().is_some_and(|ch| ch.is_ascii_whitespace()) {
+            // New line is counted when '\n' is consumed as a token
             if self.peek_char().is_some_and(|ch| *ch == '\n') {
-                self.location.new_line();
                 return;
             }
 
@@ -132,15 +144,36 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    fn is_attribute_start(&self) -> bool {
+        let mut input = self.input.clone();
+
+        input.next() == Some('#') && input.next() == Some('[')
+    }
+
+    fn is_ellipsis_start(&self) -> bool {
+        let mut input = self.input.clone();
+
+        input.next() == Some('.') && input.next() == Some('.') && input.next() == Some('.')
+    }
+
     fn skip_comment(&mut self) {
         let Some(ch) = self.peek_char() else {
             return;
         };
 
-        if *ch == '#' {
-            while self.next_char() != Some('\n') {}
+        if *ch == '#' && !self.is_attribute_start() {
+            let location = self.location;
+            let mut text = String::new();
 
-            self.location.new_line();
+            // '\n' after the comment is still a token, so the comment doesn't join lines
+            while self.peek_char().is_some_and(|ch| *ch != '\n') {
+                text.extend(self.next_char());
+            }
+
+            self.comments.push(Comment {
+                text: text.trim_end().to_string(),
+                location,
+            });
         }
     }
 
@@ -155,6 +188,10 @@ impl<'a> Lexer<'a> {
             self.skip_spaces();
             self.skip_comment();
 
+            if self.is_attribute_start() {
+                break;
+            }
+
             let ch = self.peek_char();
 
             if ch.is_some_and(|ch| *ch != '#' && !ch.is_ascii_whitespace() || *ch == '\n')
@@ -164,6 +201,9 @@ impl<'a> Lexer<'a> {
             }
         }
 
+        // Tokens are located by their first character
+        let start_location = self.location;
+
         let next_char = self.peek_char()?;
 
         let lexem = match next_char {
@@ -196,8 +236,15 @@ impl<'a> Lexer<'a> {
                 Lexeme::Rcb
             }
             '.' => {
-                self.next_char();
-                Lexeme::Dot
+                if self.is_ellipsis_start() {
+                    self.next_char();
+                    self.next_char();
+                    self.next_char();
+                    Lexeme::Ellipsis
+                } else {
+                    self.next_char();
+                    Lexeme::Dot
+                }
             }
             ',' => {
                 self.next_char();
@@ -357,6 +404,11 @@ impl<'a> Lexer<'a> {
 
                 lexeme
             }
+            '#' => {
+                self.next_char();
+                self.next_char();
+                Lexeme::HashLsb
+            }
             ':' => {
                 self.next_char();
                 let mut lexeme = Lexeme::Colon;
@@ -375,7 +427,7 @@ impl<'a> Lexer<'a> {
             _ => Lexeme::Unknown,
         };
 
-        Some(Token::new(lexem, *self.location_ref()))
+        Some(Token::new(lexem, start_location, *self.location_ref()))
     }
 
     fn get_numeric_lexem(&mut self) -> Option<Lexeme> {
@@ -487,71 +539,152 @@ mod tests {
         let mut tkn = lexer.get().unwrap();
         let mut location = Location::new(&test_path);
 
-        location.col = 1;
+        location.col = 0;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::Plus);
         assert_eq!(tkn.get_location(), location);
 
         tkn = lexer.get().unwrap();
-        location.col = 7;
+        location.col = 2;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::identifier("hello"));
         assert_eq!(tkn.get_location(), location);
+        location.col = 7;
+        assert_eq!(tkn.get_end_location(), location);
 
         tkn = lexer.get().unwrap();
-        location.col = 12;
+        location.col = 8;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::KwFunc);
         assert_eq!(tkn.get_location(), location);
 
         tkn = lexer.get().unwrap();
-        location.col = 16;
+        location.col = 13;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::KwVar);
         assert_eq!(tkn.get_location(), location);
 
         tkn = lexer.get().unwrap();
-        location.col = 19;
+        location.col = 17;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::integer(65));
         assert_eq!(tkn.get_location(), location);
 
         tkn = lexer.get().unwrap();
-        location.col = 22;
+        location.col = 20;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::SubAssign);
         assert_eq!(tkn.get_location(), location);
 
         tkn = lexer.get().unwrap();
-        location.col = 25;
+        location.col = 23;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::LShift);
         assert_eq!(tkn.get_location(), location);
 
         tkn = lexer.get().unwrap();
-        location.row = 2;
-        location.col = 0;
+        location.col = 25;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::EndOfLine);
         assert_eq!(tkn.get_location(), location);
 
         tkn = lexer.get().unwrap();
-        location.col = 7;
+        location.row = 1;
+        location.col = 1;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::KwStruct);
         assert_eq!(tkn.get_location(), location);
 
         tkn = lexer.get().unwrap();
-        location.col = 13;
+        location.col = 8;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::identifier("alpha"));
         assert_eq!(tkn.get_location(), location);
 
         tkn = lexer.get().unwrap();
-        location.col = 18;
+        location.col = 14;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::KwSafe);
         assert_eq!(tkn.get_location(), location);
 
         tkn = lexer.get().unwrap();
-        location.col = 25;
+        location.col = 19;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::KwUnsafe);
         assert_eq!(tkn.get_location(), location);
 
         tkn = lexer.get().unwrap();
-        location.col = 30;
+        location.col = 26;
         assert_eq!(*tkn.lexeme_ref(), Lexeme::KwImpl);
         assert_eq!(tkn.get_location(), location);
 
         assert_eq!(lexer.get(), None);
     }
+
+    #[test]
+    fn lexer_attribute_test() {
+        const SRC_TEXT: &str = "# comment\n#[inline] # [not attribute\nfunc";
+
+        let test_path = PathBuf::from("test");
+
+        let mut lexer = Lexer::new(SRC_TEXT.chars().peekable(), &test_path);
+
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::EndOfLine);
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::HashLsb);
+        assert_eq!(
+            *lexer.get().unwrap().lexeme_ref(),
+            Lexeme::identifier("inline")
+        );
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::Rsb);
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::EndOfLine);
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::KwFunc);
+        assert_eq!(lexer.get(), None);
+    }
+
+    #[test]
+    fn lexer_comments_test() {
+        const SRC_TEXT: &str = "# first\nvar a = 1 # second  \n#third";
+
+        let test_path = PathBuf::from("test");
+
+        let mut lexer = Lexer::new(SRC_TEXT.chars().peekable(), &test_path);
+        let tokens = lexer.tokenize();
+
+        assert_eq!(tokens.len(), 6);
+        assert_eq!(*tokens[0].lexeme_ref(), Lexeme::EndOfLine);
+        assert_eq!(*tokens[4].lexeme_ref(), Lexeme::integer(1));
+        assert_eq!(*tokens[5].lexeme_ref(), Lexeme::EndOfLine);
+
+        let comments = lexer.take_comments();
+        assert_eq!(comments.len(), 3);
+
+        let mut location = Location::new(&test_path);
+        assert_eq!(comments[0].text, "# first");
+        assert_eq!(comments[0].location, location);
+
+        location.row = 1;
+        location.col = 10;
+        assert_eq!(comments[1].text, "# second");
+        assert_eq!(comments[1].location, location);
+
+        location.row = 2;
+        location.col = 0;
+        assert_eq!(comments[2].text, "#third");
+        assert_eq!(comments[2].location, location);
+
+        assert!(lexer.comments_ref().is_empty());
+    }
+
+    #[test]
+    fn lexer_ellipsis_test() {
+        const SRC_TEXT: &str = "(fmt: *u8, ...) a.b";
+
+        let test_path = PathBuf::from("test");
+
+        let mut lexer = Lexer::new(SRC_TEXT.chars().peekable(), &test_path);
+
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::LParen);
+        assert_eq!(
+            *lexer.get().unwrap().lexeme_ref(),
+            Lexeme::identifier("fmt")
+        );
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::Colon);
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::Star);
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::identifier("u8"));
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::Comma);
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::Ellipsis);
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::RParen);
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::identifier("a"));
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::Dot);
+        assert_eq!(*lexer.get().unwrap().lexeme_ref(), Lexeme::identifier("b"));
+        assert_eq!(lexer.get(), None);
+    }
 }
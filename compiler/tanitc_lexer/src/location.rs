@@ -1,8 +1,9 @@
+use serde::Serialize;
 use std::path::{Path, PathBuf};
 
 use tanitc_path::PathId;
 
-#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd, Ord, Serialize)]
 pub struct Location {
     pub path: PathId,
     pub row: usize,
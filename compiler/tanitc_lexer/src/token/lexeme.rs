@@ -1,8 +1,9 @@
+use serde::Serialize;
 use std::fmt::Display;
 
 use tanitc_ident::Ident;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub enum Lexeme {
     EndOfLine,
 
@@ -44,8 +45,10 @@ pub enum Lexeme {
     XorAssign,    // ^=
     Comma,        // ,
     Dot,          // .
+    Ellipsis,     // ...
     Colon,        // :
     Dcolon,       // ::
+    HashLsb,      // #[
 
     KwVar,
     KwFunc,
@@ -116,6 +119,10 @@ impl Lexeme {
         matches!(self, Self::Decimal(_))
     }
 
+    pub fn is_text(&self) -> bool {
+        matches!(self, Self::Text(_))
+    }
+
     pub fn get_string(&self) -> String {
         match self {
             Self::Identifier(val) => val.to_string(),
@@ -186,8 +193,10 @@ impl Display for Lexeme {
             Self::XorAssign => write!(f, "'^='"),
             Self::Comma => write!(f, "','"),
             Self::Dot => write!(f, "'.'"),
+            Self::Ellipsis => write!(f, "'...'"),
             Self::Colon => write!(f, "':'"),
             Self::Dcolon => write!(f, "'::'"),
+            Self::HashLsb => write!(f, "'#['"),
 
             Self::Lt => write!(f, "'<'"),
             Self::Lte => write!(f, "'<='"),
@@ -1,18 +1,24 @@
 use crate::{location::Location, token::lexeme::Lexeme};
+use serde::Serialize;
 use std::fmt::Display;
 use tanitc_ident::Ident;
 
 pub mod lexeme;
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct Token {
     lexeme: Lexeme,
     location: Location,
+    end_location: Location, // location right after the last character
 }
 
 impl Token {
-    pub fn new(lexeme: Lexeme, location: Location) -> Self {
-        Self { lexeme, location }
+    pub fn new(lexeme: Lexeme, location: Location, end_location: Location) -> Self {
+        Self {
+            lexeme,
+            location,
+            end_location,
+        }
     }
 
     pub fn is_identifier(&self) -> bool {
@@ -31,6 +37,10 @@ impl Token {
         self.location
     }
 
+    pub fn get_end_location(&self) -> Location {
+        self.end_location
+    }
+
     pub fn lexeme_ref(&self) -> &Lexeme {
         &self.lexeme
     }
@@ -0,0 +1,130 @@
+use std::fmt::Display;
+
+use tanitc_messages::codes::ErrorCode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LintLevel {
+    Allow,
+    Warn,
+    Deny,
+}
+
+impl LintLevel {
+    // Level set by `#[allow(...)]`, `#[warn(...)]` and `#[deny(...)]` attributes
+    pub fn from_attribute_name(name: &str) -> Option<Self> {
+        match name {
+            "allow" => Some(Self::Allow),
+            "warn" => Some(Self::Warn),
+            "deny" => Some(Self::Deny),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Allow => "allow",
+            Self::Warn => "warn",
+            Self::Deny => "deny",
+        }
+    }
+}
+
+impl Display for LintLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Lint {
+    pub name: &'static str,
+    pub default_level: LintLevel,
+    pub description: &'static str,
+    pub code: ErrorCode,
+}
+
+pub const DEAD_CODE: Lint = Lint {
+    name: "dead_code",
+    default_level: LintLevel::Warn,
+    description: "private functions, struct fields and enum units that are never used",
+    code: ErrorCode::DeadCode,
+};
+
+pub const DEPRECATED: Lint = Lint {
+    name: "deprecated",
+    default_level: LintLevel::Warn,
+    description: "use of items marked with #[deprecated]",
+    code: ErrorCode::Deprecated,
+};
+
+pub const LOSSY_CONVERSIONS: Lint = Lint {
+    name: "lossy_conversions",
+    default_level: LintLevel::Warn,
+    description: "numeric conversions that may lose data",
+    code: ErrorCode::LossyConversion,
+};
+
+pub const UNION_FIELD_MISMATCH: Lint = Lint {
+    name: "union_field_mismatch",
+    default_level: LintLevel::Warn,
+    description: "reads of union fields other than the last written one",
+    code: ErrorCode::UnionFieldMismatch,
+};
+
+pub const UNUSED_IMPORTS: Lint = Lint {
+    name: "unused_imports",
+    default_level: LintLevel::Warn,
+    description: "imports that are never used",
+    code: ErrorCode::UnusedImport,
+};
+
+pub const UNUSED_MUT: Lint = Lint {
+    name: "unused_mut",
+    default_level: LintLevel::Warn,
+    description: "mutable variables that are never mutated",
+    code: ErrorCode::UnusedMut,
+};
+
+pub const UNUSED_VARIABLES: Lint = Lint {
+    name: "unused_variables",
+    default_level: LintLevel::Warn,
+    description: "variables and parameters that are never used",
+    code: ErrorCode::UnusedVariable,
+};
+
+pub const LINTS: &[&Lint] = &[
+    &DEAD_CODE,
+    &DEPRECATED,
+    &LOSSY_CONVERSIONS,
+    &UNION_FIELD_MISMATCH,
+    &UNUSED_IMPORTS,
+    &UNUSED_MUT,
+    &UNUSED_VARIABLES,
+];
+
+pub fn find_lint(name: &str) -> Option<&'static Lint> {
+    LINTS.iter().copied().find(|lint| lint.name == name)
+}
+
+pub fn get_lint_names() -> String {
+    LINTS
+        .iter()
+        .map(|lint| lint.name)
+        .collect::<Vec<&str>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_lint_test() {
+        assert_eq!(find_lint("unused_imports"), Some(&UNUSED_IMPORTS));
+        assert_eq!(find_lint("unused_import"), None);
+
+        for lint in LINTS.iter() {
+            assert_eq!(LINTS.iter().filter(|l| l.name == lint.name).count(), 1);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+use std::path::Path;
+
+use tanitc_ast_lowering::AstLowering;
+use tanitc_hir::hir::Hir;
+use tanitc_hir_analyzer::{index::SymbolIndex, Analyzer};
+use tanitc_lexer::Lexer;
+use tanitc_messages::{listener::MessageListener, Message};
+use tanitc_options::{CompileOptions, CrateType};
+use tanitc_parser::Parser;
+
+// Result of the compilation of a document without code generation
+#[derive(Default)]
+pub struct Analysis {
+    pub errors: Vec<Message>,
+    pub warnings: Vec<Message>,
+    pub hir: Option<Box<Hir>>, // not set if the document can't be parsed
+    pub index: SymbolIndex,
+}
+
+impl Analysis {
+    pub fn run(path: &Path, text: &str) -> Self {
+        let mut analysis = Self::default();
+
+        let mut parser = Parser::new(Lexer::new(text.chars().peekable(), path));
+        let program_ctx = match parser.parse_program() {
+            Ok(program_ctx) => program_ctx,
+            Err(messages) => {
+                analysis.add_messages(&messages);
+                return analysis;
+            }
+        };
+        analysis.add_messages(parser.messages_ref());
+
+        let mut lowering = AstLowering::new();
+        let mut hir = match lowering.low(&program_ctx) {
+            Ok(hir) => hir,
+            Err(messages) => {
+                analysis.add_messages(&messages);
+                return analysis;
+            }
+        };
+        analysis.add_messages(lowering.messages_ref());
+
+        // Document may be a module of a program, so entry point is not required
+        let mut analyzer = Analyzer::with_compile_options(CompileOptions {
+            crate_type: CrateType::StaticLib,
+            ..Default::default()
+        });
+
+        match analyzer.analyze_program(&mut hir) {
+            Ok(_) => analysis.add_messages(analyzer.messages_ref()),
+            Err(messages) => analysis.add_messages(&messages),
+        }
+
+        analysis.index = analyzer.index_ref().clone();
+        analysis.hir = Some(hir);
+
+        analysis
+    }
+
+    fn add_messages(&mut self, messages: &MessageListener) {
+        self.errors.extend(messages.errors_ref().iter().cloned());
+        self.warnings
+            .extend(messages.warnings_ref().iter().cloned());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn analysis_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                              \n    var count: i32 = 1\
+                              \n    var _copy: i32 = cont\
+                              \n}";
+
+        // When
+        let analysis = Analysis::run(&PathBuf::from("analysis_test.tt"), SRC_TEXT);
+
+        // Then
+        assert!(analysis.hir.is_some());
+        assert_eq!(analysis.errors.len(), 1);
+        assert_eq!(
+            analysis.errors[0].text,
+            "Semantic error: undefined id: \"cont\""
+        );
+        assert!(analysis
+            .index
+            .definitions()
+            .iter()
+            .any(|def| def.id.to_string() == "count" && def.is_local));
+    }
+
+    #[test]
+    fn syntax_error_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                              \n    var x: = 5\
+                              \n}";
+
+        // When
+        let analysis = Analysis::run(&PathBuf::from("syntax_error_test.tt"), SRC_TEXT);
+
+        // Then
+        assert!(analysis.hir.is_none());
+        assert!(!analysis.errors.is_empty());
+    }
+}
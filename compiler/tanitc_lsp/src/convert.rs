@@ -0,0 +1,176 @@
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use lsp_types::{
+    Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, NumberOrString, Position, Range,
+    Uri,
+};
+use tanitc_lexer::location::Location;
+use tanitc_messages::{render::get_mark_width, Message};
+use tanitc_path::PathId;
+
+// Compiler counts columns in characters, LSP counts them in UTF-16 code units
+pub fn to_position(location: &Location) -> Position {
+    let character = match location.path.get_source_line(location.row) {
+        Some(line) => line
+            .chars()
+            .take(location.col)
+            .map(char::len_utf16)
+            .sum::<usize>(),
+        None => location.col,
+    };
+
+    Position::new(location.row as u32, character as u32)
+}
+
+pub fn to_location(path: PathId, position: Position) -> Location {
+    let row = position.line as usize;
+    let character = position.character as usize;
+
+    let col = match path.get_source_line(row) {
+        Some(line) => {
+            let mut units = 0;
+            line.chars()
+                .take_while(|ch| {
+                    units += ch.len_utf16();
+                    units <= character
+                })
+                .count()
+        }
+        None => character,
+    };
+
+    Location { path, row, col }
+}
+
+// Range of "width" characters starting at the location
+pub fn to_range(location: &Location, width: usize) -> Range {
+    let mut end = *location;
+    end.col += width;
+
+    Range::new(to_position(location), to_position(&end))
+}
+
+pub fn to_lsp_location(location: &Location, width: usize) -> Option<lsp_types::Location> {
+    let uri = path_to_uri(&location.path.as_path_buf())?;
+    Some(lsp_types::Location::new(uri, to_range(location, width)))
+}
+
+// Only "file" URIs can be compiled
+pub fn uri_to_path(uri: &Uri) -> Option<PathBuf> {
+    if !uri.scheme()?.as_str().eq_ignore_ascii_case("file") {
+        return None;
+    }
+
+    let path = uri.path().as_estr().decode().into_string().ok()?;
+    Some(PathBuf::from(path.as_ref()))
+}
+
+pub fn path_to_uri(path: &Path) -> Option<Uri> {
+    let path = std::path::absolute(path).ok()?;
+
+    let mut uri = String::from("file://");
+    for byte in path.to_string_lossy().bytes() {
+        match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'/' | b'-' | b'.' | b'_' | b'~' => {
+                uri.push(byte as char)
+            }
+            _ => uri.push_str(&format!("%{byte:02X}")),
+        }
+    }
+
+    Uri::from_str(&uri).ok()
+}
+
+// Messages without location or located in other files are shown at the start of the document
+pub fn to_diagnostic(msg: &Message, severity: DiagnosticSeverity, path: PathId) -> Diagnostic {
+    let mut text = msg.text.clone();
+    for note in msg.notes.iter() {
+        text.push_str(&format!("\n{}: {}", note.kind, note.text));
+    }
+
+    let range = match msg.location {
+        Some(location) if location.path == path => {
+            let width = msg
+                .mark_width
+                .map(|width| width as usize)
+                .or_else(|| {
+                    let line = location.path.get_source_line(location.row)?;
+                    Some(get_mark_width(&line, &location))
+                })
+                .unwrap_or(1);
+
+            to_range(&location, width)
+        }
+        Some(location) => {
+            text = format!("{location}: {text}");
+            Range::default()
+        }
+        None => Range::default(),
+    };
+
+    let related_information = msg
+        .labels
+        .iter()
+        .filter_map(|label| {
+            Some(DiagnosticRelatedInformation {
+                location: to_lsp_location(&label.location, 1)?,
+                message: label.text.clone(),
+            })
+        })
+        .collect::<Vec<_>>();
+
+    Diagnostic {
+        range,
+        severity: Some(severity),
+        code: msg
+            .code
+            .map(|code| NumberOrString::String(code.as_str().to_string())),
+        source: Some("tanitc".to_string()),
+        message: text,
+        related_information: (!related_information.is_empty()).then_some(related_information),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_test() {
+        // Given
+        let path = PathId::from(PathBuf::from("/tmp/position_test.tt"));
+        path.set_source("func main() {\n    var s = \"😀😀\" + x\n}");
+
+        // Each emoji takes two UTF-16 code units
+        let location = Location {
+            path,
+            row: 1,
+            col: 19,
+        };
+
+        // When
+        let position = to_position(&location);
+        let converted = to_location(path, position);
+
+        // Then
+        assert_eq!(position, Position::new(1, 21));
+        assert_eq!(converted, location);
+    }
+
+    #[test]
+    fn uri_test() {
+        // Given
+        let path = PathBuf::from("/tmp/my project/main.tt");
+
+        // When
+        let uri = path_to_uri(&path).expect("Expected URI");
+
+        // Then
+        assert_eq!(uri.as_str(), "file:///tmp/my%20project/main.tt");
+        assert_eq!(uri_to_path(&uri), Some(path));
+    }
+}
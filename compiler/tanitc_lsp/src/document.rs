@@ -0,0 +1,299 @@
+use std::collections::BTreeMap;
+
+use lsp_types::{
+    CompletionItem, CompletionItemKind, Diagnostic, DiagnosticSeverity, DocumentSymbol,
+    GotoDefinitionResponse, Hover, HoverContents, MarkupContent, MarkupKind, Position, Range,
+    SymbolKind,
+};
+use tanitc_hir::hir::type_spec::Type;
+use tanitc_hir_analyzer::index::SymbolDefinition;
+use tanitc_lexer::location::Location;
+use tanitc_path::PathId;
+
+use crate::{
+    analysis::Analysis,
+    convert::{to_diagnostic, to_location, to_lsp_location, to_position, to_range},
+    outline::{get_enclosing_items, get_outline, OutlineItem},
+};
+
+// Opened document, text of the document is stored as the source of its path
+pub struct Document {
+    pub path: PathId,
+    pub version: i32,
+    pub analysis: Analysis,
+}
+
+impl Document {
+    pub fn new(path: PathId, version: i32, text: &str) -> Self {
+        path.set_source(text);
+
+        Self {
+            path,
+            version,
+            analysis: Analysis::run(&path.as_path_buf(), text),
+        }
+    }
+
+    // Names of the last parsed version are used while the document has syntax errors
+    pub fn update(&mut self, version: i32, text: &str) {
+        self.path.set_source(text);
+
+        let mut analysis = Analysis::run(&self.path.as_path_buf(), text);
+        if analysis.hir.is_none() {
+            analysis.hir = self.analysis.hir.take();
+            analysis.index = std::mem::take(&mut self.analysis.index);
+        }
+
+        self.version = version;
+        self.analysis = analysis;
+    }
+
+    pub fn get_diagnostics(&self) -> Vec<Diagnostic> {
+        let errors = self
+            .analysis
+            .errors
+            .iter()
+            .map(|msg| to_diagnostic(msg, DiagnosticSeverity::ERROR, self.path));
+
+        let warnings = self
+            .analysis
+            .warnings
+            .iter()
+            .map(|msg| to_diagnostic(msg, DiagnosticSeverity::WARNING, self.path));
+
+        errors.chain(warnings).collect()
+    }
+
+    pub fn get_hover(&self, position: Position) -> Option<Hover> {
+        let location = to_location(self.path, position);
+
+        let (text, range) = match self.analysis.index.find_reference(location) {
+            Some(reference) => (
+                get_hover_text(reference.kind, &reference.name, reference.ty.as_ref()),
+                to_range(&reference.location, reference.width),
+            ),
+            None => {
+                let (definition, name_location) = self.find_definition_at(&location)?;
+                let name = definition.id.to_string();
+
+                (
+                    get_hover_text(definition.kind, &name, definition.ty.as_ref()),
+                    to_range(&name_location, name.chars().count()),
+                )
+            }
+        };
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```tanit\n{text}\n```"),
+            }),
+            range: Some(range),
+        })
+    }
+
+    pub fn get_definition(&self, position: Position) -> Option<GotoDefinitionResponse> {
+        let location = to_location(self.path, position);
+        let reference = self.analysis.index.find_reference(location)?;
+
+        let definition = self
+            .analysis
+            .index
+            .definitions()
+            .iter()
+            .find(|definition| definition.location == reference.definition);
+
+        let lsp_location = match definition {
+            Some(definition) => {
+                let name = definition.id.to_string();
+                let name_location = find_name(&definition.location, &name);
+                to_lsp_location(&name_location, name.chars().count())?
+            }
+            None => to_lsp_location(&reference.definition, 0)?,
+        };
+
+        Some(GotoDefinitionResponse::Scalar(lsp_location))
+    }
+
+    pub fn get_symbols(&self) -> Vec<DocumentSymbol> {
+        let end = self.get_end_location();
+
+        self.get_outline()
+            .iter()
+            .map(|item| to_document_symbol(item, end))
+            .collect()
+    }
+
+    // Locals of the enclosing function and items of the enclosing module
+    pub fn get_completion(&self, position: Position) -> Vec<CompletionItem> {
+        let location = to_location(self.path, position);
+        let outline = self.get_outline();
+        let enclosing = get_enclosing_items(&outline, &location);
+
+        let module = enclosing
+            .iter()
+            .filter(|item| item.kind == SymbolKind::MODULE)
+            .map(|item| item.name.clone());
+
+        let module: Vec<String> = module.collect();
+
+        let func = enclosing
+            .iter()
+            .rev()
+            .find(|item| matches!(item.kind, SymbolKind::FUNCTION | SymbolKind::METHOD));
+
+        let is_visible = |definition: &SymbolDefinition| {
+            if definition.is_local {
+                definition.location.path == self.path
+                    && definition.location < location
+                    && func.is_some_and(|func| func.contains(&definition.location))
+            } else {
+                definition
+                    .module
+                    .iter()
+                    .map(ToString::to_string)
+                    .eq(module.iter().cloned())
+            }
+        };
+
+        // Locals shadow items with the same name
+        let mut definitions: Vec<&SymbolDefinition> = self
+            .analysis
+            .index
+            .definitions()
+            .iter()
+            .filter(|definition| is_visible(definition))
+            .collect();
+        definitions.sort_by_key(|definition| definition.is_local);
+
+        let mut items = BTreeMap::<String, CompletionItem>::new();
+        for definition in definitions {
+            let name = definition.id.to_string();
+
+            items.insert(
+                name.clone(),
+                CompletionItem {
+                    label: name,
+                    kind: Some(get_completion_kind(definition.kind)),
+                    detail: definition.ty.as_ref().map(ToString::to_string),
+                    ..Default::default()
+                },
+            );
+        }
+
+        items.into_values().collect()
+    }
+
+    fn get_outline(&self) -> Vec<OutlineItem> {
+        self.analysis
+            .hir
+            .as_ref()
+            .map(|hir| get_outline(hir))
+            .unwrap_or_default()
+    }
+
+    fn find_definition_at(&self, location: &Location) -> Option<(&SymbolDefinition, Location)> {
+        self.analysis
+            .index
+            .definitions()
+            .iter()
+            .filter(|definition| definition.location.path == self.path)
+            .find_map(|definition| {
+                let name = definition.id.to_string();
+                let name_location = find_name(&definition.location, &name);
+                let is_inside = name_location.row == location.row
+                    && (name_location.col..name_location.col + name.chars().count())
+                        .contains(&location.col);
+
+                is_inside.then_some((definition, name_location))
+            })
+    }
+
+    fn get_end_location(&self) -> Location {
+        let source = self.path.get_source().unwrap_or_default();
+        let row = source.lines().count().saturating_sub(1);
+        let col = source.lines().last().map_or(0, |line| line.chars().count());
+
+        Location {
+            path: self.path,
+            row,
+            col,
+        }
+    }
+}
+
+// Definitions are located at their keywords ("var", "func"), names follow them
+fn find_name(location: &Location, name: &str) -> Location {
+    const MAX_ROWS: usize = 3;
+
+    let name: Vec<char> = name.chars().collect();
+    let is_word_char = |ch: &char| ch.is_alphanumeric() || *ch == '_';
+
+    for row in location.row..location.row + MAX_ROWS {
+        let Some(line) = location.path.get_source_line(row) else {
+            break;
+        };
+
+        let line: Vec<char> = line.chars().collect();
+        let first_col = if row == location.row { location.col } else { 0 };
+
+        for col in first_col..line.len() {
+            let is_start = col == 0 || !is_word_char(&line[col - 1]);
+            let is_end = line
+                .get(col + name.len())
+                .is_none_or(|ch| !is_word_char(ch));
+
+            if is_start && is_end && line[col..].starts_with(&name) {
+                return Location {
+                    path: location.path,
+                    row,
+                    col,
+                };
+            }
+        }
+    }
+
+    *location
+}
+
+fn get_hover_text(kind: &str, name: &str, ty: Option<&Type>) -> String {
+    match ty {
+        Some(ty) => format!("{kind} {name}: {ty}"),
+        None => format!("{kind} {name}"),
+    }
+}
+
+fn get_completion_kind(kind: &str) -> CompletionItemKind {
+    match kind {
+        "variable" => CompletionItemKind::VARIABLE,
+        "function" => CompletionItemKind::FUNCTION,
+        "method" => CompletionItemKind::METHOD,
+        "struct" | "union" => CompletionItemKind::STRUCT,
+        "enum" | "variant" => CompletionItemKind::ENUM,
+        "enum unit" | "variant unit" => CompletionItemKind::ENUM_MEMBER,
+        "module" => CompletionItemKind::MODULE,
+        "alias" => CompletionItemKind::TYPE_PARAMETER,
+        _ => CompletionItemKind::TEXT,
+    }
+}
+
+#[allow(deprecated)] // "deprecated" field is replaced by tags, but still must be set
+fn to_document_symbol(item: &OutlineItem, parent_end: Location) -> DocumentSymbol {
+    let end = item.end.unwrap_or(parent_end);
+    let children: Vec<DocumentSymbol> = item
+        .children
+        .iter()
+        .map(|child| to_document_symbol(child, end))
+        .collect();
+
+    DocumentSymbol {
+        name: item.name.clone(),
+        detail: None,
+        kind: item.kind,
+        tags: None,
+        deprecated: None,
+        range: Range::new(to_position(&item.location), to_position(&end)),
+        selection_range: to_range(&item.name_location, item.name.chars().count()),
+        children: (!children.is_empty()).then_some(children),
+    }
+}
@@ -0,0 +1,29 @@
+pub mod analysis;
+pub mod convert;
+pub mod document;
+pub mod outline;
+pub mod server;
+
+use lsp_server::Connection;
+
+use crate::server::{Server, ServerResult};
+
+// Language server, the client talks to it through stdin and stdout
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
+}
+
+fn run() -> ServerResult<()> {
+    let (connection, io_threads) = Connection::stdio();
+
+    let capabilities = serde_json::to_value(Server::get_capabilities())?;
+    connection.initialize(capabilities)?;
+
+    Server::new(connection).run()?;
+    io_threads.join()?;
+
+    Ok(())
+}
@@ -0,0 +1,150 @@
+use lsp_types::SymbolKind;
+use tanitc_hir::hir::{
+    definitions::{functions::FunctionDef, modules::ModuleDefBody, Definition},
+    Hir,
+};
+use tanitc_lexer::location::Location;
+use tanitc_name::NameSpec;
+
+// Named definition of the document. Definitions have no end location,
+// so each of them lasts until the next statement of the same block
+#[derive(Debug, Clone)]
+pub struct OutlineItem {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub location: Location,      // start of the definition
+    pub name_location: Location, // start of the name of the definition
+    pub end: Option<Location>,   // None if the item lasts until the end of the parent
+    pub children: Vec<OutlineItem>,
+}
+
+impl OutlineItem {
+    pub fn contains(&self, location: &Location) -> bool {
+        self.location <= *location && self.end.is_none_or(|end| *location < end)
+    }
+}
+
+pub fn get_outline(hir: &Hir) -> Vec<OutlineItem> {
+    match hir {
+        Hir::Block(block) => get_block_outline(&block.statements, None),
+        _ => vec![],
+    }
+}
+
+// Returns items that contain the location, the outermost item first
+pub fn get_enclosing_items<'a>(
+    items: &'a [OutlineItem],
+    location: &Location,
+) -> Vec<&'a OutlineItem> {
+    let mut enclosing = Vec::<&OutlineItem>::new();
+    let mut items = items;
+
+    while let Some(item) = items.iter().find(|item| item.contains(location)) {
+        enclosing.push(item);
+        items = &item.children;
+    }
+
+    enclosing
+}
+
+fn get_block_outline(statements: &[Hir], block_end: Option<Location>) -> Vec<OutlineItem> {
+    let mut items = Vec::<OutlineItem>::new();
+
+    for (index, statement) in statements.iter().enumerate() {
+        let end = statements.get(index + 1).map(Hir::location).or(block_end);
+
+        if let Hir::Definition(definition) = statement {
+            items.append(&mut get_definition_outline(definition, end));
+        }
+    }
+
+    items
+}
+
+fn get_definition_outline(definition: &Definition, end: Option<Location>) -> Vec<OutlineItem> {
+    let location = definition.location();
+
+    let mut item = match definition {
+        Definition::Module(node) => {
+            let mut item = create_item(&node.name, SymbolKind::MODULE, location, end);
+
+            // Bodies of external modules are placed in other files
+            if let ModuleDefBody::Internal(body) = &node.body {
+                item.children = get_block_outline(&body.statements, end);
+            }
+
+            item
+        }
+        Definition::Struct(node) => create_item(&node.name, SymbolKind::STRUCT, location, end),
+        Definition::Union(node) => create_item(&node.name, SymbolKind::STRUCT, location, end),
+        Definition::Variant(node) => create_item(&node.name, SymbolKind::ENUM, location, end),
+        Definition::Enum(node) => create_item(&node.name, SymbolKind::ENUM, location, end),
+        Definition::Alias(node) => {
+            create_item(&node.name, SymbolKind::TYPE_PARAMETER, location, end)
+        }
+        Definition::Func(node) => create_func_item(node, SymbolKind::FUNCTION, end),
+        Definition::Impl(node) => {
+            let mut item = create_item(&node.name, SymbolKind::OBJECT, location, end);
+            item.name = format!("impl {}", item.name);
+            item.name_location = location;
+            item.children = get_functions_outline(&node.methods, SymbolKind::METHOD, end);
+            item
+        }
+        Definition::Extern(node) => {
+            return get_functions_outline(&node.functions, SymbolKind::FUNCTION, end)
+        }
+        Definition::Variable(node) => OutlineItem {
+            name: node.identifier.to_string(),
+            kind: SymbolKind::VARIABLE,
+            location,
+            name_location: location,
+            end,
+            children: vec![],
+        },
+    };
+
+    item.children
+        .retain(|child| child.location.path == location.path);
+
+    vec![item]
+}
+
+fn get_functions_outline(
+    functions: &[FunctionDef],
+    kind: SymbolKind,
+    end: Option<Location>,
+) -> Vec<OutlineItem> {
+    functions
+        .iter()
+        .enumerate()
+        .map(|(index, func_def)| {
+            let func_end = functions.get(index + 1).map(|next| next.location).or(end);
+
+            create_func_item(func_def, kind, func_end)
+        })
+        .collect()
+}
+
+fn create_func_item(
+    func_def: &FunctionDef,
+    kind: SymbolKind,
+    end: Option<Location>,
+) -> OutlineItem {
+    create_item(&func_def.name, kind, func_def.location, end)
+}
+
+fn create_item(
+    name: &NameSpec,
+    kind: SymbolKind,
+    location: Location,
+    end: Option<Location>,
+) -> OutlineItem {
+    OutlineItem {
+        name: name.get_id().map(|id| id.to_string()).unwrap_or_default(),
+        kind,
+        location,
+        name_location: name.location,
+        end,
+        children: vec![],
+    }
+}
@@ -0,0 +1,374 @@
+use std::{collections::HashMap, error::Error, path::PathBuf};
+
+use lsp_server::{Connection, ErrorCode, Message, Notification, Request, Response};
+use lsp_types::{
+    notification::{
+        DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument,
+        Notification as LspNotification, PublishDiagnostics,
+    },
+    request::{
+        Completion, DocumentSymbolRequest, GotoDefinition, HoverRequest, Request as LspRequest,
+    },
+    CompletionOptions, CompletionParams, CompletionResponse, DocumentSymbolParams,
+    DocumentSymbolResponse, GotoDefinitionParams, GotoDefinitionResponse, Hover, HoverParams,
+    HoverProviderCapability, OneOf, PublishDiagnosticsParams, ServerCapabilities,
+    TextDocumentSyncCapability, TextDocumentSyncKind, Uri,
+};
+use tanitc_path::PathId;
+
+use crate::{convert::uri_to_path, document::Document};
+
+pub type ServerResult<T> = Result<T, Box<dyn Error + Send + Sync>>;
+
+pub struct Server {
+    connection: Connection,
+    documents: HashMap<Uri, Document>,
+}
+
+impl Server {
+    pub fn new(connection: Connection) -> Self {
+        Self {
+            connection,
+            documents: HashMap::new(),
+        }
+    }
+
+    pub fn get_capabilities() -> ServerCapabilities {
+        ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Kind(TextDocumentSyncKind::FULL)),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            document_symbol_provider: Some(OneOf::Left(true)),
+            completion_provider: Some(CompletionOptions::default()),
+            ..Default::default()
+        }
+    }
+
+    // Handles messages until the client shuts the server down
+    pub fn run(&mut self) -> ServerResult<()> {
+        while let Ok(msg) = self.connection.receiver.recv() {
+            match msg {
+                Message::Request(req) => {
+                    if self.connection.handle_shutdown(&req)? {
+                        return Ok(());
+                    }
+
+                    let response = self.handle_request(req);
+                    self.connection.sender.send(response.into())?;
+                }
+                Message::Notification(notification) => self.handle_notification(notification)?,
+                Message::Response(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Requests
+impl Server {
+    fn handle_request(&self, req: Request) -> Response {
+        match req.method.as_str() {
+            HoverRequest::METHOD => self.on_request::<HoverRequest>(req, Self::hover),
+            GotoDefinition::METHOD => self.on_request::<GotoDefinition>(req, Self::definition),
+            DocumentSymbolRequest::METHOD => {
+                self.on_request::<DocumentSymbolRequest>(req, Self::document_symbols)
+            }
+            Completion::METHOD => self.on_request::<Completion>(req, Self::completion),
+            _ => Response::new_err(
+                req.id,
+                ErrorCode::MethodNotFound as i32,
+                format!("Unsupported request \"{}\"", req.method),
+            ),
+        }
+    }
+
+    fn on_request<R: LspRequest>(
+        &self,
+        req: Request,
+        handler: fn(&Self, R::Params) -> R::Result,
+    ) -> Response {
+        match serde_json::from_value::<R::Params>(req.params) {
+            Ok(params) => Response::new_ok(req.id, handler(self, params)),
+            Err(err) => Response::new_err(req.id, ErrorCode::InvalidParams as i32, err.to_string()),
+        }
+    }
+
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let position = params.text_document_position_params;
+        self.documents
+            .get(&position.text_document.uri)?
+            .get_hover(position.position)
+    }
+
+    fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let position = params.text_document_position_params;
+        self.documents
+            .get(&position.text_document.uri)?
+            .get_definition(position.position)
+    }
+
+    fn document_symbols(&self, params: DocumentSymbolParams) -> Option<DocumentSymbolResponse> {
+        let document = self.documents.get(&params.text_document.uri)?;
+        Some(DocumentSymbolResponse::Nested(document.get_symbols()))
+    }
+
+    fn completion(&self, params: CompletionParams) -> Option<CompletionResponse> {
+        let position = params.text_document_position;
+        let document = self.documents.get(&position.text_document.uri)?;
+        Some(CompletionResponse::Array(
+            document.get_completion(position.position),
+        ))
+    }
+}
+
+// Notifications
+impl Server {
+    fn handle_notification(&mut self, notification: Notification) -> ServerResult<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params = notification
+                    .extract::<lsp_types::DidOpenTextDocumentParams>(DidOpenTextDocument::METHOD)?;
+                let document = params.text_document;
+
+                let path = PathId::from(get_document_path(&document.uri));
+                self.documents.insert(
+                    document.uri.clone(),
+                    Document::new(path, document.version, &document.text),
+                );
+
+                self.publish_diagnostics(&document.uri)
+            }
+            DidChangeTextDocument::METHOD => {
+                let params = notification.extract::<lsp_types::DidChangeTextDocumentParams>(
+                    DidChangeTextDocument::METHOD,
+                )?;
+                let uri = params.text_document.uri;
+
+                // Documents are synchronized fully, so the last change is the whole text
+                let (Some(document), Some(change)) =
+                    (self.documents.get_mut(&uri), params.content_changes.last())
+                else {
+                    return Ok(());
+                };
+
+                document.update(params.text_document.version, &change.text);
+
+                self.publish_diagnostics(&uri)
+            }
+            DidCloseTextDocument::METHOD => {
+                let params = notification.extract::<lsp_types::DidCloseTextDocumentParams>(
+                    DidCloseTextDocument::METHOD,
+                )?;
+                let uri = params.text_document.uri;
+
+                self.documents.remove(&uri);
+                self.send_diagnostics(uri, vec![], None)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn publish_diagnostics(&self, uri: &Uri) -> ServerResult<()> {
+        let Some(document) = self.documents.get(uri) else {
+            return Ok(());
+        };
+
+        self.send_diagnostics(
+            uri.clone(),
+            document.get_diagnostics(),
+            Some(document.version),
+        )
+    }
+
+    fn send_diagnostics(
+        &self,
+        uri: Uri,
+        diagnostics: Vec<lsp_types::Diagnostic>,
+        version: Option<i32>,
+    ) -> ServerResult<()> {
+        let params = PublishDiagnosticsParams {
+            uri,
+            diagnostics,
+            version,
+        };
+
+        self.connection
+            .sender
+            .send(Notification::new(PublishDiagnostics::METHOD.to_string(), params).into())?;
+
+        Ok(())
+    }
+}
+
+// Documents that are not files are named by their URIs
+fn get_document_path(uri: &Uri) -> PathBuf {
+    uri_to_path(uri).unwrap_or_else(|| PathBuf::from(uri.as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use lsp_server::RequestId;
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    /* Runs the server on the memory connection, returns the client side of it */
+    fn start_server() -> (Connection, thread::JoinHandle<()>) {
+        let (server, client) = Connection::memory();
+
+        let handle = thread::spawn(move || {
+            let capabilities = serde_json::to_value(Server::get_capabilities()).unwrap();
+            server.initialize(capabilities).unwrap();
+            Server::new(server).run().unwrap();
+        });
+
+        (client, handle)
+    }
+
+    fn send_request(client: &Connection, id: i32, method: &str, params: Value) -> Value {
+        let req = Request::new(RequestId::from(id), method.to_string(), params);
+        client.sender.send(req.into()).unwrap();
+
+        loop {
+            if let Message::Response(response) = client.receiver.recv().unwrap() {
+                assert_eq!(response.id, RequestId::from(id));
+                assert!(response.error.is_none(), "{:?}", response.error);
+                return response.result.unwrap_or_default();
+            }
+        }
+    }
+
+    fn send_notification(client: &Connection, method: &str, params: Value) {
+        let notification = Notification::new(method.to_string(), params);
+        client.sender.send(notification.into()).unwrap();
+    }
+
+    fn receive_notification(client: &Connection) -> Notification {
+        loop {
+            if let Message::Notification(notification) = client.receiver.recv().unwrap() {
+                return notification;
+            }
+        }
+    }
+
+    #[test]
+    fn server_test() {
+        const URI: &str = "file:///server_test/main.tt";
+        const SRC_TEXT: &str = "func sum(a: i32, b: i32): i32 {\
+                              \n    return a + b\
+                              \n}\
+                              \nfunc main() {\
+                              \n    var count: i32 = sum(1, 2)\
+                              \n    var _copy: i32 = cont\
+                              \n}";
+
+        let (client, handle) = start_server();
+        let document = json!({ "uri": URI });
+
+        let result = send_request(&client, 1, "initialize", json!({ "capabilities": {} }));
+        assert!(result["capabilities"]["hoverProvider"].as_bool().unwrap());
+        send_notification(&client, "initialized", json!({}));
+
+        // Diagnostics
+        send_notification(
+            &client,
+            "textDocument/didOpen",
+            json!({
+                "textDocument": {
+                    "uri": URI,
+                    "languageId": "tanit",
+                    "version": 1,
+                    "text": SRC_TEXT,
+                }
+            }),
+        );
+
+        let notification = receive_notification(&client);
+        assert_eq!(notification.method, PublishDiagnostics::METHOD);
+        let diagnostics = &notification.params["diagnostics"];
+        assert_eq!(diagnostics.as_array().unwrap().len(), 2);
+        assert_eq!(diagnostics[0]["code"], "E0004");
+        assert_eq!(diagnostics[0]["severity"], 1);
+        assert_eq!(
+            diagnostics[0]["range"]["start"],
+            json!({ "line": 5, "character": 21 })
+        );
+        assert_eq!(diagnostics[1]["severity"], 2);
+        assert_eq!(diagnostics[1]["range"]["start"]["line"], 4);
+
+        // Hover over "count"
+        let result = send_request(
+            &client,
+            2,
+            "textDocument/hover",
+            json!({ "textDocument": document, "position": { "line": 4, "character": 10 } }),
+        );
+        assert_eq!(
+            result["contents"]["value"],
+            "```tanit\nvariable count: i32\n```"
+        );
+
+        // Definition of "sum"
+        let result = send_request(
+            &client,
+            3,
+            "textDocument/definition",
+            json!({ "textDocument": document, "position": { "line": 4, "character": 22 } }),
+        );
+        assert_eq!(result["uri"], URI);
+        assert_eq!(
+            result["range"]["start"],
+            json!({ "line": 0, "character": 5 })
+        );
+
+        // Outline
+        let result = send_request(
+            &client,
+            4,
+            "textDocument/documentSymbol",
+            json!({ "textDocument": document }),
+        );
+        let names: Vec<&str> = result
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|symbol| symbol["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["sum", "main"]);
+
+        // Completion inside of "main"
+        let result = send_request(
+            &client,
+            5,
+            "textDocument/completion",
+            json!({ "textDocument": document, "position": { "line": 5, "character": 21 } }),
+        );
+        let labels: Vec<&str> = result
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|item| item["label"].as_str().unwrap())
+            .collect();
+        assert!(labels.contains(&"count"));
+        assert!(labels.contains(&"sum"));
+        assert!(!labels.contains(&"a"));
+
+        // Unsupported request
+        let req = Request::new(RequestId::from(6), "unknown".to_string(), json!({}));
+        client.sender.send(req.into()).unwrap();
+        let Message::Response(response) = client.receiver.recv().unwrap() else {
+            panic!("Response expected");
+        };
+        assert_eq!(
+            response.error.unwrap().code,
+            ErrorCode::MethodNotFound as i32
+        );
+
+        send_request(&client, 7, "shutdown", Value::Null);
+        send_notification(&client, "exit", Value::Null);
+        handle.join().unwrap();
+    }
+}
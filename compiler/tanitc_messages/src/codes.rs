@@ -0,0 +1,1246 @@
+use std::fmt::Display;
+
+// Codes are stable: never renumber or reuse them, only add new ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ErrorCode {
+    UnexpectedToken,
+    UnexpectedEof,
+    MultipleIds,
+    UndefinedName,
+    UndefinedType,
+    UndefinedVariable,
+    UndefinedFunc,
+    UndefinedStruct,
+    UndefinedUnion,
+    ConstMutation,
+    ConstVarMutation,
+    ConstRefMutation,
+    NoIdInNamespace,
+    Unreachable,
+    Codegen,
+    EmptyNameSpec,
+    PrivateItem,
+    AmbiguousImport,
+    SuperOfRoot,
+    ModuleNotFound,
+    MisplacedAttribute,
+    MalformedAttribute,
+    ConflictingAttributes,
+    UnknownAttribute,
+    UnknownLint,
+    InvalidVariadic,
+    UnsupportedAbi,
+    MisplacedStatement,
+    StaticInitializer,
+    InvalidLiteral,
+    MisplacedSelf,
+    MismatchedTypes,
+    ArgumentMismatch,
+    NotCallable,
+    NotIndexable,
+    UnsafeRequired,
+    NotCCompatible,
+    InvalidOperand,
+    InvalidStructLiteral,
+    UndefinedField,
+    InvalidConversion,
+    InvalidEnumValue,
+    InvalidEnumRepr,
+    TypeAnnotationNeeded,
+    DisabledFeature,
+    InvalidImport,
+    InvalidPath,
+    InvalidEntryPoint,
+    DeadCode,
+    Deprecated,
+    LossyConversion,
+    UnionFieldMismatch,
+    UnusedImport,
+    UnusedMut,
+    UnusedVariable,
+}
+
+impl ErrorCode {
+    pub const ALL: [ErrorCode; 55] = [
+        Self::UnexpectedToken,
+        Self::UnexpectedEof,
+        Self::MultipleIds,
+        Self::UndefinedName,
+        Self::UndefinedType,
+        Self::UndefinedVariable,
+        Self::UndefinedFunc,
+        Self::UndefinedStruct,
+        Self::UndefinedUnion,
+        Self::ConstMutation,
+        Self::ConstVarMutation,
+        Self::ConstRefMutation,
+        Self::NoIdInNamespace,
+        Self::Unreachable,
+        Self::Codegen,
+        Self::EmptyNameSpec,
+        Self::PrivateItem,
+        Self::AmbiguousImport,
+        Self::SuperOfRoot,
+        Self::ModuleNotFound,
+        Self::MisplacedAttribute,
+        Self::MalformedAttribute,
+        Self::ConflictingAttributes,
+        Self::UnknownAttribute,
+        Self::UnknownLint,
+        Self::InvalidVariadic,
+        Self::UnsupportedAbi,
+        Self::MisplacedStatement,
+        Self::StaticInitializer,
+        Self::InvalidLiteral,
+        Self::MisplacedSelf,
+        Self::MismatchedTypes,
+        Self::ArgumentMismatch,
+        Self::NotCallable,
+        Self::NotIndexable,
+        Self::UnsafeRequired,
+        Self::NotCCompatible,
+        Self::InvalidOperand,
+        Self::InvalidStructLiteral,
+        Self::UndefinedField,
+        Self::InvalidConversion,
+        Self::InvalidEnumValue,
+        Self::InvalidEnumRepr,
+        Self::TypeAnnotationNeeded,
+        Self::DisabledFeature,
+        Self::InvalidImport,
+        Self::InvalidPath,
+        Self::InvalidEntryPoint,
+        Self::DeadCode,
+        Self::Deprecated,
+        Self::LossyConversion,
+        Self::UnionFieldMismatch,
+        Self::UnusedImport,
+        Self::UnusedMut,
+        Self::UnusedVariable,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::UnexpectedToken => "E0001",
+            Self::UnexpectedEof => "E0002",
+            Self::MultipleIds => "E0003",
+            Self::UndefinedName => "E0004",
+            Self::UndefinedType => "E0005",
+            Self::UndefinedVariable => "E0006",
+            Self::UndefinedFunc => "E0007",
+            Self::UndefinedStruct => "E0008",
+            Self::UndefinedUnion => "E0009",
+            Self::ConstMutation => "E0010",
+            Self::ConstVarMutation => "E0011",
+            Self::ConstRefMutation => "E0012",
+            Self::NoIdInNamespace => "E0013",
+            Self::Unreachable => "E0014",
+            Self::Codegen => "E0015",
+            Self::EmptyNameSpec => "E0016",
+            Self::PrivateItem => "E0017",
+            Self::AmbiguousImport => "E0018",
+            Self::SuperOfRoot => "E0019",
+            Self::ModuleNotFound => "E0020",
+            Self::MisplacedAttribute => "E0021",
+            Self::MalformedAttribute => "E0022",
+            Self::ConflictingAttributes => "E0023",
+            Self::UnknownAttribute => "E0024",
+            Self::UnknownLint => "E0025",
+            Self::InvalidVariadic => "E0026",
+            Self::UnsupportedAbi => "E0027",
+            Self::MisplacedStatement => "E0028",
+            Self::StaticInitializer => "E0029",
+            Self::InvalidLiteral => "E0030",
+            Self::MisplacedSelf => "E0031",
+            Self::MismatchedTypes => "E0032",
+            Self::ArgumentMismatch => "E0033",
+            Self::NotCallable => "E0034",
+            Self::NotIndexable => "E0035",
+            Self::UnsafeRequired => "E0036",
+            Self::NotCCompatible => "E0037",
+            Self::InvalidOperand => "E0038",
+            Self::InvalidStructLiteral => "E0039",
+            Self::UndefinedField => "E0040",
+            Self::InvalidConversion => "E0041",
+            Self::InvalidEnumValue => "E0042",
+            Self::InvalidEnumRepr => "E0043",
+            Self::TypeAnnotationNeeded => "E0044",
+            Self::DisabledFeature => "E0045",
+            Self::InvalidImport => "E0046",
+            Self::InvalidPath => "E0047",
+            Self::InvalidEntryPoint => "E0048",
+            Self::DeadCode => "W0001",
+            Self::Deprecated => "W0002",
+            Self::LossyConversion => "W0003",
+            Self::UnionFieldMismatch => "W0004",
+            Self::UnusedImport => "W0005",
+            Self::UnusedMut => "W0006",
+            Self::UnusedVariable => "W0007",
+        }
+    }
+
+    // Accepts "E0012" as well as "0012" and "e0012", codes of warnings need their "W"
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.to_ascii_uppercase();
+        let code = if code.starts_with(|c: char| c.is_ascii_digit()) {
+            format!("E{code}")
+        } else {
+            code
+        };
+
+        Self::ALL.into_iter().find(|known| known.as_str() == code)
+    }
+
+    pub fn get_explanation(&self) -> &'static str {
+        match self {
+            Self::UnexpectedToken => UNEXPECTED_TOKEN,
+            Self::UnexpectedEof => UNEXPECTED_EOF,
+            Self::MultipleIds => MULTIPLE_IDS,
+            Self::UndefinedName => UNDEFINED_NAME,
+            Self::UndefinedType => UNDEFINED_TYPE,
+            Self::UndefinedVariable => UNDEFINED_VARIABLE,
+            Self::UndefinedFunc => UNDEFINED_FUNC,
+            Self::UndefinedStruct => UNDEFINED_STRUCT,
+            Self::UndefinedUnion => UNDEFINED_UNION,
+            Self::ConstMutation => CONST_MUTATION,
+            Self::ConstVarMutation => CONST_VAR_MUTATION,
+            Self::ConstRefMutation => CONST_REF_MUTATION,
+            Self::NoIdInNamespace => NO_ID_IN_NAMESPACE,
+            Self::Unreachable => UNREACHABLE,
+            Self::Codegen => CODEGEN,
+            Self::EmptyNameSpec => EMPTY_NAME_SPEC,
+            Self::PrivateItem => PRIVATE_ITEM,
+            Self::AmbiguousImport => AMBIGUOUS_IMPORT,
+            Self::SuperOfRoot => SUPER_OF_ROOT,
+            Self::ModuleNotFound => MODULE_NOT_FOUND,
+            Self::MisplacedAttribute => MISPLACED_ATTRIBUTE,
+            Self::MalformedAttribute => MALFORMED_ATTRIBUTE,
+            Self::ConflictingAttributes => CONFLICTING_ATTRIBUTES,
+            Self::UnknownAttribute => UNKNOWN_ATTRIBUTE,
+            Self::UnknownLint => UNKNOWN_LINT,
+            Self::InvalidVariadic => INVALID_VARIADIC,
+            Self::UnsupportedAbi => UNSUPPORTED_ABI,
+            Self::MisplacedStatement => MISPLACED_STATEMENT,
+            Self::StaticInitializer => STATIC_INITIALIZER,
+            Self::InvalidLiteral => INVALID_LITERAL,
+            Self::MisplacedSelf => MISPLACED_SELF,
+            Self::MismatchedTypes => MISMATCHED_TYPES,
+            Self::ArgumentMismatch => ARGUMENT_MISMATCH,
+            Self::NotCallable => NOT_CALLABLE,
+            Self::NotIndexable => NOT_INDEXABLE,
+            Self::UnsafeRequired => UNSAFE_REQUIRED,
+            Self::NotCCompatible => NOT_C_COMPATIBLE,
+            Self::InvalidOperand => INVALID_OPERAND,
+            Self::InvalidStructLiteral => INVALID_STRUCT_LITERAL,
+            Self::UndefinedField => UNDEFINED_FIELD,
+            Self::InvalidConversion => INVALID_CONVERSION,
+            Self::InvalidEnumValue => INVALID_ENUM_VALUE,
+            Self::InvalidEnumRepr => INVALID_ENUM_REPR,
+            Self::TypeAnnotationNeeded => TYPE_ANNOTATION_NEEDED,
+            Self::DisabledFeature => DISABLED_FEATURE,
+            Self::InvalidImport => INVALID_IMPORT,
+            Self::InvalidPath => INVALID_PATH,
+            Self::InvalidEntryPoint => INVALID_ENTRY_POINT,
+            Self::DeadCode => DEAD_CODE,
+            Self::Deprecated => DEPRECATED,
+            Self::LossyConversion => LOSSY_CONVERSION,
+            Self::UnionFieldMismatch => UNION_FIELD_MISMATCH,
+            Self::UnusedImport => UNUSED_IMPORT,
+            Self::UnusedMut => UNUSED_MUT,
+            Self::UnusedVariable => UNUSED_VARIABLE,
+        }
+    }
+}
+
+impl Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+const UNEXPECTED_TOKEN: &str = r#"The parser met a token that can not appear at this place.
+
+Erroneous code example:
+
+    func main() {
+        var value: = 5 # error: type is expected after the colon
+    }
+
+Either complete the construction or remove the stray token:
+
+    func main() {
+        var value: i32 = 5
+    }
+"#;
+
+const UNEXPECTED_EOF: &str = r#"The source file ended in the middle of a construction.
+
+Erroneous code example:
+
+    func main() {
+        var value: i32 = 5
+    # error: the body of "main" is not closed
+
+Close every block and finish every expression before the end of the file:
+
+    func main() {
+        var value: i32 = 5
+    }
+"#;
+
+const MULTIPLE_IDS: &str = r#"A name is defined more than once in the same scope.
+
+Erroneous code example:
+
+    func main() {
+        var value: i32 = 1
+        var value: i32 = 2 # error: "value" is already defined
+    }
+
+Each name can be defined only once in a scope. Rename one of the definitions:
+
+    func main() {
+        var value: i32 = 1
+        var other: i32 = 2
+    }
+"#;
+
+const UNDEFINED_NAME: &str = r#"A name is used, but it is not defined in any visible scope.
+
+Erroneous code example:
+
+    func main() {
+        var value: i32 = count # error: "count" is not defined
+    }
+
+Define the name before using it, or fix the spelling:
+
+    func main() {
+        var count: i32 = 1
+        var value: i32 = count
+    }
+"#;
+
+const UNDEFINED_TYPE: &str = r#"A type is used, but it is neither a built-in type nor a defined one.
+
+Erroneous code example:
+
+    func main() {
+        var value: Point # error: "Point" is not defined
+    }
+
+Define the type or use an existing one:
+
+    struct Point {
+        x: i32
+    }
+
+    func main() {
+        var value: Point
+    }
+"#;
+
+const UNDEFINED_VARIABLE: &str = r#"Something that is not a variable is assigned.
+
+Erroneous code example:
+
+    func run() {
+    }
+
+    func main() {
+        run = 1 # error: "run" is a function
+    }
+
+Only variables can be assigned. Define a variable for the value:
+
+    func main() {
+        var mut counter: i32 = 0
+        counter = 1
+    }
+"#;
+
+const UNDEFINED_FUNC: &str = r#"A function is called, but no function with this name is visible.
+
+Erroneous code example:
+
+    func main() {
+        run() # error: "run" is not defined
+    }
+
+Define the function, or import it from the module it belongs to:
+
+    func run() {
+    }
+
+    func main() {
+        run()
+    }
+"#;
+
+const UNDEFINED_STRUCT: &str = r#"A struct is used, but no struct with this name is visible.
+
+Erroneous code example:
+
+    func main() {
+        var p: Point = Point { x: 1 } # error: "Point" is not defined
+    }
+
+Define the struct before using it:
+
+    struct Point {
+        x: i32
+    }
+
+    func main() {
+        var p: Point = Point { x: 1 }
+    }
+"#;
+
+const UNDEFINED_UNION: &str = r#"A union is used, but no union with this name is visible.
+
+Erroneous code example:
+
+    func main() {
+        var n: Number = Number { i: 1 } # error: "Number" is not defined
+    }
+
+Define the union before using it:
+
+    union Number {
+        i: i32
+        f: f32
+    }
+
+    func main() {
+        var n: Number = Number { i: 1 }
+    }
+"#;
+
+const CONST_MUTATION: &str = r#"An immutable object is mutated.
+
+Erroneous code example:
+
+    func main() {
+        var value: i32 = 1
+        var r = &value
+        *r = 2 # error: the object is immutable
+    }
+
+Declare the object as mutable and take a mutable reference to it:
+
+    func main() {
+        var mut value: i32 = 1
+        var r = &mut value
+        *r = 2
+    }
+"#;
+
+const CONST_VAR_MUTATION: &str = r#"An immutable variable is assigned after its definition.
+
+Erroneous code example:
+
+    func main() {
+        var value: i32 = 1
+        value = 2 # error: "value" is immutable
+    }
+
+Variables are immutable by default. Declare the variable with "mut":
+
+    func main() {
+        var mut value: i32 = 1
+        value = 2
+    }
+"#;
+
+const CONST_REF_MUTATION: &str = r#"A value is mutated through an immutable reference.
+
+Erroneous code example:
+
+    func main() {
+        var mut value: i32 = 1
+        var r = &value
+        *r = 2 # error: "r" is an immutable reference
+    }
+
+Take a mutable reference to change the referenced value:
+
+    func main() {
+        var mut value: i32 = 1
+        var r = &mut value
+        *r = 2
+    }
+"#;
+
+const NO_ID_IN_NAMESPACE: &str = r#"A name is looked up in a module or an enum, but it is not defined there.
+
+Erroneous code example:
+
+    module math {
+        pub func add(a: i32, b: i32): i32 {
+            return a + b
+        }
+    }
+
+    func main() {
+        math::sub(1, 2) # error: "math" has no "sub"
+    }
+
+Use a name that the module defines, or add it to the module:
+
+    module math {
+        pub func add(a: i32, b: i32): i32 {
+            return a + b
+        }
+    }
+
+    func main() {
+        math::add(1, 2)
+    }
+"#;
+
+const UNREACHABLE: &str = r#"The compiler reached a state that it considers impossible.
+
+This is a bug in the compiler, not in your code. Please report it together
+with the source file that triggers it.
+"#;
+
+const CODEGEN: &str = r#"The compiler failed to write generated code.
+
+This usually means that the output file or its directory can not be
+written, e.g. because of missing permissions or no free disk space.
+Check the path passed with "-o" and try again.
+"#;
+
+const EMPTY_NAME_SPEC: &str = r#"A name is expected, but an empty one is found.
+
+This error is reported for malformed paths in definitions, calls and
+"use" declarations. Make sure that every path names an item, e.g.
+"math::add" instead of "math::".
+"#;
+
+const PRIVATE_ITEM: &str = r#"An item of a module is used outside of the module, but it is not public.
+
+Erroneous code example:
+
+    module math {
+        func add(a: i32, b: i32): i32 {
+            return a + b
+        }
+    }
+
+    func main() {
+        math::add(1, 2) # error: "add" is private
+    }
+
+Items are private to their module by default. Mark the item with "pub":
+
+    module math {
+        pub func add(a: i32, b: i32): i32 {
+            return a + b
+        }
+    }
+
+    func main() {
+        math::add(1, 2)
+    }
+"#;
+
+const AMBIGUOUS_IMPORT: &str = r#"A name is imported by several glob imports, so it is not clear which
+item it refers to.
+
+Erroneous code example:
+
+    module a {
+        pub func run() {
+        }
+    }
+
+    module b {
+        pub func run() {
+        }
+    }
+
+    use a::*
+    use b::*
+
+    func main() {
+        run() # error: "run" is imported from both "a" and "b"
+    }
+
+Call the item by its full path instead:
+
+    module a {
+        pub func run() {
+        }
+    }
+
+    module b {
+        pub func run() {
+        }
+    }
+
+    use a::*
+    use b::*
+
+    func main() {
+        a::run()
+    }
+"#;
+
+const SUPER_OF_ROOT: &str = r#""super" is used where there is no enclosing module.
+
+Erroneous code example:
+
+    use super::math # error: the crate root has no parent module
+
+    func main() {
+    }
+
+"super" refers to the parent of the current module, so it can only be
+used inside a module. At the crate root, name the item directly:
+
+    module math {
+        pub func add(a: i32, b: i32): i32 {
+            return a + b
+        }
+    }
+
+    use math::add
+
+    func main() {
+        add(1, 2)
+    }
+"#;
+
+const MODULE_NOT_FOUND: &str = r#"A module is declared without a body, but its file can not be found or read.
+
+Erroneous code example:
+
+    module shapes # error: neither "shapes.tt" nor "shapes/mod.tt" exists
+
+    func main() {
+    }
+
+A module without a body is loaded from "<name>.tt" or "<name>/mod.tt" next to
+the current file. Create the file or define the module in place:
+
+    module shapes {
+    }
+
+    func main() {
+    }
+"#;
+
+const MISPLACED_ATTRIBUTE: &str = r#"An attribute is used on an item that does not support it.
+
+Erroneous code example:
+
+    func main() {
+        #[inline]
+        var value = 5 # error: "inline" is allowed only on functions
+    }
+
+Move the attribute to an item it applies to or remove it:
+
+    #[inline]
+    func five(): i32 {
+        return 5
+    }
+
+    func main() {
+        var value = five()
+    }
+"#;
+
+const MALFORMED_ATTRIBUTE: &str = r#"An attribute has arguments that do not match its form.
+
+Erroneous code example:
+
+    #[inline(always)] # error: "inline" takes no arguments
+    func five(): i32 {
+        return 5
+    }
+
+Write the attribute in the form it expects:
+
+    #[inline]
+    func five(): i32 {
+        return 5
+    }
+"#;
+
+const CONFLICTING_ATTRIBUTES: &str = r#"Attributes of an item contradict each other or are repeated.
+
+Erroneous code example:
+
+    #[inline]
+    #[no_mangle] # error: inlined functions have no symbol to keep
+    func five(): i32 {
+        return 5
+    }
+
+Keep only one of the conflicting attributes:
+
+    #[no_mangle]
+    func five(): i32 {
+        return 5
+    }
+"#;
+
+const UNKNOWN_ATTRIBUTE: &str = r#"An attribute is not known to the compiler.
+
+Erroneous code example:
+
+    #[inlined] # error: there is no attribute "inlined"
+    func five(): i32 {
+        return 5
+    }
+
+Check the spelling of the attribute:
+
+    #[inline]
+    func five(): i32 {
+        return 5
+    }
+"#;
+
+const UNKNOWN_LINT: &str = r#"A lint level attribute names a lint that does not exist.
+
+Erroneous code example:
+
+    #[allow(unused_variable)] # error: the lint is named "unused_variables"
+    func main() {
+        var value = 5
+    }
+
+Use one of the lints listed in the message:
+
+    #[allow(unused_variables)]
+    func main() {
+        var value = 5
+    }
+"#;
+
+const INVALID_VARIADIC: &str = r#"A function or a function type is declared with "..." where it is not allowed.
+
+Erroneous code example:
+
+    func sum(first: i32, ...): i32 { # error: only extern functions are variadic
+        return first
+    }
+
+Only functions declared in "extern" blocks and "C" function types can accept
+variable arguments, and they need at least one parameter before "...":
+
+    extern "C" {
+        func printf(format: *const str, ...): i32
+    }
+"#;
+
+const UNSUPPORTED_ABI: &str = r#"A function or an extern block uses an ABI that is not supported here.
+
+Erroneous code example:
+
+    extern "Rust" { # error: only "C" is supported
+        func puts(text: *const str): i32
+    }
+
+Use the "C" ABI:
+
+    extern "C" {
+        func puts(text: *const str): i32
+    }
+
+Associated functions can not have "C" ABI, use a free function instead.
+"#;
+
+const MISPLACED_STATEMENT: &str = r#"A statement is used in a place where it is not allowed.
+
+Erroneous code example:
+
+    func main() {
+        break # error: "break" outside of a loop
+    }
+
+Definitions of extern blocks and impls, global statements and control flow
+statements are allowed only in their own contexts:
+
+    func main() {
+        loop {
+            break
+        }
+    }
+"#;
+
+const STATIC_INITIALIZER: &str = r#"A static is not initialized, or an extern static is initialized.
+
+Erroneous code example:
+
+    static COUNTER: i32 # error: static must have a value
+
+Statics defined in Tanit must be initialized. Statics declared in "extern"
+blocks are defined by other objects and must not be initialized:
+
+    static COUNTER: i32 = 0
+"#;
+
+const INVALID_LITERAL: &str = r#"A literal can not be represented by its type.
+
+Erroneous code example:
+
+    func main() {
+        var big = 340282366920938463463374607431768211456 # error: too large
+    }
+
+Use a value that fits into the largest integer type.
+"#;
+
+const MISPLACED_SELF: &str = r#"A "self" parameter is used outside of an associated function, or not as
+the first parameter.
+
+Erroneous code example:
+
+    func len(self): f32 { # error: "len" is not defined in an impl
+        return 0.0
+    }
+
+Define the function in the impl of the type and take "self" first:
+
+    struct Vector {
+        x: f32
+    }
+
+    impl Vector {
+        func len(&self): f32 {
+            return self.x
+        }
+    }
+"#;
+
+const MISMATCHED_TYPES: &str = r#"A value has a type that differs from the expected one.
+
+Erroneous code example:
+
+    func main() {
+        var a: i32 = 1
+        var b: f32 = 2.0
+        var c = a + b # error: "i32" and "f32" are added
+    }
+
+Convert one of the values explicitly:
+
+    func main() {
+        var a: i32 = 1
+        var b: f32 = 2.0
+        var c = a as f32 + b
+    }
+"#;
+
+const ARGUMENT_MISMATCH: &str = r#"Arguments of a call do not match the parameters of the function.
+
+Erroneous code example:
+
+    func add(a: i32, b: i32): i32 {
+        return a + b
+    }
+
+    func main() {
+        add(1) # error: too few arguments
+    }
+
+Pass an argument for every parameter. Positional arguments go before the
+named ones, and names must match the names of the parameters:
+
+    func main() {
+        add(1, b: 2)
+    }
+"#;
+
+const NOT_CALLABLE: &str = r#"Something that is not a function is called.
+
+Erroneous code example:
+
+    func main() {
+        var value = 5
+        value() # error: "value" is "i32"
+    }
+
+Only functions and values of function types can be called.
+"#;
+
+const NOT_INDEXABLE: &str = r#"Something that is not an array is indexed, or the index is not an integer.
+
+Erroneous code example:
+
+    func main() {
+        var value = 5
+        var first = value[0] # error: "value" is not an array
+    }
+
+Index only arrays, and use integer indices:
+
+    func main() {
+        var values = [5, 6]
+        var first = values[0]
+    }
+"#;
+
+const UNSAFE_REQUIRED: &str = r#"An operation that the compiler can not check is used in safe code.
+
+Erroneous code example:
+
+    func main() {
+        var value = 5
+        var ptr = &value as *const i32
+        var copy = *ptr # error: dereferencing of raw pointer
+    }
+
+Dereferencing raw pointers, reading union fields, calling unsafe functions,
+using mutable extern statics and unchecked conversions require an unsafe
+function or block:
+
+    func main() {
+        var value = 5
+        var ptr = &value as *const i32
+        unsafe {
+            var copy = *ptr
+        }
+    }
+"#;
+
+const NOT_C_COMPATIBLE: &str = r#"A value that C code can not use is passed to or returned from C code.
+
+Erroneous code example:
+
+    extern "C" {
+        func printf(format: *const str, ...): i32
+    }
+
+    func main() {
+        unsafe {
+            printf("%d", (1, 2)) # error: tuples can not be variadic arguments
+        }
+    }
+
+Pass numbers, "bool", enums or pointers to variadic parameters, and use only
+C-compatible types in the signatures of callbacks.
+"#;
+
+const INVALID_OPERAND: &str = r#"An operation is applied to an operand that does not support it.
+
+Erroneous code example:
+
+    func main() {
+        1 = 2 # error: literals can not be assigned
+    }
+
+Only variables and their fields can be assigned, and bitwise operations on
+enums require the enum to be marked with #[flags]:
+
+    func main() {
+        var mut value = 1
+        value = 2
+    }
+"#;
+
+const INVALID_STRUCT_LITERAL: &str = r#"A struct or union literal does not initialize the fields properly.
+
+Erroneous code example:
+
+    struct Point {
+        x: f32
+        y: f32
+    }
+
+    func main() {
+        var p = Point { x: 1.0 } # error: "y" is not initialized
+    }
+
+Initialize every field of a struct, and exactly one field of a union:
+
+    func main() {
+        var p = Point { x: 1.0, y: 2.0 }
+    }
+"#;
+
+const UNDEFINED_FIELD: &str = r#"A field is accessed or initialized, but the type has no field with this name.
+
+Erroneous code example:
+
+    struct Point {
+        x: f32
+    }
+
+    func main() {
+        var p = Point { x: 1.0 }
+        var z = p.z # error: "Point" has no field "z"
+    }
+
+Use one of the fields of the type or add the field to it.
+"#;
+
+const INVALID_CONVERSION: &str = r#"A value is converted with "as" to a type it can not be converted to.
+
+Erroneous code example:
+
+    struct Point {
+        x: f32
+    }
+
+    func main() {
+        var p = Point { x: 1.0 }
+        var x = p as f32 # error: structs can not be converted
+    }
+
+Only primitive types, enums and pointers can be converted with "as":
+
+    func main() {
+        var p = Point { x: 1.0 }
+        var x = p.x as f64
+    }
+"#;
+
+const INVALID_ENUM_VALUE: &str = r#"A constant converted to an enum is not a value of any of its units.
+
+Erroneous code example:
+
+    enum Color {
+        Red: 0
+        Green: 1
+    }
+
+    func main() {
+        var color = 5 as Color # error: no unit has value 5
+    }
+
+Convert only values of the units, or use one of the units directly:
+
+    func main() {
+        var color = Color::Green
+    }
+"#;
+
+const INVALID_ENUM_REPR: &str = r#"The representation type of an enum is not an integer, or values of units do
+not fit into it.
+
+Erroneous code example:
+
+    enum Code: u8 {
+        Ok: 0
+        Big: 300 # error: 300 does not fit into "u8"
+    }
+
+Use a wider integer type or smaller values:
+
+    enum Code: u16 {
+        Ok: 0
+        Big: 300
+    }
+"#;
+
+const TYPE_ANNOTATION_NEEDED: &str = r#"The type of a variable can not be inferred.
+
+Erroneous code example:
+
+    func main() {
+        var value # error: neither the type nor the value is set
+    }
+
+Annotate the type of the variable or initialize it:
+
+    func main() {
+        var value: i32 = 0
+    }
+"#;
+
+const DISABLED_FEATURE: &str = r#"A feature of the language is used, but it is not enabled.
+
+Erroneous code example:
+
+    variant Shape { # error: variants are disabled by default
+        Dot
+        Circle(f32)
+    }
+
+Enable the feature with its command line option, e.g. "--variants".
+"#;
+
+const INVALID_IMPORT: &str = r#"A "use" declaration imports something that can not be imported.
+
+Erroneous code example:
+
+    module math {
+        pub func add(a: i32, b: i32): i32 {
+            return a + b
+        }
+    }
+
+    use math::add::* # error: "add" is not a module or enum
+
+Import items only from modules and enums, and name the import with "as" when
+the path has no name of its own:
+
+    use math::add
+"#;
+
+const INVALID_PATH: &str = r#"A path contains a segment that can not appear at its place.
+
+Erroneous code example:
+
+    module math {
+        pub func add(a: i32, b: i32): i32 {
+            return a + b
+        }
+    }
+
+    use math::crate::add # error: "crate" can only start a path
+
+"crate", "super" and "self" can appear only at the start of a path:
+
+    use crate::math::add
+"#;
+
+const INVALID_ENTRY_POINT: &str = r#"An executable has no "main" function, or its "main" has wrong type.
+
+Erroneous code example:
+
+    func start() { # error: executable has no "main"
+    }
+
+Define "main" without parameters, that returns nothing or "i32":
+
+    func main(): i32 {
+        return 0
+    }
+"#;
+
+const DEAD_CODE: &str = r#"An item is defined, but never used.
+
+Example:
+
+    func helper() { # warning: "helper" is never used
+    }
+
+    func main() {
+    }
+
+Remove the item, use it, or allow the lint with #[allow(dead_code)].
+"#;
+
+const DEPRECATED: &str = r#"An item marked with #[deprecated] is used.
+
+Example:
+
+    #[deprecated = "use new_api instead"]
+    func old_api() {
+    }
+
+    func main() {
+        old_api() # warning: "old_api" is deprecated
+    }
+
+Switch to the replacement mentioned in the note of the warning.
+"#;
+
+const LOSSY_CONVERSION: &str = r#"A conversion may lose data or change the sign of the value.
+
+Example:
+
+    func main() {
+        var big: i64 = 300
+        var small = big as u8 # warning: narrowing conversion
+    }
+
+Check the value before the conversion, or allow the lint with
+#[allow(lossy_conversions)] when the truncation is intended.
+"#;
+
+const UNION_FIELD_MISMATCH: &str = r#"A field of a union is read, but another field was written last.
+
+Example:
+
+    union Bits {
+        i: i32
+        f: f32
+    }
+
+    func main() {
+        unsafe {
+            var u = Bits { i: 1 }
+            var f = u.f # warning: "i" was written last
+        }
+    }
+
+Read the field that was written, or allow the lint with
+#[allow(union_field_mismatch)] when the reinterpretation is intended.
+"#;
+
+const UNUSED_IMPORT: &str = r#"An imported name is never used.
+
+Example:
+
+    module math {
+        pub func add(a: i32, b: i32): i32 {
+            return a + b
+        }
+    }
+
+    use math::add # warning: "add" is never used
+
+    func main() {
+    }
+
+Remove the "use" declaration.
+"#;
+
+const UNUSED_MUT: &str = r#"A variable is declared mutable, but it is never mutated.
+
+Example:
+
+    func main() {
+        var mut value = 5 # warning: "value" is never mutated
+        var copy = value
+    }
+
+Remove "mut" from the definition.
+"#;
+
+const UNUSED_VARIABLE: &str = r#"A variable or a parameter is defined, but never read.
+
+Example:
+
+    func main() {
+        var value = 5 # warning: "value" is never read
+    }
+
+Remove the variable, or prefix its name with an underscore if it is
+intentionally unused.
+"#;
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+
+    #[test]
+    fn error_codes_test() {
+        let codes: BTreeSet<&str> = ErrorCode::ALL.iter().map(|code| code.as_str()).collect();
+        assert_eq!(codes.len(), ErrorCode::ALL.len());
+
+        for code in ErrorCode::ALL {
+            assert_eq!(ErrorCode::from_code(code.as_str()), Some(code));
+            assert!(!code.get_explanation().is_empty());
+        }
+
+        assert_eq!(
+            ErrorCode::from_code("e0012"),
+            Some(ErrorCode::ConstRefMutation)
+        );
+        assert_eq!(
+            ErrorCode::from_code("w0003"),
+            Some(ErrorCode::LossyConversion)
+        );
+        assert_eq!(ErrorCode::from_code("E9999"), None);
+    }
+}
@@ -0,0 +1,170 @@
+use serde::Serialize;
+use tanitc_lexer::location::Location;
+
+use crate::{
+    messages::Message,
+    render::{get_mark_width, Severity},
+};
+
+#[derive(Serialize)]
+struct JsonSpan {
+    line: usize,
+    column: usize,
+    end_line: usize,
+    end_column: usize,
+}
+
+#[derive(Serialize)]
+struct JsonLabel<'a> {
+    file: String,
+    span: JsonSpan,
+    message: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonNote<'a> {
+    kind: String,
+    message: &'a str,
+}
+
+#[derive(Serialize)]
+struct JsonMessage<'a> {
+    severity: String,
+    code: Option<&'static str>,
+    file: Option<String>,
+    span: Option<JsonSpan>,
+    message: &'a str,
+    labels: Vec<JsonLabel<'a>>,
+    notes: Vec<JsonNote<'a>>,
+}
+
+/* Serializes message to one-line JSON object:
+ * {"severity":"error","code":"E0001","file":"main.tt",
+ *  "span":{"line":2,"column":12,"end_line":2,"end_column":13},
+ *  "message":"Syntax error: ...","labels":[...],"notes":[...]}
+ * Lines and columns start from 1, end column is exclusive.
+ */
+pub fn to_json(msg: &Message, severity: Severity) -> String {
+    let json_msg = JsonMessage {
+        severity: severity.to_string(),
+        code: msg.code.map(|code| code.as_str()),
+        file: msg.location.map(|location| location.path.to_string()),
+        span: msg
+            .location
+            .map(|location| get_span(&location, get_width(msg))),
+        message: &msg.text,
+        labels: msg
+            .labels
+            .iter()
+            .map(|label| {
+                let location = &label.location;
+                let width = location
+                    .path
+                    .get_source_line(location.row)
+                    .map(|line| get_mark_width(&line, location))
+                    .unwrap_or(1);
+
+                JsonLabel {
+                    file: location.path.to_string(),
+                    span: get_span(location, width),
+                    message: &label.text,
+                }
+            })
+            .collect(),
+        notes: msg
+            .notes
+            .iter()
+            .map(|note| JsonNote {
+                kind: note.kind.to_string(),
+                message: &note.text,
+            })
+            .collect(),
+    };
+
+    serde_json::to_string(&json_msg).expect("Message must be serializable")
+}
+
+fn get_width(msg: &Message) -> usize {
+    if let Some(width) = msg.mark_width {
+        return width as usize;
+    }
+
+    msg.location
+        .and_then(|location| {
+            let line = location.path.get_source_line(location.row)?;
+            Some(get_mark_width(&line, &location))
+        })
+        .unwrap_or(1)
+}
+
+fn get_span(location: &Location, width: usize) -> JsonSpan {
+    JsonSpan {
+        line: location.row + 1,
+        column: location.col + 1,
+        end_line: location.row + 1,
+        end_column: location.col + 1 + width,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use tanitc_lexer::location::Location;
+
+    use super::*;
+
+    fn get_location(path: &str, row: usize, col: usize) -> Location {
+        let mut location = Location::new(&PathBuf::from(path));
+        location.row = row;
+        location.col = col;
+        location
+    }
+
+    #[test]
+    fn to_json_test() {
+        // Given
+        let location = get_location("to_json_test.tt", 2, 8);
+        location
+            .path
+            .set_source("func main() {\n    var value = 1\n    var value = 2\n}");
+
+        let msg = Message::new(location, "Identifier \"value\" defined multiple times")
+            .with_label(
+                get_location("to_json_test.tt", 1, 8),
+                "previous definition is here",
+            )
+            .with_help("rename\tone of the variables");
+
+        // When
+        let res = to_json(&msg, Severity::Error);
+
+        // Then
+        const EXPECTED: &str = "{\"severity\":\"error\",\"code\":null,\
+                                \"file\":\"to_json_test.tt\",\
+                                \"span\":{\"line\":3,\"column\":9,\"end_line\":3,\"end_column\":14},\
+                                \"message\":\"Identifier \\\"value\\\" defined multiple times\",\
+                                \"labels\":[{\"file\":\"to_json_test.tt\",\
+                                \"span\":{\"line\":2,\"column\":9,\"end_line\":2,\"end_column\":14},\
+                                \"message\":\"previous definition is here\"}],\
+                                \"notes\":[{\"kind\":\"help\",\"message\":\"rename\\tone of the variables\"}]}";
+
+        assert_eq!(res, EXPECTED);
+    }
+
+    #[test]
+    fn to_json_without_location_test() {
+        // Given
+        let msg = Message::reached_eof();
+
+        // When
+        let res = to_json(&msg, Severity::Warning);
+
+        // Then
+        const EXPECTED: &str =
+            "{\"severity\":\"warning\",\"code\":\"E0002\",\"file\":null,\"span\":null,\
+                                \"message\":\"Reached EOF\",\"labels\":[],\"notes\":[]}";
+
+        assert_eq!(res, EXPECTED);
+    }
+}
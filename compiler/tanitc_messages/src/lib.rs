@@ -1,5 +1,9 @@
+pub mod codes;
+pub mod json;
 pub mod listener;
 pub mod messages;
+pub mod render;
+pub mod suggestions;
 
 pub use messages::Message;
 
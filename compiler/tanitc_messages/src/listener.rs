@@ -1,9 +1,17 @@
-use crate::{messages::Message, Errors, Warnings};
+use crate::{
+    json::to_json,
+    messages::Message,
+    render::{render, Severity},
+    Errors, Warnings,
+};
 
 #[derive(Default, Debug, Clone)]
 pub struct MessageListener {
     errors: Errors,
     warnings: Warnings,
+    error_limit: Option<usize>,
+    color: bool, // print messages with ANSI colours
+    json: bool,  // print messages as JSON objects, one per line
 }
 
 impl MessageListener {
@@ -11,14 +19,44 @@ impl MessageListener {
         Self::default()
     }
 
+    pub fn with_error_limit(error_limit: Option<usize>) -> Self {
+        Self {
+            error_limit,
+            ..Default::default()
+        }
+    }
+
+    pub fn set_color(&mut self, color: bool) {
+        self.color = color;
+    }
+
+    pub fn set_json(&mut self, json: bool) {
+        self.json = json;
+    }
+
+    // Errors after the limit are dropped
     pub fn error(&mut self, msg: Message) {
-        self.errors.push(msg);
+        if !self.is_error_limit_reached() {
+            self.errors.push(msg);
+        }
+    }
+
+    pub fn is_error_limit_reached(&self) -> bool {
+        self.error_limit
+            .is_some_and(|limit| self.errors.len() >= limit)
     }
 
     pub fn warn(&mut self, msg: Message) {
         self.warnings.push(msg);
     }
 
+    pub fn append(&mut self, mut other: Self) {
+        for error in other.errors.drain(..) {
+            self.error(error);
+        }
+        self.warnings.append(&mut other.warnings);
+    }
+
     pub fn errors_ref(&self) -> &Errors {
         &self.errors
     }
@@ -37,13 +75,38 @@ impl MessageListener {
 
     pub fn print_errors(&self) {
         for msg in self.errors.iter() {
-            eprintln!("{msg}");
+            eprintln!("{}", self.format(msg, Severity::Error));
+        }
+
+        if self.json {
+            return;
+        }
+
+        if let (true, Some(limit)) = (self.is_error_limit_reached(), self.error_limit) {
+            eprintln!("Error limit {limit} is reached, further errors are not reported");
+        }
+
+        if let Some(code) = self.errors.iter().find_map(|msg| msg.code) {
+            eprintln!("For more information about an error, try `tanitc --explain {code}`");
         }
     }
 
+    // JSON diagnostics share one stream, so tools read them in order of reporting
     pub fn print_warnings(&self) {
         for msg in self.warnings.iter() {
-            println!("{msg}");
+            if self.json {
+                eprintln!("{}", self.format(msg, Severity::Warning));
+            } else {
+                println!("{}", self.format(msg, Severity::Warning));
+            }
+        }
+    }
+
+    fn format(&self, msg: &Message, severity: Severity) -> String {
+        if self.json {
+            to_json(msg, severity)
+        } else {
+            render(msg, severity, self.color)
         }
     }
 }
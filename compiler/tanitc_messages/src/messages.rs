@@ -7,9 +7,34 @@ use tanitc_name::NameSpec;
 
 use std::{error::Error, fmt::Display};
 
+use crate::{codes::ErrorCode, suggestions::Suggestion};
+
 #[derive(Default, Debug, Clone)]
 pub struct Message {
     pub location: Option<Location>,
+    pub code: Option<ErrorCode>,
+    pub mark_width: Option<u32>, // word at location is marked if not set
+    pub text: String,
+    pub labels: Vec<Label>,
+    pub notes: Vec<Note>,
+}
+
+// Secondary location of the message, e.g. previous definition of the name
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub location: Location,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteKind {
+    Note,
+    Help,
+}
+
+#[derive(Debug, Clone)]
+pub struct Note {
+    pub kind: NoteKind,
     pub text: String,
 }
 
@@ -21,13 +46,61 @@ impl Message {
         Self {
             location: Some(location),
             text: text.to_string(),
+            ..Default::default()
         }
     }
 
     pub fn reached_eof() -> Self {
         Self {
-            location: None,
-            text: "Readched EOF".to_string(),
+            code: Some(ErrorCode::UnexpectedEof),
+            text: "Reached EOF".to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_code(mut self, code: ErrorCode) -> Self {
+        self.code = Some(code);
+        self
+    }
+
+    // Only spans within one line are marked entirely
+    pub fn with_end_location(mut self, end_location: Location) -> Self {
+        if let Some(location) = &self.location {
+            if end_location.row == location.row && end_location.col > location.col {
+                self.mark_width = u32::try_from(end_location.col - location.col).ok();
+            }
+        }
+        self
+    }
+
+    pub fn with_label<E: Display>(mut self, location: Location, text: E) -> Self {
+        self.labels.push(Label {
+            location,
+            text: text.to_string(),
+        });
+        self
+    }
+
+    pub fn with_note<E: Display>(mut self, text: E) -> Self {
+        self.notes.push(Note {
+            kind: NoteKind::Note,
+            text: text.to_string(),
+        });
+        self
+    }
+
+    pub fn with_help<E: Display>(mut self, text: E) -> Self {
+        self.notes.push(Note {
+            kind: NoteKind::Help,
+            text: text.to_string(),
+        });
+        self
+    }
+
+    pub fn with_suggestion(self, suggestion: Option<Suggestion>) -> Self {
+        match suggestion {
+            Some(suggestion) => self.with_help(suggestion.get_help()),
+            None => self,
         }
     }
 
@@ -45,6 +118,8 @@ impl Message {
         }
 
         Self::new(token.get_location(), text)
+            .with_code(ErrorCode::UnexpectedToken)
+            .with_end_location(token.get_end_location())
     }
 
     pub fn multiple_ids(location: Location, id: Ident) -> Self {
@@ -52,22 +127,26 @@ impl Message {
             location,
             format!("Identifier \"{id}\" defined multiple times"),
         )
+        .with_code(ErrorCode::MultipleIds)
     }
 
     pub fn undefined_id(location: Location, id: Ident) -> Self {
-        Self::new(location, format!("Undefined name \"{id}\""))
+        Self::new(location, format!("Undefined name \"{id}\"")).with_code(ErrorCode::UndefinedName)
     }
 
     pub fn undefined_name(location: Location, name: &NameSpec) -> Self {
         Self::new(location, format!("Undefined name \"{name}\""))
+            .with_code(ErrorCode::UndefinedName)
     }
 
     pub fn undefined_type(location: Location, type_str: String) -> Self {
         Self::new(location, format!("Undefined type \"{type_str}\""))
+            .with_code(ErrorCode::UndefinedType)
     }
 
     pub fn undefined_variable(location: Location, var_name: &NameSpec) -> Self {
         Self::new(location, format!("No variable \"{var_name}\" found"))
+            .with_code(ErrorCode::UndefinedVariable)
     }
 
     pub fn in_func_def(func_name: Ident, mut msg: Self) -> Self {
@@ -77,14 +156,17 @@ impl Message {
 
     pub fn undefined_func(location: Location, func_name: Ident) -> Self {
         Self::new(location, format!("No function \"{func_name}\" found"))
+            .with_code(ErrorCode::UndefinedFunc)
     }
 
     pub fn undefined_struct(location: Location, struct_name: Ident) -> Self {
         Self::new(location, format!("No struct \"{struct_name}\" found"))
+            .with_code(ErrorCode::UndefinedStruct)
     }
 
     pub fn undefined_union(location: Location, union_name: Ident) -> Self {
-        Self::new(location, format!("No struct \"{union_name}\" found"))
+        Self::new(location, format!("No union \"{union_name}\" found"))
+            .with_code(ErrorCode::UndefinedUnion)
     }
 
     pub fn const_mutation(location: Location, s: &str) -> Self {
@@ -92,6 +174,7 @@ impl Message {
             location,
             format!("Cannot mutate immutable object of type \"{s}\" is immutable in current scope"),
         )
+        .with_code(ErrorCode::ConstMutation)
     }
 
     pub fn const_var_mutation(location: Location, var_name: &NameSpec) -> Self {
@@ -99,6 +182,7 @@ impl Message {
             location,
             format!("Variable \"{var_name}\" is immutable in current scope"),
         )
+        .with_code(ErrorCode::ConstVarMutation)
     }
 
     pub fn const_ref_mutation(location: Location, var_name: &NameSpec) -> Self {
@@ -106,6 +190,7 @@ impl Message {
             location,
             format!("Reference \"{var_name}\" is immutable in current scope"),
         )
+        .with_code(ErrorCode::ConstRefMutation)
     }
 
     pub fn no_id_in_namespace(location: Location, namespace: &NameSpec, id: Ident) -> Self {
@@ -113,6 +198,7 @@ impl Message {
             location,
             format!("No object named \"{id}\" in namespace {namespace}"),
         )
+        .with_code(ErrorCode::NoIdInNamespace)
     }
 
     pub fn unreachable(location: Location, msg: String) -> Self {
@@ -120,14 +206,155 @@ impl Message {
             location,
             format!("Compiler reached unreachable code: {msg}"),
         )
+        .with_code(ErrorCode::Unreachable)
     }
 
     pub fn codegen_err(location: Location, err: std::io::Error) -> Self {
-        Self::new(location, format!("Codegen error: {err}"))
+        Self::new(location, format!("Codegen error: {err}")).with_code(ErrorCode::Codegen)
     }
 
     pub fn empty_name_spec(location: Location) -> Self {
-        Self::new(location, "Empty Name Specifier")
+        Self::new(location, "Empty Name Specifier").with_code(ErrorCode::EmptyNameSpec)
+    }
+
+    // Text of the errors below depends on the place they are found at,
+    // so only their codes are fixed
+
+    pub fn module_not_found<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::ModuleNotFound)
+    }
+
+    pub fn misplaced_attribute<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::MisplacedAttribute)
+    }
+
+    pub fn malformed_attribute<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::MalformedAttribute)
+    }
+
+    pub fn conflicting_attributes<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::ConflictingAttributes)
+    }
+
+    pub fn unknown_attribute<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::UnknownAttribute)
+    }
+
+    pub fn unknown_lint<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::UnknownLint)
+    }
+
+    pub fn invalid_variadic<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::InvalidVariadic)
+    }
+
+    pub fn unsupported_abi<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::UnsupportedAbi)
+    }
+
+    pub fn misplaced_statement<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::MisplacedStatement)
+    }
+
+    pub fn static_initializer<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::StaticInitializer)
+    }
+
+    pub fn invalid_literal<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::InvalidLiteral)
+    }
+
+    pub fn misplaced_self<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::MisplacedSelf)
+    }
+
+    pub fn mismatched_types<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::MismatchedTypes)
+    }
+
+    pub fn argument_mismatch<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::ArgumentMismatch)
+    }
+
+    pub fn not_callable<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::NotCallable)
+    }
+
+    pub fn not_indexable<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::NotIndexable)
+    }
+
+    pub fn unsafe_required<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::UnsafeRequired)
+    }
+
+    pub fn not_c_compatible<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::NotCCompatible)
+    }
+
+    pub fn invalid_operand<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::InvalidOperand)
+    }
+
+    pub fn invalid_struct_literal<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::InvalidStructLiteral)
+    }
+
+    pub fn private_item<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::PrivateItem)
+    }
+
+    pub fn undefined_field<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::UndefinedField)
+    }
+
+    pub fn invalid_conversion<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::InvalidConversion)
+    }
+
+    pub fn invalid_enum_value<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::InvalidEnumValue)
+    }
+
+    pub fn invalid_enum_repr<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::InvalidEnumRepr)
+    }
+
+    pub fn type_annotation_needed<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::TypeAnnotationNeeded)
+    }
+
+    pub fn disabled_feature<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::DisabledFeature)
+    }
+
+    pub fn invalid_import<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::InvalidImport)
+    }
+
+    pub fn invalid_path<E: Display>(location: Location, text: E) -> Self {
+        Self::new(location, text).with_code(ErrorCode::InvalidPath)
+    }
+
+    // Entry point is missing from the whole crate, not from a place in it
+    pub fn invalid_entry_point<E: Display>(text: E) -> Self {
+        Self {
+            code: Some(ErrorCode::InvalidEntryPoint),
+            text: text.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn unexpected_token_expecting(token: &Token, expected: &str) -> Self {
+        Self::new(
+            token.get_location(),
+            format!(
+                "Unexpected token: {}. Expected {expected}.",
+                token.lexeme_ref()
+            ),
+        )
+        .with_code(ErrorCode::UnexpectedToken)
+        .with_end_location(token.get_end_location())
     }
 
     pub fn map_in_func_def(mut self, func_name: &NameSpec) -> Self {
@@ -141,6 +368,15 @@ impl Message {
     }
 }
 
+impl Display for NoteKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Note => write!(f, "note"),
+            Self::Help => write!(f, "help"),
+        }
+    }
+}
+
 impl Error for Message {}
 impl Display for Message {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -0,0 +1,234 @@
+use tanitc_lexer::location::Location;
+
+use crate::messages::Message;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+        }
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const RED: &str = "\x1b[1;31m";
+const YELLOW: &str = "\x1b[1;33m";
+const BLUE: &str = "\x1b[1;34m";
+
+struct Style {
+    color: bool,
+}
+
+impl Style {
+    fn paint(&self, code: &str, text: &str) -> String {
+        if self.color {
+            format!("{code}{text}{RESET}")
+        } else {
+            text.to_string()
+        }
+    }
+}
+
+/* Renders message with annotated source lines:
+ * main.tt:2:12: [E0001] Syntax error: Unexpected token: '='.
+ *   |
+ * 2 |     var y: = 5
+ *   |            ^
+ *   = help: ...
+ */
+pub fn render(msg: &Message, severity: Severity, color: bool) -> String {
+    let style = Style { color };
+    let accent = match severity {
+        Severity::Error => RED,
+        Severity::Warning => YELLOW,
+    };
+
+    let text = match msg.code {
+        Some(code) => format!("[{code}] {}", msg.text),
+        None => msg.text.clone(),
+    };
+
+    let mut out = match &msg.location {
+        Some(location) => format!(
+            "{}{}",
+            style.paint(BOLD, &format!("{location}: ")),
+            style.paint(accent, &text)
+        ),
+        None => style.paint(accent, &text),
+    };
+
+    let Some(location) = msg.location else {
+        return out;
+    };
+
+    let rows = std::iter::once(location.row).chain(msg.labels.iter().map(|l| l.location.row));
+    let gutter = rows
+        .max()
+        .unwrap_or_default()
+        .saturating_add(1)
+        .to_string()
+        .len();
+    let pipe = style.paint(BLUE, "|");
+    let empty_gutter = " ".repeat(gutter);
+
+    let mut has_snippet = false;
+    if let Some(line) = location.path.get_source_line(location.row) {
+        let width = msg
+            .mark_width
+            .map(|width| width as usize)
+            .unwrap_or_else(|| get_mark_width(&line, &location));
+
+        out.push_str(&format!("\n{empty_gutter} {pipe}"));
+        out.push_str(&render_line(&line, location.row, gutter, &style));
+        out.push_str(&format!(
+            "\n{empty_gutter} {pipe} {}{}",
+            get_mark_indent(&line, location.col),
+            style.paint(accent, &"^".repeat(width))
+        ));
+
+        has_snippet = true;
+    }
+
+    for label in msg.labels.iter() {
+        let label_location = label.location;
+
+        if label_location.path != location.path || !has_snippet {
+            out.push_str(&format!(
+                "\n{empty_gutter}{} {label_location}",
+                style.paint(BLUE, ":::")
+            ));
+        }
+
+        let Some(line) = label_location.path.get_source_line(label_location.row) else {
+            out.push_str(&format!("\n{empty_gutter} {pipe} {}", label.text));
+            continue;
+        };
+
+        let width = get_mark_width(&line, &label_location);
+
+        out.push_str(&render_line(&line, label_location.row, gutter, &style));
+        out.push_str(&format!(
+            "\n{empty_gutter} {pipe} {}{}",
+            get_mark_indent(&line, label_location.col),
+            style.paint(BLUE, &format!("{} {}", "-".repeat(width), label.text))
+        ));
+    }
+
+    for note in msg.notes.iter() {
+        out.push_str(&format!(
+            "\n{empty_gutter} {} {}: {}",
+            style.paint(BLUE, "="),
+            style.paint(BOLD, &note.kind.to_string()),
+            note.text
+        ));
+    }
+
+    out
+}
+
+fn render_line(line: &str, row: usize, gutter: usize, style: &Style) -> String {
+    let number = format!("{:>gutter$}", row + 1);
+    format!("\n{} {line}", style.paint(BLUE, &format!("{number} |")))
+}
+
+// Keeps tabs of the source line, so marks stay under the marked text
+fn get_mark_indent(line: &str, col: usize) -> String {
+    line.chars()
+        .take(col)
+        .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+        .collect()
+}
+
+// Marks the word at the location, or a single character
+pub fn get_mark_width(line: &str, location: &Location) -> usize {
+    let word_len = line
+        .chars()
+        .skip(location.col)
+        .take_while(|ch| ch.is_alphanumeric() || *ch == '_')
+        .count();
+
+    word_len.max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use tanitc_ident::Ident;
+    use tanitc_lexer::location::Location;
+
+    use super::*;
+
+    fn get_location(path: &str, row: usize, col: usize) -> Location {
+        let mut location = Location::new(&PathBuf::from(path));
+        location.row = row;
+        location.col = col;
+        location
+    }
+
+    #[test]
+    fn render_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                              \n    var value = 1\
+                              \n    var value = 2\
+                              \n}";
+
+        let location = get_location("render_test.tt", 2, 8);
+        location.path.set_source(SRC_TEXT);
+
+        let msg = Message::multiple_ids(location, Ident::from("value".to_string()))
+            .with_label(
+                get_location("render_test.tt", 1, 8),
+                "previous definition is here",
+            )
+            .with_help("rename one of the variables");
+
+        // When
+        let res = render(&msg, Severity::Error, false);
+
+        // Then
+        const EXPECTED: &str =
+            "render_test.tt:3:9: [E0003] Identifier \"value\" defined multiple times\
+                              \n  |\
+                              \n3 |     var value = 2\
+                              \n  |         ^^^^^\
+                              \n2 |     var value = 1\
+                              \n  |         ----- previous definition is here\
+                              \n  = help: rename one of the variables";
+
+        assert_eq!(res, EXPECTED);
+    }
+
+    #[test]
+    fn render_span_test() {
+        // Given
+        let location = get_location("render_span_test.tt", 0, 4);
+        location.path.set_source("var: = 5");
+
+        let msg = Message::new(location, "Unexpected token").with_end_location(get_location(
+            "render_span_test.tt",
+            0,
+            6,
+        ));
+
+        // When
+        let res = render(&msg, Severity::Warning, false);
+
+        // Then
+        const EXPECTED: &str = "render_span_test.tt:1:5: Unexpected token\
+                              \n  |\
+                              \n1 | var: = 5\
+                              \n  |     ^^";
+
+        assert_eq!(res, EXPECTED);
+    }
+}
@@ -0,0 +1,126 @@
+// Defined name that is similar to the name that was not found
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suggestion {
+    pub name: String,
+    pub kind: &'static str,
+    pub is_private: bool, // would match if it was "pub"
+}
+
+impl Suggestion {
+    pub fn new(name: impl ToString, kind: &'static str, is_private: bool) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            is_private,
+        }
+    }
+
+    // Returns the closest candidate, accessible ones are preferred on equal distance
+    pub fn find_closest(name: &str, candidates: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let max_distance = (name.chars().count() / 3).max(1);
+
+        candidates
+            .into_iter()
+            .filter(|candidate| candidate.name != name)
+            .map(|candidate| (get_edit_distance(name, &candidate.name), candidate))
+            .filter(|(distance, _)| *distance <= max_distance)
+            .min_by(|(lhs_distance, lhs), (rhs_distance, rhs)| {
+                (lhs_distance, lhs.is_private, &lhs.name).cmp(&(
+                    rhs_distance,
+                    rhs.is_private,
+                    &rhs.name,
+                ))
+            })
+            .map(|(_, candidate)| candidate)
+    }
+
+    pub fn get_help(&self) -> String {
+        if self.is_private {
+            format!(
+                "{} \"{}\" has a similar name, but it is private: mark it with \"pub\" to use it here",
+                self.kind, self.name
+            )
+        } else {
+            // "union" starts with a consonant sound
+            let article = if self.kind.starts_with(['a', 'e', 'i', 'o']) {
+                "an"
+            } else {
+                "a"
+            };
+
+            format!(
+                "{article} {} with a similar name exists: \"{}\"",
+                self.kind, self.name
+            )
+        }
+    }
+}
+
+// Levenshtein distance: number of inserted, removed and replaced characters
+pub fn get_edit_distance(lhs: &str, rhs: &str) -> usize {
+    let rhs: Vec<char> = rhs.chars().collect();
+    let mut prev_row: Vec<usize> = (0..=rhs.len()).collect();
+
+    for (i, lhs_ch) in lhs.chars().enumerate() {
+        let mut row = vec![i + 1; rhs.len() + 1];
+
+        for (j, rhs_ch) in rhs.iter().enumerate() {
+            let cost = usize::from(lhs_ch != *rhs_ch);
+            row[j + 1] = (prev_row[j] + cost)
+                .min(prev_row[j + 1] + 1)
+                .min(row[j] + 1);
+        }
+
+        prev_row = row;
+    }
+
+    prev_row[rhs.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distance_test() {
+        assert_eq!(get_edit_distance("", ""), 0);
+        assert_eq!(get_edit_distance("count", "count"), 0);
+        assert_eq!(get_edit_distance("count", "conut"), 2);
+        assert_eq!(get_edit_distance("value", "values"), 1);
+        assert_eq!(get_edit_distance("kitten", "sitting"), 3);
+        assert_eq!(get_edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn find_closest_test() {
+        let candidates = vec![
+            Suggestion::new("counter", "variable", false),
+            Suggestion::new("count", "function", true),
+            Suggestion::new("cont", "variable", false),
+            Suggestion::new("main", "function", false),
+        ];
+
+        // Private item is suggested if nothing else is close enough
+        assert_eq!(
+            Suggestion::find_closest("coun", candidates.clone()),
+            Some(Suggestion::new("count", "function", true))
+        );
+        assert_eq!(
+            Suggestion::find_closest("counte", candidates.clone()),
+            Some(Suggestion::new("counter", "variable", false))
+        );
+        assert_eq!(Suggestion::find_closest("value", candidates), None);
+    }
+
+    #[test]
+    fn help_article_test() {
+        assert_eq!(
+            Suggestion::new("Bits", "union", false).get_help(),
+            "a union with a similar name exists: \"Bits\""
+        );
+        assert_eq!(
+            Suggestion::new("Red", "enum unit", false).get_help(),
+            "an enum unit with a similar name exists: \"Red\""
+        );
+    }
+}
@@ -1,9 +1,12 @@
+use serde::Serialize;
 use std::fmt::Display;
 
 use tanitc_ident::Ident;
 use tanitc_lexer::location::Location;
 
-#[derive(Debug, Clone, PartialEq)]
+pub mod mangling;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub enum NamePathSegment {
     SelfNameSpace,
     SuperNameSpace,
@@ -18,7 +21,7 @@ impl From<Ident> for NamePathSegment {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize)]
 pub struct NameSpec {
     pub location: Location,
     pub path: Vec<NamePathSegment>,
@@ -39,8 +42,8 @@ impl NameSpec {
 impl Display for NamePathSegment {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Self::SelfNameSpace => write!(f, "Self"),
-            Self::SuperNameSpace => write!(f, "Super"),
+            Self::SelfNameSpace => write!(f, "self"),
+            Self::SuperNameSpace => write!(f, "super"),
             Self::CrateNameSpace => write!(f, "crate"),
             Self::Id(id) => write!(f, "{id}"),
             Self::AllIdents => write!(f, "*"),
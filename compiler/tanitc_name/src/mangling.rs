@@ -0,0 +1,218 @@
+// Mangling scheme of the symbols generated for Tanit items:
+//
+//     symbol   ::= "_T" kind path "E"
+//     kind     ::= "F"                  # function
+//                | "M"                  # method, the last segment is the method, the rest is its owner
+//                | "T"                  # type
+//                | "C"                  # constant, e.g. unit of enum
+//                | "P"                  # function pointer type, segments are the parameter types,
+//                                       # "va" for variadic ones and the return type
+//     path     ::= segment+             # from the crate root to the item
+//     segment  ::= length identifier    # decimal length without leading zeros
+//
+// Functions and methods are prefixed with the name of the crate that defines them,
+// so equally named items of different crates do not collide at link time.
+// Every segment carries its length, so "a::b" ("_TF1a1bE") never collides
+// with an item named "a__b" ("_TF4a__bE").
+//
+// Generic arguments are reserved to be encoded as "I" <argument>+ "E" right before
+// the closing "E" and are rejected by the demangler until they are implemented.
+
+use std::fmt::Display;
+
+use crate::NameSpec;
+
+const SYMBOL_PREFIX: &str = "_T";
+const SYMBOL_SUFFIX: char = 'E';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemKind {
+    Function,
+    Method,
+    Type,
+    Constant,
+    FuncType,
+}
+
+impl ItemKind {
+    fn to_char(self) -> char {
+        match self {
+            Self::Function => 'F',
+            Self::Method => 'M',
+            Self::Type => 'T',
+            Self::Constant => 'C',
+            Self::FuncType => 'P',
+        }
+    }
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            'F' => Some(Self::Function),
+            'M' => Some(Self::Method),
+            'T' => Some(Self::Type),
+            'C' => Some(Self::Constant),
+            'P' => Some(Self::FuncType),
+            _ => None,
+        }
+    }
+}
+
+pub fn mangle<S: AsRef<str>>(kind: ItemKind, path: &[S]) -> String {
+    let mut symbol = format!("{SYMBOL_PREFIX}{}", kind.to_char());
+
+    for segment in path.iter().map(AsRef::as_ref) {
+        symbol.push_str(&format!("{}{segment}", segment.len()));
+    }
+
+    symbol.push(SYMBOL_SUFFIX);
+    symbol
+}
+
+pub fn mangle_name(kind: ItemKind, name: &NameSpec) -> String {
+    let path: Vec<String> = name.path.iter().map(ToString::to_string).collect();
+    mangle(kind, &path)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Demangled {
+    pub kind: ItemKind,
+    pub path: Vec<String>,
+}
+
+impl Display for Demangled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.path.join("::"))
+    }
+}
+
+pub fn demangle(symbol: &str) -> Option<Demangled> {
+    let rest = symbol.strip_prefix(SYMBOL_PREFIX)?;
+
+    let mut chars = rest.chars();
+    let kind = ItemKind::from_char(chars.next()?)?;
+    let mut rest = chars.as_str();
+
+    let mut path = Vec::<String>::new();
+    while !rest.starts_with(SYMBOL_SUFFIX) {
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits == 0 || rest.starts_with('0') {
+            return None;
+        }
+
+        let len: usize = rest[..digits].parse().ok()?;
+        let segment = rest.get(digits..digits + len)?;
+        if !segment.chars().all(is_ident_char) {
+            return None;
+        }
+
+        path.push(segment.to_string());
+        rest = &rest[digits + len..];
+    }
+
+    if path.is_empty() || rest.len() != 1 {
+        return None;
+    }
+
+    Some(Demangled { kind, path })
+}
+
+// Replaces every mangled symbol in the text with the path of the item,
+// e.g. in linker errors or in the output of "nm"
+pub fn demangle_text(text: &str) -> String {
+    let mut res = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find(is_ident_char) {
+        res.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let end = rest.find(|c: char| !is_ident_char(c)).unwrap_or(rest.len());
+        let word = &rest[..end];
+
+        // Some platforms prepend an underscore to C symbols
+        let demangled = demangle(word).or_else(|| word.strip_prefix('_').and_then(demangle));
+        match demangled {
+            Some(demangled) => res.push_str(&demangled.to_string()),
+            None => res.push_str(word),
+        }
+
+        rest = &rest[end..];
+    }
+
+    res.push_str(rest);
+    res
+}
+
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mangle_test() {
+        assert_eq!(mangle(ItemKind::Function, &["app", "foo"]), "_TF3app3fooE");
+        assert_eq!(
+            mangle(ItemKind::Method, &["geo", "Point", "len"]),
+            "_TM3geo5Point3lenE"
+        );
+        assert_eq!(mangle(ItemKind::Type, &["Point"]), "_TT5PointE");
+        assert_eq!(
+            mangle(ItemKind::Constant, &["Color", "Red"]),
+            "_TC5Color3RedE"
+        );
+        assert_eq!(
+            mangle(ItemKind::FuncType, &["pci32", "pci32", "i32"]),
+            "_TP5pci325pci323i32E"
+        );
+
+        // Module path does not collide with the item named by separators
+        assert_ne!(
+            mangle(ItemKind::Function, &["a", "b"]),
+            mangle(ItemKind::Function, &["a__b"])
+        );
+    }
+
+    #[test]
+    fn demangle_test() {
+        assert_eq!(
+            demangle("_TM3geo5Point3lenE"),
+            Some(Demangled {
+                kind: ItemKind::Method,
+                path: vec!["geo".to_string(), "Point".to_string(), "len".to_string()],
+            })
+        );
+        assert_eq!(
+            demangle("_TF12long_segment1aE").unwrap().to_string(),
+            "long_segment::a"
+        );
+
+        assert_eq!(demangle("main"), None);
+        assert_eq!(demangle("_TX3fooE"), None);
+        assert_eq!(demangle("_TF3fooEE"), None);
+        assert_eq!(demangle("_TF03fooE"), None);
+        assert_eq!(demangle("_TF9fooE"), None);
+        assert_eq!(demangle("_TFE"), None);
+    }
+
+    #[test]
+    fn demangle_text_test() {
+        const NM_OUTPUT: &str = "0000000000000000 T _TF3app4mainE\n\
+                                 \x20                U _TF3geo6originE\n\
+                                 \x20                U printf\n";
+        const LINKER_ERROR: &str = "main.o: undefined reference to `__TM3geo5Point3lenE'";
+
+        assert_eq!(
+            demangle_text(NM_OUTPUT),
+            "0000000000000000 T app::main\n\
+             \x20                U geo::origin\n\
+             \x20                U printf\n"
+        );
+        assert_eq!(
+            demangle_text(LINKER_ERROR),
+            "main.o: undefined reference to `geo::Point::len'"
+        );
+    }
+}
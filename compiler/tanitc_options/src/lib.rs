@@ -1,10 +1,13 @@
 use std::path::PathBuf;
 
+use tanitc_lints::LintLevel;
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum SerializationOption {
     #[default]
     Disabled,
-    Enabled,
+    Json,
+    Ron,
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -22,6 +25,39 @@ pub enum Backend {
     Clang,
 }
 
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorMode {
+    #[default]
+    Auto, // colour if messages are printed to terminal
+    Always,
+    Never,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    #[default]
+    Human,
+    Json, // one JSON object per message
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternCrate {
+    pub name: String,
+    pub path: PathBuf, // path to the library archive
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CfgOption {
+    pub name: String,
+    pub value: Option<String>, // for `name="value"` options
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintOption {
+    pub name: String,
+    pub level: LintLevel,
+}
+
 #[derive(Default, Debug, Clone)]
 pub struct CompileOptions {
     pub crate_name: String,
@@ -29,9 +65,18 @@ pub struct CompileOptions {
     pub output_file: PathBuf,
     pub verbose_tokens: bool,
     pub dump_ast_mode: SerializationOption,
+    pub dump_hir_mode: SerializationOption,
     pub allow_variants: bool,
     pub backend: Backend,
     pub crate_type: CrateType,
     pub libraries: Vec<String>,
     pub libraries_paths: Vec<PathBuf>,
+    pub include_paths: Vec<PathBuf>,
+    pub extern_crates: Vec<ExternCrate>,
+    pub cfg_options: Vec<CfgOption>,
+    pub error_limit: Option<usize>, // errors after the limit are not reported
+    pub color: ColorMode,
+    pub error_format: ErrorFormat,
+    pub lint_options: Vec<LintOption>, // later options override earlier ones
+    pub deny_warnings: bool,
 }
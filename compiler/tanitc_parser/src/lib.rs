@@ -1,18 +1,22 @@
 use std::path::{Path, PathBuf};
 
 use tanitc_ast::program_ctx::ProgramCtx;
-use tanitc_messages::{listener::MessageListener, Message};
+use tanitc_messages::{codes::ErrorCode, listener::MessageListener, Message};
 
 pub(crate) mod program_ctx;
 
 use tanitc_lexer::{
+    comment::Comment,
     token::{lexeme::Lexeme, Token},
     Lexer, Tokens,
 };
 
 pub struct Parser {
     path: PathBuf,
+    module_dir: PathBuf,         // directory of nested external modules
+    include_paths: Vec<PathBuf>, // directories to search external modules in
     tokens: Tokens,
+    comments: Vec<Comment>, // kept for the formatter
     offset: usize,
     messages: MessageListener,
     ignore_nl_opt: bool,
@@ -22,9 +26,18 @@ pub type ParseResult<T> = Result<T, Message>;
 
 impl Parser {
     pub fn new(mut lexer: Lexer) -> Self {
+        let path = lexer.get_path().to_path_buf();
+        let module_dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
         Self {
-            path: lexer.get_path().to_path_buf(),
+            path,
+            module_dir,
+            include_paths: Vec::new(),
             tokens: lexer.tokenize(),
+            comments: lexer.take_comments(),
             offset: 0,
             messages: MessageListener::new(),
             ignore_nl_opt: true,
@@ -32,9 +45,14 @@ impl Parser {
     }
 
     pub fn from_text(src: &str) -> Self {
+        let mut lexer = Lexer::new(src.chars().peekable(), &PathBuf::from("text"));
+
         Self {
             path: PathBuf::from("text"),
-            tokens: Lexer::new(src.chars().peekable(), &PathBuf::from("text")).tokenize(),
+            module_dir: PathBuf::from("."),
+            include_paths: Vec::new(),
+            tokens: lexer.tokenize(),
+            comments: lexer.take_comments(),
             offset: 0,
             messages: MessageListener::new(),
             ignore_nl_opt: true,
@@ -45,8 +63,21 @@ impl Parser {
         &self.path
     }
 
+    pub fn comments_ref(&self) -> &[Comment] {
+        &self.comments
+    }
+
+    pub fn set_include_paths(&mut self, include_paths: Vec<PathBuf>) {
+        self.include_paths = include_paths;
+    }
+
+    pub fn set_message_listener(&mut self, messages: MessageListener) {
+        self.messages = messages;
+    }
+
     pub fn parse_program(&mut self) -> Result<Box<ProgramCtx>, MessageListener> {
         match self.parse_program_ctx() {
+            Ok(_) if self.messages.has_errors() => Err(std::mem::take(self.messages_mut())),
             Ok(program_ctx) => Ok(Box::new(program_ctx)),
             Err(msg) => {
                 self.error(msg);
@@ -126,10 +157,7 @@ impl Parser {
                 Ok(tkn)
             }
 
-            _ => Err(Message::new(
-                tkn.get_location(),
-                format!("Unexpected token {tkn}. Expected identifier."),
-            )),
+            _ => Err(Message::unexpected_token_expecting(&tkn, "identifier")),
         }
     }
 
@@ -141,10 +169,7 @@ impl Parser {
                 self.get_token();
                 Ok(tkn)
             }
-            _ => Err(Message::new(
-                tkn.get_location(),
-                format!("Unexpected token {tkn}. Expected integer number."),
-            )),
+            _ => Err(Message::unexpected_token_expecting(&tkn, "integer number")),
         }
     }
 
@@ -156,10 +181,7 @@ impl Parser {
                 self.get_token();
                 Ok(tkn)
             }
-            _ => Err(Message::new(
-                tkn.get_location(),
-                format!("Unexpected token {tkn}. Expected decimal number."),
-            )),
+            _ => Err(Message::unexpected_token_expecting(&tkn, "decimal number")),
         }
     }
 
@@ -171,10 +193,7 @@ impl Parser {
                 self.get_token();
                 Ok(tkn)
             }
-            _ => Err(Message::new(
-                tkn.get_location(),
-                format!("Unexpected token {tkn}. Expected text."),
-            )),
+            _ => Err(Message::unexpected_token_expecting(&tkn, "text")),
         }
     }
 
@@ -204,6 +223,67 @@ impl Parser {
         self.ignore_nl_opt = old_opt;
     }
 
+    // Panic mode recovery: skips the rest of the broken statement, so parsing
+    // continues from the next newline, "}" or definition
+    pub(crate) fn synchronize(&mut self, start_offset: usize) {
+        // Nothing else is worth parsing if errors are not reported anymore
+        if self.messages.is_error_limit_reached() {
+            self.offset = self.tokens.len();
+            return;
+        }
+
+        let old_opt = self.ignore_nl_opt;
+        self.ignore_nl_opt = false;
+
+        // Statement must be skipped even if it failed on its first token
+        if self.offset == start_offset {
+            self.get_token();
+        }
+
+        while let Some(next) = self.peek_token() {
+            if matches!(next.lexeme_ref(), Lexeme::EndOfLine | Lexeme::Rcb)
+                || Self::is_definition_keyword(next.lexeme_ref())
+            {
+                break;
+            }
+
+            self.get_token();
+        }
+
+        self.ignore_nl_opt = old_opt;
+    }
+
+    // Records the error of one line of the body, so the next lines are still parsed
+    pub(crate) fn recover_line<T>(&mut self, res: ParseResult<T>) -> Option<T> {
+        match res {
+            Ok(ctx) => Some(ctx),
+            Err(err) => {
+                self.error(err);
+                self.skip_until(&[Lexeme::EndOfLine, Lexeme::Rcb]);
+                None
+            }
+        }
+    }
+
+    pub(crate) fn is_definition_keyword(lexeme: &Lexeme) -> bool {
+        matches!(
+            lexeme,
+            Lexeme::KwAlias
+                | Lexeme::KwEnum
+                | Lexeme::KwStruct
+                | Lexeme::KwUnion
+                | Lexeme::KwFunc
+                | Lexeme::KwVariant
+                | Lexeme::KwDef
+                | Lexeme::KwModule
+                | Lexeme::KwStatic
+                | Lexeme::KwConst
+                | Lexeme::KwVar
+                | Lexeme::KwExtern
+                | Lexeme::KwImpl
+        )
+    }
+
     pub fn get_current_token_index(&self) -> usize {
         self.offset
     }
@@ -221,6 +301,17 @@ impl Parser {
     }
 
     pub fn error(&mut self, mut error: Message) {
+        // Every enclosing construct fails at the end of file, but it is reported once
+        if error.code == Some(ErrorCode::UnexpectedEof)
+            && self
+                .messages
+                .errors_ref()
+                .iter()
+                .any(|err| err.code == Some(ErrorCode::UnexpectedEof))
+        {
+            return;
+        }
+
         error.text = format!("Syntax error: {}", error.text);
         self.messages.error(error);
     }
@@ -1,5 +1,7 @@
 use tanitc_ast::program_ctx::ProgramCtx;
 
+use tanitc_messages::Message;
+
 use crate::{ParseResult, Parser};
 
 pub(crate) mod name_ctx;
@@ -8,8 +10,196 @@ pub(crate) mod type_ctx;
 
 impl Parser {
     pub(crate) fn parse_program_ctx(&mut self) -> ParseResult<ProgramCtx> {
-        Ok(ProgramCtx {
-            statements_ctx: self.parse_statements_ctx()?,
-        })
+        let mut statements_ctx = self.parse_statements_ctx()?;
+
+        // Unbalanced "}" stops the statements, but the rest of file is still parsed
+        while let Some(rcb_tkn) = self.get_token() {
+            self.error(Message::unexpected_token(&rcb_tkn, &[]));
+
+            let mut rest_ctx = self.parse_statements_ctx()?;
+            statements_ctx.statements.append(&mut rest_ctx.statements);
+        }
+
+        Ok(ProgramCtx { statements_ctx })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_messages::{codes::ErrorCode, listener::MessageListener};
+
+    use crate::Parser;
+
+    #[test]
+    fn parse_program_recovery_test() {
+        // Given
+        const SRC_TEXT: &str = "func foo(a: i32): i32 {\
+                              \n    var x = (1 +\
+                              \n    return a\
+                              \n}\
+                              \nstruct S {\
+                              \n    a:\
+                              \n    b: i32\
+                              \n}\
+                              \n}\
+                              \nfunc main() {\
+                              \n    var y: = 5\
+                              \n    foo(1)\
+                              \n}";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let res = parser.parse_program();
+
+        // Then
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 4);
+        assert!(errors[2].text.contains("'}'"));
+        assert!(errors[3].text.contains("'='"));
+    }
+
+    #[test]
+    fn parse_program_error_limit_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                              \n    var a: = 1\
+                              \n    var b: = 2\
+                              \n    var c: = 3\
+                              \n}";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+        parser.set_message_listener(MessageListener::with_error_limit(Some(2)));
+
+        // When
+        let res = parser.parse_program();
+
+        // Then
+        let messages = res.expect_err("Expected errors");
+        assert_eq!(messages.errors_ref().len(), 2);
+        assert!(messages.is_error_limit_reached());
+    }
+
+    #[test]
+    fn parse_var_def_missing_colon_bad_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                              \n    var x i32 = 1\
+                              \n}";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let res = parser.parse_program();
+
+        // Then
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].text.contains("i32"), "{}", errors[0].text);
+    }
+
+    #[test]
+    fn parse_stray_token_after_expression_bad_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                              \n    var a = 1\
+                              \n    a = a + 2 3\
+                              \n    a = 4\
+                              \n}";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let res = parser.parse_program();
+
+        // Then
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].text.contains("3"), "{}", errors[0].text);
+    }
+
+    #[test]
+    fn parse_missing_operand_bad_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                              \n    var a = 1 +\
+                              \n}";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let res = parser.parse_program();
+
+        // Then
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].text,
+            "Syntax error: Unexpected token: EOL. Expected expression."
+        );
+        assert_eq!(errors[0].code, Some(ErrorCode::UnexpectedToken));
+    }
+
+    #[test]
+    fn parse_array_literal_good_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                              \n    var _a = [1, 2, 3]\
+                              \n}";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let res = parser.parse_program();
+
+        // Then
+        res.expect("Expected no errors");
+    }
+
+    #[test]
+    fn parse_eof_reported_once_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                              \n    var _a = [1, 2, 3]";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let res = parser.parse_program();
+
+        // Then
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].text, "Syntax error: Reached EOF");
+        assert_eq!(errors[0].code, Some(ErrorCode::UnexpectedEof));
+    }
+
+    #[test]
+    fn parse_nested_eof_reported_once_test() {
+        // Given
+        const SRC_TEXT: &str = "func main() {\
+                              \n    var _a = (1 +";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let res = parser.parse_program();
+
+        // Then
+        let messages = res.expect_err("Expected errors");
+        let errors = messages.errors_ref();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, Some(ErrorCode::UnexpectedEof));
     }
 }
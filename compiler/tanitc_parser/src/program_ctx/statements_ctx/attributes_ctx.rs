@@ -1,5 +1,9 @@
-use tanitc_ast::program_ctx::statement_ctx::attributes_ctx::AttributesCtx;
-use tanitc_lexer::token::lexeme::Lexeme;
+use tanitc_ast::program_ctx::{
+    name_ctx::NameCtx,
+    statement_ctx::attributes_ctx::{AttributeCtx, AttributesCtx, MetaItemCtx, MetaListCtx},
+};
+use tanitc_lexer::token::{lexeme::Lexeme, Token};
+use tanitc_messages::{codes::ErrorCode, Message};
 
 use crate::{ParseResult, Parser};
 
@@ -9,6 +13,10 @@ impl Parser {
 
         while let Some(next) = self.peek_token() {
             match next.lexeme_ref() {
+                Lexeme::HashLsb => {
+                    attrs.attributes.push(self.parse_attribute_ctx()?);
+                    self.skip_new_lines();
+                }
                 Lexeme::KwSafe => {
                     self.get_token();
                     attrs.safe_tkn = Some(next)
@@ -27,10 +35,126 @@ impl Parser {
 
         Ok(attrs)
     }
+
+    fn parse_attribute_ctx(&mut self) -> ParseResult<AttributeCtx> {
+        let old_opt = self.does_ignore_nl();
+        self.set_ignore_nl_option(true);
+
+        let attribute = self.parse_attribute_ctx_internal();
+
+        self.set_ignore_nl_option(old_opt);
+
+        attribute
+    }
+
+    fn parse_attribute_ctx_internal(&mut self) -> ParseResult<AttributeCtx> {
+        Ok(AttributeCtx {
+            hash_lsb_tkn: self.consume_token(Lexeme::HashLsb)?,
+            meta_ctx: self.parse_meta_item_ctx()?,
+            rsb_tkn: self.consume_token(Lexeme::Rsb)?,
+        })
+    }
+
+    fn parse_meta_item_ctx(&mut self) -> ParseResult<MetaItemCtx> {
+        let next = self.peek_token().ok_or(Message::reached_eof())?;
+
+        match next.lexeme_ref() {
+            Lexeme::Identifier(_) => {
+                let name_ctx = NameCtx {
+                    name_tkn: self.consume_identifier()?,
+                };
+
+                if self.is_next(Lexeme::Assign) {
+                    let assign_tkn = self.consume_token(Lexeme::Assign)?;
+                    let value_tkn = self.consume_meta_literal()?;
+
+                    Ok(MetaItemCtx::NameValue {
+                        name_ctx,
+                        assign_tkn,
+                        value_tkn,
+                    })
+                } else if self.is_next(Lexeme::LParen) {
+                    Ok(MetaItemCtx::List {
+                        name_ctx,
+                        list_ctx: self.parse_meta_list_ctx()?,
+                    })
+                } else {
+                    Ok(MetaItemCtx::Word(name_ctx))
+                }
+            }
+
+            Lexeme::Integer(_) | Lexeme::Decimal(_) | Lexeme::Text(_) => {
+                Ok(MetaItemCtx::Literal(self.consume_meta_literal()?))
+            }
+
+            _ => Err(Message::new(
+                next.get_location(),
+                format!(
+                    "Unexpected token in attribute: {}, expected identifier or literal",
+                    next.lexeme_ref()
+                ),
+            )
+            .with_code(ErrorCode::UnexpectedToken)),
+        }
+    }
+
+    fn parse_meta_list_ctx(&mut self) -> ParseResult<MetaListCtx> {
+        let lparen_tkn = self.consume_token(Lexeme::LParen)?;
+        let mut items = Vec::new();
+
+        while !self.is_next(Lexeme::RParen) {
+            let item_ctx = self.parse_meta_item_ctx()?;
+
+            let comma_tkn = if self.is_next(Lexeme::Comma) {
+                self.get_token()
+            } else {
+                None
+            };
+
+            let is_last = comma_tkn.is_none();
+            items.push((item_ctx, comma_tkn));
+
+            if is_last {
+                break;
+            }
+        }
+
+        let rparen_tkn = self.consume_token(Lexeme::RParen)?;
+
+        Ok(MetaListCtx {
+            lparen_tkn,
+            items,
+            rparen_tkn,
+        })
+    }
+
+    fn consume_meta_literal(&mut self) -> ParseResult<Token> {
+        let tkn = self.peek_token().ok_or(Message::reached_eof())?;
+
+        match tkn.lexeme_ref() {
+            Lexeme::Integer(_) | Lexeme::Decimal(_) | Lexeme::Text(_) => {
+                self.get_token();
+                Ok(tkn)
+            }
+            _ => Err(Message::unexpected_token_expecting(&tkn, "literal")),
+        }
+    }
+
+    fn skip_new_lines(&mut self) {
+        let old_opt = self.does_ignore_nl();
+        self.set_ignore_nl_option(false);
+
+        while self.is_next(Lexeme::EndOfLine) {
+            self.get_token();
+        }
+
+        self.set_ignore_nl_option(old_opt);
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use tanitc_ast::program_ctx::statement_ctx::attributes_ctx::MetaItemCtx;
     use tanitc_lexer::token::lexeme::Lexeme;
 
     use crate::Parser;
@@ -76,4 +200,48 @@ mod tests {
         );
         assert_eq!(attrs.unsafe_tkn, None);
     }
+
+    #[test]
+    fn attrs_meta_test() {
+        const SRC_TEXT: &str = "#[inline]\n#[deprecated(note = \"use bar\", 1)]\npub func";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+        let attrs = parser.parse_attributes_ctx().unwrap();
+
+        assert_eq!(attrs.attributes.len(), 2);
+        assert_eq!(*attrs.pub_tkn.as_ref().unwrap().lexeme_ref(), Lexeme::KwPub);
+        assert!(parser.is_next(Lexeme::KwFunc));
+
+        let MetaItemCtx::Word(name_ctx) = &attrs.attributes[0].meta_ctx else {
+            panic!(
+                "Expected word, actually: {:?}",
+                attrs.attributes[0].meta_ctx
+            );
+        };
+        assert_eq!(name_ctx.to_string(), "inline");
+
+        let MetaItemCtx::List { name_ctx, list_ctx } = &attrs.attributes[1].meta_ctx else {
+            panic!(
+                "Expected list, actually: {:?}",
+                attrs.attributes[1].meta_ctx
+            );
+        };
+        assert_eq!(name_ctx.to_string(), "deprecated");
+        assert_eq!(list_ctx.items.len(), 2);
+        assert!(matches!(list_ctx.items[0].0, MetaItemCtx::NameValue { .. }));
+        assert!(matches!(list_ctx.items[1].0, MetaItemCtx::Literal(_)));
+    }
+
+    #[test]
+    fn attrs_bad_meta_test() {
+        const SRC_TEXT: &str = "#[inline(]";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+        let err = parser.parse_attributes_ctx().expect_err("Expected error");
+
+        assert_eq!(
+            err.text,
+            "Unexpected token in attribute: ']', expected identifier or literal"
+        );
+    }
 }
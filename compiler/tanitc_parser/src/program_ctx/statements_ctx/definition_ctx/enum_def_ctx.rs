@@ -1,5 +1,5 @@
 use tanitc_ast::program_ctx::statement_ctx::definition_ctx::enum_def_ctx::{
-    EnumDefBodyCtx, EnumDefCtx, EnumDefUnitAssignCtx, EnumDefUnitCtx,
+    EnumDefBodyCtx, EnumDefCtx, EnumDefReprCtx, EnumDefUnitAssignCtx, EnumDefUnitCtx,
 };
 use tanitc_lexer::token::{lexeme::Lexeme, Token};
 use tanitc_messages::Message;
@@ -12,10 +12,22 @@ impl Parser {
             attributes_ctx: Box::default(),
             enum_tkn: self.consume_token(Lexeme::KwEnum)?,
             name_ctx: Box::new(self.parse_name_ctx()?),
+            repr_ctx: if self.is_next(Lexeme::Colon) {
+                Some(self.parse_enum_def_repr_ctx()?)
+            } else {
+                None
+            },
             body_ctx: self.parse_enum_def_body_ctx()?,
         })
     }
 
+    fn parse_enum_def_repr_ctx(&mut self) -> ParseResult<EnumDefReprCtx> {
+        Ok(EnumDefReprCtx {
+            colon_tkn: self.consume_token(Lexeme::Colon)?,
+            type_ctx: Box::new(self.parse_type_ctx()?),
+        })
+    }
+
     fn parse_enum_def_body_ctx(&mut self) -> ParseResult<EnumDefBodyCtx> {
         Ok(EnumDefBodyCtx {
             lcb_tkn: self.consume_token(Lexeme::Lcb)?,
@@ -42,14 +54,17 @@ impl Parser {
             let unit_ctx = match next.lexeme_ref() {
                 Lexeme::Rcb => break,
                 Lexeme::EndOfLine => None,
-                Lexeme::Identifier(_) => Some(self.parse_enum_def_unit_ctx()?),
+                Lexeme::Identifier(_) => {
+                    let unit_ctx = self.parse_enum_def_unit_ctx();
+                    self.recover_line(unit_ctx)
+                }
                 Lexeme::Lcb => {
-                    return Err(Message::new(
-                        next.get_location(),
-                        "Unexpected token: \"{{\" during parsing enum fields.\n\
-                            Help: if you tried to declare struct-like field, place \"{{\" \
-                            in the same line with name of the field.",
-                    ));
+                    return Err(
+                        Message::unexpected_token_expecting(&next, "name of enum unit").with_help(
+                            "if you tried to declare struct-like field, place \"{\" \
+                        in the same line with name of the field",
+                        ),
+                    );
                 }
                 _ => {
                     self.error(Message::unexpected_token(&next, &[]));
@@ -154,6 +169,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_enum_def_with_repr_test() {
+        const SRC_TEXT: &str = "\nenum Opcode: u8 {\
+                            \n    Nop\
+                            \n}";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        let enum_def_ctx = parser.parse_enum_def_ctx().unwrap();
+
+        assert_eq!(enum_def_ctx.name_ctx.to_string(), "Opcode");
+
+        let Some(repr_ctx) = &enum_def_ctx.repr_ctx else {
+            panic!("Expected repr ctx");
+        };
+        assert_eq!(*repr_ctx.colon_tkn.lexeme_ref(), Lexeme::Colon);
+        assert!(repr_ctx.type_ctx.is_named());
+        assert_eq!(enum_def_ctx.body_ctx.units_ctx.len(), 2);
+    }
+
     #[test]
     fn parse_empty_enum_def_test() {
         const SRC_TEXT: &str = "\nenum EmptyEnum { }";
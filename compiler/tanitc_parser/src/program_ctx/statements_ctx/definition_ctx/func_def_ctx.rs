@@ -35,6 +35,7 @@ impl Parser {
                 body_ctx
             },
             attributes_ctx: Box::default(),
+            abi_ctx: None,
         })
     }
 }
@@ -110,7 +111,7 @@ impl Parser {
                 let mut params = Vec::<FuncDefParamCtx>::new();
 
                 loop {
-                    if self.is_next(Lexeme::RParen) {
+                    if self.is_next(Lexeme::RParen) || self.is_next(Lexeme::Ellipsis) {
                         break;
                     }
 
@@ -145,6 +146,7 @@ impl Parser {
 
                 params
             },
+            ellipsis_tkn: self.consume_token(Lexeme::Ellipsis).ok(),
             rparen_tkn: self.consume_token(Lexeme::RParen)?,
         })
     }
@@ -192,6 +194,23 @@ mod tests {
         assert_eq!(func_def_ctx.params_ctx.params_ctx.len(), 1);
     }
 
+    #[test]
+    fn parse_variadic_func_def_test() {
+        const SRC_TEXT: &str = "func printf(fmt: *const u8, ...): i32\n";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+        let func_def_ctx = parser.parse_func_def_ctx().unwrap();
+
+        assert_eq!(func_def_ctx.name_ctx.to_string(), "printf");
+        assert!(func_def_ctx.body_ctx.is_none());
+        assert_eq!(func_def_ctx.params_ctx.params_ctx.len(), 1);
+
+        let Some(ellipsis_tkn) = &func_def_ctx.params_ctx.ellipsis_tkn else {
+            panic!("Expected '...'");
+        };
+        assert_eq!(*ellipsis_tkn.lexeme_ref(), Lexeme::Ellipsis);
+    }
+
     #[test]
     fn parse_func_def_with_self_test() {
         const SRC_TEXT: &str = "func with_self(self) { }";
@@ -30,7 +30,7 @@ impl Parser {
             Lexeme::KwConst => self.parse_const_def_ctx().map(DefinitionCtx::Const),
             Lexeme::KwVar => self.parse_var_def_ctx().map(DefinitionCtx::Variable),
             Lexeme::KwImpl => self.parse_impl_ctx().map(DefinitionCtx::Impl),
-            Lexeme::KwExtern => self.parse_extern_ctx().map(DefinitionCtx::Extern),
+            Lexeme::KwExtern => self.parse_extern_definition_ctx(),
             Lexeme::KwDef | Lexeme::KwModule => {
                 self.parse_module_def_ctx().map(DefinitionCtx::Module)
             }
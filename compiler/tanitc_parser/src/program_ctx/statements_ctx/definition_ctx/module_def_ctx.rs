@@ -1,4 +1,7 @@
-use std::{io::Read, path::PathBuf};
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
 
 use tanitc_ast::program_ctx::statement_ctx::definition_ctx::module_def_ctx::{
     ModuleDefBodyCtx, ModuleDefCtx,
@@ -45,61 +48,35 @@ impl Parser {
         )))
     }
 
+    // Searches "<name>.tt" and "<name>/mod.tt" in the directory of the current
+    // module and then in the include paths, in order
     fn get_external_module_path(&mut self, module_tkn: &Token) -> Result<PathBuf, Message> {
         let name = module_tkn.identifier().to_string();
 
-        let Some(current_path_str) = self.get_path().to_str() else {
-            return Err(Message::new(
-                module_tkn.get_location(),
-                "Failed to get path",
-            ));
-        };
-
-        let mut path = current_path_str
-            .chars()
-            .rev()
-            .collect::<String>()
-            .splitn(2, '/')
-            .collect::<Vec<&str>>()[1]
-            .chars()
-            .rev()
-            .collect::<String>();
-
-        path.push('/');
-        path.push_str(&name);
-
-        let mut file_exists: bool;
-
-        {
-            // Try to search in external_module.tt
-            let mut path = path.clone();
-            path.push_str(".tt");
-
-            file_exists = std::path::Path::new(&path).exists();
-            if file_exists {
-                return Ok(PathBuf::from(path));
+        let mut candidates = Vec::<PathBuf>::new();
+        for dir in std::iter::once(&self.module_dir).chain(self.include_paths.iter()) {
+            for path in [
+                dir.join(format!("{name}.tt")),
+                dir.join(&name).join("mod.tt"),
+            ] {
+                if path.exists() {
+                    return Ok(path);
+                }
+
+                candidates.push(path);
             }
         }
 
-        if !file_exists {
-            // Try to search in external_module/mod.tt
-            let mut path = path.clone();
-            path.push_str("/mod.tt");
-
-            file_exists = std::path::Path::new(&path).exists();
-            if file_exists {
-                return Ok(PathBuf::from(path));
-            }
-        }
+        let candidates = candidates
+            .iter()
+            .map(|path| format!("\"{}\"", path.display()))
+            .collect::<Vec<String>>()
+            .join(", ");
 
-        if !file_exists {
-            return Err(Message::new(
-                module_tkn.get_location(),
-                format!("Module \"{name}\" not found"),
-            ));
-        }
-
-        Ok(PathBuf::from(path))
+        Err(Message::module_not_found(
+            module_tkn.get_location(),
+            format!("Module \"{name}\" not found, searched in: {candidates}"),
+        ))
     }
 
     fn parse_module_body_external(&mut self, module_tkn: &Token) -> ParseResult<ModuleDefBodyCtx> {
@@ -107,7 +84,12 @@ impl Parser {
 
         let mut file = match std::fs::File::open(&path) {
             Ok(file) => file,
-            Err(err) => return Err(Message::new(module_tkn.get_location(), err.to_string())),
+            Err(err) => {
+                return Err(Message::module_not_found(
+                    module_tkn.get_location(),
+                    format!("Module file \"{}\" can not be read: {err}", path.display()),
+                ))
+            }
         };
 
         let mut buffer = String::new();
@@ -116,21 +98,33 @@ impl Parser {
 
         let lexer = Lexer::new(buffer.chars().peekable(), &path);
 
+        // Nested modules of both "<name>.tt" and "<name>/mod.tt" live in "<name>/"
+        let dir = path.parent().unwrap_or(Path::new("."));
+
         let mut parser = Parser::new(lexer);
+        parser.module_dir = if path.ends_with("mod.tt") {
+            dir.to_path_buf()
+        } else {
+            dir.join(module_tkn.identifier().to_string())
+        };
+        parser.include_paths = self.include_paths.clone();
 
-        Ok(ModuleDefBodyCtx::External(Box::new(
-            parser.parse_program_ctx()?,
-        )))
+        let program_ctx = parser.parse_program_ctx();
+        self.messages.append(std::mem::take(parser.messages_mut()));
+
+        Ok(ModuleDefBodyCtx::External(Box::new(program_ctx?)))
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::path::{Path, PathBuf};
 
     use tanitc_ast::program_ctx::statement_ctx::{
         definition_ctx::{module_def_ctx::ModuleDefBodyCtx, DefinitionCtx},
         StatementCtx,
     };
+    use tanitc_lexer::Lexer;
 
     use crate::Parser;
 
@@ -182,4 +176,91 @@ mod tests {
             }
         }
     }
+
+    fn create_test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tanitc_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn create_test_file(path: &Path, text: &str) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, text).unwrap();
+    }
+
+    fn create_parser(path: &Path, text: &str) -> Parser {
+        Parser::new(Lexer::new(text.chars().peekable(), path))
+    }
+
+    #[test]
+    fn module_in_include_path_test() {
+        let dir = create_test_dir("include_path");
+        create_test_file(&dir.join("include/shared/mod.tt"), "module inner { }\n");
+
+        let mut parser = create_parser(&dir.join("src/main.tt"), "def module shared\n");
+        parser.set_include_paths(vec![dir.join("missing"), dir.join("include")]);
+
+        let module_def_ctx = parser.parse_module_def_ctx().unwrap();
+
+        let ModuleDefBodyCtx::External(program_ctx) = &module_def_ctx.body_ctx else {
+            panic!("Unexpected: {}", module_def_ctx.body_ctx.kind_str());
+        };
+        assert_eq!(program_ctx.statements_ctx.statements.len(), 1);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn nested_module_directories_test() {
+        let dir = create_test_dir("nested_modules");
+        create_test_file(&dir.join("a.tt"), "def module b\n");
+        create_test_file(&dir.join("a/b/mod.tt"), "def module c\n");
+        create_test_file(&dir.join("a/b/c.tt"), "module d { }\n");
+
+        let mut parser = create_parser(&dir.join("main.tt"), "def module a\n");
+
+        let module_def_ctx = parser.parse_module_def_ctx().unwrap();
+
+        let ModuleDefBodyCtx::External(a_ctx) = &module_def_ctx.body_ctx else {
+            panic!("Unexpected: {}", module_def_ctx.body_ctx.kind_str());
+        };
+        let Some((Some(StatementCtx::Definition(DefinitionCtx::Module(b_ctx))), _)) =
+            a_ctx.statements_ctx.statements.first()
+        else {
+            panic!("Expected module \"b\"");
+        };
+        let ModuleDefBodyCtx::External(b_ctx) = &b_ctx.body_ctx else {
+            panic!("Unexpected: {}", b_ctx.body_ctx.kind_str());
+        };
+        let Some((Some(StatementCtx::Definition(DefinitionCtx::Module(c_ctx))), _)) =
+            b_ctx.statements_ctx.statements.first()
+        else {
+            panic!("Expected module \"c\"");
+        };
+        assert!(matches!(c_ctx.body_ctx, ModuleDefBodyCtx::External(_)));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn module_not_found_test() {
+        let dir = create_test_dir("module_not_found");
+
+        let mut parser = create_parser(&dir.join("main.tt"), "def module missing\n");
+        parser.set_include_paths(vec![dir.join("include")]);
+
+        let err = parser.parse_module_def_ctx().unwrap_err();
+
+        let expected = format!(
+            "Module \"missing\" not found, searched in: \"{}\", \"{}\", \"{}\", \"{}\"",
+            dir.join("missing.tt").display(),
+            dir.join("missing/mod.tt").display(),
+            dir.join("include/missing.tt").display(),
+            dir.join("include/missing/mod.tt").display(),
+        );
+        assert_eq!(err.text, expected);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
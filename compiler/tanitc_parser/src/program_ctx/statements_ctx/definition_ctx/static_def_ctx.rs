@@ -24,11 +24,15 @@ impl Parser {
         })
     }
 
-    fn parse_static_def_value_ctx(&mut self) -> ParseResult<StaticDefValueCtx> {
-        Ok(StaticDefValueCtx {
+    fn parse_static_def_value_ctx(&mut self) -> ParseResult<Option<StaticDefValueCtx>> {
+        if !self.is_next(Lexeme::Assign) {
+            return Ok(None);
+        }
+
+        Ok(Some(StaticDefValueCtx {
             equal_tkn: self.consume_token(Lexeme::Assign)?,
             value_ctx: Box::new(self.parse_expression_ctx()?),
-        })
+        }))
     }
 }
 
@@ -49,7 +53,7 @@ mod tests {
         assert_eq!(static_def_ctx.name_ctx.to_string(), STATIC_NAME);
         assert!(static_def_ctx.mut_tkn.is_none());
         assert!(static_def_ctx.type_ctx.type_ctx.is_named());
-        assert!(static_def_ctx.value_ctx.value_ctx.is_literal());
+        assert!(static_def_ctx.value_ctx.unwrap().value_ctx.is_literal());
     }
 
     #[test]
@@ -64,6 +68,19 @@ mod tests {
         assert_eq!(static_def_ctx.name_ctx.to_string(), STATIC_NAME);
         assert!(static_def_ctx.mut_tkn.is_some());
         assert!(static_def_ctx.type_ctx.type_ctx.is_named());
-        assert!(static_def_ctx.value_ctx.value_ctx.is_literal());
+        assert!(static_def_ctx.value_ctx.unwrap().value_ctx.is_literal());
+    }
+
+    #[test]
+    fn static_without_value_test() {
+        const SRC_TEXT: &str = "static mut errno: i32\n";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        let static_def_ctx = parser.parse_static_def_ctx().unwrap();
+
+        assert_eq!(static_def_ctx.name_ctx.to_string(), "errno");
+        assert!(static_def_ctx.mut_tkn.is_some());
+        assert!(static_def_ctx.value_ctx.is_none());
     }
 }
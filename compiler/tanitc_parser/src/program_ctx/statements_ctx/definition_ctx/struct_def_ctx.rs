@@ -43,12 +43,10 @@ impl Parser {
 
                 Lexeme::EndOfLine => None,
 
-                Lexeme::Identifier(_) => Some(StructDefFieldCtx {
-                    pub_tkn: self.consume_token(Lexeme::KwPub).ok(),
-                    name_ctx: Box::new(self.parse_name_ctx()?),
-                    colon_tkn: self.consume_token(Lexeme::Colon)?,
-                    type_ctx: Box::new(self.parse_type_ctx()?),
-                }),
+                Lexeme::Identifier(_) | Lexeme::KwPub => {
+                    let field_ctx = self.parse_struct_def_field_ctx();
+                    self.recover_line(field_ctx)
+                }
 
                 _ => {
                     self.error(Message::unexpected_token(&next, &[]));
@@ -65,6 +63,15 @@ impl Parser {
 
         Ok(fields)
     }
+
+    fn parse_struct_def_field_ctx(&mut self) -> ParseResult<StructDefFieldCtx> {
+        Ok(StructDefFieldCtx {
+            pub_tkn: self.consume_token(Lexeme::KwPub).ok(),
+            name_ctx: Box::new(self.parse_name_ctx()?),
+            colon_tkn: self.consume_token(Lexeme::Colon)?,
+            type_ctx: Box::new(self.parse_type_ctx()?),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -129,6 +136,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_struct_def_pub_fields() {
+        const SRC_TEXT: &str = r#"
+            struct Foo {
+                pub x: i32
+                y: f64
+            }
+        "#;
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+        let struct_def_ctx = parser.parse_struct_def_ctx().unwrap();
+        assert!(!parser.messages_ref().has_errors());
+
+        let fields: Vec<_> = struct_def_ctx
+            .body_ctx
+            .fields_ctx
+            .iter()
+            .filter_map(|(field_ctx, _)| field_ctx.as_ref())
+            .collect();
+        assert_eq!(fields.len(), 2);
+
+        assert_eq!(fields[0].name_ctx.to_string(), "x");
+        assert!(fields[0].pub_tkn.is_some());
+
+        assert_eq!(fields[1].name_ctx.to_string(), "y");
+        assert!(fields[1].pub_tkn.is_none());
+    }
+
     #[test]
     fn parse_empty_struct_def() {
         const SRC_TEXT: &str = r#"
@@ -143,4 +178,31 @@ mod tests {
         assert_eq!(*struct_def_ctx.body_ctx.rcb_tkn.lexeme_ref(), Lexeme::Rcb);
         assert!(struct_def_ctx.body_ctx.fields_ctx.is_empty());
     }
+
+    #[test]
+    fn parse_struct_def_with_pub_fields() {
+        const SRC_TEXT: &str = r#"
+            struct Foo {
+                pub x: i32
+                y: f64
+            }
+        "#;
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+        let struct_def_ctx = parser.parse_struct_def_ctx().unwrap();
+
+        assert!(!parser.messages_ref().has_errors());
+
+        let (Some(x_ctx), _) = &struct_def_ctx.body_ctx.fields_ctx[1] else {
+            panic!("Unexpected field context");
+        };
+        assert_eq!(x_ctx.name_ctx.to_string(), "x");
+        assert!(x_ctx.pub_tkn.is_some());
+
+        let (Some(y_ctx), _) = &struct_def_ctx.body_ctx.fields_ctx[2] else {
+            panic!("Unexpected field context");
+        };
+        assert_eq!(y_ctx.name_ctx.to_string(), "y");
+        assert!(y_ctx.pub_tkn.is_none());
+    }
 }
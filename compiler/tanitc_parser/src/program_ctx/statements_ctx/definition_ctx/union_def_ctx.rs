@@ -42,12 +42,10 @@ impl Parser {
                 Lexeme::Rcb => break,
                 Lexeme::EndOfLine => None,
 
-                Lexeme::Identifier(_) => Some(UnionDefFieldCtx {
-                    pub_tkn: self.consume_token(Lexeme::KwPub).ok(),
-                    name_ctx: Box::new(self.parse_name_ctx()?),
-                    colon_tkn: self.consume_token(Lexeme::Colon)?,
-                    type_ctx: Box::new(self.parse_type_ctx()?),
-                }),
+                Lexeme::Identifier(_) | Lexeme::KwPub => {
+                    let field_ctx = self.parse_union_def_field_ctx();
+                    self.recover_line(field_ctx)
+                }
 
                 _ => {
                     self.error(Message::unexpected_token(&next, &[]));
@@ -64,6 +62,15 @@ impl Parser {
 
         Ok(fields)
     }
+
+    fn parse_union_def_field_ctx(&mut self) -> ParseResult<UnionDefFieldCtx> {
+        Ok(UnionDefFieldCtx {
+            pub_tkn: self.consume_token(Lexeme::KwPub).ok(),
+            name_ctx: Box::new(self.parse_name_ctx()?),
+            colon_tkn: self.consume_token(Lexeme::Colon)?,
+            type_ctx: Box::new(self.parse_type_ctx()?),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -128,6 +135,34 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_union_def_pub_fields() {
+        const SRC_TEXT: &str = r#"
+            union Foo {
+                pub x: i32
+                y: f64
+            }
+        "#;
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+        let union_def_ctx = parser.parse_union_def_ctx().unwrap();
+        assert!(!parser.messages_ref().has_errors());
+
+        let fields: Vec<_> = union_def_ctx
+            .body_ctx
+            .fields_ctx
+            .iter()
+            .filter_map(|(field_ctx, _)| field_ctx.as_ref())
+            .collect();
+        assert_eq!(fields.len(), 2);
+
+        assert_eq!(fields[0].name_ctx.to_string(), "x");
+        assert!(fields[0].pub_tkn.is_some());
+
+        assert_eq!(fields[1].name_ctx.to_string(), "y");
+        assert!(fields[1].pub_tkn.is_none());
+    }
+
     #[test]
     fn parse_empty_union_def() {
         const SRC_TEXT: &str = r#"
@@ -1,9 +1,8 @@
 use tanitc_ast::program_ctx::statement_ctx::expression_ctx::{
-    call_ctx::{CallCtx, CallParamCtx, CallParamsCtx, NamedCallParamCtx},
+    call_ctx::{CallCtx, CallParamCtx, CallParamsCtx, NamedCallParamCtx, PositionalCallParamCtx},
     ExpressionCtx,
 };
 use tanitc_lexer::token::{lexeme::Lexeme, Token};
-use tanitc_messages::Message;
 
 use crate::{ParseResult, Parser};
 
@@ -18,32 +17,129 @@ impl Parser {
     }
 
     pub fn parse_call_params_ctx(&mut self) -> ParseResult<CallParamsCtx> {
-        let mut params = Vec::<(Option<CallParamCtx>, Option<Token>)>::new();
+        let old_opt = self.does_ignore_nl();
+        self.set_ignore_nl_option(true);
+
+        let params_ctx = self.parse_call_params_ctx_internal();
 
-        while let Some(next) = self.peek_token() {
-            let param_ctx = match next.lexeme_ref() {
-                Lexeme::Rcb => break,
-                Lexeme::EndOfLine => None,
+        self.set_ignore_nl_option(old_opt);
 
-                Lexeme::Identifier(_) => Some(CallParamCtx::Named(NamedCallParamCtx {
-                    name_ctx: Box::new(self.parse_name_ctx()?),
-                    colon_tkn: self.consume_token(Lexeme::Colon)?,
-                    expression_ctx: Box::new(self.parse_expression_ctx()?),
-                })),
+        params_ctx
+    }
 
-                _ => {
-                    self.error(Message::unexpected_token(&next, &[]));
-                    self.skip_until(&[Lexeme::EndOfLine]);
+    fn parse_call_params_ctx_internal(&mut self) -> ParseResult<CallParamsCtx> {
+        let mut params = Vec::<(Option<CallParamCtx>, Option<Token>)>::new();
 
-                    continue;
+        while !self.is_next(Lexeme::RParen) {
+            let param_ctx = match self.parse_call_param_ctx() {
+                Ok(param_ctx) => Some(param_ctx),
+                Err(err) => {
+                    self.error(err);
+                    self.skip_until(&[Lexeme::Comma, Lexeme::RParen]);
+                    None
                 }
             };
 
-            let nl_tkn = self.consume_token(Lexeme::EndOfLine).ok();
+            let comma_tkn = self.consume_token(Lexeme::Comma).ok();
+            let is_last = comma_tkn.is_none();
 
-            params.push((param_ctx, nl_tkn));
+            params.push((param_ctx, comma_tkn));
+
+            if is_last {
+                break;
+            }
         }
 
         Ok(CallParamsCtx { params })
     }
+
+    fn parse_call_param_ctx(&mut self) -> ParseResult<CallParamCtx> {
+        if self.is_named_call_param() {
+            return Ok(CallParamCtx::Named(NamedCallParamCtx {
+                name_ctx: Box::new(self.parse_name_ctx()?),
+                colon_tkn: self.consume_token(Lexeme::Colon)?,
+                expression_ctx: Box::new(self.parse_expression_ctx()?),
+            }));
+        }
+
+        Ok(CallParamCtx::Positional(PositionalCallParamCtx {
+            expression_ctx: Box::new(self.parse_expression_ctx()?),
+        }))
+    }
+
+    // Named parameter is "ID ':' expr"
+    fn is_named_call_param(&mut self) -> bool {
+        let index = self.get_current_token_index();
+
+        let is_named = self
+            .get_token()
+            .is_some_and(|tkn| tkn.lexeme_ref().is_identifier())
+            && self.is_next(Lexeme::Colon);
+
+        self.set_current_token_index(index);
+
+        is_named
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tanitc_ast::program_ctx::statement_ctx::expression_ctx::{
+        call_ctx::CallParamCtx, literal_ctx::LiteralCtx, ExpressionCtx,
+    };
+
+    use crate::Parser;
+
+    #[test]
+    fn parse_call_params_test() {
+        // Given
+        const SRC_TEXT: &str = "printf(\"%d %s\\n\", 42,\n    text: name)";
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let expression_ctx = parser.parse_expression_ctx().unwrap();
+
+        // Then
+        assert!(!parser.messages_ref().has_errors());
+
+        let ExpressionCtx::Call(call_ctx) = expression_ctx else {
+            panic!("Expected call, actually: {expression_ctx:?}");
+        };
+
+        let params = &call_ctx.params_ctx.params;
+        assert_eq!(params.len(), 3);
+
+        let Some(CallParamCtx::Positional(first)) = &params[0].0 else {
+            panic!("Expected positional parameter, actually: {:?}", params[0].0);
+        };
+        assert!(matches!(
+            first.expression_ctx.as_ref(),
+            ExpressionCtx::Literal(LiteralCtx::Text(_))
+        ));
+        assert!(params[0].1.is_some());
+
+        assert!(matches!(params[1].0, Some(CallParamCtx::Positional(_))));
+
+        let Some(CallParamCtx::Named(named)) = &params[2].0 else {
+            panic!("Expected named parameter, actually: {:?}", params[2].0);
+        };
+        assert_eq!(named.name_ctx.to_string(), "text");
+        assert!(params[2].1.is_none());
+    }
+
+    #[test]
+    fn parse_empty_call_test() {
+        // Given
+        const SRC_TEXT: &str = "foo()";
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let expression_ctx = parser.parse_expression_ctx().unwrap();
+
+        // Then
+        let ExpressionCtx::Call(call_ctx) = expression_ctx else {
+            panic!("Expected call, actually: {expression_ctx:?}");
+        };
+        assert!(call_ctx.params_ctx.params.is_empty());
+    }
 }
@@ -20,7 +20,7 @@ impl Parser {
         let old_opt = self.does_ignore_nl();
         self.set_ignore_nl_option(true);
         while let Some(next) = self.peek_token() {
-            if *next.lexeme_ref() == Lexeme::Rcb {
+            if matches!(next.lexeme_ref(), Lexeme::Rsb | Lexeme::Rcb) {
                 break;
             }
 
@@ -28,7 +28,7 @@ impl Parser {
 
             if let Err(err) = &expression_ctx {
                 self.error(err.clone());
-                self.skip_until(&[Lexeme::Comma]);
+                self.skip_until(&[Lexeme::Comma, Lexeme::Rsb]);
             }
 
             elements.push((expression_ctx.ok(), self.consume_token(Lexeme::Comma).ok()));
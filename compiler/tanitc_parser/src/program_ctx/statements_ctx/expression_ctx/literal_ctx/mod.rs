@@ -15,6 +15,7 @@ impl Parser {
         match next.lexeme_ref() {
             lexem if lexem.is_integer() => Ok(LiteralCtx::Integer(self.consume_integer()?)),
             lexem if lexem.is_decimal() => Ok(LiteralCtx::Decimal(self.consume_decimal()?)),
+            lexem if lexem.is_text() => Ok(LiteralCtx::Text(self.consume_text()?)),
 
             Lexeme::Lsb => self.parse_array_literal_ctx().map(LiteralCtx::Array),
 
@@ -2,6 +2,7 @@ use tanitc_ast::program_ctx::statement_ctx::expression_ctx::{
     binary_ctx::{BinaryCtx, BinaryOpCtx},
     conversion_ctx::ConversionCtx,
     literal_ctx::{tuple_literal_ctx::TupleLiteralCtx, LiteralCtx},
+    member_access_ctx::MemberAccessCtx,
     paren_ctx::ParenCtx,
     unary_ctx::{UnaryCtx, UnaryOpCtx},
     ExpressionCtx,
@@ -59,13 +60,17 @@ impl Parser {
                 expression_ctx: Box::new(self.parse_expression_ctx()?),
             })),
 
-            lexem if lexem.is_integer() || lexem.is_integer() || *lexem == Lexeme::Lsb => {
+            lexem
+                if lexem.is_integer()
+                    || lexem.is_decimal()
+                    || lexem.is_text()
+                    || *lexem == Lexeme::Lsb =>
+            {
                 self.parse_literal_ctx().map(ExpressionCtx::Literal)
             }
 
             lexem if lexem.is_identifier() => {
                 let name_spec_ctx = self.parse_name_spec_ctx()?;
-                println!("{name_spec_ctx:?}");
 
                 let old_opt = self.does_ignore_nl();
                 self.set_ignore_nl_option(true);
@@ -90,10 +95,7 @@ impl Parser {
 
             Lexeme::LParen => self.parse_paren(),
 
-            _ => Err(Message::new(
-                next.get_location(),
-                format!("Unexpected token \"{next}\" within expression"),
-            )),
+            _ => Err(Message::unexpected_token_expecting(&next, "expression")),
         }
     }
 
@@ -169,7 +171,7 @@ impl Parser {
             op if *op == Lexeme::RShiftAssign => {
                 BinaryOpCtx::RightShiftAssign(self.consume_token(op.clone())?)
             }
-            _ => return Err(Message::unexpected_token(&next, &[])),
+            _ => return Ok(lhs),
         };
 
         Ok(ExpressionCtx::Binary(BinaryCtx {
@@ -189,7 +191,7 @@ impl Parser {
         let binary_op_ctx = match next.lexeme_ref() {
             exp if *exp == Lexeme::Or => BinaryOpCtx::LogicOr(self.consume_token(exp.clone())?),
 
-            _ => return Err(Message::unexpected_token(&next, &[])),
+            _ => return Ok(lhs),
         };
 
         Ok(ExpressionCtx::Binary(BinaryCtx {
@@ -209,7 +211,7 @@ impl Parser {
         let binary_op_ctx = match next.lexeme_ref() {
             exp if *exp == Lexeme::And => BinaryOpCtx::Lt(self.consume_token(exp.clone())?),
 
-            _ => return Err(Message::unexpected_token(&next, &[])),
+            _ => return Ok(lhs),
         };
 
         Ok(ExpressionCtx::Binary(BinaryCtx {
@@ -229,7 +231,7 @@ impl Parser {
         let binary_op_ctx = match next.lexeme_ref() {
             exp if *exp == Lexeme::Stick => BinaryOpCtx::BitOr(self.consume_token(exp.clone())?),
 
-            _ => return Err(Message::unexpected_token(&next, &[])),
+            _ => return Ok(lhs),
         };
 
         Ok(ExpressionCtx::Binary(BinaryCtx {
@@ -249,7 +251,7 @@ impl Parser {
         let binary_op_ctx = match next.lexeme_ref() {
             exp if *exp == Lexeme::Xor => BinaryOpCtx::BitXor(self.consume_token(exp.clone())?),
 
-            _ => return Err(Message::unexpected_token(&next, &[])),
+            _ => return Ok(lhs),
         };
 
         Ok(ExpressionCtx::Binary(BinaryCtx {
@@ -271,7 +273,7 @@ impl Parser {
                 BinaryOpCtx::BitAnd(self.consume_token(exp.clone())?)
             }
 
-            _ => return Err(Message::unexpected_token(&next, &[])),
+            _ => return Ok(lhs),
         };
 
         Ok(ExpressionCtx::Binary(BinaryCtx {
@@ -292,7 +294,7 @@ impl Parser {
             exp if *exp == Lexeme::Eq => BinaryOpCtx::Eq(self.consume_token(exp.clone())?),
             exp if *exp == Lexeme::Neq => BinaryOpCtx::Ne(self.consume_token(exp.clone())?),
 
-            _ => return Err(Message::unexpected_token(&next, &[])),
+            _ => return Ok(lhs),
         };
 
         Ok(ExpressionCtx::Binary(BinaryCtx {
@@ -315,7 +317,7 @@ impl Parser {
             exp if *exp == Lexeme::Lte => BinaryOpCtx::Le(self.consume_token(exp.clone())?),
             exp if *exp == Lexeme::Gte => BinaryOpCtx::Ge(self.consume_token(exp.clone())?),
 
-            _ => return Err(Message::unexpected_token(&next, &[])),
+            _ => return Ok(lhs),
         };
 
         Ok(ExpressionCtx::Binary(BinaryCtx {
@@ -336,7 +338,7 @@ impl Parser {
             exp if *exp == Lexeme::LShift => BinaryOpCtx::Shl(self.consume_token(exp.clone())?),
             exp if *exp == Lexeme::RShift => BinaryOpCtx::Shr(self.consume_token(exp.clone())?),
 
-            _ => return Err(Message::unexpected_token(&next, &[])),
+            _ => return Ok(lhs),
         };
 
         Ok(ExpressionCtx::Binary(BinaryCtx {
@@ -357,7 +359,7 @@ impl Parser {
             exp if *exp == Lexeme::Plus => BinaryOpCtx::Add(self.consume_token(exp.clone())?),
             exp if *exp == Lexeme::Minus => BinaryOpCtx::Sub(self.consume_token(exp.clone())?),
 
-            _ => return Err(Message::unexpected_token(&next, &[])),
+            _ => return Ok(lhs),
         };
 
         Ok(ExpressionCtx::Binary(BinaryCtx {
@@ -379,7 +381,7 @@ impl Parser {
             exp if *exp == Lexeme::Slash => BinaryOpCtx::Div(self.consume_token(exp.clone())?),
             exp if *exp == Lexeme::Percent => BinaryOpCtx::Mod(self.consume_token(exp.clone())?),
 
-            _ => return Err(Message::unexpected_token(&next, &[])),
+            _ => return Ok(lhs),
         };
 
         Ok(ExpressionCtx::Binary(BinaryCtx {
@@ -390,38 +392,64 @@ impl Parser {
     }
 
     fn parse_dot_or_as(&mut self) -> ParseResult<ExpressionCtx> {
-        let lhs = self.parse_factor()?;
+        let mut lhs = self.parse_factor()?;
 
-        let Some(next) = self.peek_token() else {
-            return Ok(lhs);
-        };
+        // "a.b.c" means "(a.b).c"
+        while self.is_next(Lexeme::Dot) {
+            lhs = ExpressionCtx::MemberAccess(MemberAccessCtx {
+                expression_ctx: Box::new(lhs),
+                dot_tkn: self.consume_token(Lexeme::Dot)?,
+                id_tkn: self.consume_identifier()?,
+            });
+        }
 
-        match next.lexeme_ref() {
-            lexem if *lexem == Lexeme::KwAs => Ok(ExpressionCtx::Conversion(ConversionCtx {
+        if self.is_next(Lexeme::KwAs) {
+            return Ok(ExpressionCtx::Conversion(ConversionCtx {
                 expression_ctx: Box::new(lhs),
-                as_tkn: self.consume_token(lexem.clone())?,
+                as_tkn: self.consume_token(Lexeme::KwAs)?,
                 type_ctx: Box::new(self.parse_type_ctx()?),
-            })),
+            }));
+        }
 
-            lexem if *lexem == Lexeme::Dot => {
-                let binary_op_ctx = BinaryOpCtx::Access(self.consume_token(lexem.clone())?);
-                Ok(ExpressionCtx::Binary(BinaryCtx {
-                    left_ctx: Box::new(lhs),
-                    binary_op_ctx,
-                    right_ctx: Box::new(self.parse_expression_ctx()?),
-                }))
-            }
+        Ok(lhs)
+    }
+}
 
-            lexem if *lexem == Lexeme::EndOfLine => {
-                self.get_token();
-                Ok(lhs)
-            }
+#[cfg(test)]
+mod member_access_tests {
+    use tanitc_ast::program_ctx::statement_ctx::expression_ctx::{
+        binary_ctx::BinaryOpCtx, ExpressionCtx,
+    };
 
-            _ => Err(Message::unexpected_token(
-                &next,
-                &[Lexeme::Dot, Lexeme::KwAs],
-            )),
-        }
+    use crate::Parser;
+
+    #[test]
+    fn parse_member_access_assign_test() {
+        // Given
+        const SRC_TEXT: &str = "a.b.c = d.e";
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let expression_ctx = parser.parse_expression_ctx().unwrap();
+
+        // Then
+        assert!(!parser.messages_ref().has_errors());
+
+        let ExpressionCtx::Binary(binary_ctx) = expression_ctx else {
+            panic!("Expected binary, actually: {expression_ctx:?}");
+        };
+        assert!(matches!(binary_ctx.binary_op_ctx, BinaryOpCtx::Assign(_)));
+
+        let ExpressionCtx::MemberAccess(lhs) = binary_ctx.left_ctx.as_ref() else {
+            panic!(
+                "Expected member access, actually: {:?}",
+                binary_ctx.left_ctx
+            );
+        };
+        assert_eq!(lhs.id_tkn.identifier().to_string(), "c");
+        assert!(lhs.expression_ctx.is_member_access());
+
+        assert!(binary_ctx.right_ctx.is_member_access());
     }
 }
 
@@ -1,9 +1,34 @@
-use tanitc_ast::program_ctx::statement_ctx::definition_ctx::extern_ctx::ExternCtx;
+use tanitc_ast::program_ctx::statement_ctx::definition_ctx::{
+    extern_ctx::ExternCtx, func_def_ctx::FuncDefAbiCtx, DefinitionCtx,
+};
 use tanitc_lexer::token::lexeme::Lexeme;
 
 use crate::{ParseResult, Parser};
 
 impl Parser {
+    // Either block of foreign declarations or definition of exported function
+    pub fn parse_extern_definition_ctx(&mut self) -> ParseResult<DefinitionCtx> {
+        let extern_tkn = self.consume_token(Lexeme::KwExtern)?;
+        let abi_tkn = self.consume_text()?;
+
+        if self.is_next(Lexeme::KwFunc) {
+            let mut func_def_ctx = self.parse_func_def_ctx()?;
+            func_def_ctx.abi_ctx = Some(Box::new(FuncDefAbiCtx {
+                extern_tkn,
+                abi_tkn,
+            }));
+
+            return Ok(DefinitionCtx::Func(func_def_ctx));
+        }
+
+        Ok(DefinitionCtx::Extern(ExternCtx {
+            attributes_ctx: Box::default(),
+            extern_tkn,
+            abi_tkn,
+            body_ctx: Box::new(self.parse_block_ctx()?),
+        }))
+    }
+
     pub fn parse_extern_ctx(&mut self) -> ParseResult<ExternCtx> {
         Ok(ExternCtx {
             attributes_ctx: Box::default(),
@@ -75,4 +100,30 @@ mod tests {
         assert_eq!(*rparen_tkn.lexeme_ref(), Lexeme::RParen);
         assert!(units_ctx.is_empty());
     }
+
+    #[test]
+    fn parse_extern_func_def_test() {
+        const SRC_TEXT: &str = "extern \"C\" func add(a: i32, b: i32): i32 {\
+                              \n    return a + b\
+                              \n}";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+        let definition_ctx = parser.parse_extern_definition_ctx().unwrap();
+
+        let DefinitionCtx::Func(func_def_ctx) = definition_ctx else {
+            panic!("Expected function, actually: {}", definition_ctx.kind_str());
+        };
+
+        let Some(abi_ctx) = &func_def_ctx.abi_ctx else {
+            panic!("Expected ABI");
+        };
+        assert_eq!(*abi_ctx.extern_tkn.lexeme_ref(), Lexeme::KwExtern);
+        assert_eq!(
+            *abi_ctx.abi_tkn.lexeme_ref(),
+            Lexeme::Text("\"C\"".to_string())
+        );
+        assert_eq!(func_def_ctx.name_ctx.to_string(), "add");
+        assert_eq!(func_def_ctx.params_ctx.params_ctx.len(), 2);
+        assert!(func_def_ctx.body_ctx.is_some());
+    }
 }
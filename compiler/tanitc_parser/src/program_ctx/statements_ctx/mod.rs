@@ -1,5 +1,6 @@
 use tanitc_ast::program_ctx::statement_ctx::{
-    attributes_ctx::AttributesCtx, StatementCtx, StatementsCtx,
+    attributes_ctx::{AttributeCtx, AttributesCtx},
+    AttributedCtx, StatementCtx, StatementsCtx,
 };
 use tanitc_lexer::token::lexeme::Lexeme;
 use tanitc_messages::Message;
@@ -25,18 +26,23 @@ impl Parser {
             }
 
             let statement = if *next.lexeme_ref() != Lexeme::EndOfLine {
-                let attrs = match self.parse_attributes_ctx() {
-                    Ok(attrs) => attrs,
-                    Err(err) => {
-                        self.error(err);
-                        AttributesCtx::default()
-                    }
-                };
-
-                match self.parse_statement_ctx(attrs) {
+                let start_offset = self.get_current_token_index();
+                let old_opt = self.does_ignore_nl();
+
+                let statement = self
+                    .parse_attributes_ctx()
+                    .and_then(|attrs| self.parse_statement_ctx(attrs))
+                    .and_then(|statement| {
+                        self.check_statement_end()?;
+                        Ok(statement)
+                    });
+
+                match statement {
                     Ok(statement) => Some(statement),
                     Err(err) => {
                         self.error(err);
+                        self.set_ignore_nl_option(old_opt);
+                        self.synchronize(start_offset);
                         None
                     }
                 }
@@ -62,29 +68,20 @@ impl Parser {
                 StatementCtx::Block(ctx)
             }),
 
-            Lexeme::KwAlias
-            | Lexeme::KwEnum
-            | Lexeme::KwStruct
-            | Lexeme::KwUnion
-            | Lexeme::KwFunc
-            | Lexeme::KwVariant
-            | Lexeme::KwDef
-            | Lexeme::KwModule
-            | Lexeme::KwStatic
-            | Lexeme::KwConst
-            | Lexeme::KwVar
-            | Lexeme::KwExtern
-            | Lexeme::KwImpl => self.parse_definition_ctx().map(|mut ctx| {
-                ctx.set_attributes(attrs);
-                StatementCtx::Definition(ctx)
-            }),
+            lexeme if Self::is_definition_keyword(lexeme) => {
+                self.parse_definition_ctx().map(|mut ctx| {
+                    ctx.set_attributes(attrs);
+                    StatementCtx::Definition(ctx)
+                })
+            }
 
             Lexeme::KwReturn | Lexeme::KwBreak | Lexeme::KwContinue => {
                 self.check_default_attrs(&attrs).map_err(|mut err| {
                     err.text = format!("In {}: {}", next.lexeme_ref(), err.text);
                     err
                 })?;
-                self.parse_control_flow_ctx().map(StatementCtx::ControlFlow)
+                self.parse_control_flow_ctx()
+                    .map(|ctx| Self::attributed(attrs, StatementCtx::ControlFlow(ctx)))
             }
 
             Lexeme::KwLoop | Lexeme::KwWhile | Lexeme::KwIf | Lexeme::KwElse => {
@@ -92,16 +89,14 @@ impl Parser {
                     err.text = format!("In {}: {}", next.lexeme_ref(), err.text);
                     err
                 })?;
-                self.parse_branch_ctx().map(StatementCtx::Branch)
+                self.parse_branch_ctx()
+                    .map(|ctx| Self::attributed(attrs, StatementCtx::Branch(ctx)))
             }
 
-            Lexeme::KwUse => {
-                self.check_default_attrs(&attrs).map_err(|mut err| {
-                    err.text = format!("In use statement: {}", err.text);
-                    err
-                })?;
-                self.parse_use_ctx().map(StatementCtx::Use)
-            }
+            Lexeme::KwUse => self.parse_use_ctx().map(|mut ctx| {
+                ctx.attributes_ctx = Box::new(attrs);
+                StatementCtx::Use(ctx)
+            }),
 
             Lexeme::Identifier(_)
             | Lexeme::Integer(_)
@@ -116,37 +111,103 @@ impl Parser {
                     err.text = format!("In expression: {}", err.text);
                     err
                 })?;
-                self.parse_expression_ctx().map(StatementCtx::Expression)
+                self.parse_expression_ctx()
+                    .map(|ctx| Self::attributed(attrs, StatementCtx::Expression(ctx)))
             }
 
-            _ => {
-                self.skip_until(&[Lexeme::EndOfLine]);
-                self.get_token();
-
-                return Err(Message::unexpected_token(&next, &[]));
-            }
+            _ => Err(Message::unexpected_token(&next, &[])),
         };
 
         item
     }
 
+    // Statement must end with the line, so tokens like "i32" in "var x i32 = 1" are rejected
+    fn check_statement_end(&mut self) -> Result<(), Message> {
+        // Some statements consume the new line themselves
+        let is_line_ended = self
+            .offset
+            .checked_sub(1)
+            .and_then(|offset| self.tokens.get(offset))
+            .is_some_and(|tkn| *tkn.lexeme_ref() == Lexeme::EndOfLine);
+
+        if is_line_ended {
+            return Ok(());
+        }
+
+        let old_opt = self.does_ignore_nl();
+        self.set_ignore_nl_option(false);
+
+        let next = self.peek_token();
+
+        self.set_ignore_nl_option(old_opt);
+
+        match next {
+            Some(next)
+                if !matches!(
+                    next.lexeme_ref(),
+                    Lexeme::EndOfLine | Lexeme::Rcb | Lexeme::KwElse
+                ) =>
+            {
+                Err(Message::unexpected_token(&next, &[Lexeme::EndOfLine]))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // Statements without attributes of their own still can be conditionally compiled
+    fn attributed(attrs: AttributesCtx, statement_ctx: StatementCtx) -> StatementCtx {
+        if attrs.attributes.is_empty() {
+            return statement_ctx;
+        }
+
+        StatementCtx::Attributed(AttributedCtx {
+            attributes_ctx: Box::new(attrs),
+            statement_ctx: Box::new(statement_ctx),
+        })
+    }
+
+    fn is_cfg_attribute(attribute: &AttributeCtx) -> bool {
+        attribute
+            .meta_ctx
+            .name_ctx()
+            .is_some_and(|name_ctx| name_ctx.to_string() == "cfg")
+    }
+
     fn check_default_attrs(&self, attrs: &AttributesCtx) -> Result<(), Message> {
+        let non_cfg = attrs
+            .attributes
+            .iter()
+            .find(|attribute| !Self::is_cfg_attribute(attribute));
+
+        if let Some(attribute) = non_cfg {
+            let name = attribute
+                .meta_ctx
+                .name_ctx()
+                .map(|name_ctx| name_ctx.to_string())
+                .unwrap_or_default();
+
+            return Err(Message::misplaced_attribute(
+                attribute.hash_lsb_tkn.get_location(),
+                format!("Unexpected attribute \"#[{name}]\"."),
+            ));
+        }
+
         if let Some(pub_tkn) = &attrs.pub_tkn {
-            return Err(Message::new(
+            return Err(Message::misplaced_attribute(
                 pub_tkn.get_location(),
                 "Unexpected attribute \"pub\".",
             ));
         }
 
         if let Some(safe_tkn) = &attrs.safe_tkn {
-            return Err(Message::new(
+            return Err(Message::misplaced_attribute(
                 safe_tkn.get_location(),
                 "Unexpected attribute \"safe\".",
             ));
         }
 
         if let Some(unsafe_tkn) = &attrs.unsafe_tkn {
-            return Err(Message::new(
+            return Err(Message::misplaced_attribute(
                 unsafe_tkn.get_location(),
                 "Unexpected attribute \"unsafe\".",
             ));
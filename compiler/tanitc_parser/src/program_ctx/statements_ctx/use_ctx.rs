@@ -1,16 +1,139 @@
-use tanitc_ast::program_ctx::statement_ctx::use_ctx::UseCtx;
-use tanitc_lexer::token::lexeme::Lexeme;
+use tanitc_ast::program_ctx::{
+    name_ctx::{NameCtx, NameSpecCtx, NameSpecSegmentCtx},
+    statement_ctx::use_ctx::{UseAliasCtx, UseCtx, UseGroupCtx, UseTailCtx, UseTreeCtx},
+};
+use tanitc_lexer::token::{lexeme::Lexeme, Token};
+use tanitc_messages::{codes::ErrorCode, Message};
 
 use crate::{ParseResult, Parser};
 
 impl Parser {
     pub(crate) fn parse_use_ctx(&mut self) -> ParseResult<UseCtx> {
+        let old = self.does_ignore_nl();
+        self.set_ignore_nl_option(false);
+
+        let use_ctx = self.parse_use_ctx_internal();
+
+        self.set_ignore_nl_option(old);
+
+        use_ctx
+    }
+
+    fn parse_use_ctx_internal(&mut self) -> ParseResult<UseCtx> {
         let use_tkn = self.consume_token(Lexeme::KwUse)?;
-        let name_spec_ctx = self.parse_name_spec_ctx()?;
+        let tree_ctx = self.parse_use_tree_ctx(false)?;
+
+        if let Some(next) = self.peek_token() {
+            if !matches!(next.lexeme_ref(), Lexeme::EndOfLine | Lexeme::Rcb) {
+                return Err(Self::unexpected_use_token(&next, "new line"));
+            }
+        }
 
         Ok(UseCtx {
+            attributes_ctx: Box::default(),
             use_tkn,
-            name_spec_ctx,
+            tree_ctx,
+        })
+    }
+
+    fn parse_use_tree_ctx(&mut self, is_nested: bool) -> ParseResult<UseTreeCtx> {
+        let mut names = Vec::<NameSpecSegmentCtx>::new();
+        let mut tail_ctx = None;
+
+        loop {
+            let next = self.peek_token().ok_or(Message::reached_eof())?;
+
+            match next.lexeme_ref() {
+                Lexeme::Identifier(_) | Lexeme::KwSuper | Lexeme::KwSelf | Lexeme::KwCrate => {
+                    self.get_token();
+
+                    if self.is_next(Lexeme::Dcolon) {
+                        names.push((next, self.get_token()));
+                    } else {
+                        names.push((next, None));
+                        break;
+                    }
+                }
+
+                Lexeme::Star if is_nested || !names.is_empty() => {
+                    self.get_token();
+                    tail_ctx = Some(UseTailCtx::Glob(next));
+                    break;
+                }
+
+                Lexeme::Lcb => {
+                    tail_ctx = Some(UseTailCtx::Group(self.parse_use_group_ctx()?));
+                    break;
+                }
+
+                _ => return Err(Self::unexpected_use_token(&next, "ID, \"*\" or \"{\"")),
+            }
+        }
+
+        if tail_ctx.is_none() && self.is_next(Lexeme::KwAs) {
+            let as_tkn = self.consume_token(Lexeme::KwAs)?;
+            let name_ctx = NameCtx {
+                name_tkn: self.consume_identifier()?,
+            };
+
+            tail_ctx = Some(UseTailCtx::Alias(UseAliasCtx { as_tkn, name_ctx }));
+        }
+
+        Ok(UseTreeCtx {
+            name_spec_ctx: NameSpecCtx { names },
+            tail_ctx,
+        })
+    }
+
+    fn unexpected_use_token(tkn: &Token, expected: &str) -> Message {
+        Message::new(
+            tkn.get_location(),
+            format!(
+                "Unexpected token in use declaration: \"{}\", expected {expected}",
+                tkn.lexeme_ref()
+            ),
+        )
+        .with_code(ErrorCode::UnexpectedToken)
+    }
+
+    fn parse_use_group_ctx(&mut self) -> ParseResult<UseGroupCtx> {
+        let old = self.does_ignore_nl();
+        self.set_ignore_nl_option(true);
+
+        let group = self.parse_use_group_items();
+
+        self.set_ignore_nl_option(old);
+
+        group
+    }
+
+    fn parse_use_group_items(&mut self) -> ParseResult<UseGroupCtx> {
+        let lcb_tkn = self.consume_token(Lexeme::Lcb)?;
+        let mut trees = Vec::new();
+
+        while !self.is_next(Lexeme::Rcb) {
+            let tree_ctx = self.parse_use_tree_ctx(true)?;
+
+            let comma_tkn = if self.is_next(Lexeme::Comma) {
+                self.get_token()
+            } else {
+                None
+            };
+
+            let is_last = comma_tkn.is_none();
+            trees.push((tree_ctx, comma_tkn));
+
+            if is_last {
+                break;
+            }
+        }
+
+        let rcb_tkn = self.consume_token(Lexeme::Rcb)?;
+
+        Ok(UseGroupCtx {
+            lcb_tkn,
+            trees,
+            rcb_tkn,
         })
     }
 }
@@ -27,7 +150,7 @@ mod tests {
 
         // When
         let use_ctx = parser.parse_use_ctx().unwrap();
-        let ids = &use_ctx.name_spec_ctx.names;
+        let ids = &use_ctx.tree_ctx.name_spec_ctx.names;
 
         // Then
         assert_eq!(*use_ctx.use_tkn.lexeme_ref(), Lexeme::KwUse);
@@ -42,4 +165,86 @@ mod tests {
         assert_ne!(ids[1].1, None);
         assert_eq!(ids[2].1, None);
     }
+
+    #[test]
+    fn good_parse_use_glob_and_alias_test() {
+        // Given
+        const SRC_TEXT: &str = "use super::math::*\nuse crate::math::Vector2 as Vec2\n";
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let glob_ctx = parser.parse_use_ctx().unwrap();
+        parser.consume_new_line().unwrap();
+        let alias_ctx = parser.parse_use_ctx().unwrap();
+
+        // Then
+        let ids = &glob_ctx.tree_ctx.name_spec_ctx.names;
+        assert_eq!(ids.len(), 2);
+        assert_eq!(*ids[0].0.lexeme_ref(), Lexeme::KwSuper);
+        assert_eq!(ids[1].0.identifier().to_string(), "math");
+        assert!(matches!(
+            glob_ctx.tree_ctx.tail_ctx,
+            Some(UseTailCtx::Glob(_))
+        ));
+
+        let ids = &alias_ctx.tree_ctx.name_spec_ctx.names;
+        assert_eq!(ids.len(), 3);
+        assert_eq!(*ids[0].0.lexeme_ref(), Lexeme::KwCrate);
+        let Some(UseTailCtx::Alias(alias)) = &alias_ctx.tree_ctx.tail_ctx else {
+            panic!(
+                "Expected alias, actually: {:?}",
+                alias_ctx.tree_ctx.tail_ctx
+            );
+        };
+        assert_eq!(alias.name_ctx.identifier().to_string(), "Vec2");
+    }
+
+    #[test]
+    fn good_parse_use_group_test() {
+        // Given
+        const SRC_TEXT: &str = "use math::{\n    sin,\n    vectors::{Vector2 as V2, *},\n}\n";
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let use_ctx = parser.parse_use_ctx().unwrap();
+
+        // Then
+        assert!(parser.consume_new_line().is_ok());
+
+        let Some(UseTailCtx::Group(group)) = &use_ctx.tree_ctx.tail_ctx else {
+            panic!("Expected group, actually: {:?}", use_ctx.tree_ctx.tail_ctx);
+        };
+        assert_eq!(group.trees.len(), 2);
+        assert!(group.trees[0].0.tail_ctx.is_none());
+
+        let Some(UseTailCtx::Group(inner)) = &group.trees[1].0.tail_ctx else {
+            panic!("Expected group, actually: {:?}", group.trees[1].0.tail_ctx);
+        };
+        assert_eq!(inner.trees.len(), 2);
+        assert!(matches!(
+            inner.trees[0].0.tail_ctx,
+            Some(UseTailCtx::Alias(_))
+        ));
+        assert!(matches!(
+            inner.trees[1].0.tail_ctx,
+            Some(UseTailCtx::Glob(_))
+        ));
+    }
+
+    #[test]
+    fn bad_parse_use_test() {
+        // Given
+        const SRC_TEXT: &str = "use math::sin cos\n";
+        let mut parser = Parser::from_text(SRC_TEXT);
+
+        // When
+        let res = parser.parse_use_ctx();
+
+        // Then
+        let err = res.expect_err("Expected error");
+        assert_eq!(
+            err.text,
+            "Unexpected token in use declaration: \"cos\", expected new line"
+        );
+    }
 }
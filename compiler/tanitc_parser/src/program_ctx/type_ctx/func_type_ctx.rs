@@ -1,5 +1,8 @@
-use tanitc_ast::program_ctx::type_ctx::func_type_ctx::{
-    FuncTypeCtx, FuncTypeParamCtx, FuncTypeParamsCtx, FuncTypeReturnTypeCtx,
+use tanitc_ast::program_ctx::{
+    statement_ctx::definition_ctx::func_def_ctx::FuncDefAbiCtx,
+    type_ctx::func_type_ctx::{
+        FuncTypeCtx, FuncTypeParamCtx, FuncTypeParamsCtx, FuncTypeReturnTypeCtx,
+    },
 };
 use tanitc_lexer::token::lexeme::Lexeme;
 
@@ -8,12 +11,24 @@ use crate::{ParseResult, Parser};
 impl Parser {
     pub(crate) fn parse_func_type_ctx(&mut self) -> ParseResult<FuncTypeCtx> {
         Ok(FuncTypeCtx {
+            abi_ctx: self.parse_func_type_abi_ctx()?,
             func_tkn: self.consume_token(Lexeme::KwFunc)?,
             params_ctx: self.parse_func_type_params_ctx()?,
             return_type: self.parse_func_type_return_type_ctx()?,
         })
     }
 
+    fn parse_func_type_abi_ctx(&mut self) -> ParseResult<Option<Box<FuncDefAbiCtx>>> {
+        if !self.is_next(Lexeme::KwExtern) {
+            return Ok(None);
+        }
+
+        Ok(Some(Box::new(FuncDefAbiCtx {
+            extern_tkn: self.consume_token(Lexeme::KwExtern)?,
+            abi_tkn: self.consume_text()?,
+        })))
+    }
+
     pub(crate) fn parse_func_type_return_type_ctx(
         &mut self,
     ) -> ParseResult<Option<FuncTypeReturnTypeCtx>> {
@@ -38,6 +53,7 @@ impl Parser {
         Ok(FuncTypeParamsCtx {
             lparen_tkn: self.consume_token(Lexeme::LParen)?,
             parameters: self.parse_func_type_params()?,
+            ellipsis_tkn: self.consume_token(Lexeme::Ellipsis).ok(),
             rparen_tkn: self.consume_token(Lexeme::RParen)?,
         })
     }
@@ -46,7 +62,7 @@ impl Parser {
         let mut params = Vec::<FuncTypeParamCtx>::new();
 
         loop {
-            if self.is_next(Lexeme::RParen) {
+            if self.is_next(Lexeme::RParen) || self.is_next(Lexeme::Ellipsis) {
                 break;
             }
 
@@ -219,4 +235,32 @@ mod tests {
         assert_eq!(name_ctx.to_string(), "i32");
         assert!(generic_ctx.is_none());
     }
+
+    #[test]
+    fn parse_extern_func_type_test() {
+        const SRC_TEXT: &str = "extern \"C\" func(*const u8, ...): i32";
+
+        let mut parser = Parser::from_text(SRC_TEXT);
+        let type_ctx = parser.parse_type_ctx().unwrap();
+
+        let TypeCtx::Func(func_type_ctx) = &type_ctx else {
+            panic!("Unexpected {}", type_ctx.kind_str());
+        };
+
+        let Some(abi_ctx) = &func_type_ctx.abi_ctx else {
+            panic!("Expected ABI");
+        };
+        assert_eq!(*abi_ctx.extern_tkn.lexeme_ref(), Lexeme::KwExtern);
+        assert_eq!(
+            *abi_ctx.abi_tkn.lexeme_ref(),
+            Lexeme::Text("\"C\"".to_string())
+        );
+
+        assert_eq!(func_type_ctx.params_ctx.parameters.len(), 1);
+        let Some(ellipsis_tkn) = &func_type_ctx.params_ctx.ellipsis_tkn else {
+            panic!("Expected \"...\"");
+        };
+        assert_eq!(*ellipsis_tkn.lexeme_ref(), Lexeme::Ellipsis);
+        assert!(func_type_ctx.return_type.is_some());
+    }
 }
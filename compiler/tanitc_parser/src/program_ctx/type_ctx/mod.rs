@@ -18,7 +18,7 @@ impl Parser {
 
         match next.lexeme_ref() {
             Lexeme::Identifier(_) => self.parse_named_type_ctx().map(TypeCtx::Named),
-            Lexeme::KwFunc => self.parse_func_type_ctx().map(TypeCtx::Func),
+            Lexeme::KwFunc | Lexeme::KwExtern => self.parse_func_type_ctx().map(TypeCtx::Func),
             Lexeme::Ampersand => self.parse_ref_type_ctx().map(TypeCtx::Ref),
             Lexeme::Star => self.parse_ptr_type_ctx().map(TypeCtx::Ptr),
             Lexeme::LParen => self.parse_tuple_type_ctx().map(TypeCtx::Tuple),
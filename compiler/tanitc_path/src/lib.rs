@@ -1,5 +1,11 @@
 use lazy_static::lazy_static;
-use std::{fmt::Display, path::PathBuf, sync::Mutex};
+use serde::{Serialize, Serializer};
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
 
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct PathId(usize);
@@ -39,6 +45,29 @@ impl PathId {
             "".into()
         }
     }
+
+    // Remembers text of the file, so diagnostics don't have to read it again
+    pub fn set_source(&self, source: &str) {
+        let mut sources = SOURCES.lock().unwrap();
+        sources.insert(*self, Arc::from(source));
+    }
+
+    // Returns text of the file, files without remembered text are read from disk
+    pub fn get_source(&self) -> Option<Arc<str>> {
+        if let Some(source) = SOURCES.lock().unwrap().get(self) {
+            return Some(source.clone());
+        }
+
+        let source: Arc<str> = Arc::from(std::fs::read_to_string(self.as_path_buf()).ok()?);
+        self.set_source(&source);
+
+        Some(source)
+    }
+
+    // Returns line of the file by zero-based row
+    pub fn get_source_line(&self, row: usize) -> Option<String> {
+        self.get_source()?.lines().nth(row).map(ToString::to_string)
+    }
 }
 
 impl Display for PathId {
@@ -47,8 +76,16 @@ impl Display for PathId {
     }
 }
 
+// Paths are interned, so they are written as their text
+impl Serialize for PathId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
 lazy_static! {
     static ref PATHS: Mutex<Vec<PathBuf>> = Mutex::new(vec!["TestLocation".into()]);
+    static ref SOURCES: Mutex<HashMap<PathId, Arc<str>>> = Mutex::new(HashMap::new());
 }
 
 #[test]
@@ -65,4 +102,11 @@ fn path_test() {
     assert_eq!(first.to_string(), "foo");
     assert_eq!(second.to_string(), "bar");
     assert_eq!(third.to_string(), "baz");
+
+    // Paths are registered in the same test, since their indices are global
+    let path = PathId::from(PathBuf::from("source_test.tt"));
+    path.set_source("func main() {\n    var a = 1\n}");
+
+    assert_eq!(path.get_source_line(1).as_deref(), Some("    var a = 1"));
+    assert_eq!(path.get_source_line(3), None);
 }